@@ -0,0 +1,147 @@
+use std::fmt::Debug;
+
+use anyhow::{anyhow, bail};
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::{
+    aead::{rand_core::RngCore, Aead, AeadCore, KeyInit, OsRng},
+    XChaCha20Poly1305, XNonce,
+};
+
+/// Marker at the start of encrypted files to tell them apart from plain JSON files.
+const MAGIC_HEADER: &[u8] = b"TJENC2";
+const NONCE_LEN: usize = 24;
+/// Length of the generated salts in bytes.
+pub const SALT_LEN: usize = 16;
+/// Length of the key derivation parameters in the header: The salt followed by the memory cost,
+/// the iterations and the parallelism of Argon2 as little-endian `u32` values.
+const KDF_PARAMS_LEN: usize = SALT_LEN + 3 * 4;
+
+/// Parameters to derive the key from the passphrase with. They are stored in the header of the
+/// encrypted file, so the file can be decrypted with the passphrase alone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KdfParams {
+    salt: [u8; SALT_LEN],
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+}
+
+impl KdfParams {
+    /// Generates parameters with a random salt and the default costs of Argon2.
+    pub fn generate() -> Self {
+        let mut salt = [0; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+
+        Self {
+            salt,
+            m_cost: Params::DEFAULT_M_COST,
+            t_cost: Params::DEFAULT_T_COST,
+            p_cost: Params::DEFAULT_P_COST,
+        }
+    }
+
+    /// Reads the parameters from the header of the given encrypted file content.
+    pub fn from_encrypted(data: &[u8]) -> anyhow::Result<Self> {
+        if !is_encrypted(data) {
+            bail!("Entries file isn't encrypted");
+        }
+
+        let Some(header) = data[MAGIC_HEADER.len()..].get(..KDF_PARAMS_LEN) else {
+            bail!("Encrypted entries file is corrupted");
+        };
+
+        let (salt, costs) = header.split_at(SALT_LEN);
+        let read_cost = |idx: usize| {
+            let bytes = costs[idx * 4..idx * 4 + 4]
+                .try_into()
+                .expect("cost has four bytes");
+            u32::from_le_bytes(bytes)
+        };
+
+        Ok(Self {
+            salt: salt.try_into().expect("salt has the checked length"),
+            m_cost: read_cost(0),
+            t_cost: read_cost(1),
+            p_cost: read_cost(2),
+        })
+    }
+
+    fn write_to(&self, output: &mut Vec<u8>) {
+        output.extend_from_slice(&self.salt);
+        output.extend_from_slice(&self.m_cost.to_le_bytes());
+        output.extend_from_slice(&self.t_cost.to_le_bytes());
+        output.extend_from_slice(&self.p_cost.to_le_bytes());
+    }
+}
+
+/// Key derived from a passphrase to encrypt and decrypt the entries file.
+#[derive(Clone)]
+pub struct EncryptionKey {
+    key: [u8; 32],
+    params: KdfParams,
+}
+
+impl Debug for EncryptionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("EncryptionKey(..)")
+    }
+}
+
+impl EncryptionKey {
+    /// Derives the key from the given passphrase using Argon2 with the given parameters.
+    pub fn derive(passphrase: &str, params: KdfParams) -> anyhow::Result<Self> {
+        let argon2_params = Params::new(params.m_cost, params.t_cost, params.p_cost, Some(32))
+            .map_err(|err| anyhow!("Invalid key derivation parameters. Error info: {err}"))?;
+
+        let mut key = [0; 32];
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, argon2_params)
+            .hash_password_into(passphrase.as_bytes(), &params.salt, &mut key)
+            .map_err(|err| anyhow!("Deriving encryption key failed. Error info: {err}"))?;
+
+        Ok(Self { key, params })
+    }
+
+    /// Encrypts the given data with a random nonce. The key derivation parameters and the nonce
+    /// are stored after the header.
+    pub(crate) fn encrypt(&self, data: &[u8]) -> anyhow::Result<Vec<u8>> {
+        let cipher = XChaCha20Poly1305::new(&self.key.into());
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let encrypted = cipher
+            .encrypt(&nonce, data)
+            .map_err(|_| anyhow!("Encrypting entries failed"))?;
+
+        let mut output =
+            Vec::with_capacity(MAGIC_HEADER.len() + KDF_PARAMS_LEN + NONCE_LEN + encrypted.len());
+        output.extend_from_slice(MAGIC_HEADER);
+        self.params.write_to(&mut output);
+        output.extend_from_slice(&nonce);
+        output.extend_from_slice(&encrypted);
+
+        Ok(output)
+    }
+
+    /// Decrypts the given encrypted file content, failing with a clear error if the key doesn't
+    /// match the one used for encrypting.
+    pub(crate) fn decrypt(&self, data: &[u8]) -> anyhow::Result<Vec<u8>> {
+        if !is_encrypted(data) {
+            bail!("Entries file isn't encrypted");
+        }
+
+        let data = &data[MAGIC_HEADER.len()..];
+        if data.len() < KDF_PARAMS_LEN + NONCE_LEN {
+            bail!("Encrypted entries file is corrupted");
+        }
+
+        let (nonce, encrypted) = data[KDF_PARAMS_LEN..].split_at(NONCE_LEN);
+        let cipher = XChaCha20Poly1305::new(&self.key.into());
+
+        cipher
+            .decrypt(XNonce::from_slice(nonce), encrypted)
+            .map_err(|_| anyhow!("Wrong passphrase or corrupted encrypted entries file"))
+    }
+}
+
+/// Checks if the given file content is encrypted.
+pub(crate) fn is_encrypted(data: &[u8]) -> bool {
+    data.starts_with(MAGIC_HEADER)
+}
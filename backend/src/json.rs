@@ -1,16 +1,43 @@
 use std::path::PathBuf;
 
 use anyhow::{anyhow, Context};
+use tokio::io::AsyncWriteExt;
 
 use super::*;
 
+/// Suffix of the temporary file the entries are written to before replacing the entries file.
+const TEMP_FILE_SUFFIX: &str = ".tmp";
+/// Suffix of the backup file containing the entries before the last write.
+const BACKUP_FILE_SUFFIX: &str = ".bak";
+
 pub struct JsonDataProvide {
     file_path: PathBuf,
+    /// Key to encrypt the entries file with. The file is plain JSON if it's not set.
+    #[cfg(feature = "encrypted_json")]
+    key: Option<EncryptionKey>,
 }
 
 impl JsonDataProvide {
     pub fn new(file_path: PathBuf) -> Self {
-        Self { file_path }
+        Self {
+            file_path,
+            #[cfg(feature = "encrypted_json")]
+            key: None,
+        }
+    }
+
+    /// Creates a provider encrypting the entries file with the given key, ensuring the key can
+    /// decrypt the existing entries file.
+    #[cfg(feature = "encrypted_json")]
+    pub async fn new_encrypted(file_path: PathBuf, key: EncryptionKey) -> anyhow::Result<Self> {
+        let provider = Self {
+            file_path,
+            key: Some(key),
+        };
+
+        provider.load_all_entries().await?;
+
+        Ok(provider)
     }
 }
 
@@ -20,12 +47,13 @@ impl DataProvider for JsonDataProvide {
             return Ok(Vec::new());
         }
 
-        let json_content = tokio::fs::read_to_string(&self.file_path).await?;
-        if json_content.is_empty() {
+        let content = tokio::fs::read(&self.file_path).await?;
+        if content.is_empty() {
             return Ok(Vec::new());
         }
-        let entries =
-            serde_json::from_str(&json_content).context("Error while parsing entries json data")?;
+        let json_content = self.decode_content(content)?;
+        let entries = serde_json::from_slice(&json_content)
+            .context("Error while parsing entries json data")?;
 
         Ok(entries)
     }
@@ -41,7 +69,20 @@ impl DataProvider for JsonDataProvide {
 
         entries.sort_by_key(|e| e.id);
 
-        let id: u32 = entries.last().map(|entry| entry.id + 1).unwrap_or(0);
+        let id: u32 = match entry.id {
+            Some(id) if entries.iter().any(|entry| entry.id == id) => {
+                return Err(ModifyEntryError::ValidationError(format!(
+                    "Entry id {id} is already taken"
+                )));
+            }
+            Some(id) => id,
+            None => match entries.last() {
+                Some(entry) => entry.id.checked_add(1).ok_or_else(|| {
+                    ModifyEntryError::ValidationError(String::from("No entry ids are left"))
+                })?,
+                None => 0,
+            },
+        };
 
         let new_entry = Entry::from_draft(id, entry);
 
@@ -119,15 +160,74 @@ impl DataProvider for JsonDataProvide {
 }
 
 impl JsonDataProvide {
+    /// Writes the entries into a temporary file in the same directory first, then renames it over
+    /// the entries file, so an interrupted write can't leave a truncated entries file behind.
+    /// The previous entries file is kept as a backup.
     async fn write_entries_to_file(&self, entries: &Vec<Entry>) -> anyhow::Result<()> {
-        let entries_text = serde_json::to_vec(&entries)?;
+        let entries_text = self.encode_content(serde_json::to_vec(&entries)?)?;
         if !self.file_path.exists() {
             if let Some(parent) = self.file_path.parent() {
                 tokio::fs::create_dir_all(parent).await?;
             }
         }
-        tokio::fs::write(&self.file_path, entries_text).await?;
+
+        let temp_path = self.path_with_suffix(TEMP_FILE_SUFFIX);
+        let mut temp_file = tokio::fs::File::create(&temp_path).await?;
+        temp_file.write_all(&entries_text).await?;
+        temp_file.sync_all().await?;
+        drop(temp_file);
+
+        if self.file_path.exists() {
+            tokio::fs::copy(&self.file_path, self.path_with_suffix(BACKUP_FILE_SUFFIX))
+                .await
+                .context("Error while creating backup of entries file")?;
+        }
+
+        tokio::fs::rename(&temp_path, &self.file_path).await?;
 
         Ok(())
     }
+
+    /// Decrypts the content of the entries file if encryption is used.
+    #[cfg(feature = "encrypted_json")]
+    fn decode_content(&self, content: Vec<u8>) -> anyhow::Result<Vec<u8>> {
+        match &self.key {
+            Some(key) => key.decrypt(&content),
+            None if encryption::is_encrypted(&content) => Err(anyhow!(
+                "Entries file is encrypted. Please use the encrypted JSON back-end to open it"
+            )),
+            None => Ok(content),
+        }
+    }
+
+    #[cfg(not(feature = "encrypted_json"))]
+    fn decode_content(&self, content: Vec<u8>) -> anyhow::Result<Vec<u8>> {
+        Ok(content)
+    }
+
+    /// Encrypts the content of the entries file if encryption is used.
+    #[cfg(feature = "encrypted_json")]
+    fn encode_content(&self, content: Vec<u8>) -> anyhow::Result<Vec<u8>> {
+        match &self.key {
+            Some(key) => key.encrypt(&content),
+            None => Ok(content),
+        }
+    }
+
+    #[cfg(not(feature = "encrypted_json"))]
+    fn encode_content(&self, content: Vec<u8>) -> anyhow::Result<Vec<u8>> {
+        Ok(content)
+    }
+
+    /// Returns the path of a file next to the entries file with the suffix appended to its name.
+    fn path_with_suffix(&self, suffix: &str) -> PathBuf {
+        let mut file_name = self
+            .file_path
+            .file_name()
+            .unwrap_or_default()
+            .to_os_string();
+        file_name.push(suffix);
+
+        self.file_path.with_file_name(file_name)
+    }
 }
@@ -1,3 +1,5 @@
+use std::collections::{BTreeMap, HashSet};
+
 use chrono::{DateTime, Utc};
 
 use serde::{Deserialize, Serialize};
@@ -7,6 +9,14 @@ mod json;
 #[cfg(feature = "json")]
 pub use json::JsonDataProvide;
 
+#[cfg(feature = "encrypted_json")]
+mod encryption;
+#[cfg(feature = "encrypted_json")]
+pub use encryption::{EncryptionKey, KdfParams};
+
+mod schema;
+pub use schema::entries_dto_schema;
+
 #[cfg(feature = "sqlite")]
 mod sqlite;
 #[cfg(feature = "sqlite")]
@@ -26,21 +36,51 @@ pub enum ModifyEntryError {
 #[allow(async_fn_in_trait)]
 pub trait DataProvider {
     async fn load_all_entries(&self) -> anyhow::Result<Vec<Entry>>;
+    /// Adds the given entry, using its explicit id if set. Setting an id which is already taken
+    /// is an error, therefore it's meant for importing entries only.
     async fn add_entry(&self, entry: EntryDraft) -> Result<Entry, ModifyEntryError>;
     async fn remove_entry(&self, entry_id: u32) -> anyhow::Result<()>;
     async fn update_entry(&self, entry: Entry) -> Result<Entry, ModifyEntryError>;
     async fn get_export_object(&self, entries_ids: &[u32]) -> anyhow::Result<EntriesDTO>;
-    async fn import_entries(&self, entries_dto: EntriesDTO) -> anyhow::Result<()> {
+    /// Imports the given entries keeping their original ids if they aren't taken by existing or
+    /// previously imported entries. Entries with taken ids get new ones instead.
+    /// Returns the ids of the imported entries mapped from their original ids.
+    async fn import_entries(&self, entries_dto: EntriesDTO) -> anyhow::Result<BTreeMap<u32, u32>> {
         debug_assert_eq!(
             TRANSFER_DATA_VERSION, entries_dto.version,
             "Version mismatches check if there is a need to do a converting to the data"
         );
 
-        for entry_draft in entries_dto.entries {
-            self.add_entry(entry_draft).await?;
+        let mut taken_ids: HashSet<u32> = self
+            .load_all_entries()
+            .await?
+            .into_iter()
+            .map(|entry| entry.id)
+            .collect();
+
+        // Entries keeping their ids are added first, so the ids assigned to the other entries
+        // can't collide with them.
+        let (kept, others): (Vec<_>, Vec<_>) = entries_dto
+            .entries
+            .into_iter()
+            .partition(|draft| draft.id.is_some_and(|id| taken_ids.insert(id)));
+
+        let mut ids_map = BTreeMap::new();
+
+        for entry_draft in kept {
+            let entry = self.add_entry(entry_draft).await?;
+            ids_map.insert(entry.id, entry.id);
+        }
+
+        for mut entry_draft in others {
+            let original_id = entry_draft.id.take();
+            let entry = self.add_entry(entry_draft).await?;
+            if let Some(original_id) = original_id {
+                ids_map.entry(original_id).or_insert(entry.id);
+            }
         }
 
-        Ok(())
+        Ok(ids_map)
     }
     /// Assigns priority to all entries that don't have a priority assigned to
     async fn assign_priority_to_entries(&self, priority: u32) -> anyhow::Result<()>;
@@ -56,6 +96,16 @@ pub struct Entry {
     pub tags: Vec<String>,
     #[serde(default)]
     pub priority: Option<u32>,
+    /// The time of the last modification on the entry. Entries which haven't been modified since
+    /// their creation don't have a value, falling back to their creation date.
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Utc>>,
+    /// The time when the entry has been pinned. Entries which aren't pinned don't have a value.
+    #[serde(default)]
+    pub pinned_at: Option<DateTime<Utc>>,
+    /// Color of the entry title overriding the default styling, as a color name or hex value.
+    #[serde(default)]
+    pub color: Option<String>,
 }
 
 impl Entry {
@@ -75,6 +125,9 @@ impl Entry {
             content,
             tags,
             priority,
+            updated_at: None,
+            pinned_at: None,
+            color: None,
         }
     }
 
@@ -86,17 +139,62 @@ impl Entry {
             content: draft.content,
             tags: draft.tags,
             priority: draft.priority,
+            updated_at: draft.updated_at,
+            pinned_at: draft.pinned_at,
+            color: draft.color,
         }
     }
+
+    /// Returns the time of the last modification, which is the creation date for entries that
+    /// haven't been modified.
+    pub fn last_modified(&self) -> DateTime<Utc> {
+        self.updated_at.unwrap_or(self.date)
+    }
+
+    pub fn is_pinned(&self) -> bool {
+        self.pinned_at.is_some()
+    }
+
+    /// Returns the count of the words in the content.
+    pub fn word_count(&self) -> usize {
+        count_words(&self.content)
+    }
+
+    /// Returns the count of the characters in the content, counting multi-byte characters once.
+    pub fn char_count(&self) -> usize {
+        count_chars(&self.content)
+    }
+}
+
+/// Counts the words in the given text, which are separated by whitespace.
+pub fn count_words(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+/// Counts the characters in the given text as Unicode scalar values instead of bytes.
+pub fn count_chars(text: &str) -> usize {
+    text.chars().count()
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct EntryDraft {
+    /// Explicit id of the entry, used to keep the original ids of imported entries.
+    #[serde(default)]
+    pub id: Option<u32>,
     pub date: DateTime<Utc>,
     pub title: String,
+    #[serde(default)]
     pub content: String,
+    #[serde(default)]
     pub tags: Vec<String>,
+    #[serde(default)]
     pub priority: Option<u32>,
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub pinned_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub color: Option<String>,
 }
 
 impl EntryDraft {
@@ -108,11 +206,15 @@ impl EntryDraft {
     ) -> Self {
         let content = String::new();
         Self {
+            id: None,
             date,
             title,
             content,
             tags,
             priority,
+            updated_at: None,
+            pinned_at: None,
+            color: None,
         }
     }
 
@@ -122,13 +224,24 @@ impl EntryDraft {
         self
     }
 
+    #[must_use]
+    pub fn with_id(mut self, id: u32) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Creates a draft from the given entry keeping its id.
     pub fn from_entry(entry: Entry) -> Self {
         Self {
+            id: Some(entry.id),
             date: entry.date,
             title: entry.title,
             content: entry.content,
             tags: entry.tags,
             priority: entry.priority,
+            updated_at: entry.updated_at,
+            pinned_at: entry.pinned_at,
+            color: entry.color,
         }
     }
 }
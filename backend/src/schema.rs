@@ -0,0 +1,64 @@
+use serde_json::{json, Value};
+
+use crate::TRANSFER_DATA_VERSION;
+
+/// Returns the JSON schema describing the transfer format of [`crate::EntriesDTO`] used for
+/// exporting and importing journals, matching the current [`TRANSFER_DATA_VERSION`].
+pub fn entries_dto_schema() -> Value {
+    let optional_date_time = json!({
+        "type": ["string", "null"],
+        "format": "date-time"
+    });
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "TUI-Journal journals transfer",
+        "description": "Journals exported from TUI-Journal, which can be imported into its back-ends.",
+        "type": "object",
+        "properties": {
+            "version": {
+                "description": "Version of the transfer format.",
+                "type": "integer",
+                "const": TRANSFER_DATA_VERSION
+            },
+            "entries": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "id": {
+                            "description": "Original id of the journal, kept on import if it isn't taken.",
+                            "type": ["integer", "null"],
+                            "minimum": 0
+                        },
+                        "date": {
+                            "description": "Date of the journal in RFC 3339 format.",
+                            "type": "string",
+                            "format": "date-time"
+                        },
+                        "title": { "type": "string" },
+                        "content": { "type": "string" },
+                        "tags": {
+                            "type": "array",
+                            "items": { "type": "string" }
+                        },
+                        "priority": {
+                            "type": ["integer", "null"],
+                            "minimum": 0
+                        },
+                        "updated_at": optional_date_time,
+                        "pinned_at": optional_date_time,
+                        "color": {
+                            "description": "Color of the journal title as name or hex '#RRGGBB'.",
+                            "type": ["string", "null"]
+                        }
+                    },
+                    "required": ["date", "title"],
+                    "additionalProperties": false
+                }
+            }
+        },
+        "required": ["version", "entries"],
+        "additionalProperties": false
+    })
+}
@@ -0,0 +1,2 @@
+ALTER TABLE entries
+Add COLUMN updated_at DATETIME DEFAULT NULL;
@@ -0,0 +1,2 @@
+ALTER TABLE entries
+Add COLUMN pinned_at DATETIME DEFAULT NULL;
@@ -0,0 +1,2 @@
+ALTER TABLE entries
+Add COLUMN color TEXT DEFAULT NULL;
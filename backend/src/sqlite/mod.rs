@@ -60,10 +60,25 @@ impl SqliteDataProvide {
     }
 }
 
+#[cfg(feature = "json")]
+impl SqliteDataProvide {
+    /// Imports the entries of the given JSON back-end file keeping their ids, which is meant to
+    /// migrate the journals on the first run with the SQLite back-end.
+    /// Returns the count of the imported entries.
+    pub async fn import_json_file(&self, json_path: PathBuf) -> anyhow::Result<usize> {
+        let entries = JsonDataProvide::new(json_path).load_all_entries().await?;
+        let drafts = entries.into_iter().map(EntryDraft::from_entry).collect();
+
+        let ids_map = self.import_entries(EntriesDTO::new(drafts)).await?;
+
+        Ok(ids_map.len())
+    }
+}
+
 impl DataProvider for SqliteDataProvide {
     async fn load_all_entries(&self) -> anyhow::Result<Vec<Entry>> {
         let entries: Vec<EntryIntermediate> = sqlx::query_as(
-            r"SELECT entries.id, entries.title, entries.date, entries.content, entries.priority, GROUP_CONCAT(tags.tag) AS tags
+            r"SELECT entries.id, entries.title, entries.date, entries.content, entries.priority, entries.updated_at, entries.pinned_at, entries.color, GROUP_CONCAT(tags.tag) AS tags
             FROM entries
             LEFT JOIN tags ON entries.id = tags.entry_id
             GROUP BY entries.id
@@ -83,14 +98,18 @@ impl DataProvider for SqliteDataProvide {
 
     async fn add_entry(&self, entry: EntryDraft) -> Result<Entry, ModifyEntryError> {
         let row = sqlx::query(
-            r"INSERT INTO entries (title, date, content, priority)
-            VALUES($1, $2, $3, $4)
+            r"INSERT INTO entries (id, title, date, content, priority, updated_at, pinned_at, color)
+            VALUES($1, $2, $3, $4, $5, $6, $7, $8)
             RETURNING id",
         )
+        .bind(entry.id)
         .bind(&entry.title)
         .bind(entry.date)
         .bind(&entry.content)
         .bind(entry.priority)
+        .bind(entry.updated_at)
+        .bind(entry.pinned_at)
+        .bind(&entry.color)
         .fetch_one(&self.pool)
         .await
         .map_err(|err| {
@@ -137,13 +156,19 @@ impl DataProvider for SqliteDataProvide {
             Set title = $1,
                 date = $2,
                 content = $3,
-                priority = $4
-            WHERE id = $5",
+                priority = $4,
+                updated_at = $5,
+                pinned_at = $6,
+                color = $7
+            WHERE id = $8",
         )
         .bind(&entry.title)
         .bind(entry.date)
         .bind(&entry.content)
         .bind(entry.priority)
+        .bind(entry.updated_at)
+        .bind(entry.pinned_at)
+        .bind(&entry.color)
         .bind(entry.id)
         .execute(&self.pool)
         .await
@@ -204,7 +229,7 @@ impl DataProvider for SqliteDataProvide {
             .join(", ");
 
         let sql = format!(
-            r"SELECT entries.id, entries.title, entries.date, entries.content, entries.priority, GROUP_CONCAT(tags.tag) AS tags
+            r"SELECT entries.id, entries.title, entries.date, entries.content, entries.priority, entries.updated_at, entries.pinned_at, entries.color, GROUP_CONCAT(tags.tag) AS tags
             FROM entries
             LEFT JOIN tags ON entries.id = tags.entry_id
             WHERE entries.id IN ({})
@@ -11,6 +11,9 @@ pub(crate) struct EntryIntermediate {
     pub title: String,
     pub content: String,
     pub priority: Option<u32>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub pinned_at: Option<DateTime<Utc>>,
+    pub color: Option<String>,
     /// Tags as a string with commas as separator for the tags
     pub tags: Option<String>,
 }
@@ -23,6 +26,9 @@ impl From<EntryIntermediate> for Entry {
             title: value.title,
             content: value.content,
             priority: value.priority,
+            updated_at: value.updated_at,
+            pinned_at: value.pinned_at,
+            color: value.color,
             tags: value
                 .tags
                 .map(|tags| tags.split_terminator(',').map(String::from).collect())
@@ -1,4 +1,6 @@
+mod entry;
 #[cfg(feature = "json")]
 mod json;
+mod schema;
 #[cfg(feature = "sqlite")]
 mod sqlite;
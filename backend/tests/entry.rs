@@ -0,0 +1,18 @@
+use backend::Entry;
+use chrono::{TimeZone, Utc};
+
+#[test]
+fn content_counts() {
+    let date = Utc.with_ymd_and_hms(2023, 10, 12, 11, 22, 33).unwrap();
+    let mut entry = Entry::new(1, date, "Title".into(), String::new(), Vec::new(), None);
+    assert_eq!(entry.word_count(), 0);
+    assert_eq!(entry.char_count(), 0);
+
+    entry.content = String::from("  Hello  world\n\n\tnew line ");
+    assert_eq!(entry.word_count(), 4);
+    assert_eq!(entry.char_count(), 26);
+
+    entry.content = String::from("Grüße café 日本");
+    assert_eq!(entry.word_count(), 3);
+    assert_eq!(entry.char_count(), 13);
+}
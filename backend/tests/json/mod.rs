@@ -5,7 +5,7 @@ use chrono::{TimeZone, Utc};
 
 use crate::json::temp_file::TempFile;
 
-mod temp_file;
+pub mod temp_file;
 
 async fn create_provide_with_two_entries(path_file: PathBuf) -> JsonDataProvide {
     let json_provide = JsonDataProvide::new(path_file);
@@ -141,6 +141,50 @@ async fn export_import() {
     assert_eq!(dto_source, dto_dist);
 }
 
+#[tokio::test]
+async fn import_keeps_free_ids() {
+    let temp_file = TempFile::new("json_import_ids");
+    let provider = create_provide_with_two_entries(temp_file.file_path.clone()).await;
+
+    let draft =
+        |id| EntryDraft::new(Utc::now(), format!("Imported {id}"), Vec::new(), None).with_id(id);
+    let dto = EntriesDTO::new(vec![draft(1), draft(5), draft(2)]);
+
+    let ids_map = provider.import_entries(dto).await.unwrap();
+
+    // Taken ids get new ones after the kept ids
+    assert_eq!(ids_map, [(1, 6), (2, 2), (5, 5)].into());
+
+    let entries = provider.load_all_entries().await.unwrap();
+    let title_of = |id| entries.iter().find(|e| e.id == id).unwrap().title.as_str();
+    assert_eq!(title_of(1), "Title 2");
+    assert_eq!(title_of(5), "Imported 5");
+    assert_eq!(title_of(2), "Imported 2");
+    assert_eq!(title_of(6), "Imported 1");
+
+    // Adding entries with taken ids is rejected
+    assert!(provider.add_entry(draft(5)).await.is_err());
+}
+
+#[tokio::test]
+async fn add_entry_after_max_id() {
+    let temp_file = TempFile::new("json_max_id");
+    let provider = JsonDataProvide::new(temp_file.file_path.clone());
+
+    let draft = EntryDraft::new(Utc::now(), String::from("Title"), Vec::new(), None);
+    provider
+        .add_entry(draft.clone().with_id(u32::MAX))
+        .await
+        .unwrap();
+
+    // No id is left after the maximum one
+    assert!(matches!(
+        provider.add_entry(draft).await,
+        Err(ModifyEntryError::ValidationError(_))
+    ));
+    assert_eq!(provider.load_all_entries().await.unwrap().len(), 1);
+}
+
 #[tokio::test]
 async fn assign_priority() {
     let temp_file = TempFile::new("json_assign_priority");
@@ -153,3 +197,73 @@ async fn assign_priority() {
     assert_eq!(entries[0].priority, Some(3));
     assert_eq!(entries[1].priority, Some(1));
 }
+
+#[tokio::test]
+async fn interrupted_write_keeps_entries() {
+    let temp_file = TempFile::new("json_interrupted_write");
+    let partial_file = TempFile::new("json_interrupted_write.tmp");
+    let provider = create_provide_with_two_entries(temp_file.file_path.clone()).await;
+
+    // Backup contains the entries before the last write
+    let backup_provider = JsonDataProvide::new(temp_file.backup_path());
+    assert_eq!(backup_provider.load_all_entries().await.unwrap().len(), 1);
+
+    // Simulate a write which has been interrupted while writing the temporary file
+    std::fs::write(&partial_file.file_path, r#"[{"id":0,"title":"Tru"#).unwrap();
+
+    let entries = provider.load_all_entries().await.unwrap();
+    assert_eq!(entries.len(), 2);
+
+    let mut entry = entries[0].clone();
+    entry.title = String::from("Updated");
+    provider.update_entry(entry).await.unwrap();
+
+    assert!(!partial_file.file_path.exists());
+    assert_eq!(
+        provider.load_all_entries().await.unwrap()[0].title,
+        "Updated"
+    );
+    assert_eq!(backup_provider.load_all_entries().await.unwrap(), entries);
+}
+
+#[cfg(feature = "encrypted_json")]
+#[tokio::test]
+async fn encrypted_entries() {
+    let temp_file = TempFile::new("json_encrypted_entries");
+    let key = EncryptionKey::derive("secret", KdfParams::generate()).unwrap();
+
+    let provider = JsonDataProvide::new_encrypted(temp_file.file_path.clone(), key.clone())
+        .await
+        .unwrap();
+    let draft = EntryDraft::new(Utc::now(), String::from("Private"), Vec::new(), None);
+    provider.add_entry(draft).await.unwrap();
+
+    let raw_content = std::fs::read(&temp_file.file_path).unwrap();
+    assert!(!String::from_utf8_lossy(&raw_content).contains("Private"));
+
+    let provider = JsonDataProvide::new_encrypted(temp_file.file_path.clone(), key)
+        .await
+        .unwrap();
+    let entries = provider.load_all_entries().await.unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].title, "Private");
+
+    // The key can be derived again from the parameters in the file header.
+    let params = KdfParams::from_encrypted(&raw_content).unwrap();
+    let same_key = EncryptionKey::derive("secret", params.clone()).unwrap();
+    let provider = JsonDataProvide::new_encrypted(temp_file.file_path.clone(), same_key)
+        .await
+        .unwrap();
+    assert_eq!(provider.load_all_entries().await.unwrap().len(), 1);
+
+    let wrong_key = EncryptionKey::derive("wrong", params).unwrap();
+    let err = JsonDataProvide::new_encrypted(temp_file.file_path.clone(), wrong_key)
+        .await
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("Wrong passphrase"));
+
+    let plain_provider = JsonDataProvide::new(temp_file.file_path.clone());
+    let err = plain_provider.load_all_entries().await.unwrap_err();
+    assert!(err.to_string().contains("Entries file is encrypted"));
+}
@@ -13,14 +13,22 @@ impl TempFile {
         temp_file
     }
 
+    /// Path of the backup file the JSON back-end keeps next to the test file.
+    pub fn backup_path(&self) -> PathBuf {
+        let mut file_name = self.file_path.file_name().unwrap().to_os_string();
+        file_name.push(".bak");
+
+        self.file_path.with_file_name(file_name)
+    }
+
     pub fn clean_up(&self) {
-        if self
-            .file_path
-            .try_exists()
-            .expect("Access to check the test file should be given")
-        {
-            fs::remove_file(&self.file_path)
-                .expect("Access to delete the test file should be given");
+        for path in [self.file_path.clone(), self.backup_path()] {
+            if path
+                .try_exists()
+                .expect("Access to check the test file should be given")
+            {
+                fs::remove_file(&path).expect("Access to delete the test file should be given");
+            }
         }
     }
 }
@@ -0,0 +1,145 @@
+use backend::*;
+use chrono::{DateTime, TimeZone, Utc};
+use serde_json::{json, Value};
+
+/// Validates the given value against the subset of JSON schema keywords used by the transfer
+/// schema, returning the path of the first invalid value.
+fn validate(schema: &Value, value: &Value, path: &str) -> Result<(), String> {
+    let err = |msg: &str| Err(format!("{path}: {msg}"));
+
+    if let Some(types) = schema.get("type") {
+        let types: Vec<&str> = match types {
+            Value::String(single) => vec![single.as_str()],
+            Value::Array(multi) => multi.iter().filter_map(Value::as_str).collect(),
+            _ => return err("invalid type keyword"),
+        };
+        let matches = types.iter().any(|t| match *t {
+            "object" => value.is_object(),
+            "array" => value.is_array(),
+            "string" => value.is_string(),
+            "integer" => value.is_u64() || value.is_i64(),
+            "null" => value.is_null(),
+            _ => false,
+        });
+        if !matches {
+            return err(&format!("expected type {types:?}"));
+        }
+    }
+
+    if schema
+        .get("const")
+        .is_some_and(|expected| expected != value)
+    {
+        return err("value doesn't match const");
+    }
+
+    if let (Some(min), Some(num)) = (schema.get("minimum"), value.as_i64()) {
+        if num < min.as_i64().unwrap() {
+            return err("value is less than minimum");
+        }
+    }
+
+    if schema.get("format").and_then(Value::as_str) == Some("date-time") {
+        if let Some(text) = value.as_str() {
+            if DateTime::parse_from_rfc3339(text).is_err() {
+                return err("invalid date-time");
+            }
+        }
+    }
+
+    if let Some(obj) = value.as_object() {
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for required in schema
+            .get("required")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            if !obj.contains_key(required.as_str().unwrap()) {
+                return err(&format!("missing required property {required}"));
+            }
+        }
+        for (key, item) in obj {
+            match properties.and_then(|props| props.get(key)) {
+                Some(prop_schema) => validate(prop_schema, item, &format!("{path}.{key}"))?,
+                None if schema.get("additionalProperties") == Some(&Value::Bool(false)) => {
+                    return err(&format!("unknown property {key}"));
+                }
+                None => {}
+            }
+        }
+    }
+
+    if let (Some(items_schema), Some(items)) = (schema.get("items"), value.as_array()) {
+        for (idx, item) in items.iter().enumerate() {
+            validate(items_schema, item, &format!("{path}[{idx}]"))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn sample_export() -> EntriesDTO {
+    let mut full_draft = EntryDraft::new(
+        Utc.with_ymd_and_hms(2024, 3, 7, 10, 0, 0).unwrap(),
+        String::from("Title 1"),
+        vec![String::from("Tag 1"), String::from("Tag 2")],
+        Some(2),
+    )
+    .with_content(String::from("Content 1"))
+    .with_id(3);
+    full_draft.updated_at = Some(Utc.with_ymd_and_hms(2024, 3, 8, 10, 0, 0).unwrap());
+    full_draft.pinned_at = Some(Utc.with_ymd_and_hms(2024, 3, 9, 10, 0, 0).unwrap());
+    full_draft.color = Some(String::from("#FF0000"));
+
+    let minimal_draft = EntryDraft::new(
+        Utc.with_ymd_and_hms(2023, 1, 2, 3, 4, 5).unwrap(),
+        String::from("Title 2"),
+        Vec::new(),
+        None,
+    );
+
+    EntriesDTO::new(vec![full_draft, minimal_draft])
+}
+
+#[test]
+fn sample_export_validates() {
+    let schema = entries_dto_schema();
+    let export = serde_json::to_value(sample_export()).unwrap();
+
+    validate(&schema, &export, "$").unwrap();
+}
+
+#[test]
+fn schema_matches_serialized_fields() {
+    let schema = entries_dto_schema();
+    let export = serde_json::to_value(sample_export()).unwrap();
+
+    let keys = |obj: &Value| -> Vec<String> {
+        let mut keys: Vec<_> = obj.as_object().unwrap().keys().cloned().collect();
+        keys.sort();
+        keys
+    };
+
+    assert_eq!(keys(&schema["properties"]), keys(&export));
+    assert_eq!(
+        keys(&schema["properties"]["entries"]["items"]["properties"]),
+        keys(&export["entries"][0])
+    );
+}
+
+#[test]
+fn invalid_export_rejected() {
+    let schema = entries_dto_schema();
+
+    let invalid_values = [
+        json!({ "version": TRANSFER_DATA_VERSION, "entries": [{ "date": "2024-03-07T10:00:00Z" }] }),
+        json!({ "version": 1, "entries": [] }),
+        json!({ "version": TRANSFER_DATA_VERSION, "entries": [{ "date": "yesterday", "title": "T" }] }),
+        json!({ "version": TRANSFER_DATA_VERSION, "entries": [], "extra": true }),
+    ];
+
+    for value in invalid_values {
+        assert!(validate(&schema, &value, "$").is_err(), "{value}");
+    }
+}
@@ -95,6 +95,10 @@ async fn update_entry() {
     entries[1].title = String::from("Updated Title");
     entries[1].tags.push(String::from("Tag_4"));
     entries[1].priority = None;
+    let updated_at = Utc.with_ymd_and_hms(2024, 5, 6, 7, 8, 9).unwrap();
+    entries[1].updated_at = Some(updated_at);
+    entries[1].pinned_at = Some(updated_at);
+    entries[1].color = Some(String::from("#102030"));
 
     provider.update_entry(entries.pop().unwrap()).await.unwrap();
     provider.update_entry(entries.pop().unwrap()).await.unwrap();
@@ -108,6 +112,12 @@ async fn update_entry() {
     assert_eq!(entries[1].title, String::from("Updated Title"));
     assert!(entries[1].tags.contains(&String::from("Tag_4")));
     assert_eq!(entries[1].priority, None);
+    assert_eq!(entries[0].updated_at, None);
+    assert_eq!(entries[1].updated_at, Some(updated_at));
+    assert_eq!(entries[0].pinned_at, None);
+    assert_eq!(entries[1].pinned_at, Some(updated_at));
+    assert_eq!(entries[0].color, None);
+    assert_eq!(entries[1].color, Some(String::from("#102030")));
 }
 
 #[tokio::test]
@@ -135,6 +145,30 @@ async fn export_import() {
     assert_eq!(dto_source, dto_dist);
 }
 
+#[tokio::test]
+async fn import_keeps_free_ids() {
+    let provider = create_provider_with_two_entries().await;
+
+    let draft =
+        |id| EntryDraft::new(Utc::now(), format!("Imported {id}"), Vec::new(), None).with_id(id);
+    let dto = EntriesDTO::new(vec![draft(1), draft(5), draft(3)]);
+
+    let ids_map = provider.import_entries(dto).await.unwrap();
+
+    // Taken ids get new ones after the kept ids
+    assert_eq!(ids_map, [(1, 6), (3, 3), (5, 5)].into());
+
+    let entries = provider.load_all_entries().await.unwrap();
+    let title_of = |id| entries.iter().find(|e| e.id == id).unwrap().title.as_str();
+    assert_eq!(title_of(1), "Title 1");
+    assert_eq!(title_of(3), "Imported 3");
+    assert_eq!(title_of(5), "Imported 5");
+    assert_eq!(title_of(6), "Imported 1");
+
+    // Adding entries with taken ids is rejected
+    assert!(provider.add_entry(draft(5)).await.is_err());
+}
+
 #[tokio::test]
 async fn assign_priority() {
     let provider = create_provider_with_two_entries().await;
@@ -146,3 +180,38 @@ async fn assign_priority() {
     assert_eq!(entries[0].priority, Some(3));
     assert_eq!(entries[1].priority, Some(1));
 }
+
+#[cfg(feature = "json")]
+#[tokio::test]
+async fn import_json_file() {
+    use crate::json::temp_file::TempFile;
+
+    let temp_file = TempFile::new("sqlite_import_json");
+    let json_provider = JsonDataProvide::new(temp_file.file_path.clone());
+    let mut draft = EntryDraft::new(
+        Utc.with_ymd_and_hms(2023, 3, 23, 1, 1, 1).unwrap(),
+        String::from("Json Title"),
+        vec![String::from("Tag_1")],
+        Some(2),
+    );
+    draft.content.push_str("Json content");
+    json_provider.add_entry(draft.clone()).await.unwrap();
+    json_provider.add_entry(draft.with_id(5)).await.unwrap();
+
+    let provider = create_provider().await;
+    let count = provider
+        .import_json_file(temp_file.file_path.clone())
+        .await
+        .unwrap();
+
+    assert_eq!(count, 2);
+    let sqlite_entries = provider.load_all_entries().await.unwrap();
+    let ids: Vec<u32> = sqlite_entries.iter().map(|entry| entry.id).collect();
+    assert_eq!(ids, [0, 5]);
+    assert!(sqlite_entries
+        .iter()
+        .all(|entry| entry.title == "Json Title"
+            && entry.content == "Json content"
+            && entry.tags == ["Tag_1"]
+            && entry.priority == Some(2)));
+}
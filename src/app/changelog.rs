@@ -0,0 +1,190 @@
+use std::fmt::Display;
+
+/// The changelog of the app, bundled to show the changes of the running version.
+const CHANGELOG: &str = include_str!("../../CHANGELOG.ron");
+
+/// Version of the running app.
+pub const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl Version {
+    /// Parses versions in the format `major.minor.patch`, ignoring pre-release and build
+    /// metadata suffixes.
+    pub fn parse(text: &str) -> Option<Self> {
+        let core = text.trim().split(['-', '+']).next()?;
+        let mut parts = core.split('.').map(|part| part.parse::<u64>().ok());
+
+        let version = Self {
+            major: parts.next()??,
+            minor: parts.next()??,
+            patch: parts.next()??,
+        };
+
+        parts.next().is_none().then_some(version)
+    }
+}
+
+impl Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Checks if the what's new popup should be shown for the current version given the last version
+/// the user has seen.
+/// It's shown after upgrades only, skipping the first run, downgrades and unparsable versions.
+pub fn should_show_whats_new(last_seen: Option<&str>, current: &str) -> bool {
+    let Some(last_seen) = last_seen else {
+        return false;
+    };
+
+    match (Version::parse(last_seen), Version::parse(current)) {
+        (Some(last_seen), Some(current)) => last_seen < current,
+        _ => false,
+    }
+}
+
+/// Changes of a released version grouped by their categories (Added, Changed, Fixed...).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseNotes {
+    pub version: Version,
+    pub changes: Vec<(String, Vec<String>)>,
+}
+
+impl ReleaseNotes {
+    /// Gets the release notes of the given version from the bundled changelog.
+    pub fn for_version(version: Version) -> Option<Self> {
+        parse_release_notes(CHANGELOG, version)
+    }
+}
+
+/// Extracts the changes of the given version from a changelog in the RON format generated for
+/// this project.
+fn parse_release_notes(changelog: &str, version: Version) -> Option<ReleaseNotes> {
+    let mut lines = changelog.lines().map(str::trim);
+
+    // Move to the section of the requested version.
+    loop {
+        let line = lines.next()?;
+        if line == "version: (" && parse_section_version(&mut lines) == Some(version) {
+            break;
+        }
+    }
+
+    let mut changes: Vec<(String, Vec<String>)> = Vec::new();
+    for line in lines {
+        // Changes of the next section are reached.
+        if line == "version: (" {
+            break;
+        }
+
+        if let Some(category) = line.strip_suffix(": [") {
+            changes.push((parse_ron_string(category)?, Vec::new()));
+        } else if let Some((_, items)) = changes.last_mut() {
+            let item = line.strip_suffix(',').unwrap_or(line);
+            if item.starts_with('"') {
+                let item = parse_ron_string(item)?;
+                items.push(item.split_whitespace().collect::<Vec<_>>().join(" "));
+            }
+        }
+    }
+
+    changes.retain(|(_, items)| !items.is_empty());
+
+    (!changes.is_empty()).then_some(ReleaseNotes { version, changes })
+}
+
+fn parse_section_version<'a>(lines: &mut impl Iterator<Item = &'a str>) -> Option<Version> {
+    let mut read_number = |key: &str| {
+        lines
+            .next()?
+            .strip_prefix(key)?
+            .trim_end_matches(',')
+            .trim()
+            .parse()
+            .ok()
+    };
+
+    Some(Version {
+        major: read_number("major:")?,
+        minor: read_number("minor:")?,
+        patch: read_number("patch:")?,
+    })
+}
+
+/// Decodes quoted RON strings, which share their escape sequences with JSON.
+fn parse_ron_string(text: &str) -> Option<String> {
+    serde_json::from_str(text).ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_version() {
+        let version = |major, minor, patch| Version {
+            major,
+            minor,
+            patch,
+        };
+
+        assert_eq!(Version::parse("0.14.0"), Some(version(0, 14, 0)));
+        assert_eq!(Version::parse(" 1.2.3 "), Some(version(1, 2, 3)));
+        assert_eq!(Version::parse("1.2.3-beta.1"), Some(version(1, 2, 3)));
+        assert_eq!(Version::parse("1.2.3+build"), Some(version(1, 2, 3)));
+        assert_eq!(Version::parse("1.2"), None);
+        assert_eq!(Version::parse("1.2.3.4"), None);
+        assert_eq!(Version::parse("a.b.c"), None);
+        assert_eq!(Version::parse(""), None);
+    }
+
+    #[test]
+    fn whats_new_version_comparison() {
+        // First run
+        assert!(!should_show_whats_new(None, "0.14.0"));
+        // Same version
+        assert!(!should_show_whats_new(Some("0.14.0"), "0.14.0"));
+        // Upgrades
+        assert!(should_show_whats_new(Some("0.13.1"), "0.14.0"));
+        assert!(should_show_whats_new(Some("0.14.0"), "0.14.1"));
+        assert!(should_show_whats_new(Some("0.14.9"), "1.0.0"));
+        // Numeric and not lexical comparison
+        assert!(should_show_whats_new(Some("0.9.0"), "0.10.0"));
+        // Downgrade
+        assert!(!should_show_whats_new(Some("0.14.0"), "0.13.1"));
+        // Invalid versions
+        assert!(!should_show_whats_new(Some("invalid"), "0.14.0"));
+        assert!(!should_show_whats_new(Some("0.13.0"), "invalid"));
+    }
+
+    #[test]
+    fn bundled_release_notes() {
+        let notes = ReleaseNotes::for_version(Version::parse("0.14.0").unwrap()).unwrap();
+
+        assert_eq!(
+            notes.changes,
+            vec![(
+                String::from("Added"),
+                vec![
+                    String::from(
+                        "Configuration to set the extension of the temporary file used with the external editor."
+                    ),
+                    String::from("Custom themes on user level & Styles improvements"),
+                ]
+            )]
+        );
+
+        let notes = ReleaseNotes::for_version(Version::parse("0.13.0").unwrap()).unwrap();
+        let categories: Vec<_> = notes.changes.iter().map(|(cat, _)| cat.as_str()).collect();
+        assert_eq!(categories, vec!["Added", "Changed", "Fixed"]);
+
+        assert!(ReleaseNotes::for_version(Version::parse("99.0.0").unwrap()).is_none());
+    }
+}
@@ -1,11 +1,12 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
 
-/// Hard coded colors for the tags.
+/// Hard coded colors for the tags, offered as palette when assigning colors to tags too.
 /// Note: the order to pick the colors is from bottom to top because we are popping the colors from
 /// the end of the stack.
-const TAG_COLORS: &[TagColors] = &[
+pub const TAG_COLORS: &[TagColors] = &[
     TagColors::new(Color::Black, Color::LightMagenta),
     TagColors::new(Color::Red, Color::Cyan),
     TagColors::new(Color::Yellow, Color::Blue),
@@ -23,15 +24,22 @@ const TAG_COLORS: &[TagColors] = &[
 pub struct ColoredTagsManager {
     tag_colors_map: HashMap<String, TagColors>,
     available_colors: Vec<TagColors>,
+    /// Colors assigned by the user, which override the automatically assigned ones.
+    custom_colors: BTreeMap<String, TagColors>,
+    /// Tentative colors for a tag which override its assigned colors until they get
+    /// confirmed or canceled.
+    preview: Option<(String, TagColors)>,
 }
 
 impl ColoredTagsManager {
-    pub fn new() -> Self {
+    pub fn new(custom_colors: BTreeMap<String, TagColors>) -> Self {
         let available_colors = TAG_COLORS.to_vec();
 
         Self {
             tag_colors_map: HashMap::new(),
             available_colors,
+            custom_colors,
+            preview: None,
         }
     }
 
@@ -65,11 +73,42 @@ impl ColoredTagsManager {
 
     /// Gets the matching color for the giving tag if tag exists.
     pub fn get_tag_color(&self, tag: &str) -> Option<TagColors> {
-        self.tag_colors_map.get(tag).copied()
+        if let Some((preview_tag, colors)) = &self.preview {
+            if preview_tag == tag && self.tag_colors_map.contains_key(tag) {
+                return Some(*colors);
+            }
+        }
+
+        let colors = self.tag_colors_map.get(tag)?;
+
+        Some(self.custom_colors.get(tag).unwrap_or(colors)).copied()
+    }
+
+    /// Sets tentative colors for the given tag, replacing any existing preview.
+    pub fn set_preview(&mut self, tag: String, colors: TagColors) {
+        self.preview = Some((tag, colors));
+    }
+
+    /// Drops the tentative colors, leaving the assigned colors unchanged.
+    pub fn cancel_preview(&mut self) {
+        self.preview = None;
+    }
+
+    /// Assigns the tentative colors to their tag if it still exists, returning the tag with
+    /// its assigned colors.
+    pub fn confirm_preview(&mut self) -> Option<(String, TagColors)> {
+        let (tag, colors) = self.preview.take()?;
+        if !self.tag_colors_map.contains_key(&tag) {
+            return None;
+        }
+
+        self.custom_colors.insert(tag.clone(), colors);
+
+        Some((tag, colors))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 /// Represents the needed colors for a colored tag
 pub struct TagColors {
     pub foreground: Color,
@@ -103,7 +142,7 @@ mod tests {
             String::from("Tag 4"),
         ];
 
-        let mut manager = ColoredTagsManager::new();
+        let mut manager = ColoredTagsManager::new(BTreeMap::new());
         manager.update_tags(tags.clone());
 
         // Ensure all tags have colors.
@@ -139,4 +178,28 @@ mod tests {
         // Added tag should take the color of tag one because we removed it then added the new tag.
         assert_eq!(manager.get_tag_color(ADDED_TAG).unwrap(), tag_one_color);
     }
+
+    #[test]
+    fn test_custom_tag_colors() {
+        let custom = TagColors::new(Color::White, Color::Rgb(0x10, 0x20, 0x30));
+        let custom_colors = BTreeMap::from([
+            (String::from("Tag 1"), custom),
+            (String::from("Removed Tag"), custom),
+        ]);
+
+        let mut manager = ColoredTagsManager::new(custom_colors);
+        manager.update_tags(vec![String::from("Tag 1"), String::from("Tag 2")]);
+
+        assert_eq!(manager.get_tag_color("Tag 1").unwrap(), custom);
+        assert_ne!(manager.get_tag_color("Tag 2").unwrap(), custom);
+        assert!(manager.get_tag_color("Removed Tag").is_none());
+
+        let confirmed = TagColors::new(Color::Black, Color::Yellow);
+        manager.set_preview(String::from("Tag 2"), confirmed);
+        assert_eq!(
+            manager.confirm_preview(),
+            Some((String::from("Tag 2"), confirmed))
+        );
+        assert_eq!(manager.get_tag_color("Tag 2").unwrap(), confirmed);
+    }
 }
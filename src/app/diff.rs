@@ -0,0 +1,234 @@
+use std::{collections::HashMap, path::Path};
+
+use anyhow::{bail, Context};
+use backend::{EntriesDTO, Entry, EntryDraft};
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+
+/// Represents how the journals are matched while comparing two journal collections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
+pub enum DiffMatch {
+    /// Match journals by their ids. It needs journal files containing the ids of their entries.
+    Id,
+    #[default]
+    /// Match journals by their title, date and content.
+    Content,
+}
+
+/// Journals of another journal file loaded to be compared with the current journals.
+#[derive(Debug)]
+pub struct OtherJournals {
+    pub entries: Vec<Entry>,
+    /// Transfer files don't contain the ids of the entries.
+    pub has_ids: bool,
+}
+
+impl OtherJournals {
+    /// Loads the journals from the given file, which can be either a JSON back-end file or a
+    /// transfer file created by exporting journals.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Error while reading journal file {}", path.display()))?;
+
+        if let Ok(entries) = serde_json::from_str::<Vec<Entry>>(&content) {
+            return Ok(Self {
+                entries,
+                has_ids: true,
+            });
+        }
+
+        let dto: EntriesDTO = serde_json::from_str(&content).with_context(|| {
+            format!(
+                "File {} is neither a JSON journal file nor a transfer file",
+                path.display()
+            )
+        })?;
+
+        // Entries of transfer files get their indices as ids so they can be told apart.
+        let entries = dto
+            .entries
+            .into_iter()
+            .enumerate()
+            .map(|(idx, draft)| Entry::from_draft(idx as u32, draft))
+            .collect();
+
+        Ok(Self {
+            entries,
+            has_ids: false,
+        })
+    }
+}
+
+/// Differences between two collections of journals.
+#[derive(Debug, PartialEq, Eq)]
+pub struct JournalsDiff<'a> {
+    /// Journals found in the current collection only.
+    pub only_current: Vec<&'a Entry>,
+    /// Journals found in the other collection only.
+    pub only_other: Vec<&'a Entry>,
+}
+
+impl JournalsDiff<'_> {
+    /// Creates a transfer object with the journals found in the other collection only, so they
+    /// can be imported into the current journals.
+    pub fn other_delta_dto(&self) -> EntriesDTO {
+        let drafts = self
+            .only_other
+            .iter()
+            .map(|entry| EntryDraft::from_entry((*entry).clone()))
+            .collect();
+
+        EntriesDTO::new(drafts)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+enum MatchKey<'a> {
+    Id(u32),
+    Content {
+        title: &'a str,
+        date: DateTime<Utc>,
+        content: &'a str,
+    },
+}
+
+impl<'a> MatchKey<'a> {
+    fn new(entry: &'a Entry, match_by: DiffMatch) -> Self {
+        match match_by {
+            DiffMatch::Id => MatchKey::Id(entry.id),
+            DiffMatch::Content => MatchKey::Content {
+                title: &entry.title,
+                date: entry.date,
+                content: &entry.content,
+            },
+        }
+    }
+}
+
+/// Computes the set differences between the current and the other journals keeping the order of
+/// each collection. Duplicated journals are matched one to one, so surplus duplicates are
+/// reported as differences too.
+pub fn diff_entries<'a>(
+    current: &'a [Entry],
+    other: &'a [Entry],
+    match_by: DiffMatch,
+) -> JournalsDiff<'a> {
+    let only_in = |entries: &'a [Entry], compare_to: &'a [Entry]| {
+        let mut available: HashMap<MatchKey, usize> = HashMap::new();
+        for entry in compare_to {
+            *available.entry(MatchKey::new(entry, match_by)).or_default() += 1;
+        }
+
+        entries
+            .iter()
+            .filter(
+                |entry| match available.get_mut(&MatchKey::new(entry, match_by)) {
+                    Some(count) if *count > 0 => {
+                        *count -= 1;
+                        false
+                    }
+                    _ => true,
+                },
+            )
+            .collect()
+    };
+
+    JournalsDiff {
+        only_current: only_in(current, other),
+        only_other: only_in(other, current),
+    }
+}
+
+/// Compares the current journals with the other ones, validating that the matching is possible.
+pub fn diff_with_other<'a>(
+    current: &'a [Entry],
+    other: &'a OtherJournals,
+    match_by: DiffMatch,
+) -> anyhow::Result<JournalsDiff<'a>> {
+    if match_by == DiffMatch::Id && !other.has_ids {
+        bail!("Matching by id needs a JSON journal file. Transfer files don't contain the ids of the journals")
+    }
+
+    Ok(diff_entries(current, &other.entries, match_by))
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn entry(id: u32, title: &str, content: &str) -> Entry {
+        Entry::new(
+            id,
+            Utc.with_ymd_and_hms(2024, 3, 1, 10, 0, 0).unwrap(),
+            title.into(),
+            content.into(),
+            Vec::new(),
+            None,
+        )
+    }
+
+    fn ids(entries: &[&Entry]) -> Vec<u32> {
+        entries.iter().map(|entry| entry.id).collect()
+    }
+
+    #[test]
+    fn diff_by_content() {
+        let current = vec![
+            entry(0, "Shared", "Content"),
+            entry(1, "Only current", "Content"),
+            entry(2, "Edited", "Old content"),
+        ];
+        let other = vec![
+            entry(5, "Edited", "New content"),
+            entry(6, "Shared", "Content"),
+            entry(7, "Only other", "Content"),
+        ];
+
+        let diff = diff_entries(&current, &other, DiffMatch::Content);
+
+        assert_eq!(ids(&diff.only_current), vec![1, 2]);
+        assert_eq!(ids(&diff.only_other), vec![5, 7]);
+    }
+
+    #[test]
+    fn diff_by_id() {
+        let current = vec![entry(0, "A", ""), entry(1, "B", ""), entry(2, "C", "")];
+        let other = vec![entry(1, "Changed B", ""), entry(3, "D", "")];
+
+        let diff = diff_entries(&current, &other, DiffMatch::Id);
+
+        assert_eq!(ids(&diff.only_current), vec![0, 2]);
+        assert_eq!(ids(&diff.only_other), vec![3]);
+    }
+
+    #[test]
+    fn diff_duplicates_and_empty() {
+        let current = vec![entry(0, "Dup", ""), entry(1, "Dup", "")];
+        let other = vec![entry(0, "Dup", "")];
+
+        let diff = diff_entries(&current, &other, DiffMatch::Content);
+        assert_eq!(ids(&diff.only_current), vec![1]);
+        assert!(diff.only_other.is_empty());
+
+        let diff = diff_entries(&current, &[], DiffMatch::Content);
+        assert_eq!(ids(&diff.only_current), vec![0, 1]);
+        assert!(diff.only_other.is_empty());
+    }
+
+    #[test]
+    fn diff_transfer_without_ids() {
+        let other = OtherJournals {
+            entries: vec![entry(0, "A", "")],
+            has_ids: false,
+        };
+
+        assert!(diff_with_other(&[], &other, DiffMatch::Id).is_err());
+
+        let diff = diff_with_other(&[], &other, DiffMatch::Content).unwrap();
+        let dto = diff.other_delta_dto();
+        assert_eq!(dto.entries.len(), 1);
+        assert_eq!(dto.entries[0].title, "A");
+    }
+}
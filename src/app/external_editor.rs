@@ -1,4 +1,9 @@
-use std::{env, ffi::OsStr, io, path::Path};
+use std::{
+    env,
+    ffi::OsStr,
+    io,
+    path::{Path, PathBuf},
+};
 
 use anyhow::{anyhow, bail};
 
@@ -7,11 +12,38 @@ use crossterm::{
     ExecutableCommand,
 };
 use scopeguard::defer;
-use tokio::process::Command;
+use tokio::{fs, process::Command};
 
-use crate::settings::Settings;
+use crate::{app::reset_cursor_style, settings::Settings};
 
 const ENV_EDITOR_OPTIONS: [&str; 2] = ["VISUAL", "EDITOR"];
+const TEMP_FILENAME: &str = "tui_journal";
+
+/// Writes the given content to the temporary file edited with the external editor in the given
+/// directory, naming it with the given extension so editors can detect its file type.
+/// Leading dots in the extension are ignored, and the file has no extension if it's empty.
+pub async fn create_temp_file(
+    dir: &Path,
+    extension: &str,
+    content: &str,
+) -> anyhow::Result<PathBuf> {
+    let extension = extension.trim_start_matches('.');
+    let file_name = if extension.is_empty() {
+        String::from(TEMP_FILENAME)
+    } else {
+        format!("{TEMP_FILENAME}.{extension}")
+    };
+
+    let file_path = dir.join(file_name);
+
+    if file_path.exists() {
+        fs::remove_file(&file_path).await?;
+    }
+
+    fs::write(&file_path, content).await?;
+
+    Ok(file_path)
+}
 
 pub async fn open_editor(file_path: &Path, settings: &Settings) -> anyhow::Result<()> {
     if !file_path.exists() {
@@ -58,6 +90,8 @@ pub async fn open_editor(file_path: &Path, settings: &Settings) -> anyhow::Resul
 
     args.push(file_path.as_os_str());
 
+    // The editor cursor style is applied again after handling the command opening the editor.
+    reset_cursor_style();
     io::stdout().execute(LeaveAlternateScreen)?;
     defer! {
         io::stdout().execute(EnterAlternateScreen).unwrap();
@@ -90,3 +124,30 @@ fn get_git_editor() -> anyhow::Result<String> {
 
     Ok(editor)
 }
+
+#[cfg(test)]
+mod test {
+    use crate::app::test::temp_dir::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn temp_file_extension() {
+        let temp_dir = TempDir::new("editor");
+        let dir = temp_dir.path.as_path();
+
+        let path = create_temp_file(dir, "md", "Content").await.unwrap();
+        assert_eq!(path, dir.join("tui_journal.md"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "Content");
+
+        // Leading dot is accepted and existing files are replaced
+        let path = create_temp_file(dir, ".txt", "Other").await.unwrap();
+        assert_eq!(path, dir.join("tui_journal.txt"));
+
+        let path = create_temp_file(dir, ".txt", "New").await.unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "New");
+
+        let path = create_temp_file(dir, "", "Content").await.unwrap();
+        assert_eq!(path, dir.join("tui_journal"));
+    }
+}
@@ -1,19 +1,52 @@
+use std::{collections::BTreeSet, sync::OnceLock};
+
 use aho_corasick::AhoCorasick;
 use backend::Entry;
+use chrono::{DateTime, Utc};
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum FilterCriterion {
     Tag(String),
     Title(String),
     Content(String),
     Priority(u32),
+    /// Matches the entries with titles matching the given regular expression.
+    TitleRegex(RegexPattern),
+    /// Matches the entries with contents matching the given regular expression.
+    ContentRegex(RegexPattern),
+    /// Matches the entries with the given ids only, used to pin the view to specific entries.
+    Ids(BTreeSet<u32>),
+    /// Matches the entries with dates within the given inclusive bounds, where a missing bound
+    /// leaves its side of the range open.
+    DateRange {
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    },
+    /// Matches the entries which don't meet the given criterion.
+    Not(Box<FilterCriterion>),
 }
 
 impl FilterCriterion {
-    /// Checks if the entry meets the criterion
-    pub fn check_entry(&self, entry: &Entry) -> bool {
+    /// Wraps the criterion in [`FilterCriterion::Not`] if `negate` is set.
+    pub fn negate_if(self, negate: bool) -> Self {
+        if negate {
+            FilterCriterion::Not(Box::new(self))
+        } else {
+            self
+        }
+    }
+
+    /// Checks if the entry meets the criterion.
+    /// With `tag_hierarchy` enabled, tags match their `/` separated children too.
+    pub fn check_entry(&self, entry: &Entry, tag_hierarchy: bool) -> bool {
         match self {
-            FilterCriterion::Tag(tag) => entry.tags.contains(tag),
+            FilterCriterion::Tag(tag) => entry
+                .tags
+                .iter()
+                .any(|entry_tag| tag_matches(entry_tag, tag, tag_hierarchy)),
             FilterCriterion::Title(search) => {
                 // Use simple smart-case search for title
                 if search.chars().any(|c| c.is_uppercase()) {
@@ -45,6 +78,88 @@ impl FilterCriterion {
                 }
             }
             FilterCriterion::Priority(prio) => entry.priority.is_some_and(|pr| pr == *prio),
+            FilterCriterion::TitleRegex(pattern) => pattern.is_match(&entry.title),
+            FilterCriterion::ContentRegex(pattern) => pattern.is_match(&entry.content),
+            FilterCriterion::Ids(ids) => ids.contains(&entry.id),
+            FilterCriterion::DateRange { from, to } => {
+                from.map_or(true, |from| entry.date >= from)
+                    && to.map_or(true, |to| entry.date <= to)
+            }
+            FilterCriterion::Not(criterion) => !criterion.check_entry(entry, tag_hierarchy),
         }
     }
 }
+
+/// Regular expression used in filter criteria, which is compiled once on first use.
+/// Matching is case-insensitive unless `case_sensitive` is set, and an empty pattern matches
+/// all texts.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RegexPattern {
+    pub pattern: String,
+    #[serde(default)]
+    pub case_sensitive: bool,
+    #[serde(skip)]
+    compiled: OnceLock<Option<Regex>>,
+}
+
+impl PartialEq for RegexPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern && self.case_sensitive == other.case_sensitive
+    }
+}
+
+impl Eq for RegexPattern {}
+
+impl RegexPattern {
+    pub fn new(pattern: String, case_sensitive: bool) -> Self {
+        Self {
+            pattern,
+            case_sensitive,
+            compiled: OnceLock::new(),
+        }
+    }
+
+    /// Checks if the pattern is a valid regular expression.
+    pub fn validate(&self) -> Result<(), regex::Error> {
+        self.build().map(|_| ())
+    }
+
+    fn build(&self) -> Result<Regex, regex::Error> {
+        RegexBuilder::new(&self.pattern)
+            .case_insensitive(!self.case_sensitive)
+            .build()
+    }
+
+    /// Checks if the given text matches the pattern. Invalid patterns don't match any text.
+    fn is_match(&self, text: &str) -> bool {
+        self.compiled
+            .get_or_init(|| {
+                self.build()
+                    .map_err(|err| {
+                        log::error!("Invalid regex pattern {}: {err}", self.pattern);
+                    })
+                    .ok()
+            })
+            .as_ref()
+            .is_some_and(|regex| regex.is_match(text))
+    }
+}
+
+/// Checks if the tag of an entry matches the given filter tag, which includes its children in
+/// the `parent/child` notation when `tag_hierarchy` is enabled.
+fn tag_matches(entry_tag: &str, filter_tag: &str, tag_hierarchy: bool) -> bool {
+    entry_tag == filter_tag
+        || tag_hierarchy
+            && entry_tag
+                .strip_prefix(filter_tag)
+                .is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Returns the parents of the given tag in the `parent/child` notation followed by the tag
+/// itself, e.g. `a`, `a/b` and `a/b/c` for the tag `a/b/c`.
+pub fn tag_with_parents(tag: &str) -> impl Iterator<Item = &str> {
+    tag.match_indices('/')
+        .map(|(idx, _)| &tag[..idx])
+        .chain(std::iter::once(tag))
+        .filter(|path| !path.is_empty())
+}
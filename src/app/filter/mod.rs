@@ -1,18 +1,22 @@
 use backend::Entry;
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 
 pub mod criterion;
 
-pub use criterion::FilterCriterion;
+pub use criterion::{tag_with_parents, FilterCriterion, RegexPattern};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum CriteriaRelation {
+    #[default]
     And,
     Or,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Filter {
+    #[serde(default)]
     pub relation: CriteriaRelation,
     pub criteria: Vec<FilterCriterion>,
 }
@@ -27,11 +31,37 @@ impl Default for Filter {
 }
 
 impl Filter {
-    /// Checks if the entry meets the filter criteria
-    pub fn check_entry(&self, entry: &Entry) -> bool {
+    /// Validates the criteria of the filter, returning an error for invalid regular expressions.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        for mut criterion in self.criteria.iter() {
+            while let FilterCriterion::Not(inner) = criterion {
+                criterion = inner;
+            }
+
+            if let FilterCriterion::TitleRegex(pattern) | FilterCriterion::ContentRegex(pattern) =
+                criterion
+            {
+                pattern
+                    .validate()
+                    .map_err(|err| anyhow::anyhow!("Invalid regex '{}': {err}", pattern.pattern))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks if the entry meets the filter criteria.
+    /// With `tag_hierarchy` enabled, tag criteria match the `/` separated children tags too.
+    pub fn check_entry(&self, entry: &Entry, tag_hierarchy: bool) -> bool {
         match self.relation {
-            CriteriaRelation::And => self.criteria.par_iter().all(|cr| cr.check_entry(entry)),
-            CriteriaRelation::Or => self.criteria.par_iter().any(|cr| cr.check_entry(entry)),
+            CriteriaRelation::And => self
+                .criteria
+                .par_iter()
+                .all(|cr| cr.check_entry(entry, tag_hierarchy)),
+            CriteriaRelation::Or => self
+                .criteria
+                .par_iter()
+                .any(|cr| cr.check_entry(entry, tag_hierarchy)),
         }
     }
 }
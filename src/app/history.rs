@@ -1,7 +1,11 @@
-use std::collections::VecDeque;
+use std::{
+    collections::{BTreeMap, VecDeque},
+    time::{Duration, Instant},
+};
 
 use backend::Entry;
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug)]
 /// Keeps history of the changes on entries, enabling undo & redo operations
@@ -10,6 +14,23 @@ pub struct HistoryManager {
     redo_stack: VecDeque<Change>,
     /// Sets the size limit of each stack
     stacks_limit: usize,
+    /// The size of the undo stack at the last explicit save, marking the saved state in the
+    /// history. It's `None` when there is no save yet or the marker is dropped from the history.
+    save_position: Option<usize>,
+    /// Interval to group consecutive content changes of the same entry into one change.
+    grouping_interval: Duration,
+    /// Entry id and time of the latest content change on top of the undo stack, used to group
+    /// the following content changes with it.
+    last_content_change: Option<(u32, Instant)>,
+    /// The latest content change has been grouped into the change on top of the undo stack
+    /// instead of being added to it.
+    latest_grouped: bool,
+    /// Stack and changes registered since beginning a batch, ordered from the earliest to the
+    /// latest. They are added as one change at the end of the batch.
+    batch: Option<(HistoryStack, Vec<Change>)>,
+    /// A popped redo change is being applied, so the changes added to the undo stack meanwhile
+    /// move the history forward instead of diverging from it.
+    redo_in_progress: bool,
 }
 
 impl HistoryManager {
@@ -18,20 +39,62 @@ impl HistoryManager {
             undo_stack: VecDeque::new(),
             redo_stack: VecDeque::new(),
             stacks_limit,
+            save_position: None,
+            grouping_interval: Duration::ZERO,
+            last_content_change: None,
+            latest_grouped: false,
+            batch: None,
+            redo_in_progress: false,
         }
     }
 
+    /// Ends grouping the content changes, so the next content change is a separate change.
+    pub fn stop_grouping(&mut self) {
+        self.last_content_change = None;
+    }
+
+    /// Sets the interval to group consecutive content changes of the same entry into one
+    /// change, keeping the content before the first one of them. Zero disables grouping.
+    pub fn set_grouping_interval(&mut self, interval: Duration) {
+        self.grouping_interval = interval;
+    }
+
     /// Adds the given history [`Change`] to the corresponding stack of the given [`HistoryStack`]
     /// and keeping the stack within its allowed limit by dropping changes from the bottom if
     /// needed.
     fn add_to_stack(&mut self, change: Change, target: HistoryStack) {
+        if let Some((batch_target, changes)) = self.batch.as_mut() {
+            if *batch_target == target {
+                changes.push(change);
+                return;
+            }
+        }
+
+        // New changes made after undoing past the save point diverge from the saved state,
+        // which can't be reached with redo anymore.
+        if matches!(target, HistoryStack::Undo)
+            && !self.redo_in_progress
+            && self
+                .save_position
+                .is_some_and(|pos| pos > self.undo_stack.len())
+        {
+            self.save_position = None;
+        }
+
         let stack = match target {
             HistoryStack::Undo => &mut self.undo_stack,
             HistoryStack::Redo => &mut self.redo_stack,
         };
         stack.push_front(change);
+        self.last_content_change = None;
+        self.latest_grouped = false;
         if stack.len() > self.stacks_limit {
             _ = stack.pop_back();
+
+            // Dropping from the bottom of the undo stack shifts the save marker too.
+            if matches!(target, HistoryStack::Undo) {
+                self.save_position = self.save_position.and_then(|pos| pos.checked_sub(1));
+            }
         }
     }
 
@@ -60,18 +123,235 @@ impl HistoryManager {
         self.add_to_stack(change, target);
     }
 
-    /// Register changes on Entry content on the corresponding stack of the [`HistoryStack`]
+    /// Register changes on Entry content on the corresponding stack of the [`HistoryStack`].
+    /// Consecutive changes on the content of the same entry within the grouping interval are
+    /// grouped on the undo stack, keeping the content before the first change.
     pub fn register_change_content(&mut self, target: HistoryStack, entry_before_change: &Entry) {
         log::trace!(
             "History Register Change content: Entry ID: {}",
             entry_before_change.id
         );
+        let id = entry_before_change.id;
+        let now = Instant::now();
+
+        let is_recent = self
+            .last_content_change
+            .is_some_and(|(last_id, at)| last_id == id && now - at <= self.grouping_interval);
+        let is_on_top = matches!(
+            self.undo_stack.front(),
+            Some(Change::EntryContent { id: top_id, .. }) if *top_id == id
+        );
+
+        let group = matches!(target, HistoryStack::Undo)
+            && self.batch.is_none()
+            && !self.grouping_interval.is_zero()
+            && is_recent
+            && is_on_top;
+
+        if group {
+            log::trace!("History Group Change content: Entry ID: {id}");
+            self.last_content_change = Some((id, now));
+            self.latest_grouped = true;
+            return;
+        }
+
         let change = Change::EntryContent {
-            id: entry_before_change.id,
+            id,
             content: entry_before_change.content.to_owned(),
         };
 
         self.add_to_stack(change, target);
+
+        if matches!(target, HistoryStack::Undo) {
+            self.last_content_change = Some((id, now));
+        }
+    }
+
+    /// Register changes on the pin state of an Entry on the corresponding stack of the
+    /// [`HistoryStack`]
+    pub fn register_change_pin(&mut self, target: HistoryStack, entry_before_change: &Entry) {
+        log::trace!(
+            "History Register Change pin: Entry ID: {}",
+            entry_before_change.id
+        );
+        let change = Change::EntryPin {
+            id: entry_before_change.id,
+            pinned_at: entry_before_change.pinned_at,
+        };
+
+        self.add_to_stack(change, target);
+    }
+
+    /// Register changes on the color of an Entry on the corresponding stack of the
+    /// [`HistoryStack`]
+    pub fn register_change_color(&mut self, target: HistoryStack, entry_before_change: &Entry) {
+        log::trace!(
+            "History Register Change color: Entry ID: {}",
+            entry_before_change.id
+        );
+        let change = Change::EntryColor {
+            id: entry_before_change.id,
+            color: entry_before_change.color.clone(),
+        };
+
+        self.add_to_stack(change, target);
+    }
+
+    /// Marks the current state in the history as the last explicit save point.
+    pub fn mark_save_point(&mut self) {
+        self.save_position = Some(self.undo_stack.len());
+    }
+
+    /// Checks if applying undo would move the history back past the save point.
+    pub fn undo_crosses_save_point(&self) -> bool {
+        self.jump_crosses_save_point(HistoryStack::Undo, 1)
+    }
+
+    /// Checks if applying redo would move the history forward past the save point.
+    pub fn redo_crosses_save_point(&self) -> bool {
+        self.jump_crosses_save_point(HistoryStack::Redo, 1)
+    }
+
+    /// Checks if applying undo or redo according to the given [`HistoryStack`] the given count
+    /// of times would move the history past the save point.
+    pub fn jump_crosses_save_point(&self, target: HistoryStack, count: usize) -> bool {
+        let Some(save_position) = self.save_position else {
+            return false;
+        };
+
+        let undo_len = self.undo_stack.len();
+        match target {
+            HistoryStack::Undo => {
+                let count = count.min(undo_len);
+                undo_len - count < save_position && save_position <= undo_len
+            }
+            HistoryStack::Redo => {
+                let count = count.min(self.redo_stack.len());
+                undo_len <= save_position && save_position < undo_len + count
+            }
+        }
+    }
+
+    /// Begins collecting the changes registered on the stack of the given [`HistoryStack`] into
+    /// one batch change, so they can be undone and redone at once.
+    pub fn begin_batch(&mut self, target: HistoryStack) {
+        debug_assert!(self.batch.is_none(), "batches can't be nested");
+        self.stop_grouping();
+        self.batch = Some((target, Vec::new()));
+    }
+
+    /// Ends the current batch, adding its changes as one change taking a single place on its
+    /// stack.
+    pub fn end_batch(&mut self) {
+        let Some((target, mut changes)) = self.batch.take() else {
+            return;
+        };
+
+        changes.reverse();
+        let change = match changes.len() {
+            0 => return,
+            1 => changes.pop().expect("batch has one change"),
+            _ => Change::Batch(changes),
+        };
+
+        self.add_to_stack(change, target);
+    }
+
+    /// Changes the size limit of each stack, dropping the oldest changes from the bottom of the
+    /// stacks if they exceed the new limit. A limit of zero disables the history.
+    pub fn set_limit(&mut self, stacks_limit: usize) {
+        self.stacks_limit = stacks_limit;
+
+        let undo_len = self.undo_stack.len();
+        self.undo_stack.truncate(stacks_limit);
+        self.redo_stack.truncate(stacks_limit);
+
+        // Dropping from the bottom of the undo stack shifts the save marker too.
+        let dropped = undo_len - self.undo_stack.len();
+        self.save_position = self.save_position.and_then(|pos| pos.checked_sub(dropped));
+    }
+
+    /// Returns the changes of the history stacks to be persisted between runs, using the given
+    /// function to get the stamps of the entries they refer to by their ids.
+    pub fn to_persisted(&self, get_stamp: impl Fn(u32) -> Option<EntryStamp>) -> PersistedHistory {
+        let entries = self
+            .undo_stack
+            .iter()
+            .chain(self.redo_stack.iter())
+            .flat_map(Change::entry_ids)
+            .filter_map(|id| get_stamp(id).map(|stamp| (id, stamp)))
+            .collect();
+
+        PersistedHistory {
+            undo: self.undo_stack.iter().cloned().collect(),
+            redo: self.redo_stack.iter().cloned().collect(),
+            entries,
+        }
+    }
+
+    /// Replaces the history stacks with the given persisted changes, keeping the stacks within
+    /// their limit. Changes are dropped if the entries they refer to don't exist anymore or
+    /// their stamps, given by the function, differ from the persisted ones since the ids have
+    /// been reused by other entries.
+    pub fn restore(
+        &mut self,
+        persisted: PersistedHistory,
+        get_stamp: impl Fn(u32) -> Option<EntryStamp>,
+    ) {
+        let PersistedHistory {
+            undo,
+            redo,
+            entries,
+        } = persisted;
+
+        let entry_exists = |id: u32| {
+            entries
+                .get(&id)
+                .is_some_and(|stamp| get_stamp(id).as_ref() == Some(stamp))
+        };
+
+        let restore_stack = |changes: Vec<Change>| -> VecDeque<Change> {
+            changes
+                .into_iter()
+                .filter_map(|change| change.drop_stale(&entry_exists))
+                .take(self.stacks_limit)
+                .collect()
+        };
+
+        self.undo_stack = restore_stack(undo);
+        self.redo_stack = restore_stack(redo);
+        self.save_position = None;
+        self.stop_grouping();
+    }
+
+    /// Gets the changes on the stack of the given [`HistoryStack`] ordered from the latest to
+    /// the earliest.
+    pub fn changes(&self, target: HistoryStack) -> impl Iterator<Item = &Change> {
+        match target {
+            HistoryStack::Undo => self.undo_stack.iter(),
+            HistoryStack::Redo => self.redo_stack.iter(),
+        }
+    }
+
+    /// Drops the latest Change from the stack of the given [`HistoryStack`], used when the
+    /// registered change couldn't be applied.
+    pub fn discard_latest(&mut self, target: HistoryStack) {
+        // Grouped changes aren't added to the stack, keeping the change they are grouped into.
+        if std::mem::take(&mut self.latest_grouped) {
+            return;
+        }
+
+        if let Some((batch_target, changes)) = self.batch.as_mut() {
+            if *batch_target == target {
+                changes.pop();
+                return;
+            }
+        }
+
+        match target {
+            HistoryStack::Undo => self.undo_stack.pop_front(),
+            HistoryStack::Redo => self.redo_stack.pop_front(),
+        };
     }
 
     /// Pops the latest undo Change from its stack if available
@@ -79,20 +359,56 @@ impl HistoryManager {
         self.undo_stack.pop_front()
     }
 
-    /// Pops the latest redo Change from its stack if available
+    /// Pops the latest redo Change from its stack if available. [`Self::end_redo()`] must be
+    /// called after applying it.
     pub fn pop_redo(&mut self) -> Option<Change> {
-        self.redo_stack.pop_front()
+        let change = self.redo_stack.pop_front();
+        self.redo_in_progress = change.is_some();
+
+        change
+    }
+
+    /// Marks the end of applying the change popped with [`Self::pop_redo()`].
+    pub fn end_redo(&mut self) {
+        self.redo_in_progress = false;
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 /// Represents the types of history targets within the [`HistoryManager`]
 pub enum HistoryStack {
     Undo,
     Redo,
 }
 
-#[derive(Debug, Clone)]
+/// Changes of the history stacks persisted in the app state, ordered from the latest to the
+/// earliest.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct PersistedHistory {
+    pub undo: Vec<Change>,
+    pub redo: Vec<Change>,
+    /// Stamps of the entries which the changes refer to at the time of persisting.
+    #[serde(default)]
+    pub entries: BTreeMap<u32, EntryStamp>,
+}
+
+/// Identifies the state of an entry beyond its id, since ids can be reused by other entries.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntryStamp {
+    pub date: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<&Entry> for EntryStamp {
+    fn from(entry: &Entry) -> Self {
+        Self {
+            date: entry.date,
+            updated_at: entry.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 /// Represents a change to the entries and infos about their previous states.
 pub enum Change {
     /// Entry added with the given id
@@ -103,9 +419,84 @@ pub enum Change {
     EntryAttribute(Box<EntryAttributes>),
     /// Entry content changed. It contains the content before the change.
     EntryContent { id: u32, content: String },
+    /// Entry pinned or unpinned. It contains the pin time before the change.
+    EntryPin {
+        id: u32,
+        pinned_at: Option<DateTime<Utc>>,
+    },
+    /// Entry color changed. It contains the color before the change.
+    EntryColor { id: u32, color: Option<String> },
+    /// Multiple changes applied at once, ordered from the latest to the earliest.
+    Batch(Vec<Change>),
+}
+
+impl Change {
+    /// Describes what applying the change does in a human-readable way, using the given
+    /// function to get the current titles of the entries by their ids.
+    pub fn describe(&self, get_title: &impl Fn(u32) -> Option<String>) -> String {
+        let title = |id: u32| {
+            get_title(id)
+                .map(|title| format!("'{title}'"))
+                .unwrap_or_else(|| format!("journal #{id}"))
+        };
+
+        match self {
+            Change::AddEntry { id } => format!("Delete {}", title(*id)),
+            Change::RemoveEntry(entry) => format!("Restore '{}'", entry.title),
+            Change::EntryAttribute(attr) => format!("Restore attributes of {}", title(attr.id)),
+            Change::EntryContent { id, .. } => format!("Restore content of {}", title(*id)),
+            Change::EntryPin { id, pinned_at } => match pinned_at {
+                Some(_) => format!("Pin {}", title(*id)),
+                None => format!("Unpin {}", title(*id)),
+            },
+            Change::EntryColor { id, .. } => format!("Restore color of {}", title(*id)),
+            Change::Batch(changes) => match changes.as_slice() {
+                [] => String::from("No changes"),
+                [change] => change.describe(get_title),
+                [change, rest @ ..] => {
+                    format!("{} and {} more", change.describe(get_title), rest.len())
+                }
+            },
+        }
+    }
+
+    /// Returns the ids of the existing entries which the change refers to.
+    fn entry_ids(&self) -> Vec<u32> {
+        match self {
+            Change::RemoveEntry(_) => Vec::new(),
+            Change::Batch(changes) => changes.iter().flat_map(Change::entry_ids).collect(),
+            Change::EntryAttribute(attr) => vec![attr.id],
+            Change::AddEntry { id }
+            | Change::EntryContent { id, .. }
+            | Change::EntryPin { id, .. }
+            | Change::EntryColor { id, .. } => vec![*id],
+        }
+    }
+
+    /// Returns the change if the entry it refers to still exists, keeping only the valid
+    /// changes of batches.
+    fn drop_stale(self, entry_exists: &impl Fn(u32) -> bool) -> Option<Self> {
+        let id = match self {
+            Change::RemoveEntry(_) => return Some(self),
+            Change::Batch(changes) => {
+                let changes: Vec<Change> = changes
+                    .into_iter()
+                    .filter_map(|change| change.drop_stale(entry_exists))
+                    .collect();
+                return (!changes.is_empty()).then_some(Change::Batch(changes));
+            }
+            Change::EntryAttribute(ref attr) => attr.id,
+            Change::AddEntry { id }
+            | Change::EntryContent { id, .. }
+            | Change::EntryPin { id, .. }
+            | Change::EntryColor { id, .. } => id,
+        };
+
+        entry_exists(id).then_some(self)
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 /// Contains the changes of attributes on an [`Entry`] to be saved in the history stacks
 pub struct EntryAttributes {
     pub id: u32,
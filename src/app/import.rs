@@ -0,0 +1,393 @@
+use anyhow::{anyhow, bail};
+use backend::EntryDraft;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use clap::ValueEnum;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Fields of the imported entries which contain dates and get normalized before parsing.
+const DATE_FIELDS: [&str; 2] = ["date", "updated_at"];
+
+/// Formats of dates with time and zone accepted besides RFC3339.
+const ZONED_FORMATS: &[&str] = &["%Y-%m-%d %H:%M:%S%:z", "%Y-%m-%d %H:%M:%S %z"];
+
+/// Formats of dates with time but without zone, which are considered to be in UTC.
+const NAIVE_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%d %H:%M",
+];
+
+/// Delimiter of the YAML front matter in Markdown files.
+const FRONT_MATTER_DELIMITER: &str = "---";
+/// Maximum level of Markdown headings.
+const MAX_HEADING_LEVEL: usize = 6;
+
+/// Represents how imported journals matching existing journals by their title and date are
+/// handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
+pub enum ImportStrategy {
+    #[default]
+    /// Add all imported journals even if they match existing ones.
+    AddAll,
+    /// Skip the imported journals matching existing ones.
+    SkipDuplicates,
+    /// Overwrite the existing journals with the content and metadata of the matching imported
+    /// journals.
+    Overwrite,
+}
+
+/// Entries data transfer object with raw entries, used to parse each entry on its own
+/// so invalid entries don't fail the whole import.
+#[derive(Debug, Deserialize)]
+pub struct RawEntriesDTO {
+    pub version: u16,
+    pub entries: Vec<Value>,
+}
+
+/// Parses the given date text tolerating the common formats, normalizing it to UTC.
+/// Dates without zone are considered to be in UTC and dates without time start at midnight.
+pub fn parse_import_date(text: &str) -> anyhow::Result<DateTime<Utc>> {
+    let text = text.trim();
+
+    if let Ok(date) = DateTime::parse_from_rfc3339(text) {
+        return Ok(date.to_utc());
+    }
+
+    if let Some(date) = ZONED_FORMATS
+        .iter()
+        .find_map(|format| DateTime::parse_from_str(text, format).ok())
+    {
+        return Ok(date.to_utc());
+    }
+
+    if let Some(date) = NAIVE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+    {
+        return Ok(date.and_utc());
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(text, "%Y-%m-%d") {
+        return Ok(date
+            .and_hms_opt(0, 0, 0)
+            .expect("Midnight is a valid time")
+            .and_utc());
+    }
+
+    bail!(
+        "Invalid date '{text}'. Supported formats: RFC3339, 'YYYY-MM-DD' or 'YYYY-MM-DD HH:MM[:SS]' with optional zone"
+    )
+}
+
+/// Parses an entry draft from the given raw JSON value, normalizing its dates first.
+pub fn parse_import_draft(mut value: Value) -> anyhow::Result<EntryDraft> {
+    for field in DATE_FIELDS {
+        let Some(date_value) = value.get_mut(field) else {
+            continue;
+        };
+
+        if let Some(text) = date_value.as_str() {
+            let date = parse_import_date(text)?;
+            *date_value = Value::String(date.to_rfc3339());
+        }
+    }
+
+    serde_json::from_value(value).map_err(|err| anyhow!("{err}"))
+}
+
+/// Parses an entry draft from the given Markdown file content with optional YAML front matter.
+/// The title is taken from the front matter, the first heading or the given file stem in this
+/// order, and the date from the front matter or the given modification date of the file.
+pub fn parse_markdown_draft(
+    text: &str,
+    file_stem: &str,
+    modified: DateTime<Utc>,
+) -> anyhow::Result<EntryDraft> {
+    let (front_matter, body) = split_front_matter(text);
+
+    let mut title = None;
+    let mut date = None;
+    let mut priority = None;
+    let mut tags = Vec::new();
+
+    let mut lines = front_matter.unwrap_or_default().lines().peekable();
+    while let Some(line) = lines.next() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+
+        let value = value.trim();
+        match key.trim() {
+            "title" => title = Some(unquote_yaml(value)),
+            "date" => date = Some(parse_import_date(&unquote_yaml(value))?),
+            "priority" if !value.is_empty() => {
+                let parsed = value
+                    .parse()
+                    .map_err(|_| anyhow!("Invalid priority '{value}'"))?;
+                priority = Some(parsed);
+            }
+            "tags" if value.starts_with('[') => {
+                let inner = value
+                    .strip_prefix('[')
+                    .and_then(|value| value.strip_suffix(']'))
+                    .ok_or_else(|| anyhow!("Invalid tags '{value}'"))?;
+                tags = split_yaml_list(inner);
+            }
+            "tags" => {
+                // Tags as YAML block list on the following lines
+                while let Some(item) = lines.next_if(|line| line.trim_start().starts_with('-')) {
+                    tags.push(unquote_yaml(item.trim_start()[1..].trim()));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let content = strip_line_break(body).unwrap_or(body).to_owned();
+
+    let title = title
+        .filter(|title| !title.is_empty())
+        .or_else(|| content.lines().find_map(parse_heading))
+        .filter(|title| !title.is_empty())
+        .unwrap_or_else(|| file_stem.to_owned());
+
+    let mut draft = EntryDraft::new(date.unwrap_or(modified), title, tags, priority);
+    draft.content = content;
+
+    Ok(draft)
+}
+
+/// Returns the text of the given line if it's a Markdown heading, which starts with up to six
+/// `#` characters followed by a space. Lines like `#tag` aren't headings.
+fn parse_heading(line: &str) -> Option<String> {
+    let line = line.trim_start();
+    let text = line.trim_start_matches('#');
+    let level = line.len() - text.len();
+
+    let is_heading = (1..=MAX_HEADING_LEVEL).contains(&level)
+        && (text.is_empty() || text.starts_with(char::is_whitespace));
+
+    is_heading.then(|| text.trim().trim_end_matches('#').trim_end().to_owned())
+}
+
+/// Removes the line break at the start of the given text, supporting Windows line endings.
+fn strip_line_break(text: &str) -> Option<&str> {
+    text.strip_prefix("\r\n")
+        .or_else(|| text.strip_prefix('\n'))
+}
+
+/// Splits the given Markdown text into its front matter if exists and its body.
+fn split_front_matter(text: &str) -> (Option<&str>, &str) {
+    let Some(rest) = text
+        .strip_prefix(FRONT_MATTER_DELIMITER)
+        .and_then(strip_line_break)
+    else {
+        return (None, text);
+    };
+
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == FRONT_MATTER_DELIMITER {
+            return (Some(&rest[..offset]), &rest[offset + line.len()..]);
+        }
+        offset += line.len();
+    }
+
+    // Front matter isn't closed so the whole text is considered as body.
+    (None, text)
+}
+
+/// Splits the items of an inline YAML list without its brackets.
+fn split_yaml_list(inner: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut current = String::new();
+    let mut quote = None;
+    let mut escaped = false;
+
+    for ch in inner.chars() {
+        match (quote, ch) {
+            (Some('"'), _) if escaped => escaped = false,
+            (Some('"'), '\\') => escaped = true,
+            (Some(q), ch) if ch == q => quote = None,
+            (None, '"' | '\'') => quote = Some(ch),
+            (None, ',') => {
+                items.push(unquote_yaml(current.trim()));
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(ch);
+    }
+
+    if !current.trim().is_empty() {
+        items.push(unquote_yaml(current.trim()));
+    }
+
+    items
+}
+
+/// Removes the quotes of the given YAML scalar, resolving the escaped characters in double
+/// quoted values.
+fn unquote_yaml(value: &str) -> String {
+    if let Some(inner) = value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+    {
+        let mut unquoted = String::with_capacity(inner.len());
+        let mut chars = inner.chars();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\\' => unquoted.extend(chars.next()),
+                ch => unquoted.push(ch),
+            }
+        }
+        return unquoted;
+    }
+
+    if let Some(inner) = value
+        .strip_prefix('\'')
+        .and_then(|value| value.strip_suffix('\''))
+    {
+        return inner.replace("''", "'");
+    }
+
+    value.to_owned()
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32, s: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, s).unwrap()
+    }
+
+    #[test]
+    fn parse_supported_formats() {
+        let cases = [
+            ("2024-01-02T10:20:30Z", utc(2024, 1, 2, 10, 20, 30)),
+            ("2024-01-02T10:20:30+02:00", utc(2024, 1, 2, 8, 20, 30)),
+            ("2024-01-02 10:20:30+02:00", utc(2024, 1, 2, 8, 20, 30)),
+            ("2024-01-02 10:20:30 -0100", utc(2024, 1, 2, 11, 20, 30)),
+            ("2024-01-02T10:20:30", utc(2024, 1, 2, 10, 20, 30)),
+            ("2024-01-02T10:20", utc(2024, 1, 2, 10, 20, 0)),
+            ("2024-01-02 10:20:30", utc(2024, 1, 2, 10, 20, 30)),
+            ("2024-01-02 10:20", utc(2024, 1, 2, 10, 20, 0)),
+            ("2024-01-02", utc(2024, 1, 2, 0, 0, 0)),
+            (" 2024-01-02 ", utc(2024, 1, 2, 0, 0, 0)),
+        ];
+
+        for (text, expected) in cases {
+            let date = parse_import_date(text)
+                .unwrap_or_else(|err| panic!("Parsing '{text}' failed: {err}"));
+            assert_eq!(date, expected, "Input: '{text}'");
+        }
+    }
+
+    #[test]
+    fn reject_invalid_date() {
+        let err = parse_import_date("last tuesday").unwrap_err();
+        assert!(
+            err.to_string().contains("Invalid date 'last tuesday'"),
+            "Unclear error: {err}"
+        );
+
+        assert!(parse_import_date("2024-13-45").is_err());
+    }
+
+    #[test]
+    fn parse_draft_normalizes_dates() {
+        let value = serde_json::json!({
+            "date": "2024-01-02",
+            "title": "Title",
+            "updated_at": "2024-01-03 10:00",
+        });
+
+        let draft = parse_import_draft(value).unwrap();
+        assert_eq!(draft.date, utc(2024, 1, 2, 0, 0, 0));
+        assert_eq!(draft.updated_at, Some(utc(2024, 1, 3, 10, 0, 0)));
+
+        let value = serde_json::json!({ "date": "not a date", "title": "Title" });
+        assert!(parse_import_draft(value).is_err());
+    }
+
+    #[test]
+    fn parse_markdown_with_front_matter() {
+        let text = "---\ntitle: \"Say \\\"Hi\\\"\"\ndate: 2024-03-07\npriority: 2\n\
+                    tags: [\"Tag 1\", Tag 2]\n---\n\n# Heading\nContent";
+        let modified = utc(2025, 1, 1, 0, 0, 0);
+
+        let draft = parse_markdown_draft(text, "file-name", modified).unwrap();
+
+        assert_eq!(draft.title, "Say \"Hi\"");
+        assert_eq!(draft.date, utc(2024, 3, 7, 0, 0, 0));
+        assert_eq!(draft.priority, Some(2));
+        assert_eq!(
+            draft.tags,
+            vec![String::from("Tag 1"), String::from("Tag 2")]
+        );
+        assert_eq!(draft.content, "# Heading\nContent");
+
+        // Block list of tags and title from the first heading
+        let text = "---\ntags:\n  - work\n  - 'home'\n---\n## Weekly Review\nDone";
+        let draft = parse_markdown_draft(text, "file-name", modified).unwrap();
+
+        assert_eq!(draft.title, "Weekly Review");
+        assert_eq!(draft.date, modified);
+        assert_eq!(draft.tags, vec![String::from("work"), String::from("home")]);
+        assert_eq!(draft.content, "## Weekly Review\nDone");
+
+        let text = "---\ndate: someday\n---\nContent";
+        assert!(parse_markdown_draft(text, "file-name", modified).is_err());
+    }
+
+    #[test]
+    fn parse_markdown_without_front_matter() {
+        let modified = utc(2025, 1, 1, 10, 0, 0);
+
+        let draft =
+            parse_markdown_draft("Plain note\nSecond line", "plain-note", modified).unwrap();
+
+        assert_eq!(draft.title, "plain-note");
+        assert_eq!(draft.date, modified);
+        assert!(draft.tags.is_empty());
+        assert_eq!(draft.priority, None);
+        assert_eq!(draft.content, "Plain note\nSecond line");
+
+        // Not closed front matter is part of the content
+        let text = "---\ntitle: Open\nContent";
+        let draft = parse_markdown_draft(text, "open", modified).unwrap();
+        assert_eq!(draft.title, "open");
+        assert_eq!(draft.content, text);
+    }
+
+    #[test]
+    fn parse_markdown_headings_only() {
+        let modified = utc(2025, 1, 1, 10, 0, 0);
+
+        // Tags and lines without space after the hashes aren't headings
+        let text = "#tag\n####### Too deep\n### Real heading ###\nContent";
+        let draft = parse_markdown_draft(text, "file-name", modified).unwrap();
+        assert_eq!(draft.title, "Real heading");
+
+        let draft = parse_markdown_draft("#work #home\nContent", "file-name", modified).unwrap();
+        assert_eq!(draft.title, "file-name");
+    }
+
+    #[test]
+    fn parse_markdown_windows_line_endings() {
+        let text = "---\r\ntitle: Windows\r\ntags: [work]\r\n---\r\n\r\nContent\r\n";
+        let modified = utc(2025, 1, 1, 10, 0, 0);
+
+        let draft = parse_markdown_draft(text, "file-name", modified).unwrap();
+
+        assert_eq!(draft.title, "Windows");
+        assert_eq!(draft.tags, vec![String::from("work")]);
+        assert_eq!(draft.content, "Content\r\n");
+    }
+}
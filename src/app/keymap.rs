@@ -1,7 +1,14 @@
-use std::fmt::{Display, Formatter};
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
 
+use anyhow::bail;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
+use crate::settings::KeybindingConflicts;
+
 use super::ui::UICommand;
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq)]
@@ -78,6 +85,70 @@ impl Display for Input {
     }
 }
 
+impl FromStr for Input {
+    type Err = String;
+
+    /// Parses keys in the same format they are displayed in, like `x`, `Enter` or `<Ctrl-e>`.
+    /// Uppercase characters imply the shift modifier.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid key '{s}'");
+
+        let mut modifiers = KeyModifiers::NONE;
+        let mut key_text = match s.strip_prefix('<').and_then(|s| s.strip_suffix('>')) {
+            Some(inner) if !inner.is_empty() => inner,
+            _ => s,
+        };
+
+        loop {
+            if let Some(rest) = key_text.strip_prefix("Ctrl-") {
+                modifiers |= KeyModifiers::CONTROL;
+                key_text = rest;
+            } else if let Some(rest) = key_text.strip_prefix("Shift-") {
+                modifiers |= KeyModifiers::SHIFT;
+                key_text = rest;
+            } else if let Some(rest) = key_text.strip_prefix("Alt-") {
+                modifiers |= KeyModifiers::ALT;
+                key_text = rest;
+            } else {
+                break;
+            }
+        }
+
+        let key_code = match key_text {
+            "Backspace" | "<Backspace>" => KeyCode::Backspace,
+            "Enter" => KeyCode::Enter,
+            "Left" => KeyCode::Left,
+            "Right" => KeyCode::Right,
+            "Up" => KeyCode::Up,
+            "Down" => KeyCode::Down,
+            "Home" => KeyCode::Home,
+            "End" => KeyCode::End,
+            "PageUp" => KeyCode::PageUp,
+            "PageDown" => KeyCode::PageDown,
+            "Tab" => KeyCode::Tab,
+            "BackTab" => KeyCode::BackTab,
+            "Delete" => KeyCode::Delete,
+            "Insert" => KeyCode::Insert,
+            "Esc" => KeyCode::Esc,
+            "Space" | "<Space>" => KeyCode::Char(' '),
+            text => {
+                let mut chars = text.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(ch), None) => {
+                        if ch.is_uppercase() {
+                            modifiers |= KeyModifiers::SHIFT;
+                        }
+                        KeyCode::Char(ch)
+                    }
+                    _ => return Err(invalid()),
+                }
+            }
+        };
+
+        Ok(Self::new(key_code, modifiers))
+    }
+}
+
 #[derive(Debug)]
 pub struct Keymap {
     pub key: Input,
@@ -90,6 +161,115 @@ impl Keymap {
     }
 }
 
+/// Overrides the keys of the commands in the given keymaps with the keys from the settings,
+/// where the overrides map the command names in snake case to their new keys, replacing all
+/// their default keys.
+///
+/// Global keymaps are checked before the others, so keys of global commands must not be used
+/// by other commands in any keymaps and vice versa. Overrides with invalid keys, unknown
+/// commands or conflicting keys keep the default keys of their commands and are returned as
+/// warnings, or fail according to the given conflicts behavior.
+pub fn apply_keybinding_overrides(
+    global_keymaps: &mut Vec<Keymap>,
+    other_keymaps: &mut [&mut Vec<Keymap>],
+    overrides: &BTreeMap<String, String>,
+    conflicts: KeybindingConflicts,
+) -> anyhow::Result<Vec<String>> {
+    let mut warnings = Vec::new();
+
+    for (command_name, key_text) in overrides {
+        let result = key_text.parse().and_then(|key| {
+            apply_keybinding_override(global_keymaps, other_keymaps, command_name, key)
+        });
+
+        if let Err(err) = result {
+            let msg = format!("Keybinding for '{command_name}': {err}");
+            match conflicts {
+                KeybindingConflicts::Warn => warnings.push(msg),
+                KeybindingConflicts::Fail => bail!(msg),
+            }
+        }
+    }
+
+    Ok(warnings)
+}
+
+fn apply_keybinding_override(
+    global_keymaps: &mut Vec<Keymap>,
+    other_keymaps: &mut [&mut Vec<Keymap>],
+    command_name: &str,
+    key: Input,
+) -> Result<(), String> {
+    let command = std::iter::once(&*global_keymaps)
+        .chain(other_keymaps.iter().map(|keymaps| &**keymaps))
+        .flatten()
+        .map(|keymap| keymap.command)
+        .find(|command| get_command_name(command).is_some_and(|name| name == command_name))
+        .ok_or_else(|| String::from("Unknown command"))?;
+
+    let conflicting_cmd = |keymaps: &Vec<Keymap>| {
+        keymaps
+            .iter()
+            .find(|keymap| keymap.key == key && keymap.command != command)
+            .map(|keymap| keymap.command)
+    };
+
+    let has_command =
+        |keymaps: &Vec<Keymap>| keymaps.iter().any(|keymap| keymap.command == command);
+
+    // Global commands can conflict with all keymaps, while the others can conflict with the
+    // global keymaps and the keymaps they are used in only.
+    let in_global = has_command(global_keymaps);
+    let conflict = std::iter::once(&*global_keymaps)
+        .chain(
+            other_keymaps
+                .iter()
+                .map(|keymaps| &**keymaps)
+                .filter(|keymaps| in_global || has_command(keymaps)),
+        )
+        .find_map(conflicting_cmd);
+
+    if let Some(other_cmd) = conflict {
+        return Err(format!(
+            "Key '{key}' is already used by '{}'",
+            get_command_name(&other_cmd).unwrap_or_else(|| format!("{other_cmd:?}"))
+        ));
+    }
+
+    std::iter::once(global_keymaps)
+        .chain(other_keymaps.iter_mut().map(|keymaps| &mut **keymaps))
+        .filter(|keymaps| has_command(keymaps))
+        .for_each(|keymaps| {
+            keymaps.retain(|keymap| keymap.command != command);
+            keymaps.push(Keymap::new(key, command));
+        });
+
+    Ok(())
+}
+
+/// Returns the name of the given command in snake case to refer to it in the settings.
+/// Commands with parameters don't have names since they can't be overridden.
+fn get_command_name(command: &UICommand) -> Option<String> {
+    let debug_name = format!("{command:?}");
+    if !debug_name.chars().all(char::is_alphanumeric) {
+        return None;
+    }
+
+    let mut name = String::with_capacity(debug_name.len() + 4);
+    for (idx, ch) in debug_name.chars().enumerate() {
+        if ch.is_uppercase() {
+            if idx > 0 {
+                name.push('_');
+            }
+            name.extend(ch.to_lowercase());
+        } else {
+            name.push(ch);
+        }
+    }
+
+    Some(name)
+}
+
 pub(crate) fn get_global_keymaps() -> Vec<Keymap> {
     vec![
         Keymap::new(
@@ -107,6 +287,10 @@ pub(crate) fn get_global_keymaps() -> Vec<Keymap> {
             Input::new(KeyCode::Char('h'), KeyModifiers::NONE),
             UICommand::ShowHelp,
         ),
+        Keymap::new(
+            Input::new(KeyCode::Char(','), KeyModifiers::NONE),
+            UICommand::ShowSettings,
+        ),
         Keymap::new(
             Input::new(KeyCode::Char('l'), KeyModifiers::CONTROL),
             UICommand::CycleFocusedControlForward,
@@ -159,11 +343,15 @@ pub(crate) fn get_global_keymaps() -> Vec<Keymap> {
             Input::new(KeyCode::Char('U'), KeyModifiers::SHIFT),
             UICommand::Redo,
         ),
+        Keymap::new(
+            Input::new(KeyCode::Char('H'), KeyModifiers::SHIFT),
+            UICommand::ShowHistory,
+        ),
     ]
 }
 
 pub fn get_entries_list_keymaps() -> Vec<Keymap> {
-    vec![
+    let mut keymaps = vec![
         Keymap::new(
             Input::new(KeyCode::Up, KeyModifiers::NONE),
             UICommand::SelectedPrevEntry,
@@ -184,6 +372,10 @@ pub fn get_entries_list_keymaps() -> Vec<Keymap> {
             Input::new(KeyCode::Char('n'), KeyModifiers::NONE),
             UICommand::CreateEntry,
         ),
+        Keymap::new(
+            Input::new(KeyCode::Char('N'), KeyModifiers::SHIFT),
+            UICommand::CreateQuickNote,
+        ),
         Keymap::new(
             Input::new(KeyCode::Char('e'), KeyModifiers::NONE),
             UICommand::EditCurrentEntry,
@@ -204,6 +396,14 @@ pub fn get_entries_list_keymaps() -> Vec<Keymap> {
             Input::new(KeyCode::Char('>'), KeyModifiers::NONE),
             UICommand::ExportEntryContent,
         ),
+        Keymap::new(
+            Input::new(KeyCode::Char('E'), KeyModifiers::SHIFT),
+            UICommand::ExportEntryDocument,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Char('Y'), KeyModifiers::SHIFT),
+            UICommand::CopyEntriesMarkdown,
+        ),
         Keymap::new(
             Input::new(KeyCode::Char('y'), KeyModifiers::NONE),
             UICommand::EditInExternalEditor,
@@ -220,6 +420,22 @@ pub fn get_entries_list_keymaps() -> Vec<Keymap> {
             Input::new(KeyCode::Char('x'), KeyModifiers::NONE),
             UICommand::ResetFilter,
         ),
+        Keymap::new(
+            Input::new(KeyCode::Char('S'), KeyModifiers::SHIFT),
+            UICommand::ShowSavedFilters,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Char('/'), KeyModifiers::NONE),
+            UICommand::ShowSearch,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Char('n'), KeyModifiers::CONTROL),
+            UICommand::SearchNextMatch,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Char('p'), KeyModifiers::CONTROL),
+            UICommand::SearchPrevMatch,
+        ),
         Keymap::new(
             Input::new(KeyCode::Char('a'), KeyModifiers::NONE),
             UICommand::ShowFuzzyFind,
@@ -232,6 +448,10 @@ pub fn get_entries_list_keymaps() -> Vec<Keymap> {
             Input::new(KeyCode::Char('o'), KeyModifiers::NONE),
             UICommand::ShowSortOptions,
         ),
+        Keymap::new(
+            Input::new(KeyCode::Char('I'), KeyModifiers::SHIFT),
+            UICommand::ShowStats,
+        ),
         Keymap::new(
             Input::new(KeyCode::Home, KeyModifiers::NONE),
             UICommand::GoToTopEntry,
@@ -248,6 +468,92 @@ pub fn get_entries_list_keymaps() -> Vec<Keymap> {
             Input::new(KeyCode::PageDown, KeyModifiers::NONE),
             UICommand::PageDownEntries,
         ),
+        Keymap::new(
+            Input::new(KeyCode::Char('T'), KeyModifiers::SHIFT),
+            UICommand::ToggleTagsSidebar,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Char('D'), KeyModifiers::SHIFT),
+            UICommand::CycleListDensity,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Char('R'), KeyModifiers::SHIFT),
+            UICommand::RemoveEntryTags,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Char('+'), KeyModifiers::NONE),
+            UICommand::BumpEntryPriority,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Char('-'), KeyModifiers::NONE),
+            UICommand::LowerEntryPriority,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Char('p'), KeyModifiers::NONE),
+            UICommand::TogglePinEntry,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Char('C'), KeyModifiers::SHIFT),
+            UICommand::SetEntryColor,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Char('c'), KeyModifiers::NONE),
+            UICommand::DuplicateEntry,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Char('z'), KeyModifiers::NONE),
+            UICommand::ToggleFocusMode,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Char('['), KeyModifiers::NONE),
+            UICommand::GoBackViewedEntries,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Char(']'), KeyModifiers::NONE),
+            UICommand::GoForwardViewedEntries,
+        ),
+    ];
+
+    keymaps.extend((1..=9).map(|key: u8| {
+        Keymap::new(
+            Input::new(KeyCode::Char((b'0' + key).into()), KeyModifiers::NONE),
+            UICommand::ApplyFilterPreset(key),
+        )
+    }));
+
+    keymaps
+}
+
+pub(crate) fn get_tags_sidebar_keymaps() -> Vec<Keymap> {
+    vec![
+        Keymap::new(
+            Input::new(KeyCode::Char(' '), KeyModifiers::NONE),
+            UICommand::TagsSidebarToggleTag,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Enter, KeyModifiers::NONE),
+            UICommand::TagsSidebarToggleTag,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Char('c'), KeyModifiers::NONE),
+            UICommand::TagsSidebarSetColor,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Char('r'), KeyModifiers::NONE),
+            UICommand::TagsSidebarRenameTag,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Char('m'), KeyModifiers::NONE),
+            UICommand::TagsSidebarMergeTag,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Char('x'), KeyModifiers::NONE),
+            UICommand::ResetFilter,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Char('T'), KeyModifiers::SHIFT),
+            UICommand::ToggleTagsSidebar,
+        ),
     ]
 }
 
@@ -362,6 +668,22 @@ pub fn get_multi_select_keymaps() -> Vec<Keymap> {
             Input::new(KeyCode::Char('>'), KeyModifiers::NONE),
             UICommand::MulSelExportEntries,
         ),
+        Keymap::new(
+            Input::new(KeyCode::Char('t'), KeyModifiers::NONE),
+            UICommand::MulSelAddTag,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Char('T'), KeyModifiers::SHIFT),
+            UICommand::MulSelRemoveTag,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Char('p'), KeyModifiers::NONE),
+            UICommand::MulSelSetPriority,
+        ),
+        Keymap::new(
+            Input::new(KeyCode::Char('Y'), KeyModifiers::SHIFT),
+            UICommand::CopyEntriesMarkdown,
+        ),
         // Char '?' isn't recognized on windows
         #[cfg(not(target_os = "windows"))]
         Keymap::new(
@@ -375,3 +697,123 @@ pub fn get_multi_select_keymaps() -> Vec<Keymap> {
         ),
     ]
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn keys_of(keymaps: &[Keymap], command: UICommand) -> Vec<Input> {
+        keymaps
+            .iter()
+            .filter(|keymap| keymap.command == command)
+            .map(|keymap| keymap.key)
+            .collect()
+    }
+
+    fn apply(
+        global: &mut Vec<Keymap>,
+        list: &mut Vec<Keymap>,
+        overrides: &[(&str, &str)],
+        conflicts: KeybindingConflicts,
+    ) -> anyhow::Result<Vec<String>> {
+        let overrides = overrides
+            .iter()
+            .map(|(cmd, key)| (cmd.to_string(), key.to_string()))
+            .collect();
+        apply_keybinding_overrides(global, &mut [list], &overrides, conflicts)
+    }
+
+    #[test]
+    fn parse_keys() {
+        let parse = |text: &str| text.parse::<Input>().unwrap();
+
+        assert_eq!(
+            parse("x"),
+            Input::new(KeyCode::Char('x'), KeyModifiers::NONE)
+        );
+        assert_eq!(
+            parse("U"),
+            Input::new(KeyCode::Char('U'), KeyModifiers::SHIFT)
+        );
+        assert_eq!(
+            parse("Enter"),
+            Input::new(KeyCode::Enter, KeyModifiers::NONE)
+        );
+        assert_eq!(
+            parse("<Ctrl-e>"),
+            Input::new(KeyCode::Char('e'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(
+            parse("<Ctrl-Alt-Space>"),
+            Input::new(
+                KeyCode::Char(' '),
+                KeyModifiers::CONTROL | KeyModifiers::ALT
+            )
+        );
+
+        assert!("Ctrl-".parse::<Input>().is_err());
+        assert!("<Ctrl-xy>".parse::<Input>().is_err());
+    }
+
+    #[test]
+    fn override_keybindings() {
+        let mut global = get_global_keymaps();
+        let mut list = get_entries_list_keymaps();
+
+        let warnings = apply(
+            &mut global,
+            &mut list,
+            &[("show_filter", "<Ctrl-g>")],
+            KeybindingConflicts::Warn,
+        )
+        .unwrap();
+
+        assert!(warnings.is_empty());
+        assert_eq!(
+            keys_of(&list, UICommand::ShowFilter),
+            vec![Input::new(KeyCode::Char('g'), KeyModifiers::CONTROL)]
+        );
+    }
+
+    #[test]
+    fn conflicts_warn_keep_defaults() {
+        let mut global = get_global_keymaps();
+        let mut list = get_entries_list_keymaps();
+        let default_filter_keys = keys_of(&list, UICommand::ShowFilter);
+        let default_quit_keys = keys_of(&global, UICommand::Quit);
+
+        let warnings = apply(
+            &mut global,
+            &mut list,
+            &[
+                // Conflicts with the global command `Quit`
+                ("show_filter", "q"),
+                // Conflicts with command `ShowFilter` in the entries list
+                ("quit", "f"),
+                ("not_existing_command", "x"),
+                ("reload_all", "<Ctrl-xx>"),
+            ],
+            KeybindingConflicts::Warn,
+        )
+        .unwrap();
+
+        assert_eq!(warnings.len(), 4, "{warnings:?}");
+        assert_eq!(keys_of(&list, UICommand::ShowFilter), default_filter_keys);
+        assert_eq!(keys_of(&global, UICommand::Quit), default_quit_keys);
+    }
+
+    #[test]
+    fn conflicts_fail() {
+        let mut global = get_global_keymaps();
+        let mut list = get_entries_list_keymaps();
+
+        let result = apply(
+            &mut global,
+            &mut list,
+            &[("show_filter", "q")],
+            KeybindingConflicts::Fail,
+        );
+
+        assert!(result.is_err());
+    }
+}
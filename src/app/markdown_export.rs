@@ -0,0 +1,242 @@
+use std::collections::HashSet;
+
+use backend::Entry;
+
+/// Name of the index file linking to the exported journals.
+pub const INDEX_FILE_NAME: &str = "index.md";
+const FALLBACK_FILE_STEM: &str = "journal";
+
+/// Builds the Markdown files for the given entries, returning their names and contents with the
+/// index file as first item. Each entry gets its own file with front matter metadata, and the
+/// index links to them keeping the order of the given entries.
+pub fn build_markdown_files(entries: &[&Entry]) -> Vec<(String, String)> {
+    let mut used_stems = HashSet::from([String::from("index")]);
+    let mut index = String::from("# Journals\n\n");
+    let mut files = Vec::with_capacity(entries.len() + 1);
+
+    for entry in entries {
+        let file_name = format!("{}.md", unique_file_stem(&entry.title, &mut used_stems));
+
+        index.push_str(&format!(
+            "- [{}]({file_name}) - {}\n",
+            escape_markdown(&entry.title),
+            entry.date.format("%Y-%m-%d")
+        ));
+
+        files.push((file_name, entry_to_markdown_file(entry)));
+    }
+
+    files.insert(0, (INDEX_FILE_NAME.into(), index));
+
+    files
+}
+
+/// Formats the given entry as a Markdown section with its title as heading followed by its
+/// metadata and content.
+pub fn entry_to_markdown(entry: &Entry) -> String {
+    let mut markdown = format!("# {}\n\n", escape_markdown(&entry.title));
+
+    markdown.push_str(&format!("- Date: {}\n", entry.date.format("%Y-%m-%d")));
+    if let Some(priority) = entry.priority {
+        markdown.push_str(&format!("- Priority: {priority}\n"));
+    }
+    if !entry.tags.is_empty() {
+        markdown.push_str(&format!("- Tags: {}\n", entry.tags.join(", ")));
+    }
+
+    if !entry.content.is_empty() {
+        markdown.push('\n');
+        markdown.push_str(&entry.content);
+        if !entry.content.ends_with('\n') {
+            markdown.push('\n');
+        }
+    }
+
+    markdown
+}
+
+/// Formats the given entry as a standalone HTML page, keeping the content as preformatted text.
+pub fn entry_to_html(entry: &Entry) -> String {
+    let title = escape_html(&entry.title);
+
+    let mut html = String::from("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str(&format!(
+        "<title>{title}</title>\n</head>\n<body>\n<h1>{title}</h1>\n<ul>\n"
+    ));
+
+    html.push_str(&format!(
+        "<li>Date: {}</li>\n",
+        entry.date.format("%Y-%m-%d")
+    ));
+    if let Some(priority) = entry.priority {
+        html.push_str(&format!("<li>Priority: {priority}</li>\n"));
+    }
+    if !entry.tags.is_empty() {
+        let tags = escape_html(&entry.tags.join(", "));
+        html.push_str(&format!("<li>Tags: {tags}</li>\n"));
+    }
+    html.push_str("</ul>\n");
+
+    if !entry.content.is_empty() {
+        html.push_str(&format!("<pre>{}</pre>\n", escape_html(&entry.content)));
+    }
+
+    html.push_str("</body>\n</html>\n");
+
+    html
+}
+
+/// Escapes the characters in the given text which would be interpreted as HTML markup.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Converts the given title to a file stem containing lowercase alphanumeric characters,
+/// underscores and hyphens only.
+pub fn sanitize_file_stem(title: &str) -> String {
+    let mut stem = String::with_capacity(title.len());
+
+    for ch in title.chars().flat_map(char::to_lowercase) {
+        if ch.is_alphanumeric() || ch == '_' {
+            stem.push(ch);
+        } else if !stem.is_empty() && !stem.ends_with('-') {
+            stem.push('-');
+        }
+    }
+
+    let trimmed_len = stem.trim_end_matches('-').len();
+    stem.truncate(trimmed_len);
+
+    if stem.is_empty() {
+        stem.push_str(FALLBACK_FILE_STEM);
+    }
+
+    stem
+}
+
+/// Returns the sanitized file stem for the given title, appending a number to it if it's
+/// already used.
+fn unique_file_stem(title: &str, used_stems: &mut HashSet<String>) -> String {
+    let stem = sanitize_file_stem(title);
+
+    let unique = (1..)
+        .map(|num| match num {
+            1 => stem.clone(),
+            num => format!("{stem}-{num}"),
+        })
+        .find(|candidate| !used_stems.contains(candidate))
+        .expect("Infinite iterator must find unused stem");
+
+    used_stems.insert(unique.clone());
+
+    unique
+}
+
+/// Formats the given entry as a Markdown file with its metadata as YAML front matter.
+fn entry_to_markdown_file(entry: &Entry) -> String {
+    let mut markdown = String::from("---\n");
+
+    markdown.push_str(&format!("title: {}\n", quote_yaml(&entry.title)));
+    markdown.push_str(&format!("date: {}\n", entry.date.format("%Y-%m-%d")));
+    if let Some(priority) = entry.priority {
+        markdown.push_str(&format!("priority: {priority}\n"));
+    }
+    if !entry.tags.is_empty() {
+        let tags: Vec<String> = entry.tags.iter().map(|tag| quote_yaml(tag)).collect();
+        markdown.push_str(&format!("tags: [{}]\n", tags.join(", ")));
+    }
+
+    markdown.push_str("---\n");
+
+    if !entry.content.is_empty() {
+        markdown.push('\n');
+        markdown.push_str(&entry.content);
+    }
+
+    markdown
+}
+
+/// Wraps the given text in double quotes escaping it for YAML.
+fn quote_yaml(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Escapes the characters in the given text which would be interpreted as Markdown syntax.
+fn escape_markdown(text: &str) -> String {
+    const SPECIAL_CHARS: &[char] = &['\\', '`', '*', '_', '[', ']', '<', '>', '#', '|'];
+
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if SPECIAL_CHARS.contains(&ch) {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    #[test]
+    fn sanitize_titles() {
+        assert_eq!(sanitize_file_stem("Weekly Review"), "weekly-review");
+        assert_eq!(sanitize_file_stem("  What's new?! "), "what-s-new");
+        assert_eq!(sanitize_file_stem("a/b\\c:d"), "a-b-c-d");
+        assert_eq!(sanitize_file_stem("snake_case"), "snake_case");
+        assert_eq!(sanitize_file_stem("???"), "journal");
+    }
+
+    #[test]
+    fn unique_stems() {
+        let mut used = HashSet::from([String::from("index")]);
+
+        assert_eq!(unique_file_stem("Notes", &mut used), "notes");
+        assert_eq!(unique_file_stem("notes!", &mut used), "notes-2");
+        assert_eq!(unique_file_stem("Notes", &mut used), "notes-3");
+        assert_eq!(unique_file_stem("Index", &mut used), "index-2");
+    }
+
+    #[test]
+    fn front_matter() {
+        let entry = Entry::new(
+            0,
+            Utc.with_ymd_and_hms(2024, 3, 7, 10, 0, 0).unwrap(),
+            String::from("Say \"Hi\""),
+            String::from("Content"),
+            vec![String::from("Tag 1"), String::from("Tag 2")],
+            Some(2),
+        );
+
+        assert_eq!(
+            entry_to_markdown_file(&entry),
+            "---\ntitle: \"Say \\\"Hi\\\"\"\ndate: 2024-03-07\npriority: 2\n\
+             tags: [\"Tag 1\", \"Tag 2\"]\n---\n\nContent"
+        );
+    }
+
+    #[test]
+    fn index_escapes_titles() {
+        let entry = Entry::new(
+            0,
+            Utc.with_ymd_and_hms(2024, 3, 7, 10, 0, 0).unwrap(),
+            String::from("[Draft] *notes*"),
+            String::new(),
+            Vec::new(),
+            None,
+        );
+
+        let files = build_markdown_files(&[&entry]);
+        assert_eq!(
+            files[0].1,
+            "# Journals\n\n- [\\[Draft\\] \\*notes\\*](draft-notes.md) - 2024-03-07\n"
+        );
+    }
+}
@@ -1,38 +1,89 @@
 use self::{
+    changelog::{ReleaseNotes, Version},
     filter::{Filter, FilterCriterion},
+    import::ImportStrategy,
     sorter::{SortCriteria, SortOrder, Sorter},
     state::AppState,
+    tags_order::TagUsage,
 };
-use crate::settings::Settings;
-use anyhow::{anyhow, bail, Context};
+use crate::settings::{
+    DuplicateTagsBehavior, EmptyTitleBehavior, HiddenEntryNavigation, MissingFilterTagsBehavior,
+    PriorityCountsScope, SaveFailureBehavior, SectionLimit, SelectionAfterDelete, Settings,
+    SingleEntryFilter,
+};
+use anyhow::{anyhow, bail, ensure, Context};
 use backend::{DataProvider, EntriesDTO, Entry, EntryDraft};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Local, NaiveDate, NaiveTime, SecondsFormat, Utc};
 use colored_tags::ColoredTagsManager;
-use history::{Change, HistoryManager, HistoryStack};
+use history::{Change, EntryStamp, HistoryManager, HistoryStack, PersistedHistory};
+use ratatui::style::Color;
 use rayon::prelude::*;
 use std::{
-    collections::{BTreeSet, HashSet},
+    collections::{BTreeMap, BTreeSet, HashSet},
     fs::File,
-    path::PathBuf,
+    io::BufRead,
+    path::{Path, PathBuf},
+    time::Duration,
 };
+use view_history::ViewHistory;
 
+mod changelog;
 mod colored_tags;
+pub(crate) mod diff;
 mod external_editor;
-mod filter;
+pub(crate) mod filter;
 mod history;
+pub(crate) mod import;
 mod keymap;
+mod markdown_export;
 mod runner;
 mod sorter;
 pub mod state;
+mod stats;
+mod tags_order;
+mod templates;
 #[cfg(test)]
-mod test;
+pub(crate) mod test;
 pub mod ui;
+mod view_history;
 
+pub use runner::diff_journals;
+pub use runner::import_markdown;
+pub use runner::import_ndjson;
+pub use runner::reset_cursor_style;
 pub use runner::run;
 pub use runner::HandleInputReturnType;
 pub use ui::UIComponents;
 
-pub use colored_tags::TagColors;
+pub use colored_tags::{TagColors, TAG_COLORS};
+pub use stats::JournalStats;
+
+/// Summary of importing entries from newline-delimited JSON lines.
+#[derive(Debug, Default)]
+pub struct NdjsonImportReport {
+    /// Count of the successfully imported entries.
+    pub imported: usize,
+    /// Errors of the lines which couldn't be imported with their line numbers.
+    pub errors: Vec<(usize, String)>,
+}
+
+/// Number of journals for each priority.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PriorityCounts {
+    /// Count of journals for each assigned priority.
+    pub priorities: BTreeMap<u32, usize>,
+    /// Count of journals without priority.
+    pub no_priority: usize,
+}
+
+/// Summary of importing entries from a directory of Markdown files.
+#[derive(Debug, Default)]
+pub struct MarkdownImportReport {
+    /// Count of the successfully imported files.
+    pub imported: usize,
+    /// Errors of the files which couldn't be imported with their paths.
+    pub errors: Vec<(PathBuf, String)>,
+}
 
 pub struct App<D>
 where
@@ -43,14 +94,44 @@ where
     pub current_entry_id: Option<u32>,
     /// Selected entries' IDs in multi-select mode
     pub selected_entries: HashSet<u32>,
+    /// IDs of the selected entries in the order they have been selected.
+    selection_order: Vec<u32>,
     /// Inactive entries' IDs due to not meeting the filter criteria
     pub filtered_out_entries: HashSet<u32>,
+    /// Entries' IDs which have changes failed to be saved and kept in the app only.
+    unsynced_entries: HashSet<u32>,
     pub settings: Settings,
     pub redraw_after_restore: bool,
     pub filter: Option<Filter>,
+    /// Filter hiding all entries which is waiting for confirmation before being applied.
+    pending_filter: Option<Filter>,
+    /// Key of the filter preset which is currently applied.
+    applied_filter_preset: Option<u8>,
+    /// Tags dropped from the filter since they aren't assigned to any entry anymore, kept to
+    /// notify about them.
+    dropped_filter_tags: Vec<String>,
+    /// Duplicated tags removed from journals, kept to notify about them.
+    duplicate_tags: Vec<String>,
+    /// Entry which is the only visible one in focus mode. It overrides the filter without
+    /// changing it, so the filter is restored as it was on exit.
+    focused_entry_id: Option<u32>,
+    /// Entries hidden by the filter which are added to the visible ones after navigating to them.
+    revealed_entries: HashSet<u32>,
+    /// Entry left as the only visible one by the filter or the focus mode, kept to select it
+    /// according to the configured [`SingleEntryFilter`].
+    single_active_entry: Option<u32>,
     state: AppState,
     /// Keeps history of the changes on entries, enabling undo & redo operations
     history: HistoryManager,
+    /// The persisted history has been restored after loading the entries. The persisted history
+    /// is kept as it is otherwise, so it's not lost when loading the entries fails.
+    history_restored: bool,
+    /// Keeps the sequence of the viewed entries for navigating back and forward through them.
+    view_history: ViewHistory,
+    /// Query of the full-text search to navigate between the matching entries.
+    search_query: Option<String>,
+    /// Whether the full-text search matches the case of the query.
+    search_case_sensitive: bool,
     colored_tags: Option<ColoredTagsManager>,
 }
 
@@ -62,20 +143,36 @@ where
         let entries = Vec::new();
         let selected_entries = HashSet::new();
         let filtered_out_entries = HashSet::new();
-        let history = HistoryManager::new(settings.history_limit);
-        let colored_tags = settings.colored_tags.then(ColoredTagsManager::new);
+        let mut history = HistoryManager::new(settings.history_limit);
+        history.set_grouping_interval(Duration::from_secs(settings.history_grouping_interval));
+        let colored_tags = settings
+            .colored_tags
+            .then(|| ColoredTagsManager::new(settings.tag_colors.clone()));
 
         Self {
             data_provide,
             entries,
             current_entry_id: None,
             selected_entries,
+            selection_order: Vec::new(),
             filtered_out_entries,
+            unsynced_entries: HashSet::new(),
             settings,
             redraw_after_restore: false,
             filter: None,
+            pending_filter: None,
+            applied_filter_preset: None,
+            dropped_filter_tags: Vec::new(),
+            duplicate_tags: Vec::new(),
+            focused_entry_id: None,
+            revealed_entries: HashSet::new(),
+            single_active_entry: None,
             state: Default::default(),
             history,
+            history_restored: false,
+            view_history: ViewHistory::default(),
+            search_query: None,
+            search_case_sensitive: false,
             colored_tags,
         }
     }
@@ -87,6 +184,66 @@ where
             .filter(|entry| !self.filtered_out_entries.contains(&entry.id))
     }
 
+    /// Counts the journals for each priority over the active or all entries according to the
+    /// settings.
+    pub fn get_priority_counts(&self) -> PriorityCounts {
+        let mut counts = PriorityCounts::default();
+
+        let mut count_entry = |entry: &Entry| match entry.priority {
+            Some(priority) => *counts.priorities.entry(priority).or_default() += 1,
+            None => counts.no_priority += 1,
+        };
+
+        match self.settings.priority_counts_scope {
+            PriorityCountsScope::Active => self.get_active_entries().for_each(&mut count_entry),
+            PriorityCountsScope::All => self.entries.iter().for_each(&mut count_entry),
+        }
+
+        counts
+    }
+
+    /// Computes the statistics over the shown journals if `only_active` is set, otherwise over
+    /// all journals.
+    pub fn compute_stats(&self, only_active: bool) -> JournalStats {
+        self.compute_stats_at(only_active, Local::now().date_naive())
+    }
+
+    fn compute_stats_at(&self, only_active: bool, today: NaiveDate) -> JournalStats {
+        let filtered_out = &self.filtered_out_entries;
+        self.entries
+            .par_iter()
+            .filter(|entry| !only_active || !filtered_out.contains(&entry.id))
+            .fold(JournalStats::default, |stats, entry| {
+                stats.add_entry(entry, today)
+            })
+            .reduce(JournalStats::default, JournalStats::merge)
+    }
+
+    /// Returns the headings of the sections in the given content of the current entry which
+    /// exceed the limits of the templates of its tags.
+    pub fn get_exceeded_sections(&self, content: &str) -> Vec<String> {
+        let Some(entry) = self.get_current_entry() else {
+            return Vec::new();
+        };
+
+        let limits: BTreeMap<String, SectionLimit> = entry
+            .tags
+            .iter()
+            .filter_map(|tag| self.settings.tag_template_limits.get(tag))
+            .flat_map(|limits| {
+                limits
+                    .iter()
+                    .map(|(heading, limit)| (heading.clone(), *limit))
+            })
+            .collect();
+
+        if limits.is_empty() {
+            return Vec::new();
+        }
+
+        templates::find_exceeded_sections(content, &limits)
+    }
+
     pub fn get_entry(&self, entry_id: u32) -> Option<&Entry> {
         self.get_active_entries().find(|e| e.id == entry_id)
     }
@@ -109,6 +266,8 @@ where
                 EntryEditPart::Content => {
                     self.history.register_change_content(history_target, entry)
                 }
+                EntryEditPart::Pin => self.history.register_change_pin(history_target, entry),
+                EntryEditPart::Color => self.history.register_change_color(history_target, entry),
             };
         }
 
@@ -124,6 +283,10 @@ where
     pub async fn load_entries(&mut self) -> anyhow::Result<()> {
         log::trace!("Loading entries");
 
+        self.retry_unsynced_entries()
+            .await
+            .context("Reloading journals would drop their unsynced changes")?;
+
         self.entries = self.data_provide.load_all_entries().await?;
 
         self.sort_entries();
@@ -142,10 +305,178 @@ where
         tags: Vec<String>,
         priority: Option<u32>,
     ) -> anyhow::Result<u32> {
+        let title = self.resolve_empty_title(title, None, &date)?;
+        let tags = self.normalize_tags_notify(tags);
+
+        // Leave focus mode so the new entry can be shown.
+        self.focused_entry_id = None;
+
         self.add_entry_intern(title, date, tags, priority, None, HistoryStack::Undo)
             .await
     }
 
+    /// Creates an [`Entry`] from the given note as its content, generating the title from it.
+    pub async fn add_quick_note(&mut self, note: String) -> anyhow::Result<u32> {
+        let date = Local::now().date_naive().and_time(NaiveTime::MIN).and_utc();
+        let title = generate_title(Some(&note), &date);
+        let priority = self.settings.default_journal_priority;
+
+        // Leave focus mode so the new entry can be shown.
+        self.focused_entry_id = None;
+
+        self.add_entry_intern(
+            title,
+            date,
+            Vec::new(),
+            priority,
+            Some(note),
+            HistoryStack::Undo,
+        )
+        .await
+    }
+
+    /// Creates a new entry dated today copying the title, tags, priority and content of the
+    /// entry with the given id, returning the id of the new entry and selecting it.
+    pub async fn duplicate_entry(&mut self, entry_id: u32) -> anyhow::Result<u32> {
+        let entry = self
+            .get_entry(entry_id)
+            .ok_or_else(|| anyhow!("Journal with id {entry_id} doesn't exist"))?;
+
+        let title = format!("{} (copy)", entry.title);
+        let tags = entry.tags.clone();
+        let priority = entry.priority;
+        let content = entry.content.clone();
+        let date = Local::now().date_naive().and_time(NaiveTime::MIN).and_utc();
+
+        // Leave focus mode so the new entry can be shown.
+        self.focused_entry_id = None;
+
+        let new_id = self
+            .add_entry_intern(
+                title,
+                date,
+                tags,
+                priority,
+                Some(content),
+                HistoryStack::Undo,
+            )
+            .await?;
+
+        self.current_entry_id = Some(new_id);
+
+        Ok(new_id)
+    }
+
+    /// Returns the first tag having a content template among the tags of the given entry which
+    /// aren't included in the given previous tags.
+    pub fn get_new_tag_template(&self, entry_id: u32, previous_tags: &[String]) -> Option<String> {
+        let entry = self.get_entry(entry_id)?;
+
+        entry
+            .tags
+            .iter()
+            .filter(|tag| !previous_tags.contains(tag))
+            .find(|tag| self.settings.tag_templates.contains_key(*tag))
+            .cloned()
+    }
+
+    /// Inserts the content template of the given tag into the content of the given entry,
+    /// appending it if the entry already has content. Date placeholders in the template are
+    /// expanded relative to the current date.
+    pub async fn insert_tag_template(&mut self, entry_id: u32, tag: &str) -> anyhow::Result<()> {
+        let Some(template) = self.settings.tag_templates.get(tag) else {
+            bail!("Tag '{tag}' doesn't have a content template");
+        };
+
+        let entry = self
+            .get_entry(entry_id)
+            .ok_or_else(|| anyhow!("Journal with id {entry_id} doesn't exist"))?;
+
+        let template = templates::expand_date_placeholders(template, Local::now().date_naive());
+
+        let content = if entry.content.is_empty() {
+            template
+        } else {
+            format!("{}\n\n{template}", entry.content)
+        };
+
+        self.update_entry_content(entry_id, content, HistoryStack::Undo)
+            .await
+    }
+
+    /// Converts the given tags to lowercase if enabled in the settings and removes the duplicates,
+    /// returning the normalized tags with the removed ones.
+    fn normalize_tags(&self, tags: Vec<String>) -> (Vec<String>, Vec<String>) {
+        let lowercase = self.settings.lowercase_tags;
+        let ignore_case = lowercase || self.settings.duplicate_tags_ignore_case;
+
+        let mut normalized: Vec<String> = Vec::with_capacity(tags.len());
+        let mut duplicates = Vec::new();
+        for tag in tags {
+            let tag = if lowercase { tag.to_lowercase() } else { tag };
+            let is_duplicate = normalized.iter().any(|existing| {
+                if ignore_case {
+                    existing.to_lowercase() == tag.to_lowercase()
+                } else {
+                    *existing == tag
+                }
+            });
+
+            if is_duplicate {
+                duplicates.push(tag);
+            } else {
+                normalized.push(tag);
+            }
+        }
+
+        (normalized, duplicates)
+    }
+
+    /// Normalizes the given tags keeping the removed duplicates to notify about them if
+    /// configured in the settings.
+    fn normalize_tags_notify(&mut self, tags: Vec<String>) -> Vec<String> {
+        let (tags, duplicates) = self.normalize_tags(tags);
+        if self.settings.duplicate_tags == DuplicateTagsBehavior::Warn {
+            for tag in duplicates {
+                if !self.duplicate_tags.contains(&tag) {
+                    self.duplicate_tags.push(tag);
+                }
+            }
+        }
+
+        tags
+    }
+
+    /// Applies the empty title behavior from the settings on the given title, generating a
+    /// title from the given content or date if configured.
+    fn resolve_empty_title(
+        &self,
+        title: String,
+        content: Option<&str>,
+        date: &DateTime<Utc>,
+    ) -> anyhow::Result<String> {
+        if !title.trim().is_empty() {
+            return Ok(title);
+        }
+
+        match self.settings.empty_title {
+            EmptyTitleBehavior::Allow => Ok(title),
+            EmptyTitleBehavior::Reject => bail!("Title can't be empty"),
+            EmptyTitleBehavior::Generate => Ok(generate_title(content, date)),
+        }
+    }
+
+    /// Checks if the user should be prompted to add tags to the newly created entry with the
+    /// given id.
+    pub fn needs_tags_prompt(&self, entry_id: u32) -> bool {
+        self.settings.prompt_tags_on_create
+            && self
+                .entries
+                .iter()
+                .find(|entry| entry.id == entry_id)
+                .is_some_and(|entry| entry.tags.is_empty())
+    }
+
     /// Creates an [`Entry`] from the given arguments, registering the change to the provided
     /// [`HistoryStack`].
     async fn add_entry_intern(
@@ -164,6 +495,16 @@ where
             draft = draft.with_content(content);
         }
 
+        self.add_draft_intern(draft, history_target).await
+    }
+
+    /// Creates an [`Entry`] from the given [`EntryDraft`], keeping all its fields including the
+    /// id if set, and registers the change to the provided [`HistoryStack`].
+    async fn add_draft_intern(
+        &mut self,
+        draft: EntryDraft,
+        history_target: HistoryStack,
+    ) -> anyhow::Result<u32> {
         let entry = self.data_provide.add_entry(draft).await?;
         let entry_id = entry.id;
 
@@ -189,6 +530,11 @@ where
         let current_entry_id = self
             .current_entry_id
             .expect("Current entry id must have value when updating entry attributes");
+        let content = self
+            .get_entry(current_entry_id)
+            .map(|entry| entry.content.as_str());
+        let title = self.resolve_empty_title(title, content, &date)?;
+        let tags = self.normalize_tags_notify(tags);
         self.update_entry_attributes(
             current_entry_id,
             title,
@@ -200,6 +546,255 @@ where
         .await
     }
 
+    /// Removes the given tag from the current [`Entry`] registering the change in the history.
+    pub async fn remove_tag_from_current_entry(&mut self, tag: &str) -> anyhow::Result<()> {
+        let Some(entry) = self.get_current_entry() else {
+            return Ok(());
+        };
+
+        if !entry.tags.iter().any(|t| t == tag) {
+            return Ok(());
+        }
+
+        let (entry_id, title, date, priority) =
+            (entry.id, entry.title.clone(), entry.date, entry.priority);
+        let tags = entry.tags.iter().filter(|t| *t != tag).cloned().collect();
+
+        self.update_entry_attributes(entry_id, title, date, tags, priority, HistoryStack::Undo)
+            .await
+    }
+
+    /// Adds the given tag to all the selected entries which don't have it yet, registering the
+    /// changes as one batch in the history so they can be undone at once.
+    pub async fn add_tag_to_selected(&mut self, tag: String) -> anyhow::Result<()> {
+        let tag = tag.trim().to_owned();
+        ensure!(!tag.is_empty(), "Tag can't be empty");
+
+        self.update_selected_entries(|entry| {
+            if entry.tags.contains(&tag) {
+                return false;
+            }
+
+            entry.tags.push(tag.clone());
+            true
+        })
+        .await
+    }
+
+    /// Removes the given tag from all the selected entries having it, registering the changes as
+    /// one batch in the history so they can be undone at once.
+    pub async fn remove_tag_from_selected(&mut self, tag: String) -> anyhow::Result<()> {
+        self.update_selected_entries(|entry| {
+            let count_before = entry.tags.len();
+            entry.tags.retain(|t| *t != tag);
+            entry.tags.len() != count_before
+        })
+        .await
+    }
+
+    /// Sets the given priority on all the selected entries, clearing it on `None`, registering
+    /// the changes as one batch in the history so they can be undone at once.
+    pub async fn set_priority_for_selected(&mut self, priority: Option<u32>) -> anyhow::Result<()> {
+        self.update_selected_entries(|entry| {
+            let changed = entry.priority != priority;
+            entry.priority = priority;
+            changed
+        })
+        .await
+    }
+
+    /// Applies the given update on the attributes of each selected entry, where the update
+    /// returns if the entry has been changed. The changes are grouped as one history batch.
+    async fn update_selected_entries<F>(&mut self, update: F) -> anyhow::Result<()>
+    where
+        F: Fn(&mut Entry) -> bool,
+    {
+        let mut selected_ids: Vec<u32> = self.selected_entries.iter().copied().collect();
+        selected_ids.sort_unstable();
+
+        self.update_entries_batch(selected_ids, update).await
+    }
+
+    /// Renames the given tag on all entries having it, merging it if the new tag exists already.
+    /// The changes are registered as one batch in the history so they can be undone at once.
+    pub async fn rename_tag(&mut self, old: &str, new: &str) -> anyhow::Result<()> {
+        let new = new.trim();
+        ensure!(!new.is_empty(), "Tag can't be empty");
+
+        let new = if self.settings.lowercase_tags {
+            new.to_lowercase()
+        } else {
+            new.to_owned()
+        };
+
+        self.merge_tags(old, &new).await
+    }
+
+    /// Replaces the tag `from` with the tag `into` on all entries, avoiding duplicates on entries
+    /// having both tags. The filter criteria referencing `from` are changed to `into` too once
+    /// all entries are saved.
+    /// The changes are registered as one batch in the history so they can be undone at once.
+    pub async fn merge_tags(&mut self, from: &str, into: &str) -> anyhow::Result<()> {
+        ensure!(!into.is_empty(), "Tag can't be empty");
+
+        if from == into {
+            return Ok(());
+        }
+
+        // The filter is set aside while changing the entries, so the criteria of `from` aren't
+        // dropped as missing before they are moved to `into`.
+        let filter = self.filter.take();
+
+        let entry_ids: Vec<u32> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.tags.iter().any(|tag| tag == from))
+            .map(|entry| entry.id)
+            .collect();
+
+        let result = self
+            .update_entries_batch(entry_ids, |entry| {
+                let mut tags: Vec<String> = Vec::with_capacity(entry.tags.len());
+                for tag in entry.tags.drain(..) {
+                    let tag = if tag == from { into.to_owned() } else { tag };
+                    if !tags.contains(&tag) {
+                        tags.push(tag);
+                    }
+                }
+                entry.tags = tags;
+                true
+            })
+            .await;
+
+        // The filter keeps the tag `from` if saving failed since some entries still have it.
+        self.filter = filter;
+        if result.is_ok() {
+            self.replace_filter_tag(from, into);
+        }
+        self.update_filtered_out_entries();
+
+        result
+    }
+
+    /// Replaces the given tag in the criteria of the applied filter, keeping them unique.
+    fn replace_filter_tag(&mut self, from: &str, into: &str) {
+        let Some(filter) = self.filter.as_mut() else {
+            return;
+        };
+
+        let mut criteria: Vec<FilterCriterion> = Vec::with_capacity(filter.criteria.len());
+        for mut criterion in filter.criteria.drain(..) {
+            match &mut criterion {
+                FilterCriterion::Tag(tag) if tag == from => *tag = into.to_owned(),
+                FilterCriterion::Not(inner) => {
+                    if let FilterCriterion::Tag(tag) = inner.as_mut() {
+                        if tag == from {
+                            *tag = into.to_owned();
+                        }
+                    }
+                }
+                _ => {}
+            }
+
+            if !criteria.contains(&criterion) {
+                criteria.push(criterion);
+            }
+        }
+
+        filter.criteria = criteria;
+    }
+
+    /// Applies the given update on the attributes of the entries with the given ids, where the
+    /// update returns if the entry has been changed. The changes are grouped as one history batch.
+    async fn update_entries_batch<F>(
+        &mut self,
+        entry_ids: Vec<u32>,
+        update: F,
+    ) -> anyhow::Result<()>
+    where
+        F: Fn(&mut Entry) -> bool,
+    {
+        self.history.begin_batch(HistoryStack::Undo);
+        let mut result = Ok(());
+        for entry_id in entry_ids {
+            let Some(entry) = self.entries.iter().find(|e| e.id == entry_id) else {
+                continue;
+            };
+
+            let mut entry = entry.clone();
+            if !update(&mut entry) {
+                continue;
+            }
+
+            result = self
+                .update_entry_attributes(
+                    entry_id,
+                    entry.title,
+                    entry.date,
+                    entry.tags,
+                    entry.priority,
+                    HistoryStack::Undo,
+                )
+                .await;
+            if result.is_err() {
+                break;
+            }
+        }
+
+        self.history.end_batch();
+
+        result
+    }
+
+    /// Increments the priority of the current [`Entry`] by one, clamped to the configured range.
+    /// Entries without priority get the default priority.
+    pub async fn bump_current_entry_priority(&mut self) -> anyhow::Result<()> {
+        self.shift_current_entry_priority(true).await
+    }
+
+    /// Decrements the priority of the current [`Entry`] by one, clamped to the configured range.
+    /// Entries without priority get the default priority.
+    pub async fn lower_current_entry_priority(&mut self) -> anyhow::Result<()> {
+        self.shift_current_entry_priority(false).await
+    }
+
+    async fn shift_current_entry_priority(&mut self, increment: bool) -> anyhow::Result<()> {
+        let Some(entry) = self.get_current_entry() else {
+            return Ok(());
+        };
+
+        let min = self.settings.min_journal_priority;
+        let max = self.settings.max_journal_priority.max(min);
+
+        let priority = match entry.priority {
+            Some(priority) if increment => priority.saturating_add(1),
+            Some(priority) => priority.saturating_sub(1),
+            None => self.settings.default_journal_priority.unwrap_or(min),
+        }
+        .clamp(min, max);
+
+        if entry.priority == Some(priority) {
+            return Ok(());
+        }
+
+        let (entry_id, title, date, tags) = (
+            entry.id,
+            entry.title.clone(),
+            entry.date,
+            entry.tags.clone(),
+        );
+
+        self.update_entry_attributes(
+            entry_id,
+            title,
+            date,
+            tags,
+            Some(priority),
+            HistoryStack::Undo,
+        )
+        .await
+    }
+
     /// Updates the attributes of the given [`Entry`], registering its state before the change on
     /// the given [`HistoryStack`]
     async fn update_entry_attributes(
@@ -213,20 +808,23 @@ where
     ) -> anyhow::Result<()> {
         log::trace!("Updating entry");
 
-        assert!(self.current_entry_id.is_some());
-
         let entry = self
             .get_entry_mut(entry_id, EntryEditPart::Attributes, history_target)
-            .expect("Current entry must have value when updating entry attributes");
+            .ok_or_else(|| anyhow!("Journal with id {entry_id} doesn't exist"))?;
+
+        let entry_before = entry.clone();
 
         entry.title = title;
         entry.date = date;
         entry.tags = tags;
         entry.priority = priority;
+        entry.updated_at = Some(Utc::now());
 
         let clone = entry.clone();
 
-        self.data_provide.update_entry(clone).await?;
+        let result = self
+            .persist_entry_update(clone, entry_before, history_target)
+            .await;
 
         self.sort_entries();
 
@@ -234,7 +832,7 @@ where
         self.update_filtered_out_entries();
         self.update_colored_tags();
 
-        Ok(())
+        result
     }
 
     /// Updates the content of the currently selected [`Entry`]
@@ -246,7 +844,11 @@ where
             .current_entry_id
             .expect("Current entry id must have value when updating entry content");
         self.update_entry_content(current_entry_id, entry_content, HistoryStack::Undo)
-            .await
+            .await?;
+
+        self.history.mark_save_point();
+
+        Ok(())
     }
 
     /// Update the content of the given [`Entry`], registering its previous content to the given
@@ -263,134 +865,1140 @@ where
             .get_entry_mut(entry_id, EntryEditPart::Content, history_target)
             .expect("Current entry id must have value when updating entry content");
 
+        let entry_before = entry.clone();
+
         entry.content = entry_content;
+        entry.updated_at = Some(Utc::now());
 
         let clone = entry.clone();
 
-        self.data_provide.update_entry(clone).await?;
+        let result = self
+            .persist_entry_update(clone, entry_before, history_target)
+            .await;
 
+        // Sorting is needed since the modification time has changed
+        self.sort_entries();
         self.update_filtered_out_entries();
 
-        Ok(())
+        result
     }
 
-    pub async fn delete_entry(&mut self, entry_id: u32) -> anyhow::Result<()> {
-        self.delete_entry_intern(entry_id, HistoryStack::Undo).await
+    /// Pins the current [`Entry`] if it isn't pinned and unpins it otherwise.
+    pub async fn toggle_current_entry_pin(&mut self) -> anyhow::Result<()> {
+        let Some(entry) = self.get_current_entry() else {
+            return Ok(());
+        };
+
+        let pinned_at = match entry.pinned_at {
+            Some(_) => None,
+            None => Some(Utc::now()),
+        };
+
+        self.update_entry_pin(entry.id, pinned_at, HistoryStack::Undo)
+            .await
     }
 
-    /// Removes the given entry, registering it to the given [`HistoryStack`]
-    pub async fn delete_entry_intern(
+    /// Sets the pin time of the given [`Entry`], registering its previous pin state to the given
+    /// [`HistoryStack`]
+    async fn update_entry_pin(
         &mut self,
         entry_id: u32,
+        pinned_at: Option<DateTime<Utc>>,
         history_target: HistoryStack,
     ) -> anyhow::Result<()> {
-        log::trace!("Deleting entry with id: {entry_id}");
-
-        self.data_provide.remove_entry(entry_id).await?;
-        let removed_entry = self
-            .entries
-            .iter()
-            .position(|entry| entry.id == entry_id)
-            .map(|index| self.entries.remove(index))
-            .expect("entry must be in the entries list");
+        log::trace!("Updating entry pin");
 
-        self.history.register_remove(history_target, removed_entry);
+        let entry = self
+            .get_entry_mut(entry_id, EntryEditPart::Pin, history_target)
+            .expect("Entry must exist when updating its pin");
 
-        self.update_filter();
-        self.update_filtered_out_entries();
-        self.update_colored_tags();
+        let entry_before = entry.clone();
 
-        Ok(())
-    }
+        entry.pinned_at = pinned_at;
 
-    async fn export_entry_content(&self, entry_id: u32, path: PathBuf) -> anyhow::Result<()> {
-        if let Some(parent) = path.parent() {
-            tokio::fs::create_dir_all(parent).await?;
-        }
+        let clone = entry.clone();
 
-        let entry = self.get_entry(entry_id).expect("Entry should exist");
+        let result = self
+            .persist_entry_update(clone, entry_before, history_target)
+            .await;
 
-        tokio::fs::write(path, entry.content.to_owned()).await?;
+        self.sort_entries();
+        self.update_filtered_out_entries();
 
-        Ok(())
+        result
     }
 
-    async fn export_entries(&self, path: PathBuf) -> anyhow::Result<()> {
-        if let Some(parent) = path.parent() {
-            tokio::fs::create_dir_all(parent).await?;
-        }
-
-        let selected_ids: Vec<u32> = self.selected_entries.iter().cloned().collect();
+    /// Sets the color of the current [`Entry`], where `None` removes it.
+    pub async fn set_current_entry_color(&mut self, color: Option<Color>) -> anyhow::Result<()> {
+        let Some(entry_id) = self.current_entry_id else {
+            return Ok(());
+        };
 
-        let entries_dto = self.data_provide.get_export_object(&selected_ids).await?;
+        self.update_entry_color(
+            entry_id,
+            color.map(|color| color.to_string()),
+            HistoryStack::Undo,
+        )
+        .await
+    }
 
-        let file = File::create(path)?;
-        serde_json::to_writer_pretty(&file, &entries_dto)?;
+    /// Sets the color of the given [`Entry`], registering its previous color to the given
+    /// [`HistoryStack`]
+    async fn update_entry_color(
+        &mut self,
+        entry_id: u32,
+        color: Option<String>,
+        history_target: HistoryStack,
+    ) -> anyhow::Result<()> {
+        log::trace!("Updating entry color");
 
-        Ok(())
-    }
+        let entry = self
+            .get_entry_mut(entry_id, EntryEditPart::Color, history_target)
+            .expect("Entry must exist when updating its color");
 
-    async fn import_entries(&self, file_path: PathBuf) -> anyhow::Result<()> {
-        if !file_path.exists() {
-            bail!("Import file doesn't exist: path {}", file_path.display())
-        }
+        let entry_before = entry.clone();
 
-        let file = File::open(file_path)
-            .map_err(|err| anyhow!("Error while opening import file: Error: {err}"))?;
+        entry.color = color;
 
-        let entries_dto: EntriesDTO = serde_json::from_reader(&file)
-            .map_err(|err| anyhow!("Error while parsing import file. Error: {err}"))?;
+        let clone = entry.clone();
 
-        self.data_provide
-            .import_entries(entries_dto)
+        self.persist_entry_update(clone, entry_before, history_target)
             .await
-            .map_err(|err| anyhow!("Error while importing the entry. Error: {err}"))?;
-
-        Ok(())
     }
 
-    pub fn get_all_tags(&self) -> Vec<String> {
-        let mut tags = BTreeSet::new();
+    /// Saves the given updated entry in the data provider. On failure, the change is handled
+    /// according to the configured [`SaveFailureBehavior`], either restoring the given entry
+    /// state before the change and dropping its history change, or keeping the change marking
+    /// the entry as unsynced.
+    async fn persist_entry_update(
+        &mut self,
+        entry: Entry,
+        entry_before: Entry,
+        history_target: HistoryStack,
+    ) -> anyhow::Result<()> {
+        let entry_id = entry.id;
 
-        for tag in self.entries.iter().flat_map(|entry| &entry.tags) {
-            tags.insert(tag);
-        }
+        let err = match self.data_provide.update_entry(entry).await {
+            Ok(_) => {
+                self.unsynced_entries.remove(&entry_id);
+                if let Err(err) = self.retry_unsynced_entries().await {
+                    log::warn!("Retrying to save unsynced journals failed: {err}");
+                }
 
-        tags.into_iter().map(String::from).collect()
-    }
+                return Ok(());
+            }
+            Err(err) => err,
+        };
+
+        match self.settings.save_failure {
+            SaveFailureBehavior::Rollback => {
+                if let Some(entry) = self.entries.iter_mut().find(|e| e.id == entry_id) {
+                    *entry = entry_before;
+                }
+                self.history.discard_latest(history_target);
+
+                bail!("Saving journal failed and its changes are rolled back. Error: {err}")
+            }
+            SaveFailureBehavior::KeepUnsynced => {
+                self.unsynced_entries.insert(entry_id);
+
+                bail!("Saving journal failed and its changes are kept unsynced. Error: {err}")
+            }
+        }
+    }
+
+    /// Checks if the entry with the given id has changes which failed to be saved.
+    pub fn is_entry_unsynced(&self, entry_id: u32) -> bool {
+        self.unsynced_entries.contains(&entry_id)
+    }
+
+    /// Retries saving the entries which have changes failed to be saved before.
+    pub async fn retry_unsynced_entries(&mut self) -> anyhow::Result<()> {
+        let unsynced_ids: Vec<u32> = self.unsynced_entries.iter().copied().collect();
+
+        for entry_id in unsynced_ids {
+            if let Some(entry) = self.entries.iter().find(|e| e.id == entry_id) {
+                self.data_provide.update_entry(entry.clone()).await?;
+            }
+
+            self.unsynced_entries.remove(&entry_id);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the id of the active entry to select after deleting the given entry according to
+    /// the configured [`SelectionAfterDelete`].
+    pub fn get_selection_after_delete(&self, entry_id: u32) -> Option<u32> {
+        let ids: Vec<u32> = self.get_active_entries().map(|entry| entry.id).collect();
+        let index = ids.iter().position(|id| *id == entry_id)?;
+
+        let next = ids.get(index + 1).copied();
+        let prev = index.checked_sub(1).map(|idx| ids[idx]);
+
+        match self.settings.selection_after_delete {
+            SelectionAfterDelete::Next => next.or(prev),
+            SelectionAfterDelete::Previous => prev.or(next),
+            SelectionAfterDelete::Clear => None,
+        }
+    }
+
+    pub async fn delete_entry(&mut self, entry_id: u32) -> anyhow::Result<()> {
+        self.delete_entry_intern(entry_id, HistoryStack::Undo).await
+    }
+
+    /// Removes all the selected entries, registering them as one batch in the history so they
+    /// can be restored with one undo. The selection is cleared afterwards.
+    pub async fn delete_selected_entries(&mut self) -> anyhow::Result<()> {
+        let mut selected_ids: Vec<u32> = self.selected_entries.iter().copied().collect();
+        selected_ids.sort_unstable();
+
+        self.history.begin_batch(HistoryStack::Undo);
+        let mut result = Ok(());
+        for entry_id in selected_ids {
+            if !self.entries.iter().any(|entry| entry.id == entry_id) {
+                continue;
+            }
+
+            result = self.delete_entry_intern(entry_id, HistoryStack::Undo).await;
+            if result.is_err() {
+                break;
+            }
+        }
+
+        self.history.end_batch();
+
+        self.clear_selection();
+
+        result
+    }
+
+    /// Removes the given entry, registering it to the given [`HistoryStack`]
+    pub async fn delete_entry_intern(
+        &mut self,
+        entry_id: u32,
+        history_target: HistoryStack,
+    ) -> anyhow::Result<()> {
+        log::trace!("Deleting entry with id: {entry_id}");
+
+        self.data_provide.remove_entry(entry_id).await?;
+        let removed_entry = self
+            .entries
+            .iter()
+            .position(|entry| entry.id == entry_id)
+            .map(|index| self.entries.remove(index))
+            .expect("entry must be in the entries list");
+
+        self.history.register_remove(history_target, removed_entry);
+        self.unsynced_entries.remove(&entry_id);
+
+        self.update_filter();
+        self.update_filtered_out_entries();
+        self.update_colored_tags();
+
+        Ok(())
+    }
+
+    async fn export_entry_content(
+        &self,
+        entry_id: u32,
+        path: PathBuf,
+        format: EntryExportFormat,
+    ) -> anyhow::Result<()> {
+        let content = self.get_entry_export_content(entry_id, format)?;
+
+        write_export_file(path, content).await
+    }
+
+    async fn export_entries(&self, path: PathBuf) -> anyhow::Result<()> {
+        let content = self.get_export_content().await?;
+
+        write_export_file(path, content).await
+    }
+
+    /// Writes the selected entries as sections into a single Markdown file.
+    async fn export_entries_markdown(&self, path: PathBuf) -> anyhow::Result<()> {
+        let content = self.get_markdown_export_content()?;
+
+        write_export_file(path, content).await
+    }
+
+    /// Writes the metadata of the selected entries, or all entries if none are selected, as CSV.
+    async fn export_metadata_csv(&self, path: PathBuf) -> anyhow::Result<()> {
+        let content = self.get_metadata_csv()?;
+
+        write_export_file(path, content).await
+    }
+
+    /// Writes the selected entries as Markdown files with their metadata as front matter into
+    /// the given directory, besides an index file linking to them in the order of the entries
+    /// list. Returns the path of the index file.
+    async fn export_markdown_files(&self, dir: PathBuf) -> anyhow::Result<PathBuf> {
+        let entries: Vec<&Entry> = self
+            .entries
+            .iter()
+            .filter(|entry| self.selected_entries.contains(&entry.id))
+            .collect();
+
+        if entries.is_empty() {
+            bail!("No journals are selected");
+        }
+
+        for (file_name, content) in markdown_export::build_markdown_files(&entries) {
+            let content = apply_trailing_newline(content, self.settings.export.trailing_newline);
+            write_export_file(dir.join(file_name), content).await?;
+        }
+
+        Ok(dir.join(markdown_export::INDEX_FILE_NAME))
+    }
+
+    /// Generates the text written on exporting the given entry in the given format.
+    pub fn get_entry_export_content(
+        &self,
+        entry_id: u32,
+        format: EntryExportFormat,
+    ) -> anyhow::Result<String> {
+        let entry = self
+            .get_entry(entry_id)
+            .ok_or_else(|| anyhow!("Journal with id {entry_id} doesn't exist"))?;
+
+        let content = match format {
+            EntryExportFormat::Content => entry.content.to_owned(),
+            EntryExportFormat::Markdown => markdown_export::entry_to_markdown(entry),
+            EntryExportFormat::Html => markdown_export::entry_to_html(entry),
+        };
+
+        Ok(apply_trailing_newline(
+            content,
+            self.settings.export.trailing_newline,
+        ))
+    }
+
+    /// Generates the transfer JSON written on exporting the selected entries.
+    pub async fn get_export_content(&self) -> anyhow::Result<String> {
+        if self.settings.export.selection_order {
+            let drafts = self
+                .get_selected_ids_in_order()
+                .into_iter()
+                .filter_map(|id| self.entries.iter().find(|entry| entry.id == id))
+                .map(|entry| EntryDraft::from_entry(entry.clone()))
+                .collect();
+
+            Ok(serde_json::to_string_pretty(&EntriesDTO::new(drafts))?)
+        } else {
+            let selected_ids: Vec<u32> = self.selected_entries.iter().cloned().collect();
+            let entries_dto = self.data_provide.get_export_object(&selected_ids).await?;
+
+            Ok(serde_json::to_string_pretty(&entries_dto)?)
+        }
+    }
+
+    /// Generates the Markdown written on exporting the selected entries into a single file,
+    /// keeping the selection order if it's enabled in the export settings.
+    pub fn get_markdown_export_content(&self) -> anyhow::Result<String> {
+        let entries: Vec<&Entry> = if self.settings.export.selection_order {
+            self.get_selected_ids_in_order()
+                .into_iter()
+                .filter_map(|id| self.entries.iter().find(|entry| entry.id == id))
+                .collect()
+        } else {
+            self.entries
+                .iter()
+                .filter(|entry| self.selected_entries.contains(&entry.id))
+                .collect()
+        };
+
+        if entries.is_empty() {
+            bail!("No journals are selected");
+        }
+
+        Ok(self.format_entries_markdown(entries))
+    }
+
+    /// Generates CSV with a row for the metadata of each selected entry, or each entry if none
+    /// are selected, in the order of the entries list. The content of the entries is left out,
+    /// keeping only its length in characters.
+    pub fn get_metadata_csv(&self) -> anyhow::Result<String> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer.write_record([
+            "id",
+            "title",
+            "date",
+            "priority",
+            "tag_count",
+            "content_length",
+        ])?;
+
+        let entries = self.entries.iter().filter(|entry| {
+            self.selected_entries.is_empty() || self.selected_entries.contains(&entry.id)
+        });
+
+        for entry in entries {
+            writer.write_record([
+                entry.id.to_string(),
+                entry.title.to_owned(),
+                entry.date.to_rfc3339_opts(SecondsFormat::Secs, true),
+                entry
+                    .priority
+                    .map(|prio| prio.to_string())
+                    .unwrap_or_default(),
+                entry.tags.len().to_string(),
+                entry.content.chars().count().to_string(),
+            ])?;
+        }
+
+        let bytes = writer
+            .into_inner()
+            .map_err(|err| anyhow!("Error while writing CSV. Error: {}", err.error()))?;
+
+        Ok(String::from_utf8(bytes)?)
+    }
+
+    /// Adds the entry to the selected entries, returning if it wasn't selected already.
+    pub fn select_entry(&mut self, entry_id: u32) -> bool {
+        let inserted = self.selected_entries.insert(entry_id);
+        if inserted {
+            self.selection_order.push(entry_id);
+        }
+
+        inserted
+    }
+
+    pub fn deselect_entry(&mut self, entry_id: u32) {
+        self.selected_entries.remove(&entry_id);
+        self.selection_order.retain(|id| *id != entry_id);
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.selected_entries.clear();
+        self.selection_order.clear();
+    }
+
+    /// Returns the IDs of the selected entries in the order they have been selected. Entries
+    /// without a tracked selection order follow them in the order of the entries list.
+    pub fn get_selected_ids_in_order(&self) -> Vec<u32> {
+        let mut ids: Vec<u32> = self
+            .selection_order
+            .iter()
+            .filter(|id| self.selected_entries.contains(id))
+            .copied()
+            .collect();
+
+        let unordered: Vec<u32> = self
+            .entries
+            .iter()
+            .map(|entry| entry.id)
+            .filter(|id| self.selected_entries.contains(id) && !ids.contains(id))
+            .collect();
+        ids.extend(unordered);
+
+        ids
+    }
+
+    /// Formats the selected entries as Markdown with their metadata, falling back to the current
+    /// entry if no entries are selected. Entries are kept in the order of the entries list.
+    pub fn get_entries_markdown(&self) -> Option<String> {
+        let entries: Vec<&Entry> = if self.selected_entries.is_empty() {
+            self.get_current_entry().into_iter().collect()
+        } else {
+            self.entries
+                .iter()
+                .filter(|entry| self.selected_entries.contains(&entry.id))
+                .collect()
+        };
+
+        if entries.is_empty() {
+            return None;
+        }
+
+        Some(self.format_entries_markdown(entries))
+    }
+
+    /// Joins the given entries as Markdown sections separated by horizontal rules.
+    fn format_entries_markdown(&self, entries: Vec<&Entry>) -> String {
+        let markdown = entries
+            .into_iter()
+            .map(markdown_export::entry_to_markdown)
+            .collect::<Vec<String>>()
+            .join("\n---\n\n");
+
+        apply_trailing_newline(markdown, self.settings.export.trailing_newline)
+    }
+
+    /// Imports the entries from the given transfer file keeping their original ids if they
+    /// aren't taken, returning a summary of the import. Imported entries matching existing ones
+    /// by their title and date are handled according to the given strategy.
+    async fn import_entries(
+        &self,
+        file_path: PathBuf,
+        strategy: ImportStrategy,
+    ) -> anyhow::Result<String> {
+        if !file_path.exists() {
+            bail!("Import file doesn't exist: path {}", file_path.display())
+        }
+
+        let file = File::open(file_path)
+            .map_err(|err| anyhow!("Error while opening import file: Error: {err}"))?;
+
+        let raw_dto: import::RawEntriesDTO = serde_json::from_reader(&file)
+            .map_err(|err| anyhow!("Error while parsing import file. Error: {err}"))?;
+
+        let mut entries = Vec::with_capacity(raw_dto.entries.len());
+        let mut errors = Vec::new();
+        for (index, value) in raw_dto.entries.into_iter().enumerate() {
+            match import::parse_import_draft(value) {
+                Ok(draft) => entries.push(draft),
+                Err(err) => errors.push(format!("Journal {}: {err}", index + 1)),
+            }
+        }
+
+        let mut skipped_count = 0;
+        let mut updated_count = 0;
+        if strategy != ImportStrategy::AddAll {
+            let mut existing_entries = self.data_provide.load_all_entries().await?;
+            let mut new_entries = Vec::with_capacity(entries.len());
+
+            for draft in entries {
+                let Some(existing) = existing_entries
+                    .iter_mut()
+                    .find(|entry| entry.title == draft.title && entry.date == draft.date)
+                else {
+                    new_entries.push(draft);
+                    continue;
+                };
+
+                if strategy == ImportStrategy::SkipDuplicates {
+                    skipped_count += 1;
+                    continue;
+                }
+
+                existing.content = draft.content;
+                existing.tags = draft.tags;
+                existing.priority = draft.priority;
+                existing.updated_at = Some(Utc::now());
+                existing.pinned_at = draft.pinned_at;
+                existing.color = draft.color;
+
+                self.data_provide
+                    .update_entry(existing.clone())
+                    .await
+                    .map_err(|err| anyhow!("Error while updating the entry. Error: {err}"))?;
+                updated_count += 1;
+            }
+
+            entries = new_entries;
+        }
+
+        let imported_count = entries.len();
+        let entries_dto = EntriesDTO {
+            version: raw_dto.version,
+            entries,
+        };
+
+        let ids_map = self
+            .data_provide
+            .import_entries(entries_dto)
+            .await
+            .map_err(|err| anyhow!("Error while importing the entry. Error: {err}"))?;
+
+        let remapped_count = ids_map.iter().filter(|(old, new)| old != new).count();
+        let mut summary = format!("{imported_count} journal(s) imported");
+        if remapped_count > 0 {
+            summary.push_str(&format!(
+                ". {remapped_count} journal(s) got new ids since their original ids are taken"
+            ));
+        }
+        if skipped_count > 0 {
+            summary.push_str(&format!(". {skipped_count} duplicate journal(s) skipped"));
+        }
+        if updated_count > 0 {
+            summary.push_str(&format!(". {updated_count} duplicate journal(s) updated"));
+        }
 
-    /// Sets and applies the given filter on the entries
+        if !errors.is_empty() {
+            bail!(
+                "{summary}. {} journal(s) couldn't be imported:\n\r{}",
+                errors.len(),
+                errors.join("\n\r")
+            );
+        }
+
+        Ok(summary)
+    }
+
+    /// Imports entries from the given newline-delimited JSON reader, where each line represents
+    /// one entry draft. Invalid lines are skipped and collected in the returned report.
+    pub async fn import_ndjson_entries(
+        &self,
+        reader: impl BufRead,
+    ) -> anyhow::Result<NdjsonImportReport> {
+        let mut report = NdjsonImportReport::default();
+
+        for (index, line) in reader.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.context("Error while reading import input")?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let parsed = serde_json::from_str(&line)
+                .map_err(anyhow::Error::from)
+                .and_then(import::parse_import_draft);
+            let mut draft = match parsed {
+                Ok(draft) => draft,
+                Err(err) => {
+                    report.errors.push((line_number, err.to_string()));
+                    continue;
+                }
+            };
+
+            (draft.tags, _) = self.normalize_tags(draft.tags);
+
+            match self.data_provide.add_entry(draft).await {
+                Ok(_) => report.imported += 1,
+                Err(err) => report.errors.push((line_number, err.to_string())),
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Imports the Markdown files in the given directory as entries ordered by their names,
+    /// skipping the index file created with the Markdown files export. Files which can't be
+    /// imported are reported without stopping the import.
+    pub async fn import_markdown_dir(&self, dir: &Path) -> anyhow::Result<MarkdownImportReport> {
+        let mut paths = Vec::new();
+        for dir_entry in std::fs::read_dir(dir)
+            .with_context(|| format!("Error while reading import directory {}", dir.display()))?
+        {
+            let path = dir_entry?.path();
+            let is_markdown = path.extension().is_some_and(|ext| ext == "md");
+            let is_index = path
+                .file_name()
+                .is_some_and(|name| name == markdown_export::INDEX_FILE_NAME);
+            if path.is_file() && is_markdown && !is_index {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let mut report = MarkdownImportReport::default();
+
+        for path in paths {
+            let parsed = read_markdown_draft(&path);
+            let mut draft = match parsed {
+                Ok(draft) => draft,
+                Err(err) => {
+                    report.errors.push((path, err.to_string()));
+                    continue;
+                }
+            };
+
+            (draft.tags, _) = self.normalize_tags(draft.tags);
+
+            match self.data_provide.add_entry(draft).await {
+                Ok(_) => report.imported += 1,
+                Err(err) => report.errors.push((path, err.to_string())),
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Returns all tags ordered according to the configured tags order.
+    pub fn get_all_tags(&self) -> Vec<String> {
+        self.get_tags_with_usage(false)
+            .into_iter()
+            .map(|(tag, _)| tag)
+            .collect()
+    }
+
+    /// Returns all tags with the count of entries having each of them, ordered according to the
+    /// configured tags order.
+    /// With tag hierarchy enabled, the parents of the tags are included too with the count of
+    /// entries having them or any of their children, ordered as a tree.
+    pub fn get_tags_with_counts(&self) -> Vec<(String, usize)> {
+        self.get_tags_with_usage(self.settings.tag_hierarchy)
+            .into_iter()
+            .map(|(tag, usage)| (tag, usage.count))
+            .collect()
+    }
+
+    fn get_tags_with_usage(&self, include_parents: bool) -> Vec<(String, TagUsage)> {
+        let mut usages: BTreeMap<&str, TagUsage> = BTreeMap::new();
+        for entry in self.entries.iter() {
+            let tags: BTreeSet<&str> = if include_parents {
+                entry
+                    .tags
+                    .iter()
+                    .flat_map(|tag| filter::tag_with_parents(tag))
+                    .collect()
+            } else {
+                entry.tags.iter().map(String::as_str).collect()
+            };
+
+            let used_at = entry.last_modified();
+            for tag in tags {
+                usages
+                    .entry(tag)
+                    .and_modify(|usage| usage.register(used_at))
+                    .or_insert_with(|| TagUsage::new(used_at));
+            }
+        }
+
+        tags_order::order_tags(usages, self.settings.tags_order, include_parents)
+    }
+
+    /// Returns the tags which can be used in the filter. With tag hierarchy enabled, the parents
+    /// of the tags are included too.
+    pub fn get_filter_tags(&self) -> Vec<String> {
+        if self.settings.tag_hierarchy {
+            self.get_tags_with_counts()
+                .into_iter()
+                .map(|(tag, _)| tag)
+                .collect()
+        } else {
+            self.get_all_tags()
+        }
+    }
+
+    /// Returns a map of all tags with the count of entries having each of them
+    pub fn get_tag_counts(&self) -> BTreeMap<String, usize> {
+        let mut tags: BTreeMap<String, usize> = BTreeMap::new();
+
+        for tag in self.entries.iter().flat_map(|entry| &entry.tags) {
+            *tags.entry(tag.to_owned()).or_default() += 1;
+        }
+
+        tags
+    }
+
+    /// Checks if the given tag is applied as a criterion in the current filter
+    pub fn is_tag_in_filter(&self, tag: &str) -> bool {
+        self.filter.as_ref().is_some_and(|filter| {
+            filter
+                .criteria
+                .iter()
+                .any(|c| matches!(c, FilterCriterion::Tag(t) if t == tag))
+        })
+    }
+
+    /// Adds the given tag to the filter criteria or removes it if it's already applied,
+    /// keeping the other criteria and the relation between them untouched.
+    pub fn toggle_tag_in_filter(&mut self, tag: &str) {
+        let mut filter = self.filter.clone().unwrap_or_default();
+
+        let tag_pos = filter
+            .criteria
+            .iter()
+            .position(|c| matches!(c, FilterCriterion::Tag(t) if t == tag));
+
+        match tag_pos {
+            Some(pos) => {
+                filter.criteria.remove(pos);
+            }
+            None => filter.criteria.push(FilterCriterion::Tag(tag.to_owned())),
+        }
+
+        let filter = (!filter.criteria.is_empty()).then_some(filter);
+        self.apply_filter(filter);
+    }
+
+    /// Sets and applies the given filter on the entries.
+    /// If confirming empty filters is enabled, a filter hiding all entries will be kept pending
+    /// instead until it's confirmed via [`App::apply_pending_filter()`].
     pub fn apply_filter(&mut self, filter: Option<Filter>) {
+        self.focused_entry_id = None;
+        self.revealed_entries.clear();
+        self.pending_filter = None;
+        self.applied_filter_preset = None;
+
+        let tag_hierarchy = self.settings.tag_hierarchy;
+        let hides_all = filter.as_ref().is_some_and(|filter| {
+            !self.entries.is_empty()
+                && !self
+                    .entries
+                    .par_iter()
+                    .any(|entry| filter.check_entry(entry, tag_hierarchy))
+        });
+
+        if self.settings.confirm_empty_filter && hides_all {
+            self.pending_filter = filter;
+            return;
+        }
+
         self.filter = filter;
         self.update_filtered_out_entries();
     }
 
-    /// Checks if the filter criteria still valid and update them if needed
+    /// Applies the filter preset bound to the given number key, or resets the filter if this
+    /// preset is already applied.
+    pub fn toggle_filter_preset(&mut self, key: u8) -> anyhow::Result<()> {
+        if self.applied_filter_preset == Some(key) && self.filter.is_some() {
+            self.apply_filter(None);
+            return Ok(());
+        }
+
+        let filter = self
+            .settings
+            .filter_presets
+            .iter()
+            .find(|preset| preset.key == key)
+            .map(|preset| preset.filter.clone())
+            .ok_or_else(|| anyhow!("No filter preset is bound to the key {key}"))?;
+
+        filter.validate()?;
+
+        self.apply_filter(Some(filter));
+
+        if !self.has_pending_filter() {
+            self.applied_filter_preset = Some(key);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the filters saved by the user with their names.
+    pub fn get_saved_filters(&self) -> &[(String, Filter)] {
+        &self.state.saved_filters
+    }
+
+    /// Saves the applied filter with the given name, replacing the saved filter with the same
+    /// name if exists.
+    pub fn save_current_filter(&mut self, name: &str) -> anyhow::Result<()> {
+        let name = name.trim();
+        if name.is_empty() {
+            bail!("Filter name can't be empty");
+        }
+
+        let filter = self
+            .filter
+            .clone()
+            .ok_or_else(|| anyhow!("There is no applied filter to save"))?;
+
+        match self
+            .state
+            .saved_filters
+            .iter_mut()
+            .find(|(saved_name, _)| saved_name == name)
+        {
+            Some((_, saved_filter)) => *saved_filter = filter,
+            None => self.state.saved_filters.push((name.to_owned(), filter)),
+        }
+
+        Ok(())
+    }
+
+    /// Applies the saved filter with the given name.
+    pub fn apply_saved_filter(&mut self, name: &str) -> anyhow::Result<()> {
+        let filter = self
+            .state
+            .saved_filters
+            .iter()
+            .find(|(saved_name, _)| saved_name == name)
+            .map(|(_, filter)| filter.clone())
+            .ok_or_else(|| anyhow!("No filter is saved with the name '{name}'"))?;
+
+        filter.validate()?;
+
+        self.apply_filter(Some(filter));
+
+        Ok(())
+    }
+
+    /// Deletes the saved filter with the given name, returning whether it existed.
+    pub fn delete_saved_filter(&mut self, name: &str) -> bool {
+        let count = self.state.saved_filters.len();
+        self.state
+            .saved_filters
+            .retain(|(saved_name, _)| saved_name != name);
+
+        self.state.saved_filters.len() != count
+    }
+
+    /// Checks if there is a filter hiding all entries waiting for confirmation.
+    pub fn has_pending_filter(&self) -> bool {
+        self.pending_filter.is_some()
+    }
+
+    /// Applies the filter waiting for confirmation if any.
+    pub fn apply_pending_filter(&mut self) {
+        if let Some(filter) = self.pending_filter.take() {
+            self.focused_entry_id = None;
+            self.revealed_entries.clear();
+            self.filter = Some(filter);
+            self.update_filtered_out_entries();
+        }
+    }
+
+    /// Discards the filter waiting for confirmation keeping the current filter.
+    pub fn discard_pending_filter(&mut self) {
+        self.pending_filter = None;
+    }
+
+    /// Checks if the filter criteria still valid and update them if needed according to the
+    /// configured [`MissingFilterTagsBehavior`]
     fn update_filter(&mut self) {
-        if self.filter.is_some() {
-            let all_tags = self.get_all_tags();
-            let filter = self.filter.as_mut().unwrap();
-
-            filter.criteria.retain(|cr| match cr {
-                FilterCriterion::Tag(tag) => all_tags.contains(tag),
-                FilterCriterion::Title(_) => true,
-                FilterCriterion::Content(_) => true,
-                FilterCriterion::Priority(_) => true,
-            });
+        let behavior = self.settings.missing_filter_tags;
+        if self.filter.is_none() || behavior == MissingFilterTagsBehavior::Keep {
+            return;
+        }
+
+        let all_tags = self.get_filter_tags();
+        let filter = self.filter.as_mut().unwrap();
 
-            if filter.criteria.is_empty() {
-                self.filter = None;
+        let mut dropped_tags = Vec::new();
+        filter.criteria.retain(|cr| match cr {
+            FilterCriterion::Tag(tag) if !all_tags.contains(tag) => {
+                dropped_tags.push(tag.to_owned());
+                false
             }
+            FilterCriterion::Tag(_) => true,
+            FilterCriterion::Title(_) => true,
+            FilterCriterion::Content(_) => true,
+            FilterCriterion::Priority(_) => true,
+            FilterCriterion::TitleRegex(_) => true,
+            FilterCriterion::ContentRegex(_) => true,
+            FilterCriterion::Ids(_) => true,
+            FilterCriterion::DateRange { .. } => true,
+            FilterCriterion::Not(criterion) => match criterion.as_ref() {
+                FilterCriterion::Tag(tag) if !all_tags.contains(tag) => {
+                    dropped_tags.push(tag.to_owned());
+                    false
+                }
+                _ => true,
+            },
+        });
+
+        if filter.criteria.is_empty() {
+            self.filter = None;
+        }
+
+        if behavior == MissingFilterTagsBehavior::DropNotify {
+            self.dropped_filter_tags.extend(dropped_tags);
         }
     }
 
+    /// Changes the value of the option with the given key in the settings and writes it to the
+    /// settings file. See [`Settings::set_option`]
+    pub async fn change_setting(
+        &mut self,
+        key: &str,
+        value: Option<toml::Value>,
+    ) -> anyhow::Result<()> {
+        self.settings.set_option(key, value.clone())?;
+        self.settings.persist_option(key, value).await?;
+
+        if key == "history_limit" {
+            self.set_history_limit(self.settings.history_limit);
+        }
+
+        if key == "history_grouping_interval" {
+            let interval = self.settings.history_grouping_interval;
+            self.history
+                .set_grouping_interval(Duration::from_secs(interval));
+        }
+
+        // Options like the tag hierarchy change which entries match the filter.
+        self.update_filtered_out_entries();
+
+        Ok(())
+    }
+
+    /// Changes the size limit of the undo and redo stacks, dropping the oldest changes if they
+    /// exceed the new limit. A limit of zero disables the history.
+    pub fn set_history_limit(&mut self, limit: usize) {
+        self.history.set_limit(limit);
+    }
+
+    /// Takes the tags which are dropped from the filter and not notified about yet.
+    pub fn take_dropped_filter_tags(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.dropped_filter_tags)
+    }
+
+    /// Takes the duplicated tags which are removed from journals and not notified about yet.
+    pub fn take_duplicate_tags(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.duplicate_tags)
+    }
+
+    /// Adds the entry with the given id to the history of the viewed entries.
+    pub fn record_viewed_entry(&mut self, entry_id: u32) {
+        self.view_history.visit(entry_id);
+    }
+
+    /// Moves back in the history of the viewed entries, skipping the deleted ones, and returns
+    /// the id of the entry to view.
+    pub fn go_back_viewed_entries(&mut self) -> Option<u32> {
+        let entries = &self.entries;
+        self.view_history
+            .back(|id| entries.iter().any(|entry| entry.id == id))
+    }
+
+    /// Moves forward in the history of the viewed entries, skipping the deleted ones, and returns
+    /// the id of the entry to view.
+    pub fn go_forward_viewed_entries(&mut self) -> Option<u32> {
+        let entries = &self.entries;
+        self.view_history
+            .forward(|id| entries.iter().any(|entry| entry.id == id))
+    }
+
+    /// Returns the ids of the active entries whose title or content contains the given query.
+    pub fn search(&self, query: &str) -> Vec<u32> {
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let query_lower = query.to_lowercase();
+        let contains = |text: &str| {
+            if self.search_case_sensitive {
+                text.contains(query)
+            } else {
+                text.to_lowercase().contains(&query_lower)
+            }
+        };
+
+        self.get_active_entries()
+            .filter(|entry| contains(&entry.title) || contains(&entry.content))
+            .map(|entry| entry.id)
+            .collect()
+    }
+
+    pub fn get_search_query(&self) -> Option<&str> {
+        self.search_query.as_deref()
+    }
+
+    pub fn is_search_case_sensitive(&self) -> bool {
+        self.search_case_sensitive
+    }
+
+    /// Sets the query of the full-text search, where empty queries clear the search.
+    pub fn set_search(&mut self, query: Option<String>, case_sensitive: bool) {
+        self.search_query = query.filter(|query| !query.is_empty());
+        self.search_case_sensitive = case_sensitive;
+    }
+
+    /// Returns the id of the next or previous active entry matching the search query relative
+    /// to the current entry, wrapping around at the ends of the list.
+    pub fn get_search_match(&self, next: bool) -> Option<u32> {
+        let matches = self.search(self.search_query.as_deref()?);
+        let current_pos = self.current_entry_id.and_then(|current_id| {
+            self.get_active_entries()
+                .position(|entry| entry.id == current_id)
+        });
+
+        let positions: Vec<(usize, u32)> = self
+            .get_active_entries()
+            .enumerate()
+            .filter(|(_, entry)| matches.contains(&entry.id))
+            .map(|(pos, entry)| (pos, entry.id))
+            .collect();
+
+        let found = match (current_pos, next) {
+            (Some(current), true) => positions.iter().find(|(pos, _)| *pos > current),
+            (Some(current), false) => positions.iter().rev().find(|(pos, _)| *pos < current),
+            (None, _) => None,
+        };
+
+        let wrapped = if next {
+            positions.first()
+        } else {
+            positions.last()
+        };
+
+        found.or(wrapped).map(|(_, id)| *id)
+    }
+
+    pub fn is_focus_mode(&self) -> bool {
+        self.focused_entry_id.is_some()
+    }
+
+    /// Enters the focus mode hiding all entries except the current one, or exits it restoring
+    /// the entries of the applied filter if it's already active.
+    pub fn toggle_focus_mode(&mut self) {
+        self.focused_entry_id = match self.focused_entry_id {
+            Some(_) => None,
+            None => self.current_entry_id,
+        };
+        self.revealed_entries.clear();
+
+        self.update_filtered_out_entries();
+    }
+
+    /// Makes the entry with the given id visible before navigating to it if it's hidden by the
+    /// filter, according to the configured [`HiddenEntryNavigation`].
+    /// Returns if the entry is visible.
+    pub fn reveal_entry(&mut self, entry_id: u32) -> bool {
+        if !self.filtered_out_entries.contains(&entry_id) {
+            return true;
+        }
+
+        match self.settings.hidden_entry_navigation {
+            HiddenEntryNavigation::ClearFilter => self.apply_filter(None),
+            HiddenEntryNavigation::Refuse => return false,
+            HiddenEntryNavigation::Reveal => {
+                self.revealed_entries.insert(entry_id);
+                self.update_filtered_out_entries();
+            }
+        }
+
+        !self.filtered_out_entries.contains(&entry_id)
+    }
+
+    /// Takes the entry left as the only visible one by the filter or the focus mode if it should
+    /// be selected according to the configured [`SingleEntryFilter`].
+    pub fn take_single_active_entry(&mut self) -> Option<u32> {
+        self.single_active_entry.take()
+    }
+
     /// Applies filter on the entries and filter out the ones who don't meet the filter's criteria
     fn update_filtered_out_entries(&mut self) {
+        self.update_filtered_out_entries_intern();
+
+        self.single_active_entry = None;
+        if self.settings.single_entry_filter == SingleEntryFilter::Keep
+            || (self.filter.is_none() && self.focused_entry_id.is_none())
+        {
+            return;
+        }
+
+        let active_ids: Vec<u32> = self
+            .get_active_entries()
+            .take(2)
+            .map(|entry| entry.id)
+            .collect();
+        let single_id = match active_ids.as_slice() {
+            [id] => Some(*id),
+            _ => None,
+        };
+
+        self.single_active_entry = single_id.filter(|id| self.current_entry_id != Some(*id));
+    }
+
+    fn update_filtered_out_entries_intern(&mut self) {
+        if let Some(focused_id) = self.focused_entry_id {
+            if self.entries.iter().any(|entry| entry.id == focused_id) {
+                self.filtered_out_entries = self
+                    .entries
+                    .iter()
+                    .map(|entry| entry.id)
+                    .filter(|id| *id != focused_id && !self.revealed_entries.contains(id))
+                    .collect();
+                return;
+            }
+
+            // Focus mode ends once its entry doesn't exist anymore
+            self.focused_entry_id = None;
+        }
+
         if let Some(filter) = self.filter.as_ref() {
+            let tag_hierarchy = self.settings.tag_hierarchy;
             self.filtered_out_entries = self
                 .entries
                 .par_iter()
-                .filter(|entry| !filter.check_entry(entry))
+                .filter(|entry| {
+                    !filter.check_entry(entry, tag_hierarchy)
+                        && !self.revealed_entries.contains(&entry.id)
+                })
                 .map(|entry| entry.id)
                 .collect();
         } else {
@@ -405,7 +2013,9 @@ where
             return;
         }
 
-        let tags = { self.get_all_tags() };
+        // Colors are assigned in alphabetical order regardless of the tags order to keep them
+        // stable when the usage of the tags changes.
+        let tags = self.get_tag_counts().into_keys().collect();
         if let Some(colored_tags) = self.colored_tags.as_mut() {
             colored_tags.update_tags(tags);
         }
@@ -418,13 +2028,45 @@ where
             .and_then(|c| c.get_tag_color(tag))
     }
 
+    /// Applies the given colors tentatively on the tag to preview them without assigning them.
+    pub fn preview_tag_color(&mut self, tag: &str, colors: TagColors) {
+        if let Some(colored_tags) = self.colored_tags.as_mut() {
+            colored_tags.set_preview(tag.to_owned(), colors);
+        }
+    }
+
+    /// Drops the previewed tag colors restoring the assigned ones.
+    pub fn cancel_tag_color_preview(&mut self) {
+        if let Some(colored_tags) = self.colored_tags.as_mut() {
+            colored_tags.cancel_preview();
+        }
+    }
+
+    /// Assigns the previewed tag colors to their tag, keeping them in the settings.
+    /// Call [`Self::persist_tag_color`] to write them to the settings file.
+    pub fn confirm_tag_color_preview(&mut self) {
+        let confirmed = self
+            .colored_tags
+            .as_mut()
+            .and_then(|colored_tags| colored_tags.confirm_preview());
+
+        if let Some((tag, colors)) = confirmed {
+            self.settings.set_tag_color(&tag, Some(colors));
+        }
+    }
+
+    /// Writes the colors assigned to the given tag to the settings file so they survive restarts.
+    pub async fn persist_tag_color(&self, tag: &str) -> anyhow::Result<()> {
+        self.settings.persist_tag_color(tag).await
+    }
+
     pub fn cycle_tags_in_filter(&mut self) {
         let all_tags = self.get_all_tags();
         if all_tags.len() <= 1 {
             return;
         }
 
-        if let Some(mut filter) = self.filter.take() {
+        if let Some(mut filter) = self.filter.clone() {
             let applied_tags: Vec<String> = filter
                 .criteria
                 .iter()
@@ -508,8 +2150,9 @@ where
     }
 
     fn sort_entries(&mut self) {
+        let pinned_order = self.settings.pinned_order;
         self.entries
-            .sort_by(|entry1, entry2| self.state.sorter.sort(entry1, entry2));
+            .sort_by(|entry1, entry2| self.state.sorter.sort_pinned(entry1, entry2, pinned_order));
     }
 
     pub fn load_state(&mut self, ui_components: &mut UIComponents) {
@@ -526,26 +2169,175 @@ where
         self.state = state;
     }
 
-    pub fn persist_state(&self) -> anyhow::Result<()> {
+    /// Applies the filter persisted in the app state if restoring it is enabled, validating it
+    /// the same way as the filters applied by the user. Entries must be loaded before.
+    pub fn restore_filter(&mut self) {
+        let filter = self.state.filter.take();
+        if !self.settings.restore_filter_on_startup {
+            return;
+        }
+
+        // Filter tags which don't exist anymore are handled like after changing the journals.
+        self.filter = filter;
+        self.update_filter();
+
+        let filter = self.filter.take();
+        self.apply_filter(filter);
+    }
+
+    /// Checks if the app has been upgraded since the last run, returning the release notes of the
+    /// current version to be shown once. The current version is marked as seen afterwards.
+    pub fn take_whats_new(&mut self) -> Option<ReleaseNotes> {
+        let show = changelog::should_show_whats_new(
+            self.state.last_seen_version.as_deref(),
+            changelog::CURRENT_VERSION,
+        );
+
+        self.state.last_seen_version = Some(changelog::CURRENT_VERSION.into());
+
+        if !show {
+            return None;
+        }
+
+        Version::parse(changelog::CURRENT_VERSION).and_then(ReleaseNotes::for_version)
+    }
+
+    /// Restores the undo and redo history of the current journal persisted in the app state if
+    /// enabled, dropping the changes on journals which don't exist anymore or have been replaced
+    /// by other journals. Entries must be loaded before.
+    pub fn restore_history(&mut self) -> anyhow::Result<()> {
+        let journal_key = self.settings.get_journal_key()?;
+        self.history_restored = true;
+        let Some(history) = self.state.histories.remove(&journal_key) else {
+            return Ok(());
+        };
+
+        if self.settings.is_history_persisted() {
+            let entries = &self.entries;
+            self.history.restore(history, |id| {
+                entries
+                    .iter()
+                    .find(|entry| entry.id == id)
+                    .map(EntryStamp::from)
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the undo and redo history to be persisted with the stamps of the entries it
+    /// refers to.
+    fn persisted_history(&self) -> PersistedHistory {
+        self.history.to_persisted(|id| {
+            self.entries
+                .iter()
+                .find(|entry| entry.id == id)
+                .map(EntryStamp::from)
+        })
+    }
+
+    /// Replaces the persisted history of the current journal in the app state with the current
+    /// history if enabled. Nothing is changed if the history hasn't been restored.
+    fn update_state_history(&mut self) -> anyhow::Result<()> {
+        if !self.history_restored {
+            return Ok(());
+        }
+
+        let journal_key = self.settings.get_journal_key()?;
+        if self.settings.is_history_persisted() {
+            let history = self.persisted_history();
+            self.state.histories.insert(journal_key, history);
+        } else {
+            self.state.histories.remove(&journal_key);
+        }
+
+        Ok(())
+    }
+
+    pub fn persist_state(&mut self) -> anyhow::Result<()> {
+        self.state.filter = self
+            .settings
+            .restore_filter_on_startup
+            .then(|| self.filter.clone())
+            .flatten();
+
+        self.update_state_history()?;
+
         self.state.save(&self.settings)?;
 
         Ok(())
     }
 
+    /// Checks if undo needs confirmation because it would move past the last save point.
+    pub fn undo_needs_confirm(&self) -> bool {
+        self.settings.confirm_history_past_save && self.history.undo_crosses_save_point()
+    }
+
+    /// Checks if redo needs confirmation because it would move past the last save point.
+    pub fn redo_needs_confirm(&self) -> bool {
+        self.settings.confirm_history_past_save && self.history.redo_crosses_save_point()
+    }
+
+    /// Checks if applying undo or redo according to the given [`HistoryStack`] the given count
+    /// of times needs confirmation because it would move past the last save point.
+    pub fn history_jump_needs_confirm(&self, target: HistoryStack, count: usize) -> bool {
+        self.settings.confirm_history_past_save
+            && self.history.jump_crosses_save_point(target, count)
+    }
+
+    /// Returns the descriptions of the changes on the given history stack, ordered from the
+    /// latest to the earliest.
+    pub fn describe_history(&self, target: HistoryStack) -> Vec<String> {
+        let get_title = |id| self.get_entry(id).map(|entry| entry.title.to_owned());
+        self.history
+            .changes(target)
+            .map(|change| change.describe(&get_title))
+            .collect()
+    }
+
+    /// Applies undo or redo according to the given [`HistoryStack`] the given count of times,
+    /// returning the id of the last effected entry.
+    pub async fn jump_history(
+        &mut self,
+        target: HistoryStack,
+        count: usize,
+    ) -> anyhow::Result<Option<u32>> {
+        let mut last_id = None;
+        for _ in 0..count {
+            let id = match target {
+                HistoryStack::Undo => self.undo().await?,
+                HistoryStack::Redo => self.redo().await?,
+            };
+            last_id = id.or(last_id);
+        }
+
+        Ok(last_id)
+    }
+
     /// Apply undo on entries returning the id of the effected entry.
     pub async fn undo(&mut self) -> anyhow::Result<Option<u32>> {
-        match self.history.pop_undo() {
+        let result = match self.history.pop_undo() {
             Some(change) => self.apply_history_change(change, HistoryStack::Redo).await,
             None => Ok(None),
-        }
+        };
+
+        // Following changes shouldn't be grouped with the changes before undo or redo.
+        self.history.stop_grouping();
+
+        result
     }
 
     /// Apply redo on entries returning the id of the effected entry.
     pub async fn redo(&mut self) -> anyhow::Result<Option<u32>> {
-        match self.history.pop_redo() {
+        let result = match self.history.pop_redo() {
             Some(change) => self.apply_history_change(change, HistoryStack::Undo).await,
             None => Ok(None),
-        }
+        };
+
+        self.history.end_redo();
+        self.history.stop_grouping();
+
+        result
     }
 
     async fn apply_history_change(
@@ -562,14 +2354,7 @@ where
             Change::RemoveEntry(entry) => {
                 log::trace!("History Apply: Remove Entry: {entry:?}");
                 let id = self
-                    .add_entry_intern(
-                        entry.title,
-                        entry.date,
-                        entry.tags,
-                        entry.priority,
-                        Some(entry.content),
-                        history_target,
-                    )
+                    .add_draft_intern(EntryDraft::from_entry(*entry), history_target)
                     .await?;
 
                 Ok(Some(id))
@@ -594,14 +2379,103 @@ where
                     .await?;
                 Ok(Some(id))
             }
+            Change::EntryPin { id, pinned_at } => {
+                log::trace!("History Apply: Change Pin: ID: {id}");
+                self.update_entry_pin(id, pinned_at, history_target).await?;
+                Ok(Some(id))
+            }
+            Change::EntryColor { id, color } => {
+                log::trace!("History Apply: Change Color: ID: {id}");
+                self.update_entry_color(id, color, history_target).await?;
+                Ok(Some(id))
+            }
+            Change::Batch(changes) => {
+                log::trace!("History Apply: Batch: {} changes", changes.len());
+                self.history.begin_batch(history_target);
+                let mut result = Ok(None);
+                for change in changes {
+                    result = Box::pin(self.apply_history_change(change, history_target)).await;
+                    if result.is_err() {
+                        break;
+                    }
+                }
+
+                self.history.end_batch();
+
+                result
+            }
         }
     }
 }
 
+/// Format of the file a single entry is exported to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryExportFormat {
+    /// The raw content of the entry.
+    Content,
+    /// Markdown document with the title, date, priority and tags as header.
+    Markdown,
+    /// Standalone HTML page with the title, date, priority and tags as header.
+    Html,
+}
+
 /// Represents what part of [`Entry`] will be changed.
 enum EntryEditPart {
     /// The attributes (Name, Date...) of the entry will be changed
     Attributes,
     /// The content of the entry will be changed.
     Content,
+    /// The pin state of the entry will be changed.
+    Pin,
+    /// The color of the entry will be changed.
+    Color,
+}
+
+async fn write_export_file(path: PathBuf, content: String) -> anyhow::Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+
+    tokio::fs::write(path, content).await?;
+
+    Ok(())
+}
+
+/// Ensures the given exported text ends with a newline or has no trailing newlines at all.
+/// Empty text is kept empty.
+fn apply_trailing_newline(mut text: String, trailing_newline: bool) -> String {
+    if trailing_newline {
+        if !text.is_empty() && !text.ends_with('\n') {
+            text.push('\n');
+        }
+    } else {
+        let trimmed_len = text.trim_end_matches(['\n', '\r']).len();
+        text.truncate(trimmed_len);
+    }
+
+    text
+}
+
+/// Generates a title for an entry from the first non-empty line of the given content,
+/// falling back to the given date if the content is empty.
+fn generate_title(content: Option<&str>, date: &DateTime<Utc>) -> String {
+    const MAX_TITLE_CHARS: usize = 50;
+
+    content
+        .and_then(|content| content.lines().map(str::trim).find(|line| !line.is_empty()))
+        .map(|line| line.chars().take(MAX_TITLE_CHARS).collect())
+        .unwrap_or_else(|| date.format("%d-%m-%Y").to_string())
+}
+
+/// Reads the Markdown file in the given path and parses it into an entry draft, using its
+/// modification date if the date isn't provided in the file.
+fn read_markdown_draft(path: &Path) -> anyhow::Result<EntryDraft> {
+    let text = std::fs::read_to_string(path)?;
+    let modified: DateTime<Utc> = std::fs::metadata(path)?.modified()?.into();
+    let file_stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy())
+        .unwrap_or_default();
+
+    import::parse_markdown_draft(&text, &file_stem, modified)
 }
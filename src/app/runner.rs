@@ -1,11 +1,25 @@
+use std::{
+    fs::File,
+    io::BufReader,
+    path::PathBuf,
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
 use anyhow::{Context, Result};
-use crossterm::event::{Event, EventStream, KeyEventKind};
+use chrono::{DateTime, Local, NaiveTime, TimeZone};
+use crossterm::{
+    cursor::SetCursorStyle,
+    event::{Event, EventStream, KeyEventKind},
+    execute,
+};
 use ratatui::{backend::Backend, Terminal};
 
 use crate::app::{App, UIComponents};
 use crate::cli::PendingCliCommand;
-use crate::settings::{BackendType, Settings};
+use crate::settings::{BackendType, EditorCursorStyle, Settings};
 use futures_util::StreamExt;
+use tokio::time::MissedTickBehavior;
 
 use backend::DataProvider;
 #[cfg(feature = "json")]
@@ -13,9 +27,19 @@ use backend::JsonDataProvide;
 #[cfg(feature = "sqlite")]
 use backend::SqliteDataProvide;
 
+use super::diff::{diff_with_other, DiffMatch, OtherJournals};
 use super::keymap::Input;
 use super::ui::ui_functions::render_message_centered;
-use super::ui::Styles;
+use super::ui::{AutosaveStatus, ControlType, Styles, UICommand};
+
+/// Interval to check the autosave timer and update its indicator.
+const AUTOSAVE_TICK: Duration = Duration::from_secs(1);
+
+/// Interval to check whether the timeout of the shown confirmation prompt has elapsed.
+const CONFIRMATION_TICK: Duration = Duration::from_millis(250);
+
+/// Whether the configured editor cursor style is currently applied on the terminal.
+static EDITOR_CURSOR_APPLIED: AtomicBool = AtomicBool::new(false);
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum HandleInputReturnType {
@@ -55,7 +79,7 @@ pub async fn run<B: Backend>(
             } else {
                 crate::settings::sqlite_backend::get_default_sqlite_path()?
             };
-            let data_provider = SqliteDataProvide::from_file(path).await?;
+            let data_provider = create_sqlite_provider(path, &settings).await?;
             run_intern(terminal, data_provider, settings, styles, pending_cmd).await
         }
         #[cfg(not(feature = "sqlite"))]
@@ -64,7 +88,265 @@ pub async fn run<B: Backend>(
                 "Feature 'sqlite' is not installed. Please check your configs and set your backend to an installed feature, or reinstall the program with 'sqlite' feature"
             )
         }
+        #[cfg(feature = "encrypted_json")]
+        BackendType::EncryptedJson => {
+            let data_provider = create_encrypted_json_provider(&settings).await?;
+            run_intern(terminal, data_provider, settings, styles, pending_cmd).await
+        }
+        #[cfg(not(feature = "encrypted_json"))]
+        BackendType::EncryptedJson => {
+            anyhow::bail!(
+                "Feature 'encrypted_json' is not installed. Please check your configs and set your backend to an installed feature, or reinstall the program with 'encrypted_json' feature"
+            )
+        }
+    }
+}
+
+/// Creates the data provider of the SQLite back-end, migrating the journals of the JSON back-end
+/// into the database when it's created.
+#[cfg(feature = "sqlite")]
+#[cfg_attr(not(feature = "json"), allow(unused_variables))]
+async fn create_sqlite_provider(path: PathBuf, settings: &Settings) -> Result<SqliteDataProvide> {
+    #[cfg(feature = "json")]
+    let is_first_run = !path.exists();
+
+    let data_provider = SqliteDataProvide::from_file(path.clone()).await?;
+
+    #[cfg(feature = "json")]
+    if is_first_run {
+        if let Err(err) = migrate_json_entries(&data_provider, settings).await {
+            // Remove the created database so the migration is retried on the next run.
+            drop(data_provider);
+            if let Err(remove_err) = tokio::fs::remove_file(&path).await {
+                log::error!("Removing new SQLite database failed. Error info: {remove_err}");
+            }
+            return Err(err);
+        }
+    }
+
+    Ok(data_provider)
+}
+
+/// Imports the journals of the JSON back-end file, if it exists, into the newly created SQLite
+/// database to keep the journals after switching the back-end.
+#[cfg(all(feature = "json", feature = "sqlite"))]
+async fn migrate_json_entries(
+    data_provider: &SqliteDataProvide,
+    settings: &Settings,
+) -> Result<()> {
+    let json_path = if let Some(path) = &settings.json_backend.file_path {
+        path.clone()
+    } else {
+        crate::settings::json_backend::get_default_json_path()?
+    };
+
+    if !json_path.exists() {
+        return Ok(());
     }
+
+    let count = data_provider
+        .import_json_file(json_path.clone())
+        .await
+        .with_context(|| {
+            format!(
+                "Error while migrating journals from JSON file {}",
+                json_path.display()
+            )
+        })?;
+
+    log::info!(
+        "{count} journal(s) migrated from JSON file {} to SQLite database",
+        json_path.display()
+    );
+
+    Ok(())
+}
+
+/// Creates the data provider of the encrypted JSON back-end, which must be unlocked with the
+/// passphrase beforehand.
+#[cfg(feature = "encrypted_json")]
+async fn create_encrypted_json_provider(settings: &Settings) -> Result<JsonDataProvide> {
+    let key = settings
+        .encrypted_json_backend
+        .key
+        .clone()
+        .context("Encrypted JSON back-end isn't unlocked")?;
+
+    JsonDataProvide::new_encrypted(settings.get_active_backend_path()?, key).await
+}
+
+/// Imports newline-delimited JSON entries from the given file or from the standard input into
+/// the current back-end without starting the app, reporting the lines that couldn't be imported.
+pub async fn import_ndjson(settings: Settings, file_path: Option<PathBuf>) -> Result<()> {
+    match settings.backend_type.unwrap_or_default() {
+        #[cfg(feature = "json")]
+        BackendType::Json => {
+            let data_provider = JsonDataProvide::new(settings.get_active_backend_path()?);
+            import_ndjson_intern(data_provider, settings, file_path).await
+        }
+        #[cfg(feature = "sqlite")]
+        BackendType::Sqlite => {
+            let data_provider =
+                SqliteDataProvide::from_file(settings.get_active_backend_path()?).await?;
+            import_ndjson_intern(data_provider, settings, file_path).await
+        }
+        #[cfg(feature = "encrypted_json")]
+        BackendType::EncryptedJson => {
+            let data_provider = create_encrypted_json_provider(&settings).await?;
+            import_ndjson_intern(data_provider, settings, file_path).await
+        }
+        #[allow(unreachable_patterns)]
+        backend => anyhow::bail!("Feature for backend '{backend:?}' is not installed"),
+    }
+}
+
+async fn import_ndjson_intern<D: DataProvider>(
+    data_provider: D,
+    settings: Settings,
+    file_path: Option<PathBuf>,
+) -> Result<()> {
+    let app = App::new(data_provider, settings);
+
+    let report = match file_path {
+        Some(path) => {
+            let file = File::open(&path)
+                .with_context(|| format!("Error while opening import file {}", path.display()))?;
+            app.import_ndjson_entries(BufReader::new(file)).await?
+        }
+        None => app.import_ndjson_entries(std::io::stdin().lock()).await?,
+    };
+
+    println!("{} journal(s) imported", report.imported);
+
+    if !report.errors.is_empty() {
+        for (line, err) in report.errors.iter() {
+            eprintln!("Line {line}: {err}");
+        }
+        anyhow::bail!("{} line(s) couldn't be imported", report.errors.len())
+    }
+
+    Ok(())
+}
+
+/// Imports the Markdown files of the given directory into the current back-end without starting
+/// the app, reporting the files that couldn't be imported.
+pub async fn import_markdown(settings: Settings, dir: PathBuf) -> Result<()> {
+    match settings.backend_type.unwrap_or_default() {
+        #[cfg(feature = "json")]
+        BackendType::Json => {
+            let data_provider = JsonDataProvide::new(settings.get_active_backend_path()?);
+            import_markdown_intern(data_provider, settings, dir).await
+        }
+        #[cfg(feature = "sqlite")]
+        BackendType::Sqlite => {
+            let data_provider =
+                SqliteDataProvide::from_file(settings.get_active_backend_path()?).await?;
+            import_markdown_intern(data_provider, settings, dir).await
+        }
+        #[cfg(feature = "encrypted_json")]
+        BackendType::EncryptedJson => {
+            let data_provider = create_encrypted_json_provider(&settings).await?;
+            import_markdown_intern(data_provider, settings, dir).await
+        }
+        #[allow(unreachable_patterns)]
+        backend => anyhow::bail!("Feature for backend '{backend:?}' is not installed"),
+    }
+}
+
+async fn import_markdown_intern<D: DataProvider>(
+    data_provider: D,
+    settings: Settings,
+    dir: PathBuf,
+) -> Result<()> {
+    let app = App::new(data_provider, settings);
+
+    let report = app.import_markdown_dir(&dir).await?;
+
+    println!("{} journal(s) imported", report.imported);
+
+    if !report.errors.is_empty() {
+        for (path, err) in report.errors.iter() {
+            eprintln!("{}: {err}", path.display());
+        }
+        anyhow::bail!("{} file(s) couldn't be imported", report.errors.len())
+    }
+
+    Ok(())
+}
+
+/// Compares the journals of the current back-end with the journals of the given file without
+/// starting the app, optionally exporting the journals found only in the given file.
+pub async fn diff_journals(
+    settings: Settings,
+    file_path: PathBuf,
+    match_by: DiffMatch,
+    export_path: Option<PathBuf>,
+) -> Result<()> {
+    match settings.backend_type.unwrap_or_default() {
+        #[cfg(feature = "json")]
+        BackendType::Json => {
+            let data_provider = JsonDataProvide::new(settings.get_active_backend_path()?);
+            diff_journals_intern(data_provider, file_path, match_by, export_path).await
+        }
+        #[cfg(feature = "sqlite")]
+        BackendType::Sqlite => {
+            let data_provider =
+                SqliteDataProvide::from_file(settings.get_active_backend_path()?).await?;
+            diff_journals_intern(data_provider, file_path, match_by, export_path).await
+        }
+        #[cfg(feature = "encrypted_json")]
+        BackendType::EncryptedJson => {
+            let data_provider = create_encrypted_json_provider(&settings).await?;
+            diff_journals_intern(data_provider, file_path, match_by, export_path).await
+        }
+        #[allow(unreachable_patterns)]
+        backend => anyhow::bail!("Feature for backend '{backend:?}' is not installed"),
+    }
+}
+
+async fn diff_journals_intern<D: DataProvider>(
+    data_provider: D,
+    file_path: PathBuf,
+    match_by: DiffMatch,
+    export_path: Option<PathBuf>,
+) -> Result<()> {
+    let current = data_provider.load_all_entries().await?;
+    let other = OtherJournals::load(&file_path)?;
+
+    let diff = diff_with_other(&current, &other, match_by)?;
+
+    let print_entries = |header: &str, entries: &[&backend::Entry]| {
+        println!("{header}: {}", entries.len());
+        for entry in entries {
+            println!(
+                "  {} | {}",
+                entry.date.format("%Y-%m-%d %H:%M"),
+                entry.title
+            );
+        }
+    };
+
+    print_entries("Only in current journals", &diff.only_current);
+    print_entries(
+        &format!("Only in {}", file_path.display()),
+        &diff.only_other,
+    );
+
+    if let Some(export_path) = export_path {
+        let dto = diff.other_delta_dto();
+        let file = File::create(&export_path).with_context(|| {
+            format!("Error while creating export file {}", export_path.display())
+        })?;
+        serde_json::to_writer_pretty(file, &dto)?;
+
+        println!(
+            "{} journal(s) exported to {}",
+            dto.entries.len(),
+            export_path.display()
+        );
+    }
+
+    Ok(())
 }
 
 async fn run_intern<B, D>(
@@ -79,31 +361,105 @@ where
     D: DataProvider,
 {
     let mut ui_components = UIComponents::new(styles);
+    ui_components.apply_keybindings(&settings)?;
     let mut app = App::new(data_provider, settings);
     if let Some(cmd) = pending_cmd {
-        if let Err(err) = exec_pending_cmd(terminal, &app, cmd).await {
-            ui_components.show_err_msg(err.to_string());
+        match exec_pending_cmd(terminal, &app, cmd).await {
+            Ok(Some(info)) => ui_components.show_info_msg(info),
+            Ok(None) => {}
+            Err(err) => ui_components.show_err_msg(err.to_string()),
         }
     }
 
     app.load_state(&mut ui_components);
 
-    if let Err(err) = app.load_entries().await {
-        ui_components.show_err_msg(err.to_string());
+    if let Some(notes) = app.take_whats_new() {
+        ui_components.show_whats_new(notes);
     }
 
+    match app.load_entries().await {
+        Ok(()) => {
+            app.restore_filter();
+            UICommand::ConfirmEmptyFilter
+                .execute(&mut ui_components, &mut app)
+                .await?;
+            ui_components.notify_dropped_filter_tags(&mut app);
+
+            if let Err(err) = app.restore_history() {
+                ui_components.show_err_msg(err.to_string());
+            }
+        }
+        Err(err) => ui_components.show_err_msg(err.to_string()),
+    }
+
+    let autosave_interval = app.settings.autosave_interval.map(Duration::from_secs);
+    ui_components.set_autosave_interval(autosave_interval);
+
+    let confirmation_timeout = app.settings.confirmation_timeout.map(Duration::from_secs);
+    ui_components.set_confirmation_timeout(confirmation_timeout);
+
     ui_components.set_current_entry(app.entries.first().map(|entry| entry.id), &mut app);
 
     draw_ui(terminal, &mut app, &mut ui_components)?;
 
+    let mut autosave_ticker = tokio::time::interval(AUTOSAVE_TICK);
+    autosave_ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    let mut autosave_status = AutosaveStatus::Idle;
+
+    let mut confirmation_ticker = tokio::time::interval(CONFIRMATION_TICK);
+    confirmation_ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+    // Redraw at local midnight to keep the date-dependent parts of the UI up to date.
+    let midnight_timer = tokio::time::sleep(duration_until_midnight(&Local::now()));
+    tokio::pin!(midnight_timer);
+
     let mut input_stream = EventStream::new();
-    while let Some(event) = input_stream.next().await {
-        let event = event.context("Error getting input stream")?;
-        match handle_input(event, &mut app, &mut ui_components).await {
+    loop {
+        let result = tokio::select! {
+            event = input_stream.next() => match event {
+                Some(event) => {
+                    let event = event.context("Error getting input stream")?;
+                    handle_input(event, &mut app, &mut ui_components).await
+                }
+                None => break,
+            },
+            _ = confirmation_ticker.tick(), if app.settings.confirmation_timeout.is_some_and(|secs| secs > 0) => {
+                ui_components
+                    .dismiss_timed_out_msg_box(&mut app, Instant::now())
+                    .await
+            }
+            () = &mut midnight_timer => {
+                let next_midnight = duration_until_midnight(&Local::now());
+                midnight_timer
+                    .as_mut()
+                    .reset(tokio::time::Instant::now() + next_midnight);
+                draw_ui(terminal, &mut app, &mut ui_components)?;
+                continue;
+            }
+            _ = autosave_ticker.tick(), if app.settings.autosave_interval.is_some() => {
+                if let Err(err) = ui_components.autosave_if_due(&mut app).await {
+                    ui_components.show_err_msg(err.to_string());
+                    draw_ui(terminal, &mut app, &mut ui_components)?;
+                }
+
+                // Redraw only when the indicator changes
+                let current_status = ui_components.autosave_status();
+                if current_status != autosave_status {
+                    autosave_status = current_status;
+                    draw_ui(terminal, &mut app, &mut ui_components)?;
+                }
+                continue;
+            }
+        };
+
+        match result {
             Ok(result) => {
                 match result {
                     HandleInputReturnType::Handled => {
                         ui_components.update_current_entry(&mut app);
+                        ui_components.notify_dropped_filter_tags(&mut app);
+                        ui_components.notify_duplicate_tags(&mut app);
+                        update_cursor_style(&app, &ui_components);
                         draw_ui(terminal, &mut app, &mut ui_components)?;
                     }
                     HandleInputReturnType::NotFound => {
@@ -132,24 +488,80 @@ where
     Ok(())
 }
 
+/// Returns the duration from the given time until the next midnight in its time zone.
+fn duration_until_midnight<Tz: TimeZone>(now: &DateTime<Tz>) -> Duration {
+    let next_day = now
+        .date_naive()
+        .succ_opt()
+        .expect("Current date can't be the last supported one");
+    let midnight = next_day.and_time(NaiveTime::MIN);
+
+    // Midnight can be skipped by daylight saving changes in some time zones.
+    let until_midnight = match now.timezone().from_local_datetime(&midnight).earliest() {
+        Some(midnight) => midnight.signed_duration_since(now),
+        None => midnight.signed_duration_since(now.naive_local()),
+    };
+
+    until_midnight.to_std().unwrap_or(Duration::ZERO)
+}
+
+/// Applies the configured cursor style when the editor gets focused and restores the default one
+/// once it loses the focus.
+fn update_cursor_style<D: DataProvider>(app: &App<D>, ui_components: &UIComponents) {
+    let cursor_style = app.settings.editor_cursor_style;
+    if cursor_style == EditorCursorStyle::Default {
+        return;
+    }
+
+    let editor_focused = ui_components.active_control == ControlType::EntryContentTxt;
+    match (
+        editor_focused,
+        EDITOR_CURSOR_APPLIED.load(Ordering::Relaxed),
+    ) {
+        (true, false) => {
+            set_cursor_style(cursor_style.into());
+            EDITOR_CURSOR_APPLIED.store(true, Ordering::Relaxed);
+        }
+        (false, true) => reset_cursor_style(),
+        _ => {}
+    }
+}
+
+/// Restores the default cursor style if the editor one is applied. It must be called on all
+/// paths leaving the app screen, so the cursor style doesn't leak into the terminal.
+pub fn reset_cursor_style() {
+    if EDITOR_CURSOR_APPLIED.swap(false, Ordering::Relaxed) {
+        set_cursor_style(SetCursorStyle::DefaultUserShape);
+    }
+}
+
+/// Sets the cursor style ignoring the errors since some terminals don't support it.
+fn set_cursor_style(cursor_style: SetCursorStyle) {
+    if let Err(err) = execute!(std::io::stdout(), cursor_style) {
+        log::trace!("Setting cursor style failed: Error info {err}");
+    }
+}
+
 async fn exec_pending_cmd<B: Backend, D: DataProvider>(
     terminal: &mut Terminal<B>,
     app: &App<D>,
     pending_cmd: PendingCliCommand,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<Option<String>> {
     match pending_cmd {
-        PendingCliCommand::ImportJournals(file_path) => {
+        PendingCliCommand::ImportJournals(file_path, strategy) => {
             terminal.draw(|f| render_message_centered(f, "Importing journals..."))?;
 
-            app.import_entries(file_path).await?;
+            let summary = app.import_entries(file_path, strategy).await?;
+
+            Ok(Some(summary))
         }
         PendingCliCommand::AssignPriority(priority) => {
             terminal.draw(|f| render_message_centered(f, "Assigning Priority to Journals..."))?;
             app.assign_priority_to_entries(priority).await?;
+
+            Ok(None)
         }
     }
-
-    Ok(())
 }
 
 fn draw_ui<B: Backend, D: DataProvider>(
@@ -187,3 +599,61 @@ async fn handle_input<'a, D: DataProvider>(
         Ok(HandleInputReturnType::NotFound)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use chrono::FixedOffset;
+    #[cfg(all(feature = "json", feature = "sqlite"))]
+    use chrono::Utc;
+
+    use super::*;
+
+    #[test]
+    fn next_midnight_duration() {
+        let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+
+        let now = tz.with_ymd_and_hms(2024, 3, 7, 22, 30, 0).unwrap();
+        assert_eq!(duration_until_midnight(&now), Duration::from_secs(90 * 60));
+
+        let now = tz.with_ymd_and_hms(2024, 12, 31, 23, 59, 59).unwrap();
+        assert_eq!(duration_until_midnight(&now), Duration::from_secs(1));
+
+        let now = tz.with_ymd_and_hms(2024, 3, 7, 0, 0, 0).unwrap();
+        assert_eq!(
+            duration_until_midnight(&now),
+            Duration::from_secs(24 * 3600)
+        );
+    }
+
+    #[cfg(all(feature = "json", feature = "sqlite"))]
+    #[tokio::test]
+    async fn retry_failed_json_migration() {
+        use crate::app::test::temp_dir::TempDir;
+        use backend::EntryDraft;
+
+        let dir = TempDir::new("migrate");
+        let json_path = dir.join("entries.json");
+        let sqlite_path = dir.join("entries.db");
+        let mut settings = Settings::default();
+        settings.json_backend.file_path = Some(json_path.clone());
+
+        // Corrupted JSON file fails the migration without keeping the new database.
+        std::fs::write(&json_path, "not json").unwrap();
+        assert!(create_sqlite_provider(sqlite_path.clone(), &settings)
+            .await
+            .is_err());
+        assert!(!sqlite_path.exists());
+
+        std::fs::remove_file(&json_path).unwrap();
+        let json_provider = JsonDataProvide::new(json_path.clone());
+        let draft = EntryDraft::new(Utc::now(), String::from("Json Title"), Vec::new(), None);
+        json_provider.add_entry(draft).await.unwrap();
+
+        let provider = create_sqlite_provider(sqlite_path.clone(), &settings)
+            .await
+            .unwrap();
+        let entries = provider.load_all_entries().await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].title, "Json Title");
+    }
+}
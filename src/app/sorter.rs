@@ -2,11 +2,15 @@ use backend::Entry;
 use serde::{Deserialize, Serialize};
 use std::{cmp::Ordering, fmt::Display};
 
+use crate::settings::PinnedOrder;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 pub enum SortCriteria {
     Date,
     Priority,
     Title,
+    LastModified,
+    TagCount,
 }
 
 impl Display for SortCriteria {
@@ -15,6 +19,8 @@ impl Display for SortCriteria {
             SortCriteria::Date => write!(f, "Date"),
             SortCriteria::Priority => write!(f, "Priority"),
             SortCriteria::Title => write!(f, "Title"),
+            SortCriteria::LastModified => write!(f, "Last Modified"),
+            SortCriteria::TagCount => write!(f, "Tag Count"),
         }
     }
 }
@@ -24,7 +30,14 @@ impl SortCriteria {
         let ascending_ord = match self {
             SortCriteria::Date => entry1.date.cmp(&entry2.date),
             SortCriteria::Priority => entry1.priority.cmp(&entry2.priority),
-            SortCriteria::Title => entry1.title.cmp(&entry2.title),
+            // Titles are compared ignoring case so "apple" and "Apple" are sorted together.
+            SortCriteria::Title => entry1
+                .title
+                .chars()
+                .flat_map(char::to_lowercase)
+                .cmp(entry2.title.chars().flat_map(char::to_lowercase)),
+            SortCriteria::LastModified => entry1.last_modified().cmp(&entry2.last_modified()),
+            SortCriteria::TagCount => entry1.tags.len().cmp(&entry2.tags.len()),
         };
 
         match order {
@@ -42,10 +55,14 @@ impl SortCriteria {
                 S::Date => (),
                 S::Priority => (),
                 S::Title => (),
+                S::LastModified => (),
+                S::TagCount => (),
             };
         }
 
-        [S::Date, S::Priority, S::Title].iter().copied()
+        [S::Date, S::Priority, S::Title, S::LastModified, S::TagCount]
+            .iter()
+            .copied()
     }
 }
 
@@ -90,12 +107,34 @@ impl Sorter {
         &self.criteria
     }
 
+    /// Compares the entries with the criteria in their order, falling back to the next
+    /// criterion on ties. Entries equal in all criteria are ordered by their ids ascending,
+    /// making the order deterministic regardless of the order the entries are loaded in.
     pub fn sort(&self, entry1: &Entry, entry2: &Entry) -> Ordering {
         self.criteria
             .iter()
             .map(|cr| cr.compare(entry1, entry2, &self.order))
             .find(|cmp| matches!(cmp, Ordering::Less | Ordering::Greater))
-            .unwrap_or(Ordering::Equal)
+            .unwrap_or_else(|| entry1.id.cmp(&entry2.id))
+    }
+
+    /// Compares the entries keeping the pinned ones above the unpinned regardless of the sort
+    /// order. Pinned entries are ordered among themselves according to the given [`PinnedOrder`],
+    /// while the unpinned ones are compared with [`Sorter::sort()`].
+    pub fn sort_pinned(
+        &self,
+        entry1: &Entry,
+        entry2: &Entry,
+        pinned_order: PinnedOrder,
+    ) -> Ordering {
+        match (entry1.pinned_at, entry2.pinned_at) {
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(pinned1), Some(pinned2)) if pinned_order == PinnedOrder::PinTime => pinned2
+                .cmp(&pinned1)
+                .then_with(|| entry1.id.cmp(&entry2.id)),
+            _ => self.sort(entry1, entry2),
+        }
     }
 }
 
@@ -189,6 +228,76 @@ mod test {
         assert_eq!(ids, vec![0, 2, 1], "Title Descending");
     }
 
+    #[test]
+    fn sort_title_ignore_case() {
+        let mut sorter = Sorter::default();
+        sorter.set_criteria(vec![SortCriteria::Title, SortCriteria::Priority]);
+        sorter.order = SortOrder::Ascending;
+
+        let mut entries = get_default_entries();
+        entries[0].title = String::from("banana");
+        entries[1].title = String::from("Apple");
+        entries[2].title = String::from("apple");
+        let mut clone = entries[0].clone();
+        clone.id = 3;
+        clone.title = String::from("Cherry");
+        entries.push(clone);
+
+        // Same titles in different cases are ordered by the next criteria
+        entries.sort_by(|e1, e2| sorter.sort(e1, e2));
+        let ids = get_ids(&entries);
+        assert_eq!(ids, vec![1, 2, 0, 3], "Title Ignore Case Ascending");
+
+        sorter.order = SortOrder::Descending;
+        entries.sort_by(|e1, e2| sorter.sort(e1, e2));
+        let ids = get_ids(&entries);
+        assert_eq!(ids, vec![3, 0, 2, 1], "Title Ignore Case Descending");
+    }
+
+    #[test]
+    fn sort_single_last_modified() {
+        let mut sorter = Sorter::default();
+        sorter.set_criteria(vec![SortCriteria::LastModified]);
+        sorter.order = SortOrder::Ascending;
+
+        let mut entries = get_default_entries();
+        // Entry without modification time falls back to its date.
+        entries[1].updated_at = Some(Utc.with_ymd_and_hms(2024, 2, 1, 1, 2, 3).unwrap());
+
+        entries.sort_by(|e1, e2| sorter.sort(e1, e2));
+        let ids = get_ids(&entries);
+        assert_eq!(ids, vec![0, 2, 1], "Last Modified Ascending");
+
+        sorter.order = SortOrder::Descending;
+        entries.sort_by(|e1, e2| sorter.sort(e1, e2));
+        let ids = get_ids(&entries);
+        assert_eq!(ids, vec![1, 2, 0], "Last Modified Descending");
+    }
+
+    #[test]
+    fn sort_single_tag_count() {
+        let mut sorter = Sorter::default();
+        sorter.set_criteria(vec![SortCriteria::TagCount]);
+        sorter.order = SortOrder::Ascending;
+
+        let mut entries = get_default_entries();
+        entries[2].tags = vec![String::from("Tag 1")];
+        let mut untagged = entries[0].clone();
+        untagged.id = 3;
+        entries.push(untagged);
+
+        // Entries without tags are ordered by their ids at the start
+        entries.sort_by(|e1, e2| sorter.sort(e1, e2));
+        let ids = get_ids(&entries);
+        assert_eq!(ids, vec![0, 3, 2, 1], "Tag Count Ascending");
+
+        // and at the end in descending order
+        sorter.order = SortOrder::Descending;
+        entries.sort_by(|e1, e2| sorter.sort(e1, e2));
+        let ids = get_ids(&entries);
+        assert_eq!(ids, vec![1, 2, 0, 3], "Tag Count Descending");
+    }
+
     #[test]
     fn sort_multi() {
         let mut sorter = Sorter::default();
@@ -210,4 +319,109 @@ mod test {
         let ids = get_ids(&entries);
         assert_eq!(ids, vec![3, 2, 0, 1], "Multi Descending");
     }
+
+    #[test]
+    fn sort_same_date_tiebreakers() {
+        let date = Utc.with_ymd_and_hms(2024, 5, 1, 8, 0, 0).unwrap();
+        let entries: Vec<Entry> = [
+            (0, "Beta", Some(1)),
+            (1, "alpha", Some(2)),
+            (2, "Alpha", Some(1)),
+            (3, "beta", Some(1)),
+            (4, "Gamma", None),
+        ]
+        .into_iter()
+        .map(|(id, title, priority)| {
+            Entry::new(id, date, title.into(), String::new(), vec![], priority)
+        })
+        .collect();
+
+        let mut sorter = Sorter::default();
+        sorter.set_criteria(vec![
+            SortCriteria::Date,
+            SortCriteria::Priority,
+            SortCriteria::Title,
+        ]);
+        sorter.order = SortOrder::Ascending;
+
+        // Same dates fall back to priority, then title, then id
+        for shift in 0..entries.len() {
+            let mut shuffled = entries.clone();
+            shuffled.rotate_left(shift);
+            shuffled.sort_by(|e1, e2| sorter.sort(e1, e2));
+            assert_eq!(get_ids(&shuffled), vec![4, 2, 0, 3, 1], "Shift {shift}");
+        }
+
+        // The order of the criteria decides which one breaks the ties first
+        sorter.set_criteria(vec![
+            SortCriteria::Date,
+            SortCriteria::Title,
+            SortCriteria::Priority,
+        ]);
+        let mut sorted = entries.clone();
+        sorted.sort_by(|e1, e2| sorter.sort(e1, e2));
+        assert_eq!(get_ids(&sorted), vec![2, 1, 0, 3, 4]);
+
+        sorter.order = SortOrder::Descending;
+        sorted.sort_by(|e1, e2| sorter.sort(e1, e2));
+        assert_eq!(get_ids(&sorted), vec![4, 0, 3, 1, 2]);
+    }
+
+    #[test]
+    fn sort_ties_by_id_regardless_of_input_order() {
+        let mut sorter = Sorter::default();
+        sorter.set_criteria(vec![SortCriteria::Title]);
+        sorter.order = SortOrder::Ascending;
+
+        let mut entries = get_default_entries();
+        for id in 3..6 {
+            let mut clone = entries[0].clone();
+            clone.id = id;
+            entries.push(clone);
+        }
+
+        // Rotate the input to simulate data providers returning the entries in different orders.
+        for shift in 0..entries.len() {
+            let mut shuffled = entries.clone();
+            shuffled.rotate_left(shift);
+
+            sorter.order = SortOrder::Ascending;
+            shuffled.sort_by(|e1, e2| sorter.sort(e1, e2));
+            assert_eq!(get_ids(&shuffled), vec![1, 0, 2, 3, 4, 5], "Shift {shift}");
+
+            sorter.order = SortOrder::Descending;
+            shuffled.reverse();
+            shuffled.sort_by(|e1, e2| sorter.sort(e1, e2));
+            assert_eq!(get_ids(&shuffled), vec![0, 2, 3, 4, 5, 1], "Shift {shift}");
+        }
+    }
+
+    #[test]
+    fn sort_pinned_entries() {
+        let mut sorter = Sorter::default();
+        sorter.set_criteria(vec![SortCriteria::Date]);
+        sorter.order = SortOrder::Ascending;
+
+        let mut entries = get_default_entries();
+        entries[2].pinned_at = Some(Utc.with_ymd_and_hms(2024, 3, 1, 1, 2, 3).unwrap());
+        entries[0].pinned_at = Some(Utc.with_ymd_and_hms(2024, 3, 2, 1, 2, 3).unwrap());
+
+        entries.sort_by(|e1, e2| sorter.sort_pinned(e1, e2, PinnedOrder::Sorted));
+        let ids = get_ids(&entries);
+        assert_eq!(ids, vec![0, 2, 1], "Pinned Sorted Ascending");
+
+        sorter.order = SortOrder::Descending;
+        entries.sort_by(|e1, e2| sorter.sort_pinned(e1, e2, PinnedOrder::Sorted));
+        let ids = get_ids(&entries);
+        assert_eq!(ids, vec![2, 0, 1], "Pinned Sorted Descending");
+
+        entries.sort_by(|e1, e2| sorter.sort_pinned(e1, e2, PinnedOrder::PinTime));
+        let ids = get_ids(&entries);
+        assert_eq!(ids, vec![0, 2, 1], "Pin Time Descending");
+
+        sorter.order = SortOrder::Ascending;
+        entries.sort_by(|e1, e2| sorter.sort_pinned(e1, e2, PinnedOrder::PinTime));
+        let ids = get_ids(&entries);
+        assert_eq!(ids, vec![0, 2, 1], "Pin Time Ascending");
+    }
 }
@@ -11,6 +11,20 @@ const STATE_FILE_NAME: &str = "state.json";
 pub struct AppState {
     pub sorter: Sorter,
     pub full_screen: bool,
+    /// Version of the app which the user has seen its changes last time.
+    #[serde(default)]
+    pub last_seen_version: Option<String>,
+    /// Filter applied when the app was closed, restored on the next start if enabled.
+    #[serde(default)]
+    pub filter: Option<Filter>,
+    /// Filters saved by the user with their names to be applied quickly.
+    #[serde(default)]
+    pub saved_filters: Vec<(String, Filter)>,
+    /// Undo and redo histories of the journals when the app was closed, restored on the next
+    /// start if enabled. They are kept for each journal by its key since entry ids are unique
+    /// within one journal only.
+    #[serde(default)]
+    pub histories: BTreeMap<String, PersistedHistory>,
 }
 
 impl AppState {
@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+
+use backend::{count_words, Entry};
+use chrono::{Datelike, Days, NaiveDate};
+
+/// Aggregated statistics about the journals.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct JournalStats {
+    /// Count of the journals.
+    pub total: usize,
+    /// Count of the journals dated in the current week, starting on Monday.
+    pub this_week: usize,
+    /// Count of the journals dated in the current month.
+    pub this_month: usize,
+    /// Total count of words in the contents of the journals.
+    pub words: usize,
+    /// Count of journals for each tag.
+    pub tags: BTreeMap<String, usize>,
+    /// Count of journals for each month as year and month.
+    pub per_month: BTreeMap<(i32, u32), usize>,
+}
+
+impl JournalStats {
+    /// Adds the given entry to the statistics, where `today` is the date to count the current
+    /// week and month from.
+    pub(super) fn add_entry(mut self, entry: &Entry, today: NaiveDate) -> Self {
+        let date = entry.date.date_naive();
+        let week_start = today - Days::new(today.weekday().num_days_from_monday().into());
+
+        self.total += 1;
+        if date >= week_start && date < week_start + Days::new(7) {
+            self.this_week += 1;
+        }
+        if date.year() == today.year() && date.month() == today.month() {
+            self.this_month += 1;
+        }
+        self.words += count_words(&entry.content);
+
+        for tag in entry.tags.iter() {
+            *self.tags.entry(tag.to_owned()).or_default() += 1;
+        }
+        *self
+            .per_month
+            .entry((date.year(), date.month()))
+            .or_default() += 1;
+
+        self
+    }
+
+    /// Combines the statistics of two sets of journals.
+    pub(super) fn merge(mut self, other: Self) -> Self {
+        self.total += other.total;
+        self.this_week += other.this_week;
+        self.this_month += other.this_month;
+        self.words += other.words;
+
+        for (tag, count) in other.tags {
+            *self.tags.entry(tag).or_default() += count;
+        }
+        for (month, count) in other.per_month {
+            *self.per_month.entry(month).or_default() += count;
+        }
+
+        self
+    }
+
+    /// Returns the average count of words per journal.
+    pub fn average_words(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.words as f64 / self.total as f64
+        }
+    }
+
+    /// Returns the given count of the most used tags with their counts, ordered by usage and
+    /// then by name.
+    pub fn top_tags(&self, count: usize) -> Vec<(&str, usize)> {
+        let mut tags: Vec<(&str, usize)> = self
+            .tags
+            .iter()
+            .map(|(tag, count)| (tag.as_str(), *count))
+            .collect();
+
+        tags.sort_by(|(tag_a, count_a), (tag_b, count_b)| {
+            count_b.cmp(count_a).then_with(|| tag_a.cmp(tag_b))
+        });
+        tags.truncate(count);
+
+        tags
+    }
+}
@@ -0,0 +1,89 @@
+use std::{cmp::Ordering, collections::BTreeMap};
+
+use chrono::{DateTime, Utc};
+
+use crate::settings::TagsOrder;
+
+use super::filter::tag_with_parents;
+
+/// Usage of a tag in the journals used to order the tags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TagUsage {
+    /// Count of journals having the tag.
+    pub count: usize,
+    /// Latest modification time of the journals having the tag.
+    pub last_used: DateTime<Utc>,
+}
+
+impl TagUsage {
+    pub fn new(last_used: DateTime<Utc>) -> Self {
+        Self {
+            count: 1,
+            last_used,
+        }
+    }
+
+    pub fn register(&mut self, used_at: DateTime<Utc>) {
+        self.count += 1;
+        self.last_used = self.last_used.max(used_at);
+    }
+}
+
+/// Orders the given tags with their usages according to the given order, falling back to the
+/// alphabetical order for tags with the same usage.
+/// With tag hierarchy, the usages must contain the parents of the tags too. The tags are ordered
+/// then as a tree where the children follow their parent and the siblings are ordered among
+/// themselves.
+pub fn order_tags(
+    usages: BTreeMap<&str, TagUsage>,
+    order: TagsOrder,
+    hierarchy: bool,
+) -> Vec<(String, TagUsage)> {
+    let mut tags: Vec<_> = usages.iter().map(|(tag, usage)| (*tag, *usage)).collect();
+
+    tags.sort_by(|(tag1, _), (tag2, _)| {
+        if !hierarchy {
+            return compare_siblings(tag1, tag2, &usages, order);
+        }
+
+        let mut path1 = tag_with_parents(tag1);
+        let mut path2 = tag_with_parents(tag2);
+        loop {
+            match (path1.next(), path2.next()) {
+                (Some(p1), Some(p2)) => match compare_siblings(p1, p2, &usages, order) {
+                    Ordering::Equal => continue,
+                    ord => return ord,
+                },
+                // Parents come before their children
+                (p1, p2) => return p1.is_some().cmp(&p2.is_some()),
+            }
+        }
+    });
+
+    tags.into_iter()
+        .map(|(tag, usage)| (tag.to_owned(), usage))
+        .collect()
+}
+
+fn compare_siblings(
+    tag1: &str,
+    tag2: &str,
+    usages: &BTreeMap<&str, TagUsage>,
+    order: TagsOrder,
+) -> Ordering {
+    if tag1 == tag2 {
+        return Ordering::Equal;
+    }
+
+    let usage_ord = match (usages.get(tag1), usages.get(tag2)) {
+        (Some(usage1), Some(usage2)) => match order {
+            TagsOrder::Alphabetical => Ordering::Equal,
+            TagsOrder::Count => usage2.count.cmp(&usage1.count),
+            TagsOrder::Recent => usage2.last_used.cmp(&usage1.last_used),
+        },
+        _ => Ordering::Equal,
+    };
+
+    // Siblings share their parent so comparing their paths compares their names.
+    usage_ord.then_with(|| tag1.cmp(tag2))
+}
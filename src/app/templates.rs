@@ -0,0 +1,200 @@
+use std::collections::BTreeMap;
+
+use chrono::{Days, Months, NaiveDate};
+
+use crate::settings::SectionLimit;
+
+const DATE_PLACEHOLDER: &str = "{date";
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Expands the date placeholders in the given template relative to the given date:
+/// - `{date}`: The given date in the format `YYYY-MM-DD`.
+/// - `{date+Nu}` and `{date-Nu}`: The given date moved by `N` units, where the unit `u` is
+///   `d` for days, `w` for weeks or `m` for months, like `{date+7d}`.
+///
+/// Invalid placeholders are left as they are.
+pub fn expand_date_placeholders(template: &str, date: NaiveDate) -> String {
+    let mut expanded = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find(DATE_PLACEHOLDER) {
+        expanded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let Some(end) = rest.find('}') else {
+            break;
+        };
+
+        match resolve_date(&rest[DATE_PLACEHOLDER.len()..end], date) {
+            Some(resolved) => expanded.push_str(&resolved.format(DATE_FORMAT).to_string()),
+            None => expanded.push_str(&rest[..=end]),
+        }
+
+        rest = &rest[end + 1..];
+    }
+
+    expanded.push_str(rest);
+
+    expanded
+}
+
+/// Applies the given offset expression like `+7d` on the given date, returning `None` if the
+/// expression is invalid or the resulting date is out of range.
+fn resolve_date(offset: &str, date: NaiveDate) -> Option<NaiveDate> {
+    if offset.is_empty() {
+        return Some(date);
+    }
+
+    let mut chars = offset.chars();
+    let add = match chars.next()? {
+        '+' => true,
+        '-' => false,
+        _ => return None,
+    };
+    let unit = chars.next_back()?;
+    let amount: u32 = chars.as_str().parse().ok()?;
+
+    match (unit, add) {
+        ('d', true) => date.checked_add_days(Days::new(amount.into())),
+        ('d', false) => date.checked_sub_days(Days::new(amount.into())),
+        ('w', true) => date.checked_add_days(Days::new(u64::from(amount) * 7)),
+        ('w', false) => date.checked_sub_days(Days::new(u64::from(amount) * 7)),
+        ('m', true) => date.checked_add_months(Months::new(amount)),
+        ('m', false) => date.checked_sub_months(Months::new(amount)),
+        _ => None,
+    }
+}
+
+/// Returns the headings of the sections in the given content exceeding their limits, where the
+/// sections start with Markdown headings and are matched with the limits by the heading text.
+pub fn find_exceeded_sections(
+    content: &str,
+    limits: &BTreeMap<String, SectionLimit>,
+) -> Vec<String> {
+    let mut exceeded = Vec::new();
+    let mut section: Option<(&str, &SectionLimit)> = None;
+    let (mut words, mut chars) = (0, 0);
+
+    let mut check_section = |section: Option<(&str, &SectionLimit)>, words, chars| {
+        if let Some((heading, limit)) = section {
+            if limit.max_words.is_some_and(|max| words > max)
+                || limit.max_chars.is_some_and(|max| chars > max)
+            {
+                exceeded.push(heading.to_owned());
+            }
+        }
+    };
+
+    for line in content.lines() {
+        if let Some(heading) = parse_heading(line) {
+            check_section(section, words, chars);
+            section = limits.get(heading).map(|limit| (heading, limit));
+            (words, chars) = (0, 0);
+        } else {
+            words += line.split_whitespace().count();
+            chars += line.trim().chars().count();
+        }
+    }
+
+    check_section(section, words, chars);
+
+    exceeded
+}
+
+/// Returns the text of the given line if it's a Markdown heading.
+fn parse_heading(line: &str) -> Option<&str> {
+    let line = line.trim_start();
+    let level = line.chars().take_while(|ch| *ch == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+
+    let rest = &line[level..];
+    (rest.is_empty() || rest.starts_with(' ')).then(|| rest.trim())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 2, 27).unwrap()
+    }
+
+    #[test]
+    fn expand_date() {
+        assert_eq!(
+            expand_date_placeholders("Review {date}", date()),
+            "Review 2024-02-27"
+        );
+        assert_eq!(expand_date_placeholders("No dates", date()), "No dates");
+    }
+
+    #[test]
+    fn expand_date_offsets() {
+        assert_eq!(
+            expand_date_placeholders("Follow up: {date+7d}", date()),
+            "Follow up: 2024-03-05"
+        );
+        assert_eq!(
+            expand_date_placeholders("Yesterday: {date-1d}", date()),
+            "Yesterday: 2024-02-26"
+        );
+        assert_eq!(
+            expand_date_placeholders("{date-2w} - {date+1m}", date()),
+            "2024-02-13 - 2024-03-27"
+        );
+    }
+
+    #[test]
+    fn invalid_expressions_kept() {
+        assert_eq!(
+            expand_date_placeholders("{date+7x} {date*2d} {date+d} {date+1d}", date()),
+            "{date+7x} {date*2d} {date+d} 2024-02-28"
+        );
+        assert_eq!(
+            expand_date_placeholders("Not closed {date+1d", date()),
+            "Not closed {date+1d"
+        );
+        assert_eq!(
+            expand_date_placeholders("{dates} {date+99999999999d}", date()),
+            "{dates} {date+99999999999d}"
+        );
+    }
+
+    #[test]
+    fn section_exceeding_limits() {
+        let limits = BTreeMap::from([
+            (
+                String::from("Gratitude"),
+                SectionLimit {
+                    max_words: Some(5),
+                    max_chars: None,
+                },
+            ),
+            (
+                String::from("Summary"),
+                SectionLimit {
+                    max_words: None,
+                    max_chars: Some(10),
+                },
+            ),
+        ]);
+
+        let content = "# Gratitude\nSun is out\nGood coffee\n\n## Summary\nShort day\n## Other\nNot limited at all here";
+        assert!(find_exceeded_sections(content, &limits).is_empty());
+
+        let content = "# Gratitude\nSun is out\nGood coffee and cake\n## Summary\nA long summary";
+        assert_eq!(
+            find_exceeded_sections(content, &limits),
+            vec![String::from("Gratitude"), String::from("Summary")]
+        );
+
+        // Lines with hashes only without space aren't headings
+        let content = "## Summary\nShort\n#Gratitude more text here";
+        assert_eq!(
+            find_exceeded_sections(content, &limits),
+            vec![String::from("Summary")]
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use super::*;
-use crate::app::filter::CriteriaRelation;
+use crate::app::filter::{CriteriaRelation, RegexPattern};
 
 #[tokio::test]
 async fn test_filter() {
@@ -117,6 +117,87 @@ async fn test_filter_relations() {
     assert_eq!(app.get_active_entries().count(), 0);
 }
 
+#[tokio::test]
+async fn test_filter_ids() {
+    use crate::settings::MissingFilterTagsBehavior;
+
+    let mut app = create_default_app();
+    app.settings.missing_filter_tags = MissingFilterTagsBehavior::Drop;
+    app.load_entries().await.unwrap();
+    add_extra_entries_drafts(&mut app).await;
+
+    let filter = Filter {
+        criteria: vec![FilterCriterion::Ids([0, 3, 10].into())],
+        relation: CriteriaRelation::And,
+    };
+    app.apply_filter(Some(filter.clone()));
+
+    let mut ids: Vec<u32> = app.get_active_entries().map(|entry| entry.id).collect();
+    ids.sort();
+    assert_eq!(ids, vec![0, 3]);
+
+    // Removing tags from the journals keeps the ids criterion untouched
+    app.current_entry_id = Some(0);
+    app.update_current_entry_attributes(
+        "Title 1".into(),
+        app.get_entry(0).unwrap().date,
+        vec![],
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(app.filter.as_ref(), Some(&filter));
+    assert_eq!(app.get_active_entries().count(), 2);
+}
+
+#[tokio::test]
+async fn navigate_to_hidden_entry() {
+    use crate::settings::HiddenEntryNavigation;
+
+    async fn create_filtered_app(behavior: HiddenEntryNavigation) -> App<MockDataProvider> {
+        let mut app = create_default_app();
+        app.settings.hidden_entry_navigation = behavior;
+        app.load_entries().await.unwrap();
+
+        let mut filter = Filter::default();
+        filter
+            .criteria
+            .push(FilterCriterion::Title(String::from("Title 2")));
+        app.apply_filter(Some(filter));
+        assert!(app.get_entry(0).is_none());
+
+        app
+    }
+
+    // Visible entries are always accepted
+    let mut app = create_filtered_app(HiddenEntryNavigation::Refuse).await;
+    assert!(app.reveal_entry(1));
+
+    // Clear filter
+    let mut app = create_filtered_app(HiddenEntryNavigation::ClearFilter).await;
+    assert!(app.reveal_entry(0));
+    assert!(app.filter.is_none());
+    assert_eq!(app.get_active_entries().count(), 2);
+
+    // Refuse
+    let mut app = create_filtered_app(HiddenEntryNavigation::Refuse).await;
+    assert!(!app.reveal_entry(0));
+    assert!(app.filter.is_some());
+    assert!(app.get_entry(0).is_none());
+
+    // Reveal
+    let mut app = create_filtered_app(HiddenEntryNavigation::Reveal).await;
+    assert!(app.reveal_entry(0));
+    assert!(app.filter.is_some());
+    assert!(app.get_entry(0).is_some());
+    assert_eq!(app.get_active_entries().count(), 2);
+
+    // Revealed entries are hidden again once the filter changes
+    let filter = app.filter.clone();
+    app.apply_filter(filter);
+    assert!(app.get_entry(0).is_none());
+}
+
 #[tokio::test]
 async fn cycle_tag_no_tags() {
     let mut app = App::new(MockDataProvider::default(), Settings::default());
@@ -253,3 +334,613 @@ async fn cycle_tag_existing_filter() {
         1
     );
 }
+
+#[tokio::test]
+async fn toggle_tags_in_filter() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+    add_extra_entries_drafts(&mut app).await;
+
+    assert_eq!(
+        app.get_tags_with_counts(),
+        vec![(String::from("Tag 1"), 3), (String::from("Tag 2"), 2)]
+    );
+
+    app.toggle_tag_in_filter("Tag 1");
+    app.toggle_tag_in_filter("Tag 2");
+
+    let filter = app.filter.as_ref().unwrap();
+    assert!(matches!(filter.relation, CriteriaRelation::And));
+    let tags: Vec<&str> = filter
+        .criteria
+        .iter()
+        .map(|c| match c {
+            FilterCriterion::Tag(tag) => tag.as_str(),
+            invalid => panic!("Invalid criteria: {invalid:?}"),
+        })
+        .collect();
+    assert_eq!(tags, vec!["Tag 1", "Tag 2"]);
+    assert!(app.is_tag_in_filter("Tag 1"));
+    assert!(app.is_tag_in_filter("Tag 2"));
+
+    let mut ids: Vec<u32> = app.get_active_entries().map(|e| e.id).collect();
+    ids.sort();
+    assert_eq!(ids, vec![0, 2]);
+
+    // Toggling an applied tag again removes it from the filter
+    app.toggle_tag_in_filter("Tag 1");
+    assert!(!app.is_tag_in_filter("Tag 1"));
+    assert_eq!(app.filter.as_ref().unwrap().criteria.len(), 1);
+
+    // Removing the last criterion clears the filter
+    app.toggle_tag_in_filter("Tag 2");
+    assert!(app.filter.is_none());
+    assert_eq!(app.get_active_entries().count(), 5);
+}
+
+#[tokio::test]
+async fn test_selection_after_delete() {
+    use crate::app::ui::{MsgBoxResult, Styles, UICommand};
+    use crate::settings::SelectionAfterDelete;
+
+    let cases = [
+        (SelectionAfterDelete::Next, Some(4)),
+        (SelectionAfterDelete::Previous, Some(2)),
+        (SelectionAfterDelete::Clear, None),
+    ];
+
+    for (selection, expected) in cases {
+        let mut app = create_default_app();
+        app.settings.selection_after_delete = selection;
+        app.load_entries().await.unwrap();
+        add_extra_entries_drafts(&mut app).await;
+        let mut ui_components = UIComponents::new(Styles::default());
+
+        let mut filter = Filter::default();
+        filter
+            .criteria
+            .push(FilterCriterion::Tag(String::from("Tag 1")));
+        app.apply_filter(Some(filter));
+
+        // Active entries are sorted by date descending
+        let active_ids: Vec<u32> = app.get_active_entries().map(|entry| entry.id).collect();
+        assert_eq!(active_ids, vec![2, 0, 4]);
+
+        ui_components.set_current_entry(Some(0), &mut app);
+        UICommand::DeleteCurrentEntry
+            .continue_executing(&mut ui_components, &mut app, MsgBoxResult::Yes)
+            .await
+            .unwrap();
+
+        assert!(app.get_entry(0).is_none());
+        assert_eq!(app.current_entry_id, expected, "{selection:?}");
+
+        // Cleared selection isn't replaced automatically
+        ui_components.update_current_entry(&mut app);
+        assert_eq!(app.current_entry_id, expected, "{selection:?}");
+    }
+}
+
+#[tokio::test]
+async fn confirm_empty_filter() {
+    let mut app = create_default_app();
+    app.settings.confirm_empty_filter = true;
+    app.load_entries().await.unwrap();
+
+    // Matching filter is applied directly
+    let mut filter = Filter::default();
+    filter
+        .criteria
+        .push(FilterCriterion::Title(String::from("Title 2")));
+    app.apply_filter(Some(filter));
+
+    assert!(!app.has_pending_filter());
+    assert_eq!(app.get_active_entries().count(), 1);
+
+    // Zero-match filter waits for confirmation keeping the current filter
+    let mut filter = Filter::default();
+    filter
+        .criteria
+        .push(FilterCriterion::Title(String::from("Not existing")));
+    app.apply_filter(Some(filter.clone()));
+
+    assert!(app.has_pending_filter());
+    assert_eq!(app.get_active_entries().count(), 1);
+
+    app.discard_pending_filter();
+    assert!(!app.has_pending_filter());
+    assert_eq!(app.get_active_entries().count(), 1);
+
+    app.apply_filter(Some(filter.clone()));
+    app.apply_pending_filter();
+    assert!(!app.has_pending_filter());
+    assert_eq!(app.get_active_entries().count(), 0);
+
+    // Confirmation is disabled
+    app.apply_filter(None);
+    app.settings.confirm_empty_filter = false;
+    app.apply_filter(Some(filter));
+    assert!(!app.has_pending_filter());
+    assert_eq!(app.get_active_entries().count(), 0);
+}
+
+#[tokio::test]
+async fn filter_presets() {
+    use crate::app::keymap::Input;
+    use crate::app::ui::Styles;
+    use crate::settings::FilterPreset;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut app = create_default_app();
+    let mut filter = Filter::default();
+    filter
+        .criteria
+        .push(FilterCriterion::Tag(String::from("Tag 1")));
+    app.settings
+        .filter_presets
+        .push(FilterPreset { key: 2, filter });
+    app.load_entries().await.unwrap();
+    let mut ui_components = UIComponents::new(Styles::default());
+
+    let key_2 = Input::new(KeyCode::Char('2'), KeyModifiers::NONE);
+
+    // First press applies the preset
+    ui_components.handle_input(&key_2, &mut app).await.unwrap();
+    assert!(app.filter.is_some());
+    let active_ids: Vec<u32> = app.get_active_entries().map(|entry| entry.id).collect();
+    assert_eq!(active_ids, vec![0]);
+
+    // Second press resets the filter
+    ui_components.handle_input(&key_2, &mut app).await.unwrap();
+    assert!(app.filter.is_none());
+    assert_eq!(app.get_active_entries().count(), 2);
+
+    // Keys without presets are reported as errors
+    assert!(app.toggle_filter_preset(3).is_err());
+    assert!(app.filter.is_none());
+}
+
+#[tokio::test]
+async fn saved_filters() {
+    use crate::app::keymap::Input;
+    use crate::app::ui::Styles;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    // Saving requires an applied filter and a name
+    assert!(app.save_current_filter("Tagged").is_err());
+
+    let mut filter = Filter::default();
+    filter
+        .criteria
+        .push(FilterCriterion::Tag(String::from("Tag 1")));
+    app.apply_filter(Some(filter.clone()));
+    assert!(app.save_current_filter("  ").is_err());
+    app.save_current_filter("Tagged").unwrap();
+
+    // Saving with an existing name replaces the saved filter
+    app.apply_filter(Some(filter.clone()));
+    app.save_current_filter("Tagged").unwrap();
+    assert_eq!(app.get_saved_filters().len(), 1);
+
+    app.apply_filter(None);
+    app.apply_saved_filter("Tagged").unwrap();
+    assert_eq!(app.filter, Some(filter));
+    assert!(app.apply_saved_filter("Missing").is_err());
+
+    // Saving and applying filters through the popup
+    let mut ui_components = UIComponents::new(Styles::default());
+    let input = |code| Input::new(code, KeyModifiers::NONE);
+    let show_saved = Input::new(KeyCode::Char('S'), KeyModifiers::SHIFT);
+
+    let mut filter = Filter::default();
+    filter
+        .criteria
+        .push(FilterCriterion::Title(String::from("Title 2")));
+    app.apply_filter(Some(filter.clone()));
+
+    ui_components
+        .handle_input(&show_saved, &mut app)
+        .await
+        .unwrap();
+    ui_components
+        .handle_input(&input(KeyCode::Tab), &mut app)
+        .await
+        .unwrap();
+    for ch in "Second".chars() {
+        ui_components
+            .handle_input(&input(KeyCode::Char(ch)), &mut app)
+            .await
+            .unwrap();
+    }
+    ui_components
+        .handle_input(&input(KeyCode::Enter), &mut app)
+        .await
+        .unwrap();
+    ui_components
+        .handle_input(&input(KeyCode::Esc), &mut app)
+        .await
+        .unwrap();
+
+    let names: Vec<&str> = app
+        .get_saved_filters()
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect();
+    assert_eq!(names, vec!["Tagged", "Second"]);
+
+    // Applying the first saved filter via the popup
+    app.apply_filter(None);
+    ui_components
+        .handle_input(&show_saved, &mut app)
+        .await
+        .unwrap();
+    ui_components
+        .handle_input(&input(KeyCode::Enter), &mut app)
+        .await
+        .unwrap();
+    assert!(!ui_components.has_popup());
+    let active_ids: Vec<u32> = app.get_active_entries().map(|entry| entry.id).collect();
+    assert_eq!(active_ids, vec![0]);
+
+    // Deleting the highlighted saved filter
+    ui_components
+        .handle_input(&show_saved, &mut app)
+        .await
+        .unwrap();
+    ui_components
+        .handle_input(&input(KeyCode::Char('d')), &mut app)
+        .await
+        .unwrap();
+    assert_eq!(app.get_saved_filters().len(), 1);
+    assert_eq!(app.get_saved_filters()[0], (String::from("Second"), filter));
+    assert!(!app.delete_saved_filter("Tagged"));
+}
+
+#[tokio::test]
+async fn missing_filter_tags() {
+    use crate::app::ui::Styles;
+    use crate::settings::MissingFilterTagsBehavior;
+
+    let cases = [
+        (MissingFilterTagsBehavior::Drop, false, false),
+        (MissingFilterTagsBehavior::DropNotify, false, true),
+        (MissingFilterTagsBehavior::Keep, true, false),
+    ];
+
+    for (behavior, retained, notified) in cases {
+        let mut app = create_default_app();
+        app.settings.missing_filter_tags = behavior;
+        app.load_entries().await.unwrap();
+        app.add_entry(
+            "Title 3".into(),
+            Utc::now(),
+            vec![String::from("Unique Tag")],
+            None,
+        )
+        .await
+        .unwrap();
+        let mut ui_components = UIComponents::new(Styles::default());
+
+        let mut filter = Filter::default();
+        filter
+            .criteria
+            .push(FilterCriterion::Tag(String::from("Unique Tag")));
+        app.apply_filter(Some(filter));
+        assert_eq!(app.get_active_entries().count(), 1);
+
+        app.delete_entry(2).await.unwrap();
+
+        assert_eq!(app.filter.is_some(), retained, "{behavior:?}");
+        let expected_count = if retained { 0 } else { 2 };
+        assert_eq!(app.get_active_entries().count(), expected_count);
+
+        ui_components.notify_dropped_filter_tags(&mut app);
+        assert_eq!(ui_components.has_popup(), notified, "{behavior:?}");
+        assert!(app.take_dropped_filter_tags().is_empty());
+    }
+}
+
+#[tokio::test]
+async fn tag_hierarchy() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    for tag in ["project/alpha", "project/beta/docs", "project-x"] {
+        app.add_entry("Title".into(), Utc::now(), vec![tag.into()], None)
+            .await
+            .unwrap();
+    }
+
+    let mut filter = Filter::default();
+    filter
+        .criteria
+        .push(FilterCriterion::Tag(String::from("project")));
+
+    // Parent tags match nothing without the hierarchy
+    app.apply_filter(Some(filter.clone()));
+    assert_eq!(app.get_active_entries().count(), 0);
+    assert!(!app.get_filter_tags().contains(&String::from("project")));
+
+    app.settings.tag_hierarchy = true;
+
+    app.apply_filter(Some(filter));
+    let mut ids: Vec<u32> = app.get_active_entries().map(|e| e.id).collect();
+    ids.sort();
+    assert_eq!(ids, vec![2, 3]);
+
+    // Only full paths of the children are matched
+    let mut filter = Filter::default();
+    filter
+        .criteria
+        .push(FilterCriterion::Tag(String::from("project/beta")));
+    app.apply_filter(Some(filter));
+    let ids: Vec<u32> = app.get_active_entries().map(|e| e.id).collect();
+    assert_eq!(ids, vec![3]);
+
+    assert_eq!(
+        app.get_all_tags(),
+        vec![
+            "Tag 1",
+            "Tag 2",
+            "project-x",
+            "project/alpha",
+            "project/beta/docs"
+        ]
+    );
+
+    assert_eq!(
+        app.get_tags_with_counts(),
+        vec![
+            (String::from("Tag 1"), 1),
+            (String::from("Tag 2"), 1),
+            (String::from("project"), 2),
+            (String::from("project/alpha"), 1),
+            (String::from("project/beta"), 1),
+            (String::from("project/beta/docs"), 1),
+            (String::from("project-x"), 1),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn focus_mode_restores_filter() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+    add_extra_entries_drafts(&mut app).await;
+
+    let mut filter = Filter::default();
+    filter
+        .criteria
+        .push(FilterCriterion::Tag(String::from("Tag 1")));
+    app.apply_filter(Some(filter.clone()));
+
+    let filtered_ids: Vec<u32> = app.get_active_entries().map(|e| e.id).collect();
+    assert!(filtered_ids.len() > 1);
+
+    app.current_entry_id = Some(filtered_ids[0]);
+    app.toggle_focus_mode();
+    assert!(app.is_focus_mode());
+    let ids: Vec<u32> = app.get_active_entries().map(|e| e.id).collect();
+    assert_eq!(ids, vec![filtered_ids[0]]);
+    assert_eq!(app.filter, Some(filter.clone()));
+
+    app.toggle_focus_mode();
+    assert!(!app.is_focus_mode());
+    assert_eq!(app.filter, Some(filter));
+    let ids: Vec<u32> = app.get_active_entries().map(|e| e.id).collect();
+    assert_eq!(ids, filtered_ids);
+
+    // Focus mode ends when its entry is deleted
+    app.toggle_focus_mode();
+    app.delete_entry(filtered_ids[0]).await.unwrap();
+    assert!(!app.is_focus_mode());
+    assert_eq!(app.get_active_entries().count(), filtered_ids.len() - 1);
+}
+
+#[tokio::test]
+async fn test_date_range_filter() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    let range_filter = |from, to| Filter {
+        relation: CriteriaRelation::And,
+        criteria: vec![FilterCriterion::DateRange { from, to }],
+    };
+
+    // Entries with dates exactly on the bounds are included
+    let first_date = Utc.with_ymd_and_hms(2023, 10, 12, 11, 22, 33).unwrap();
+    let second_date = Utc.with_ymd_and_hms(2023, 12, 2, 1, 2, 3).unwrap();
+    app.apply_filter(Some(range_filter(Some(first_date), Some(second_date))));
+    assert_eq!(app.get_active_entries().count(), 2);
+
+    app.apply_filter(Some(range_filter(Some(second_date), None)));
+    let ids: Vec<u32> = app.get_active_entries().map(|entry| entry.id).collect();
+    assert_eq!(ids, vec![1]);
+
+    app.apply_filter(Some(range_filter(None, Some(first_date))));
+    let ids: Vec<u32> = app.get_active_entries().map(|entry| entry.id).collect();
+    assert_eq!(ids, vec![0]);
+
+    let after_second = second_date + chrono::Duration::seconds(1);
+    app.apply_filter(Some(range_filter(Some(after_second), None)));
+    assert_eq!(app.get_active_entries().count(), 0);
+
+    // Criterion is kept when tags are dropped from the filter
+    app.apply_filter(Some(range_filter(Some(first_date), None)));
+    app.load_entries().await.unwrap();
+    assert_eq!(
+        app.filter.as_ref().unwrap().criteria,
+        vec![FilterCriterion::DateRange {
+            from: Some(first_date),
+            to: None
+        }]
+    );
+}
+
+#[tokio::test]
+async fn test_regex_filter() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    let regex_filter = |criterion| Filter {
+        relation: CriteriaRelation::And,
+        criteria: vec![criterion],
+    };
+
+    // Case-insensitive by default
+    let pattern = RegexPattern::new(String::from(r"^title\s+2$"), false);
+    app.apply_filter(Some(regex_filter(FilterCriterion::TitleRegex(pattern))));
+    let ids: Vec<u32> = app.get_active_entries().map(|entry| entry.id).collect();
+    assert_eq!(ids, vec![1]);
+
+    let pattern = RegexPattern::new(String::from(r"content \d"), true);
+    app.apply_filter(Some(regex_filter(FilterCriterion::ContentRegex(pattern))));
+    assert_eq!(app.get_active_entries().count(), 0);
+
+    let pattern = RegexPattern::new(String::from(r"Content [12]"), true);
+    app.apply_filter(Some(regex_filter(FilterCriterion::ContentRegex(pattern))));
+    assert_eq!(app.get_active_entries().count(), 2);
+
+    // Empty pattern matches all while invalid one matches nothing
+    let pattern = RegexPattern::new(String::new(), false);
+    app.apply_filter(Some(regex_filter(FilterCriterion::TitleRegex(pattern))));
+    assert_eq!(app.get_active_entries().count(), 2);
+
+    let pattern = RegexPattern::new(String::from("Title ("), false);
+    let filter = regex_filter(FilterCriterion::TitleRegex(pattern));
+    assert!(filter.validate().is_err());
+    app.apply_filter(Some(filter));
+    assert_eq!(app.get_active_entries().count(), 0);
+}
+
+#[tokio::test]
+async fn test_negated_criteria() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+    add_extra_entries_drafts(&mut app).await;
+
+    let active_ids = |app: &App<MockDataProvider>| {
+        let mut ids: Vec<u32> = app.get_active_entries().map(|entry| entry.id).collect();
+        ids.sort();
+        ids
+    };
+
+    let filter = Filter {
+        relation: CriteriaRelation::And,
+        criteria: vec![
+            FilterCriterion::Tag(String::from("Tag 1")),
+            FilterCriterion::Priority(2).negate_if(true),
+        ],
+    };
+    app.apply_filter(Some(filter));
+    assert_eq!(active_ids(&app), vec![0, 4]);
+
+    let filter = Filter {
+        relation: CriteriaRelation::Or,
+        criteria: vec![
+            FilterCriterion::Tag(String::from("Tag 2")).negate_if(true),
+            FilterCriterion::Priority(2),
+        ],
+    };
+    app.apply_filter(Some(filter));
+    assert_eq!(active_ids(&app), vec![1, 2, 3, 4]);
+
+    // Negated tags are dropped from the filter once they don't exist
+    let filter = Filter {
+        relation: CriteriaRelation::And,
+        criteria: vec![
+            FilterCriterion::Tag(String::from("Missing")).negate_if(true),
+            FilterCriterion::Priority(4).negate_if(true),
+        ],
+    };
+    app.apply_filter(Some(filter));
+    assert_eq!(active_ids(&app), vec![0, 1, 2, 4]);
+
+    app.delete_entry(1).await.unwrap();
+    assert_eq!(
+        app.filter.as_ref().unwrap().criteria,
+        vec![FilterCriterion::Not(Box::new(FilterCriterion::Priority(4)))]
+    );
+    assert_eq!(active_ids(&app), vec![0, 2, 4]);
+}
+
+#[tokio::test]
+async fn test_restore_filter_on_startup() {
+    use crate::app::ui::Styles;
+
+    let state_dir = TempDir::new("filter_state");
+
+    let create_app = |restore| {
+        let mut app = create_default_app();
+        app.settings.app_state_dir = Some(state_dir.path.clone());
+        app.settings.restore_filter_on_startup = restore;
+        app
+    };
+
+    let mut filter = Filter::default();
+    filter
+        .criteria
+        .push(FilterCriterion::Title(String::from("Title 2")));
+
+    let mut app = create_app(true);
+    app.load_entries().await.unwrap();
+    app.apply_filter(Some(filter.clone()));
+    app.persist_state().unwrap();
+
+    let mut ui_components = UIComponents::new(Styles::default());
+    let mut app = create_app(true);
+    app.load_state(&mut ui_components);
+    app.load_entries().await.unwrap();
+    app.restore_filter();
+    assert_eq!(app.filter, Some(filter.clone()));
+    let active_ids: Vec<u32> = app.get_active_entries().map(|entry| entry.id).collect();
+    assert_eq!(active_ids, vec![1]);
+
+    // Filter isn't restored with the option off
+    let mut app = create_app(false);
+    app.load_state(&mut ui_components);
+    app.load_entries().await.unwrap();
+    app.restore_filter();
+    assert!(app.filter.is_none());
+    assert_eq!(app.get_active_entries().count(), 2);
+
+    // Tags which don't exist anymore are dropped from the restored filter
+    let mut app = create_app(true);
+    app.settings.missing_filter_tags = MissingFilterTagsBehavior::DropNotify;
+    app.load_entries().await.unwrap();
+    let mut missing_filter = filter.clone();
+    missing_filter
+        .criteria
+        .push(FilterCriterion::Tag(String::from("Removed Tag")));
+    app.apply_filter(Some(missing_filter));
+    app.persist_state().unwrap();
+
+    let mut app = create_app(true);
+    app.settings.missing_filter_tags = MissingFilterTagsBehavior::DropNotify;
+    app.load_state(&mut ui_components);
+    app.load_entries().await.unwrap();
+    app.restore_filter();
+    assert_eq!(app.filter, Some(filter.clone()));
+    assert_eq!(app.take_dropped_filter_tags(), ["Removed Tag"]);
+
+    // Restored filters hiding all journals wait for confirmation
+    let mut hiding_filter = Filter::default();
+    hiding_filter
+        .criteria
+        .push(FilterCriterion::Title(String::from("Not existing")));
+    let mut app = create_app(true);
+    app.load_entries().await.unwrap();
+    app.apply_filter(Some(hiding_filter));
+    app.persist_state().unwrap();
+
+    let mut app = create_app(true);
+    app.settings.confirm_empty_filter = true;
+    app.load_state(&mut ui_components);
+    app.load_entries().await.unwrap();
+    app.restore_filter();
+    assert!(app.filter.is_none());
+    assert!(app.has_pending_filter());
+}
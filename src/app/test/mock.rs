@@ -41,7 +41,20 @@ impl DataProvider for MockDataProvider {
     async fn add_entry(&self, entry: EntryDraft) -> Result<Entry, ModifyEntryError> {
         self.early_return()?;
         let mut entries = self.entries.write().unwrap();
-        let new_id = entries.last().map_or(0, |entry| entry.id + 1);
+        let new_id = match entry.id {
+            Some(id) if entries.iter().any(|entry| entry.id == id) => {
+                return Err(ModifyEntryError::ValidationError(format!(
+                    "Entry id {id} is already taken"
+                )));
+            }
+            Some(id) => id,
+            None => match entries.iter().map(|entry| entry.id).max() {
+                Some(id) => id.checked_add(1).ok_or_else(|| {
+                    ModifyEntryError::ValidationError(String::from("No entry ids are left"))
+                })?,
+                None => 0,
+            },
+        };
 
         let entry = Entry::from_draft(new_id, entry);
 
@@ -92,16 +105,6 @@ impl DataProvider for MockDataProvider {
         ))
     }
 
-    async fn import_entries(&self, entries_dto: EntriesDTO) -> anyhow::Result<()> {
-        self.early_return()?;
-
-        for draft in entries_dto.entries {
-            self.add_entry(draft).await?;
-        }
-
-        Ok(())
-    }
-
     async fn assign_priority_to_entries(&self, _priority: u32) -> anyhow::Result<()> {
         unimplemented!("There are not tests for assigning priority on the app level");
     }
@@ -2,11 +2,12 @@ use chrono::TimeZone;
 
 mod filter;
 
-use self::mock::MockDataProvider;
+use self::{mock::MockDataProvider, temp_dir::TempDir};
 
 use super::*;
 
-mod mock;
+pub(crate) mod mock;
+pub(crate) mod temp_dir;
 mod undo_redo;
 
 fn get_default_entries() -> Vec<Entry> {
@@ -69,39 +70,929 @@ async fn test_data_provider_errors() {
     assert!(app.delete_entry(0).await.is_err());
     assert!(app.get_current_entry().is_none());
     assert!(app.export_entries(PathBuf::default()).await.is_err());
-    assert!(app.import_entries(PathBuf::default()).await.is_err());
+    assert!(app
+        .import_entries(PathBuf::default(), ImportStrategy::AddAll)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_save_failure() {
+    use crate::settings::SaveFailureBehavior;
+
+    async fn persisted_entry(app: &App<MockDataProvider>, entry_id: u32) -> Entry {
+        let entries = app.data_provide.load_all_entries().await.unwrap();
+        entries.into_iter().find(|e| e.id == entry_id).unwrap()
+    }
+
+    // Rollback
+    let mut app = create_default_app();
+    app.settings.save_failure = SaveFailureBehavior::Rollback;
+    app.load_entries().await.unwrap();
+    app.current_entry_id = Some(0);
+    let entry_before = app.get_entry(0).cloned().unwrap();
+
+    app.data_provide.set_return_err(true);
+    assert!(app
+        .update_current_entry_attributes("New".into(), Utc::now(), Vec::new(), Some(3))
+        .await
+        .is_err());
+    assert!(app
+        .update_current_entry_content("New content".into())
+        .await
+        .is_err());
+    app.data_provide.set_return_err(false);
+
+    assert_eq!(app.get_entry(0).unwrap(), &entry_before);
+    assert_eq!(persisted_entry(&app, 0).await, entry_before);
+    assert!(!app.is_entry_unsynced(0));
+    assert!(app.get_all_tags().contains(&String::from("Tag 1")));
+    // Failed changes aren't registered in the history
+    assert!(app.undo().await.unwrap().is_none());
+
+    // Keep unsynced
+    let mut app = create_default_app();
+    app.settings.save_failure = SaveFailureBehavior::KeepUnsynced;
+    app.load_entries().await.unwrap();
+    app.current_entry_id = Some(0);
+
+    app.data_provide.set_return_err(true);
+    assert!(app
+        .update_current_entry_content("New content".into())
+        .await
+        .is_err());
+    assert_eq!(app.get_entry(0).unwrap().content, "New content");
+    assert!(app.is_entry_unsynced(0));
+
+    // Reloading doesn't drop the unsynced changes if retrying fails
+    assert!(app.load_entries().await.is_err());
+    assert_eq!(app.get_entry(0).unwrap().content, "New content");
+    assert!(app.is_entry_unsynced(0));
+
+    // Unsynced changes are saved on the next successful change
+    app.data_provide.set_return_err(false);
+    app.current_entry_id = Some(1);
+    app.update_current_entry_content("Content 2 changed".into())
+        .await
+        .unwrap();
+    assert!(!app.is_entry_unsynced(0));
+    assert_eq!(persisted_entry(&app, 0).await.content, "New content");
+    assert_eq!(persisted_entry(&app, 1).await.content, "Content 2 changed");
+}
+
+#[tokio::test]
+async fn test_get_tags() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    let tags = vec![String::from("Tag 1"), String::from("Tag 2")];
+
+    assert_eq!(app.get_all_tags(), tags);
+}
+
+#[tokio::test]
+async fn test_tag_counts() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+    add_extra_entries_drafts(&mut app).await;
+
+    let counts = app.get_tag_counts();
+    let expected = BTreeMap::from([(String::from("Tag 1"), 3), (String::from("Tag 2"), 2)]);
+    assert_eq!(counts, expected);
+
+    // Counts are refreshed after changing the entries
+    app.delete_entry(0).await.unwrap();
+    let counts = app.get_tag_counts();
+    assert_eq!(counts.get("Tag 1"), Some(&2));
+    assert_eq!(counts.get("Tag 2"), Some(&1));
+}
+
+#[tokio::test]
+async fn test_tags_order() {
+    use crate::settings::TagsOrder;
+
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+    add_extra_entries_drafts(&mut app).await;
+
+    let tagged_entries = [
+        (Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap(), "Alpha"),
+        (Utc.with_ymd_and_hms(2023, 12, 1, 0, 0, 0).unwrap(), "Beta"),
+    ];
+    for (date, tag) in tagged_entries {
+        app.add_entry(tag.into(), date, vec![tag.into()], None)
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(app.get_all_tags(), ["Alpha", "Beta", "Tag 1", "Tag 2"]);
+
+    // Tags are sorted by their entries frequency, falling back to alphabetical order.
+    app.settings.tags_order = TagsOrder::Count;
+    assert_eq!(app.get_all_tags(), ["Tag 1", "Tag 2", "Alpha", "Beta"]);
+    assert_eq!(
+        app.get_tags_with_counts(),
+        vec![
+            (String::from("Tag 1"), 3),
+            (String::from("Tag 2"), 2),
+            (String::from("Alpha"), 1),
+            (String::from("Beta"), 1),
+        ]
+    );
+
+    app.settings.tags_order = TagsOrder::Recent;
+    assert_eq!(app.get_all_tags(), ["Alpha", "Beta", "Tag 1", "Tag 2"]);
+}
+
+#[tokio::test]
+async fn test_entries_markdown() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    assert!(app.get_entries_markdown().is_none());
+
+    // Current entry is used when nothing is selected
+    app.current_entry_id = Some(0);
+    assert_eq!(
+        app.get_entries_markdown().unwrap(),
+        "# Title 1\n\n- Date: 2023-10-12\n- Tags: Tag 1, Tag 2\n\nContent 1\n"
+    );
+
+    // Selected entries in the list order
+    app.selected_entries.insert(0);
+    app.selected_entries.insert(1);
+    assert_eq!(
+        app.get_entries_markdown().unwrap(),
+        "# Title 2\n\n- Date: 2023-12-02\n- Priority: 1\n\nContent 2\n\
+        \n---\n\n\
+        # Title 1\n\n- Date: 2023-10-12\n- Tags: Tag 1, Tag 2\n\nContent 1\n"
+    );
+}
+
+#[tokio::test]
+async fn test_export_trailing_newline() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+    app.current_entry_id = Some(0);
+
+    let export_dir = TempDir::new("newline");
+    let path = export_dir.join("entry.txt");
+
+    // Newline is added by default
+    app.export_entry_content(0, path.clone(), EntryExportFormat::Content)
+        .await
+        .unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "Content 1\n");
+    assert!(app.get_entries_markdown().unwrap().ends_with("Content 1\n"));
+
+    // Trailing newlines are removed when disabled
+    app.settings.export.trailing_newline = false;
+    app.update_current_entry_content("Content 1\n\n".into())
+        .await
+        .unwrap();
+    app.export_entry_content(0, path.clone(), EntryExportFormat::Content)
+        .await
+        .unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "Content 1");
+    assert!(app.get_entries_markdown().unwrap().ends_with("Content 1"));
+}
+
+#[tokio::test]
+async fn test_export_entry_formats() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    assert!(app
+        .get_entry_export_content(10, EntryExportFormat::Markdown)
+        .is_err());
+
+    assert_eq!(
+        app.get_entry_export_content(0, EntryExportFormat::Markdown)
+            .unwrap(),
+        "# Title 1\n\n- Date: 2023-10-12\n- Tags: Tag 1, Tag 2\n\nContent 1\n"
+    );
+
+    app.current_entry_id = Some(1);
+    let date = app.get_entry(1).unwrap().date;
+    app.update_current_entry_attributes("Plans <draft>".into(), date, vec![], Some(1))
+        .await
+        .unwrap();
+    app.update_current_entry_content("a & b".into())
+        .await
+        .unwrap();
+
+    let html = app
+        .get_entry_export_content(1, EntryExportFormat::Html)
+        .unwrap();
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<title>Plans &lt;draft&gt;</title>"));
+    assert!(html.contains("<h1>Plans &lt;draft&gt;</h1>"));
+    assert!(html.contains("<li>Date: 2023-12-02</li>\n<li>Priority: 1</li>\n</ul>"));
+    assert!(html.contains("<pre>a &amp; b</pre>"));
+
+    let temp_dir = TempDir::new("html");
+    let path = temp_dir.join("entry.html");
+    app.export_entry_content(1, path.clone(), EntryExportFormat::Html)
+        .await
+        .unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), html);
+}
+
+#[tokio::test]
+async fn test_export_selection_order() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+    add_extra_entries_drafts(&mut app).await;
+    app.settings.export.selection_order = true;
+
+    for id in [3, 0, 4, 1] {
+        assert!(app.select_entry(id));
+    }
+    assert!(!app.select_entry(0));
+    app.deselect_entry(4);
+    assert_eq!(app.get_selected_ids_in_order(), vec![3, 0, 1]);
+
+    let temp_dir = TempDir::new("order");
+    let path = temp_dir.join("entries.json");
+    app.export_entries(path.clone()).await.unwrap();
+
+    let exported: EntriesDTO =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    let titles: Vec<&str> = exported.entries.iter().map(|e| e.title.as_str()).collect();
+    assert_eq!(titles, ["Title 4", "Title 1", "Title 2"]);
+
+    app.clear_selection();
+    assert!(app.get_selected_ids_in_order().is_empty());
+}
+
+#[tokio::test]
+async fn test_export_preview_matches_file() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    let export_dir = TempDir::new("preview");
+
+    // Single entry content
+    let preview = app
+        .get_entry_export_content(1, EntryExportFormat::Content)
+        .unwrap();
+    let entry_path = export_dir.join("entry.txt");
+    app.export_entry_content(1, entry_path.clone(), EntryExportFormat::Content)
+        .await
+        .unwrap();
+    assert_eq!(preview, "Content 2\n");
+    assert_eq!(std::fs::read_to_string(&entry_path).unwrap(), preview);
+
+    // Selected entries
+    app.selected_entries.insert(0);
+    app.selected_entries.insert(1);
+    let preview = app.get_export_content().await.unwrap();
+    let entries_path = export_dir.join("entries.json");
+    app.export_entries(entries_path.clone()).await.unwrap();
+    assert_eq!(std::fs::read_to_string(&entries_path).unwrap(), preview);
+}
+
+#[tokio::test]
+async fn test_export_entries_markdown() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    assert!(app.get_markdown_export_content().is_err());
+
+    app.current_entry_id = Some(0);
+    let date = app.get_entry(0).unwrap().date;
+    app.update_current_entry_attributes("# Plan *v2* [draft]".into(), date, vec![], None)
+        .await
+        .unwrap();
+
+    app.selected_entries.insert(0);
+    app.selected_entries.insert(1);
+    let expected = "# Title 2\n\n- Date: 2023-12-02\n- Priority: 1\n\nContent 2\n\
+        \n---\n\n\
+        # \\# Plan \\*v2\\* \\[draft\\]\n\n- Date: 2023-10-12\n\nContent 1\n";
+    assert_eq!(app.get_markdown_export_content().unwrap(), expected);
+
+    let temp_dir = TempDir::new("md");
+    let path = temp_dir.join("entries.md");
+    app.export_entries_markdown(path.clone()).await.unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), expected);
+}
+
+#[tokio::test]
+async fn test_export_metadata_csv() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    app.current_entry_id = Some(0);
+    let date = app.get_entry(0).unwrap().date;
+    let tags = vec![String::from("Tag 1"), String::from("Tag 2")];
+    app.update_current_entry_attributes("Plans, \"draft\"".into(), date, tags, None)
+        .await
+        .unwrap();
+
+    // All entries are exported when nothing is selected
+    assert_eq!(
+        app.get_metadata_csv().unwrap(),
+        "id,title,date,priority,tag_count,content_length\n\
+        1,Title 2,2023-12-02T01:02:03Z,1,0,9\n\
+        0,\"Plans, \"\"draft\"\"\",2023-10-12T11:22:33Z,,2,9\n"
+    );
+
+    app.selected_entries.insert(1);
+    let temp_dir = TempDir::new("csv");
+    let path = temp_dir.join("journals.csv");
+    app.export_metadata_csv(path.clone()).await.unwrap();
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        "id,title,date,priority,tag_count,content_length\n\
+        1,Title 2,2023-12-02T01:02:03Z,1,0,9\n"
+    );
+}
+
+#[tokio::test]
+async fn test_export_markdown_files() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+    add_extra_entries_drafts(&mut app).await;
+    // Older entry with the same sanitized title as "Title 3"
+    let date = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+    let duplicate_id = app
+        .add_entry("Title-3?".into(), date, Vec::new(), None)
+        .await
+        .unwrap();
+
+    let export_dir = TempDir::new("markdown");
+
+    for id in [0, 2, duplicate_id] {
+        app.select_entry(id);
+    }
+
+    let index_path = app
+        .export_markdown_files(export_dir.path.clone())
+        .await
+        .unwrap();
+    assert_eq!(index_path, export_dir.join("index.md"));
+
+    let index = std::fs::read_to_string(&index_path).unwrap();
+    let linked_files: Vec<&str> = index
+        .lines()
+        .filter_map(|line| line.split_once("](")?.1.split_once(')'))
+        .map(|(file_name, _)| file_name)
+        .collect();
+
+    // Index follows the order of the entries list with sanitized and deduped file names
+    let expected_files: Vec<&str> = app
+        .entries
+        .iter()
+        .filter_map(|entry| match entry.id {
+            0 => Some("title-1.md"),
+            2 => Some("title-3.md"),
+            id if id == duplicate_id => Some("title-3-2.md"),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(linked_files, expected_files);
+
+    for file_name in linked_files {
+        let content = std::fs::read_to_string(export_dir.join(file_name)).unwrap();
+        assert!(content.starts_with("---\ntitle: "));
+    }
+}
+
+#[tokio::test]
+async fn test_add_entry() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    let tag = String::from("Added Tag");
+    let title = String::from("Added Title");
+    let date = Utc::now();
+
+    app.add_entry(title.clone(), date, vec![tag.clone()], Some(1))
+        .await
+        .unwrap();
+
+    assert_eq!(app.get_active_entries().count(), 3);
+    let added_entry = app.get_active_entries().find(|e| e.id == 2).unwrap();
+    assert_eq!(added_entry.title, title);
+    assert_eq!(added_entry.date, date);
+    assert_eq!(added_entry.tags, vec![tag]);
+    assert_eq!(added_entry.priority, Some(1));
+    assert_eq!(app.get_all_tags().len(), 3);
+}
+
+#[tokio::test]
+async fn test_tags_prompt_on_create() {
+    let settings = Settings {
+        prompt_tags_on_create: true,
+        ..Default::default()
+    };
+    let mut app = App::new(MockDataProvider::new_with_data(), settings);
+    app.load_entries().await.unwrap();
+
+    let tagless_id = app
+        .add_entry("Tagless".into(), Utc::now(), Vec::new(), None)
+        .await
+        .unwrap();
+    assert!(app.needs_tags_prompt(tagless_id));
+
+    let tagged_id = app
+        .add_entry("Tagged".into(), Utc::now(), vec!["Tag 1".into()], None)
+        .await
+        .unwrap();
+    assert!(!app.needs_tags_prompt(tagged_id));
+
+    // Prompt is opt-in
+    app.settings.prompt_tags_on_create = false;
+    assert!(!app.needs_tags_prompt(tagless_id));
+}
+
+#[tokio::test]
+async fn test_empty_title_allow() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    let id = app
+        .add_entry(String::new(), Utc::now(), Vec::new(), None)
+        .await
+        .unwrap();
+    assert_eq!(app.get_entry(id).unwrap().title, "");
+
+    app.current_entry_id = Some(0);
+    let date = app.get_entry(0).unwrap().date;
+    app.update_current_entry_attributes(String::new(), date, Vec::new(), None)
+        .await
+        .unwrap();
+    assert_eq!(app.get_entry(0).unwrap().title, "");
+}
+
+#[tokio::test]
+async fn test_empty_title_reject() {
+    let mut app = create_default_app();
+    app.settings.empty_title = EmptyTitleBehavior::Reject;
+    app.load_entries().await.unwrap();
+
+    assert!(app
+        .add_entry(String::from("  "), Utc::now(), Vec::new(), None)
+        .await
+        .is_err());
+    assert_eq!(app.entries.len(), 2);
+
+    app.current_entry_id = Some(0);
+    let date = app.get_entry(0).unwrap().date;
+    assert!(app
+        .update_current_entry_attributes(String::new(), date, Vec::new(), None)
+        .await
+        .is_err());
+    assert_eq!(app.get_entry(0).unwrap().title, "Title 1");
+}
+
+#[tokio::test]
+async fn test_empty_title_generate() {
+    let mut app = create_default_app();
+    app.settings.empty_title = EmptyTitleBehavior::Generate;
+    app.load_entries().await.unwrap();
+
+    // New entries don't have content yet, so the title is generated from the date.
+    let date = Utc.with_ymd_and_hms(2024, 3, 5, 10, 0, 0).unwrap();
+    let id = app
+        .add_entry(String::new(), date, Vec::new(), None)
+        .await
+        .unwrap();
+    assert_eq!(app.get_entry(id).unwrap().title, "05-03-2024");
+
+    // Existing entries get the title from the first line of their content.
+    app.current_entry_id = Some(0);
+    let date = app.get_entry(0).unwrap().date;
+    app.update_current_entry_attributes(String::new(), date, Vec::new(), None)
+        .await
+        .unwrap();
+    assert_eq!(app.get_entry(0).unwrap().title, "Content 1");
+}
+
+#[tokio::test]
+async fn test_lowercase_tags() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    let id = app
+        .add_entry("Title".into(), Utc::now(), vec!["Work".into()], None)
+        .await
+        .unwrap();
+    assert_eq!(app.get_entry(id).unwrap().tags, vec![String::from("Work")]);
+
+    app.settings.lowercase_tags = true;
+    let id = app
+        .add_entry(
+            "Title".into(),
+            Utc::now(),
+            vec!["Work".into(), "work".into()],
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(app.get_entry(id).unwrap().tags, vec![String::from("work")]);
+
+    // Existing tags aren't changed retroactively
+    assert_eq!(
+        app.get_entry(0).unwrap().tags,
+        vec![String::from("Tag 1"), String::from("Tag 2")]
+    );
+}
+
+#[tokio::test]
+async fn test_quick_note() {
+    let mut app = create_default_app();
+    app.settings.default_journal_priority = Some(2);
+    app.load_entries().await.unwrap();
+
+    let note = String::from("Call the plumber about the kitchen sink");
+    let id = app.add_quick_note(note.clone()).await.unwrap();
+
+    let entry = app.get_entry(id).unwrap();
+    assert_eq!(entry.title, note);
+    assert_eq!(entry.content, note);
+    assert!(entry.tags.is_empty());
+    assert_eq!(entry.priority, Some(2));
+
+    // Long notes are kept in the content while the title is shortened
+    let note = "Long note ".repeat(10).trim().to_owned();
+    let id = app.add_quick_note(note.clone()).await.unwrap();
+
+    let entry = app.get_entry(id).unwrap();
+    assert_eq!(entry.title, &note[..50]);
+    assert_eq!(entry.content, note);
+}
+
+#[tokio::test]
+async fn test_duplicate_entry() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    let id = app.duplicate_entry(0).await.unwrap();
+    assert_eq!(app.current_entry_id, Some(id));
+    assert_eq!(app.entries.len(), 3);
+
+    let entry = app.get_entry(id).unwrap();
+    assert_eq!(entry.title, "Title 1 (copy)");
+    assert_eq!(entry.content, "Content 1");
+    assert_eq!(
+        entry.tags,
+        vec![String::from("Tag 1"), String::from("Tag 2")]
+    );
+    assert_eq!(entry.date.date_naive(), Local::now().date_naive());
+
+    let id = app.duplicate_entry(1).await.unwrap();
+    let entry = app.get_entry(id).unwrap();
+    assert_eq!(entry.title, "Title 2 (copy)");
+    assert_eq!(entry.priority, Some(1));
+
+    // Duplicating is recorded in the history
+    app.undo().await.unwrap();
+    assert!(app.get_entry(id).is_none());
+    assert_eq!(app.entries.len(), 3);
+
+    assert!(app.duplicate_entry(100).await.is_err());
+}
+
+#[tokio::test]
+async fn test_priority_counts() {
+    use crate::settings::PriorityCountsScope;
+
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+    add_extra_entries_drafts(&mut app).await;
+    app.add_entry(
+        String::from("Title 6"),
+        Utc.with_ymd_and_hms(2023, 5, 6, 7, 8, 9).unwrap(),
+        vec![String::from("Tag 1")],
+        Some(1),
+    )
+    .await
+    .unwrap();
+
+    let counts = app.get_priority_counts();
+    assert_eq!(
+        counts.priorities,
+        BTreeMap::from([(1, 2), (2, 1), (3, 1), (4, 1)])
+    );
+    assert_eq!(counts.no_priority, 1);
+
+    let mut filter = Filter::default();
+    filter
+        .criteria
+        .push(FilterCriterion::Tag(String::from("Tag 1")));
+    app.apply_filter(Some(filter));
+
+    // Active entries only are counted by default
+    let counts = app.get_priority_counts();
+    assert_eq!(counts.priorities, BTreeMap::from([(1, 1), (2, 1), (3, 1)]));
+    assert_eq!(counts.no_priority, 1);
+
+    app.settings.priority_counts_scope = PriorityCountsScope::All;
+    let counts = app.get_priority_counts();
+    assert_eq!(
+        counts.priorities,
+        BTreeMap::from([(1, 2), (2, 1), (3, 1), (4, 1)])
+    );
+    assert_eq!(counts.no_priority, 1);
+}
+
+#[tokio::test]
+async fn test_compute_stats() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+    add_extra_entries_drafts(&mut app).await;
+
+    // Sunday ending the week of the journal on 2023-12-02
+    let today = NaiveDate::from_ymd_opt(2023, 12, 3).unwrap();
+
+    let stats = app.compute_stats_at(false, today);
+    assert_eq!(stats.total, 5);
+    assert_eq!(stats.this_week, 1);
+    assert_eq!(stats.this_month, 1);
+    assert_eq!(stats.words, 4);
+    assert_eq!(stats.average_words(), 0.8);
+    assert_eq!(stats.top_tags(1), vec![("Tag 1", 3)]);
+    assert_eq!(stats.top_tags(5), vec![("Tag 1", 3), ("Tag 2", 2)]);
+    assert_eq!(
+        stats.per_month,
+        BTreeMap::from([
+            ((2022, 12), 1),
+            ((2023, 1), 1),
+            ((2023, 10), 1),
+            ((2023, 11), 1),
+            ((2023, 12), 1)
+        ])
+    );
+
+    let mut filter = Filter::default();
+    filter
+        .criteria
+        .push(FilterCriterion::Tag(String::from("Tag 1")));
+    app.apply_filter(Some(filter));
+
+    // Filter is respected for the shown journals only
+    let stats = app.compute_stats_at(true, today);
+    assert_eq!(stats.total, 3);
+    assert_eq!(stats.this_week, 0);
+    assert_eq!(stats.words, 2);
+    assert_eq!(stats.per_month.len(), 3);
+
+    assert_eq!(app.compute_stats_at(false, today).total, 5);
+}
+
+#[tokio::test]
+async fn test_duplicate_tags() {
+    use crate::settings::DuplicateTagsBehavior;
+
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    let add_tags = |tags: &[&str]| -> Vec<String> {
+        ["Tag 1", "Tag 2"]
+            .iter()
+            .chain(tags)
+            .map(|tag| tag.to_string())
+            .collect()
+    };
+
+    app.current_entry_id = Some(0);
+    let date = app.get_entry(0).unwrap().date;
+
+    // Dedupe silently
+    app.update_current_entry_attributes("Title 1".into(), date, add_tags(&["Tag 1"]), None)
+        .await
+        .unwrap();
+    assert_eq!(app.get_entry(0).unwrap().tags, add_tags(&[]));
+    assert!(app.take_duplicate_tags().is_empty());
+
+    // Dedupe with notification
+    app.settings.duplicate_tags = DuplicateTagsBehavior::Warn;
+    app.update_current_entry_attributes("Title 1".into(), date, add_tags(&["Tag 2"]), None)
+        .await
+        .unwrap();
+    assert_eq!(app.get_entry(0).unwrap().tags, add_tags(&[]));
+    assert_eq!(app.take_duplicate_tags(), vec![String::from("Tag 2")]);
+    assert!(app.take_duplicate_tags().is_empty());
+
+    // Tags differing in case are kept unless ignoring the case
+    app.update_current_entry_attributes("Title 1".into(), date, add_tags(&["tag 1"]), None)
+        .await
+        .unwrap();
+    assert_eq!(app.get_entry(0).unwrap().tags, add_tags(&["tag 1"]));
+    assert!(app.take_duplicate_tags().is_empty());
+
+    app.settings.duplicate_tags_ignore_case = true;
+    app.update_current_entry_attributes("Title 1".into(), date, add_tags(&["tag 1"]), None)
+        .await
+        .unwrap();
+    assert_eq!(app.get_entry(0).unwrap().tags, add_tags(&[]));
+    assert_eq!(app.take_duplicate_tags(), vec![String::from("tag 1")]);
+}
+
+#[tokio::test]
+async fn test_tag_templates() {
+    let mut app = create_default_app();
+    app.settings
+        .tag_templates
+        .insert("Meeting".into(), "## Attendees\n\n## Notes".into());
+    app.load_entries().await.unwrap();
+
+    let id = app
+        .add_entry(
+            "Title".into(),
+            Utc::now(),
+            vec!["Other".into(), "Meeting".into()],
+            None,
+        )
+        .await
+        .unwrap();
+
+    let tag = app.get_new_tag_template(id, &[]).unwrap();
+    assert_eq!(tag, "Meeting");
+    assert_eq!(app.settings.tag_templates[&tag], "## Attendees\n\n## Notes");
+
+    app.insert_tag_template(id, &tag).await.unwrap();
+    assert_eq!(
+        app.get_entry(id).unwrap().content,
+        "## Attendees\n\n## Notes"
+    );
+
+    // Toggling the tag on an existing entry appends the template to its content.
+    app.current_entry_id = Some(0);
+    let entry = app.get_entry(0).unwrap();
+    let previous_tags = entry.tags.clone();
+    let mut tags = previous_tags.clone();
+    tags.push("Meeting".into());
+    app.update_current_entry_attributes(entry.title.clone(), entry.date, tags, entry.priority)
+        .await
+        .unwrap();
+
+    let tag = app.get_new_tag_template(0, &previous_tags).unwrap();
+    app.insert_tag_template(0, &tag).await.unwrap();
+    assert_eq!(
+        app.get_entry(0).unwrap().content,
+        "Content 1\n\n## Attendees\n\n## Notes"
+    );
+
+    // Tags without templates or already assigned ones aren't offered.
+    let current_tags = app.get_entry(0).unwrap().tags.clone();
+    assert!(app.get_new_tag_template(0, &current_tags).is_none());
+    assert!(app.get_new_tag_template(1, &[]).is_none());
+}
+
+#[tokio::test]
+async fn test_quick_priority() {
+    let mut app = create_default_app();
+    app.settings.default_journal_priority = Some(3);
+    app.settings.max_journal_priority = 4;
+    app.load_entries().await.unwrap();
+
+    // Bump from none gets the default priority
+    app.current_entry_id = Some(0);
+    app.bump_current_entry_priority().await.unwrap();
+    assert_eq!(app.get_entry(0).unwrap().priority, Some(3));
+
+    app.bump_current_entry_priority().await.unwrap();
+    assert_eq!(app.get_entry(0).unwrap().priority, Some(4));
+
+    // Bump at max is clamped
+    app.bump_current_entry_priority().await.unwrap();
+    assert_eq!(app.get_entry(0).unwrap().priority, Some(4));
+
+    // Lower at min is clamped
+    app.current_entry_id = Some(1);
+    app.lower_current_entry_priority().await.unwrap();
+    assert_eq!(app.get_entry(1).unwrap().priority, Some(1));
+
+    // Changes are undoable
+    app.current_entry_id = Some(0);
+    app.undo().await.unwrap();
+    assert_eq!(app.get_entry(0).unwrap().priority, Some(3));
+    app.undo().await.unwrap();
+    assert!(app.get_entry(0).unwrap().priority.is_none());
 }
 
 #[tokio::test]
-async fn test_get_tags() {
+async fn test_import_ndjson() {
     let mut app = create_default_app();
     app.load_entries().await.unwrap();
 
-    let tags = vec![String::from("Tag 1"), String::from("Tag 2")];
+    let input = r#"{"date":"2024-01-02T10:00:00Z","title":"Imported 1","content":"Content","tags":["Tag 3"],"priority":2}
+{"date":"2024-01-03T10:00:00Z","title":"Missing quote}
 
-    assert_eq!(app.get_all_tags(), tags);
+{"title":"Missing date"}
+{"date":"2024-01-04T10:00:00Z","title":"Imported 2"}"#;
+
+    let report = app.import_ndjson_entries(input.as_bytes()).await.unwrap();
+
+    assert_eq!(report.imported, 2);
+    let error_lines: Vec<usize> = report.errors.iter().map(|(line, _)| *line).collect();
+    assert_eq!(error_lines, vec![2, 4]);
+
+    app.load_entries().await.unwrap();
+    assert_eq!(app.entries.len(), 4);
+    let imported = app
+        .entries
+        .iter()
+        .find(|e| e.title == "Imported 2")
+        .unwrap();
+    assert!(imported.tags.is_empty());
+    assert!(imported.content.is_empty());
+    assert!(app.get_all_tags().contains(&String::from("Tag 3")));
 }
 
 #[tokio::test]
-async fn test_add_entry() {
+async fn test_import_ndjson_tolerant_dates() {
     let mut app = create_default_app();
     app.load_entries().await.unwrap();
 
-    let tag = String::from("Added Tag");
-    let title = String::from("Added Title");
-    let date = Utc::now();
+    let input = r#"{"date":"2024-01-02","title":"Date only"}
+{"date":"2024-01-03 08:30","title":"Without zone"}
+{"date":"02.01.2024","title":"Invalid date"}"#;
+
+    let report = app.import_ndjson_entries(input.as_bytes()).await.unwrap();
+
+    assert_eq!(report.imported, 2);
+    assert_eq!(report.errors.len(), 1);
+    let (line, err) = &report.errors[0];
+    assert_eq!(*line, 3);
+    assert!(err.contains("Invalid date '02.01.2024'"), "Error: {err}");
+
+    app.load_entries().await.unwrap();
+    let entry = app.entries.iter().find(|e| e.title == "Date only").unwrap();
+    assert_eq!(
+        entry.date,
+        Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap()
+    );
+    let entry = app
+        .entries
+        .iter()
+        .find(|e| e.title == "Without zone")
+        .unwrap();
+    assert_eq!(
+        entry.date,
+        Utc.with_ymd_and_hms(2024, 1, 3, 8, 30, 0).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_updated_at() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
 
-    app.add_entry(title.clone(), date.clone(), vec![tag.clone()], Some(1))
+    let entry = app.get_entry(0).unwrap();
+    let date = entry.date;
+    assert_eq!(entry.updated_at, None);
+    assert_eq!(entry.last_modified(), date);
+
+    let before_update = Utc::now();
+    app.update_entry_content(0, "Edited content".into(), HistoryStack::Undo)
         .await
         .unwrap();
 
-    assert_eq!(app.get_active_entries().count(), 3);
-    let added_entry = app.get_active_entries().find(|e| e.id == 2).unwrap();
-    assert_eq!(added_entry.title, title);
-    assert_eq!(added_entry.date, date);
-    assert_eq!(added_entry.tags, vec![tag]);
-    assert_eq!(added_entry.priority, Some(1));
-    assert_eq!(app.get_all_tags().len(), 3);
+    let entry = app.get_entry(0).unwrap();
+    assert_eq!(entry.date, date);
+    let updated_at = entry.updated_at.unwrap();
+    assert!(updated_at >= before_update);
+    assert_eq!(entry.last_modified(), updated_at);
+
+    let stored = app.data_provide.load_all_entries().await.unwrap();
+    let stored = stored.iter().find(|e| e.id == 0).unwrap();
+    assert_eq!(stored.updated_at, Some(updated_at));
+}
+
+#[tokio::test]
+async fn test_pin_entry() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    let ids = |app: &App<MockDataProvider>| app.entries.iter().map(|e| e.id).collect::<Vec<_>>();
+    assert_eq!(ids(&app), vec![1, 0]);
+
+    app.current_entry_id = Some(0);
+    app.toggle_current_entry_pin().await.unwrap();
+
+    let pinned_at = app.get_entry(0).unwrap().pinned_at;
+    assert!(pinned_at.is_some());
+    assert_eq!(ids(&app), vec![0, 1]);
+
+    let stored = app.data_provide.load_all_entries().await.unwrap();
+    let stored = stored.iter().find(|e| e.id == 0).unwrap();
+    assert_eq!(stored.pinned_at, pinned_at);
+
+    app.undo().await.unwrap();
+    assert!(!app.get_entry(0).unwrap().is_pinned());
+    assert_eq!(ids(&app), vec![1, 0]);
+
+    app.redo().await.unwrap();
+    assert_eq!(app.get_entry(0).unwrap().pinned_at, pinned_at);
+    assert_eq!(ids(&app), vec![0, 1]);
+
+    app.toggle_current_entry_pin().await.unwrap();
+    assert!(!app.get_entry(0).unwrap().is_pinned());
+    assert_eq!(ids(&app), vec![1, 0]);
 }
 
 #[tokio::test]
@@ -184,6 +1075,27 @@ async fn test_sorter() {
     assert_eq!(ids, vec![3, 4, 2, 1, 0], "Priority Descending");
 }
 
+#[tokio::test]
+async fn test_sorter_last_modified() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    add_extra_entries_drafts(&mut app).await;
+
+    app.apply_sort(vec![SortCriteria::LastModified], SortOrder::Descending);
+
+    let ids: Vec<u32> = app.get_active_entries().map(|entry| entry.id).collect();
+    assert_eq!(ids, vec![1, 2, 0, 4, 3], "Last Modified Descending by date");
+
+    // Edit the oldest entry
+    app.update_entry_content(3, "Edited content".into(), HistoryStack::Undo)
+        .await
+        .unwrap();
+
+    let ids: Vec<u32> = app.get_active_entries().map(|entry| entry.id).collect();
+    assert_eq!(ids, vec![3, 1, 2, 0, 4], "Edited entry must be on top");
+}
+
 #[tokio::test]
 async fn test_sorter_with_filter() {
     let mut app = create_default_app();
@@ -224,3 +1136,499 @@ async fn test_sorter_with_filter() {
     let ids: Vec<u32> = app.get_active_entries().map(|entry| entry.id).collect();
     assert_eq!(ids, vec![4, 2, 0], "Apply Filter Then Sorter Descending");
 }
+
+#[tokio::test]
+async fn test_read_only() {
+    use crate::app::ui::{Styles, UICommand};
+
+    let mut app = create_default_app();
+    app.settings.read_only = true;
+    app.load_entries().await.unwrap();
+    let mut ui_components = UIComponents::new(Styles::default());
+    ui_components.set_current_entry(Some(0), &mut app);
+
+    // Mutating commands are ignored showing a message
+    UICommand::BumpEntryPriority
+        .execute(&mut ui_components, &mut app)
+        .await
+        .unwrap();
+    assert_eq!(app.get_entry(0).unwrap().priority, None);
+    assert!(ui_components.has_popup());
+
+    UICommand::DeleteCurrentEntry
+        .execute(&mut ui_components, &mut app)
+        .await
+        .unwrap();
+    assert_eq!(app.entries.len(), 2);
+    assert_eq!(app.data_provide.load_all_entries().await.unwrap().len(), 2);
+
+    // Filtering still works
+    UICommand::CycleTagFilter
+        .execute(&mut ui_components, &mut app)
+        .await
+        .unwrap();
+    assert!(app.filter.is_some());
+    let active_ids: Vec<u32> = app.get_active_entries().map(|entry| entry.id).collect();
+    assert_eq!(active_ids, vec![0]);
+}
+
+#[tokio::test]
+async fn test_import_markdown_dir() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    let import_dir = TempDir::new("md_import");
+    std::fs::write(
+        import_dir.join("review.md"),
+        "---\ntitle: Review\ndate: 2024-03-07\npriority: 2\ntags: [Work]\n---\n\nContent",
+    )
+    .unwrap();
+    std::fs::write(import_dir.join("plain-note.md"), "Plain content").unwrap();
+    std::fs::write(import_dir.join("invalid.md"), "---\ndate: someday\n---\n").unwrap();
+    std::fs::write(import_dir.join("index.md"), "# Journals").unwrap();
+    std::fs::write(import_dir.join("notes.txt"), "Not markdown").unwrap();
+
+    let report = app.import_markdown_dir(&import_dir.path).await.unwrap();
+
+    assert_eq!(report.imported, 2);
+    assert_eq!(report.errors.len(), 1);
+    assert_eq!(report.errors[0].0, import_dir.join("invalid.md"));
+
+    app.load_entries().await.unwrap();
+    let review = app
+        .entries
+        .iter()
+        .find(|entry| entry.title == "Review")
+        .unwrap();
+    assert_eq!(review.priority, Some(2));
+    assert_eq!(review.tags, vec![String::from("Work")]);
+    assert_eq!(review.content, "Content");
+    assert!(app.entries.iter().any(|entry| entry.title == "plain-note"));
+    assert!(!app.entries.iter().any(|entry| entry.title == "Journals"));
+}
+
+#[tokio::test]
+async fn test_viewed_entries_history() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+    add_extra_entries_drafts(&mut app).await;
+
+    for id in [0, 2, 3, 4] {
+        app.record_viewed_entry(id);
+    }
+
+    app.delete_entry(3).await.unwrap();
+
+    // Deleted entry is skipped in both directions
+    assert_eq!(app.go_back_viewed_entries(), Some(2));
+    assert_eq!(app.go_back_viewed_entries(), Some(0));
+    assert_eq!(app.go_back_viewed_entries(), None);
+    assert_eq!(app.go_forward_viewed_entries(), Some(2));
+    assert_eq!(app.go_forward_viewed_entries(), Some(4));
+    assert_eq!(app.go_forward_viewed_entries(), None);
+
+    // Viewing a new entry after going back drops the forward history
+    assert_eq!(app.go_back_viewed_entries(), Some(2));
+    app.record_viewed_entry(1);
+    assert_eq!(app.go_forward_viewed_entries(), None);
+    assert_eq!(app.go_back_viewed_entries(), Some(2));
+}
+
+#[tokio::test]
+async fn test_full_text_search() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+    add_extra_entries_drafts(&mut app).await;
+
+    let active_ids: Vec<u32> = app.get_active_entries().map(|entry| entry.id).collect();
+    assert_eq!(active_ids, vec![1, 2, 0, 4, 3]);
+
+    // Title and content are searched ignoring case by default
+    assert_eq!(app.search("content"), vec![1, 0]);
+    assert_eq!(app.search("title 3"), vec![2]);
+    assert!(app.search("").is_empty());
+
+    app.set_search(Some(String::from("content")), true);
+    assert!(app.search("content").is_empty());
+    assert_eq!(app.search("Content 2"), vec![1]);
+
+    // Navigation between matches wraps around
+    app.set_search(Some(String::from("title")), false);
+    assert_eq!(app.get_search_match(true), Some(1));
+    assert_eq!(app.get_search_match(false), Some(3));
+    app.current_entry_id = Some(0);
+    assert_eq!(app.get_search_match(true), Some(4));
+    assert_eq!(app.get_search_match(false), Some(2));
+    app.current_entry_id = Some(3);
+    assert_eq!(app.get_search_match(true), Some(1));
+
+    // Only the active entries are searched
+    let mut filter = Filter::default();
+    filter
+        .criteria
+        .push(FilterCriterion::Tag(String::from("Tag 1")));
+    app.apply_filter(Some(filter));
+    assert_eq!(app.search("title"), vec![2, 0, 4]);
+    app.current_entry_id = Some(4);
+    assert_eq!(app.get_search_match(true), Some(2));
+
+    app.set_search(Some(String::new()), false);
+    assert_eq!(app.get_search_query(), None);
+    assert_eq!(app.get_search_match(true), None);
+}
+
+#[tokio::test]
+async fn test_import_strategies() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    let temp_dir = TempDir::new("import_dup");
+    let import_path = temp_dir.join("entries.json");
+    let input = r#"{"version": 100, "entries": [
+        {"date": "2023-10-12T11:22:33Z", "title": "Title 1", "content": "Imported", "priority": 3},
+        {"date": "2023-10-12", "title": "Title 1"},
+        {"date": "2023-12-02T01:02:03Z", "title": "title 2"}
+    ]}"#;
+    std::fs::write(&import_path, input).unwrap();
+
+    let summary = app
+        .import_entries(import_path.clone(), ImportStrategy::SkipDuplicates)
+        .await
+        .unwrap();
+    assert_eq!(
+        summary,
+        "2 journal(s) imported. 1 duplicate journal(s) skipped"
+    );
+    app.load_entries().await.unwrap();
+    assert_eq!(app.entries.len(), 4);
+    assert_eq!(app.get_entry(0).unwrap().content, "Content 1");
+
+    // Title and date are matched exactly, so the entries with other date or title case are
+    // duplicates now too.
+    let summary = app
+        .import_entries(import_path, ImportStrategy::Overwrite)
+        .await
+        .unwrap();
+    assert_eq!(
+        summary,
+        "0 journal(s) imported. 3 duplicate journal(s) updated"
+    );
+
+    app.load_entries().await.unwrap();
+    assert_eq!(app.entries.len(), 4);
+    let entry = app.get_entry(0).unwrap();
+    assert_eq!(entry.content, "Imported");
+    assert_eq!(entry.priority, Some(3));
+    assert!(entry.tags.is_empty());
+}
+
+#[tokio::test]
+async fn test_import_keeps_ids() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    let temp_dir = TempDir::new("import_ids");
+    let import_path = temp_dir.join("entries.json");
+    let input = r#"{"version": 100, "entries": [
+        {"id": 1, "date": "2024-01-01", "title": "Taken id"},
+        {"id": 7, "date": "2024-01-02", "title": "Free id"},
+        {"date": "2024-01-03", "title": "No id"}
+    ]}"#;
+    std::fs::write(&import_path, input).unwrap();
+
+    let summary = app
+        .import_entries(import_path, ImportStrategy::AddAll)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        summary,
+        "3 journal(s) imported. 1 journal(s) got new ids since their original ids are taken"
+    );
+
+    app.load_entries().await.unwrap();
+    assert_eq!(app.get_entry(7).unwrap().title, "Free id");
+    assert_eq!(app.get_entry(1).unwrap().title, "Title 2");
+    assert_eq!(app.get_entry(8).unwrap().title, "Taken id");
+}
+
+#[tokio::test]
+async fn test_add_tag_to_selected() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+    app.current_entry_id = Some(0);
+
+    assert!(app.add_tag_to_selected(String::from("  ")).await.is_err());
+
+    app.select_entry(0);
+    app.select_entry(1);
+    app.add_tag_to_selected(String::from("Tag 2"))
+        .await
+        .unwrap();
+
+    assert_eq!(app.get_entry(0).unwrap().tags, vec!["Tag 1", "Tag 2"]);
+    assert_eq!(app.get_entry(1).unwrap().tags, vec!["Tag 2"]);
+
+    app.add_tag_to_selected(String::from("Batch"))
+        .await
+        .unwrap();
+    assert_eq!(
+        app.get_entry(0).unwrap().tags,
+        vec!["Tag 1", "Tag 2", "Batch"]
+    );
+    assert_eq!(app.get_entry(1).unwrap().tags, vec!["Tag 2", "Batch"]);
+
+    let stored = app.data_provide.load_all_entries().await.unwrap();
+    assert!(stored
+        .iter()
+        .all(|e| e.tags.contains(&String::from("Batch"))));
+
+    // One undo reverts the whole batch
+    app.undo().await.unwrap();
+    assert_eq!(app.get_entry(0).unwrap().tags, vec!["Tag 1", "Tag 2"]);
+    assert_eq!(app.get_entry(1).unwrap().tags, vec!["Tag 2"]);
+
+    app.redo().await.unwrap();
+    assert!(app
+        .get_entry(0)
+        .unwrap()
+        .tags
+        .contains(&String::from("Batch")));
+    assert!(app
+        .get_entry(1)
+        .unwrap()
+        .tags
+        .contains(&String::from("Batch")));
+
+    app.undo().await.unwrap();
+    app.undo().await.unwrap();
+    assert_eq!(app.get_entry(0).unwrap().tags, vec!["Tag 1", "Tag 2"]);
+    assert!(app.get_entry(1).unwrap().tags.is_empty());
+}
+
+#[tokio::test]
+async fn test_remove_tag_from_selected() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+    app.current_entry_id = Some(0);
+
+    app.select_entry(0);
+    app.select_entry(1);
+    app.add_tag_to_selected(String::from("Batch"))
+        .await
+        .unwrap();
+
+    // Entry 1 lacks the tag and stays unchanged
+    app.remove_tag_from_selected(String::from("Tag 1"))
+        .await
+        .unwrap();
+    assert_eq!(app.get_entry(0).unwrap().tags, vec!["Tag 2", "Batch"]);
+    assert_eq!(app.get_entry(1).unwrap().tags, vec!["Batch"]);
+
+    app.remove_tag_from_selected(String::from("Batch"))
+        .await
+        .unwrap();
+    assert_eq!(app.get_entry(0).unwrap().tags, vec!["Tag 2"]);
+    assert!(app.get_entry(1).unwrap().tags.is_empty());
+
+    let stored = app.data_provide.load_all_entries().await.unwrap();
+    assert!(stored
+        .iter()
+        .all(|e| !e.tags.contains(&String::from("Batch"))));
+
+    // One undo restores the tag on all the entries
+    app.undo().await.unwrap();
+    assert_eq!(app.get_entry(0).unwrap().tags, vec!["Tag 2", "Batch"]);
+    assert_eq!(app.get_entry(1).unwrap().tags, vec!["Batch"]);
+}
+
+#[tokio::test]
+async fn test_set_priority_for_selected() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+    app.current_entry_id = Some(0);
+
+    app.select_entry(0);
+    app.select_entry(1);
+    app.set_priority_for_selected(Some(3)).await.unwrap();
+
+    assert_eq!(app.get_entry(0).unwrap().priority, Some(3));
+    assert_eq!(app.get_entry(1).unwrap().priority, Some(3));
+
+    let stored = app.data_provide.load_all_entries().await.unwrap();
+    assert!(stored.iter().all(|e| e.priority == Some(3)));
+
+    app.set_priority_for_selected(None).await.unwrap();
+    assert!(app.get_active_entries().all(|e| e.priority.is_none()));
+
+    // Each undo restores the previous priorities of all entries
+    app.undo().await.unwrap();
+    assert!(app.get_active_entries().all(|e| e.priority == Some(3)));
+
+    app.undo().await.unwrap();
+    assert!(app.get_entry(0).unwrap().priority.is_none());
+    assert_eq!(app.get_entry(1).unwrap().priority, Some(1));
+}
+
+#[tokio::test]
+async fn test_update_selected_without_current_entry() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+    app.current_entry_id = None;
+
+    app.select_entry(0);
+    app.add_tag_to_selected(String::from("New")).await.unwrap();
+    assert_eq!(
+        app.get_entry(0).unwrap().tags,
+        vec!["Tag 1", "Tag 2", "New"]
+    );
+
+    app.remove_tag_from_selected(String::from("Tag 1"))
+        .await
+        .unwrap();
+    assert_eq!(app.get_entry(0).unwrap().tags, vec!["Tag 2", "New"]);
+
+    app.set_priority_for_selected(Some(3)).await.unwrap();
+    assert_eq!(app.get_entry(0).unwrap().priority, Some(3));
+
+    app.undo().await.unwrap();
+    assert!(app.get_entry(0).unwrap().priority.is_none());
+    app.redo().await.unwrap();
+    assert_eq!(app.get_entry(0).unwrap().priority, Some(3));
+    assert!(app.current_entry_id.is_none());
+}
+
+#[tokio::test]
+async fn test_delete_selected_entries() {
+    let mut app = create_default_app();
+    add_extra_entries_drafts(&mut app).await;
+    app.load_entries().await.unwrap();
+    let count_before = app.entries.len();
+
+    app.select_entry(0);
+    app.select_entry(1);
+    app.delete_selected_entries().await.unwrap();
+
+    assert_eq!(app.entries.len(), count_before - 2);
+    assert!(app.get_entry(0).is_none());
+    assert!(app.get_entry(1).is_none());
+    assert!(app.selected_entries.is_empty());
+    assert_eq!(
+        app.data_provide.load_all_entries().await.unwrap().len(),
+        count_before - 2
+    );
+
+    // One undo restores all deleted entries
+    app.undo().await.unwrap();
+    assert_eq!(app.entries.len(), count_before);
+    let titles: Vec<&str> = app.entries.iter().map(|e| e.title.as_str()).collect();
+    assert!(titles.contains(&"Title 1"));
+    assert!(titles.contains(&"Title 2"));
+
+    app.redo().await.unwrap();
+    assert_eq!(app.entries.len(), count_before - 2);
+}
+
+#[tokio::test]
+async fn test_rename_tag() {
+    let mut app = create_default_app();
+    add_extra_entries_drafts(&mut app).await;
+    app.load_entries().await.unwrap();
+    app.current_entry_id = Some(0);
+
+    let tag_1_count = app
+        .entries
+        .iter()
+        .filter(|e| e.tags.contains(&String::from("Tag 1")))
+        .count();
+    assert!(tag_1_count > 1);
+
+    assert!(app.rename_tag("Tag 1", " ").await.is_err());
+
+    app.rename_tag("Tag 1", "Project").await.unwrap();
+
+    let tags = app.get_all_tags();
+    assert!(!tags.contains(&String::from("Tag 1")));
+    assert!(tags.contains(&String::from("Project")));
+    assert_eq!(app.get_entry(0).unwrap().tags, vec!["Project", "Tag 2"]);
+
+    let stored = app.data_provide.load_all_entries().await.unwrap();
+    assert!(stored
+        .iter()
+        .all(|e| !e.tags.contains(&String::from("Tag 1"))));
+
+    // Renaming to an existing tag merges them without duplicates
+    app.rename_tag("Project", "Tag 2").await.unwrap();
+    assert_eq!(app.get_entry(0).unwrap().tags, vec!["Tag 2"]);
+
+    // Each rename is undone at once
+    app.undo().await.unwrap();
+    assert_eq!(app.get_entry(0).unwrap().tags, vec!["Project", "Tag 2"]);
+    app.undo().await.unwrap();
+    assert_eq!(app.get_entry(0).unwrap().tags, vec!["Tag 1", "Tag 2"]);
+    let restored_count = app
+        .entries
+        .iter()
+        .filter(|e| e.tags.contains(&String::from("Tag 1")))
+        .count();
+    assert_eq!(restored_count, tag_1_count);
+}
+
+#[tokio::test]
+async fn test_merge_tags() {
+    let mut app = create_default_app();
+    add_extra_entries_drafts(&mut app).await;
+    app.load_entries().await.unwrap();
+    app.current_entry_id = Some(0);
+
+    app.apply_filter(Some(Filter {
+        relation: crate::app::filter::CriteriaRelation::Or,
+        criteria: vec![
+            FilterCriterion::Tag(String::from("Tag 1")),
+            FilterCriterion::Tag(String::from("Tag 2")),
+        ],
+    }));
+
+    app.merge_tags("Tag 1", "Tag 2").await.unwrap();
+
+    assert!(!app.get_all_tags().contains(&String::from("Tag 1")));
+    assert_eq!(app.get_entry(0).unwrap().tags, vec!["Tag 2"]);
+
+    // Filter criteria are moved to the target tag without duplicates
+    assert_eq!(
+        app.filter.as_ref().unwrap().criteria,
+        vec![FilterCriterion::Tag(String::from("Tag 2"))]
+    );
+
+    app.undo().await.unwrap();
+    assert_eq!(app.get_entry(0).unwrap().tags, vec!["Tag 1", "Tag 2"]);
+}
+
+#[tokio::test]
+async fn test_merge_tags_failed() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+    app.current_entry_id = None;
+
+    let filter = Filter {
+        relation: crate::app::filter::CriteriaRelation::And,
+        criteria: vec![FilterCriterion::Tag(String::from("Tag 1"))],
+    };
+    app.apply_filter(Some(filter.clone()));
+
+    // Filter is kept on the old tag which the entries still have after saving failed
+    app.data_provide.set_return_err(true);
+    assert!(app.rename_tag("Tag 1", "Renamed").await.is_err());
+    assert_eq!(app.filter, Some(filter));
+    assert_eq!(app.get_active_entries().count(), 1);
+
+    // Filter follows the renamed tag once saving succeeds
+    app.data_provide.set_return_err(false);
+    app.rename_tag("Tag 1", "Renamed").await.unwrap();
+    assert_eq!(
+        app.filter.as_ref().unwrap().criteria,
+        vec![FilterCriterion::Tag(String::from("Renamed"))]
+    );
+    assert_eq!(app.get_active_entries().count(), 1);
+    assert!(app.take_dropped_filter_tags().is_empty());
+}
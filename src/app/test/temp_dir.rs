@@ -0,0 +1,43 @@
+use std::{env, fs, path::PathBuf, process};
+
+/// Directory for the files of a test in the temporary directory of the system. It's empty on
+/// creation and removed with its content when dropped.
+pub struct TempDir {
+    pub path: PathBuf,
+}
+
+impl TempDir {
+    /// Creates the directory with the given name, which is made unique per test process.
+    pub fn new(name: &str) -> Self {
+        let path = env::temp_dir().join(format!("tjournal_{name}_{}", process::id()));
+
+        let temp_dir = Self { path };
+        temp_dir.clean_up();
+        fs::create_dir_all(&temp_dir.path)
+            .expect("Access to create the test directory should be given");
+
+        temp_dir
+    }
+
+    /// Returns the path of the file with the given name inside the directory.
+    pub fn join(&self, file_name: &str) -> PathBuf {
+        self.path.join(file_name)
+    }
+
+    fn clean_up(&self) {
+        if self
+            .path
+            .try_exists()
+            .expect("Access to check the test directory should be given")
+        {
+            fs::remove_dir_all(&self.path)
+                .expect("Access to delete the test directory should be given");
+        }
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        self.clean_up();
+    }
+}
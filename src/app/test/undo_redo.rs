@@ -56,6 +56,38 @@ async fn remove() {
     assert_eq!(app.get_active_entries().count(), original_count - 1);
 }
 
+#[tokio::test]
+/// Test that restoring a removed Entry keeps its id, pin and color
+async fn remove_keeps_all_fields() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    let id = 1;
+    app.current_entry_id = Some(id);
+    app.toggle_current_entry_pin().await.unwrap();
+    app.set_current_entry_color(Some(Color::Rgb(16, 32, 48)))
+        .await
+        .unwrap();
+
+    let original = app.get_entry(id).unwrap().clone();
+    assert!(original.pinned_at.is_some());
+    assert!(original.color.is_some());
+
+    app.delete_entry(id).await.unwrap();
+    assert!(app.get_entry(id).is_none());
+
+    let restored_id = app.undo().await.unwrap().unwrap();
+    assert_eq!(restored_id, id);
+
+    let restored = app.get_entry(id).unwrap();
+    assert_eq!(restored.pinned_at, original.pinned_at);
+    assert_eq!(restored.color, original.color);
+    assert_eq!(restored, &original);
+
+    app.redo().await.unwrap();
+    assert!(app.get_entry(id).is_none());
+}
+
 #[tokio::test]
 /// Test for Updating entry attributes
 async fn update_attributes() {
@@ -151,3 +183,419 @@ async fn many() {
         assert_eq!(app.entries.len(), current_count);
     }
 }
+
+#[tokio::test]
+/// Test for confirming undo & redo when they move past the last save point
+async fn save_point_confirmation() {
+    use crate::app::ui::{Styles, UICommand};
+
+    let mut app = create_default_app();
+    app.settings.confirm_history_past_save = true;
+    app.load_entries().await.unwrap();
+    let mut ui_components = UIComponents::new(Styles::default());
+
+    app.current_entry_id = Some(1);
+    app.update_current_entry_content("Saved content".into())
+        .await
+        .unwrap();
+    app.add_entry("Added".into(), DateTime::default(), vec![], None)
+        .await
+        .unwrap();
+
+    // Undo within the latest segment after the save doesn't need confirmation.
+    assert!(!app.undo_needs_confirm());
+    UICommand::Undo
+        .execute(&mut ui_components, &mut app)
+        .await
+        .unwrap();
+    assert!(!ui_components.has_popup());
+    assert_eq!(app.get_active_entries().count(), 2);
+
+    // Undo the saved content crosses the save point.
+    assert!(app.undo_needs_confirm());
+    UICommand::Undo
+        .execute(&mut ui_components, &mut app)
+        .await
+        .unwrap();
+    assert!(ui_components.has_popup());
+    assert_eq!(app.get_entry(1).unwrap().content, "Saved content");
+
+    // Back to the save point with redo then redo past it needs confirmation.
+    app.undo().await.unwrap();
+    assert!(!app.redo_needs_confirm());
+    app.redo().await.unwrap();
+    assert!(app.redo_needs_confirm());
+
+    // Confirmation is opt-in
+    app.settings.confirm_history_past_save = false;
+    assert!(!app.redo_needs_confirm());
+    app.settings.confirm_history_past_save = true;
+
+    // New changes after undoing past the save point drop it, since redo can't reach it anymore.
+    app.undo().await.unwrap();
+    assert!(app.history_jump_needs_confirm(HistoryStack::Redo, 2));
+    app.add_entry("Diverged".into(), DateTime::default(), vec![], None)
+        .await
+        .unwrap();
+    assert!(!app.history_jump_needs_confirm(HistoryStack::Undo, 1));
+    assert!(!app.history_jump_needs_confirm(HistoryStack::Redo, 2));
+    app.redo().await.unwrap();
+    assert!(!app.redo_needs_confirm());
+}
+
+#[tokio::test]
+/// Test for confirmation of history jumps past the save point from the history popup
+async fn history_jump_past_save() {
+    use crate::app::{
+        keymap::Input,
+        ui::{Styles, UICommand},
+    };
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut app = create_default_app();
+    app.settings.confirm_history_past_save = true;
+    app.load_entries().await.unwrap();
+    let mut ui_components = UIComponents::new(Styles::default());
+    let press = |key_code| Input::new(key_code, KeyModifiers::NONE);
+
+    app.current_entry_id = Some(1);
+    app.update_current_entry_content("Saved content".into())
+        .await
+        .unwrap();
+    app.add_entry("Added".into(), DateTime::default(), vec![], None)
+        .await
+        .unwrap();
+
+    // Jumps within the latest segment after the save don't need confirmation.
+    assert!(!app.history_jump_needs_confirm(HistoryStack::Undo, 1));
+    assert!(app.history_jump_needs_confirm(HistoryStack::Undo, 2));
+
+    // Picking the saved content change in the popup asks for confirmation first.
+    UICommand::ShowHistory
+        .execute(&mut ui_components, &mut app)
+        .await
+        .unwrap();
+    for key_code in [KeyCode::Down, KeyCode::Down, KeyCode::Enter] {
+        ui_components
+            .handle_input(&press(key_code), &mut app)
+            .await
+            .unwrap();
+    }
+    assert!(ui_components.has_popup());
+    assert_eq!(app.get_active_entries().count(), 3);
+    assert_eq!(app.get_entry(1).unwrap().content, "Saved content");
+
+    // Confirming applies the whole jump.
+    ui_components
+        .handle_input(&press(KeyCode::Char('y')), &mut app)
+        .await
+        .unwrap();
+    assert!(!ui_components.has_popup());
+    assert_eq!(app.get_active_entries().count(), 2);
+    assert_ne!(app.get_entry(1).unwrap().content, "Saved content");
+
+    // Redo jumps crossing the save point need confirmation too.
+    assert!(!app.history_jump_needs_confirm(HistoryStack::Redo, 1));
+    assert!(app.history_jump_needs_confirm(HistoryStack::Redo, 2));
+
+    app.settings.confirm_history_past_save = false;
+    assert!(!app.history_jump_needs_confirm(HistoryStack::Redo, 2));
+}
+
+#[tokio::test]
+/// Test for changing the history limit at runtime
+async fn history_limit() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    for title in ["Added 1", "Added 2", "Added 3"] {
+        app.add_entry(title.into(), DateTime::default(), vec![], None)
+            .await
+            .unwrap();
+    }
+    assert_eq!(app.get_active_entries().count(), 5);
+
+    // Oldest changes are dropped when the limit gets smaller
+    app.set_history_limit(2);
+    app.undo().await.unwrap();
+    app.undo().await.unwrap();
+    assert_eq!(app.undo().await.unwrap(), None);
+    assert_eq!(app.get_active_entries().count(), 3);
+
+    // Zero disables the history
+    app.set_history_limit(0);
+    assert_eq!(app.redo().await.unwrap(), None);
+    app.add_entry("Added 4".into(), DateTime::default(), vec![], None)
+        .await
+        .unwrap();
+    assert_eq!(app.undo().await.unwrap(), None);
+    assert_eq!(app.get_active_entries().count(), 4);
+}
+
+#[tokio::test]
+/// Test for restoring the persisted history on the next start
+async fn persist_history() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    app.current_entry_id = Some(0);
+    let current = app.get_current_entry().unwrap().clone();
+    app.update_current_entry_attributes("Changed".into(), current.date, current.tags, None)
+        .await
+        .unwrap();
+    app.delete_entry(1).await.unwrap();
+
+    let json = serde_json::to_string(&app.persisted_history()).unwrap();
+    let updated_at = app.get_entry(0).unwrap().updated_at;
+    let journal_key = app.settings.get_journal_key().unwrap();
+
+    // Loads the app with the state of entry 0 as it was when the history was persisted.
+    let load_app = |persist_history: bool, history_key: &str| {
+        let persisted: PersistedHistory = serde_json::from_str(&json).unwrap();
+        let history_key = history_key.to_owned();
+        async move {
+            let mut app = create_default_app();
+            app.settings.persist_history = persist_history;
+            app.load_entries().await.unwrap();
+            app.current_entry_id = Some(0);
+            let entry = app.entries.iter_mut().find(|entry| entry.id == 0).unwrap();
+            entry.updated_at = updated_at;
+            app.state.histories.insert(history_key, persisted);
+            app
+        }
+    };
+
+    // History is restored only if enabled
+    let mut app = load_app(false, &journal_key).await;
+    app.restore_history().unwrap();
+    assert!(app.state.histories.is_empty());
+    assert_eq!(app.undo().await.unwrap(), None);
+
+    // History of encrypted journals isn't restored since it's kept in plain text
+    let mut app = load_app(true, &journal_key).await;
+    app.settings.backend_type = Some(crate::settings::BackendType::EncryptedJson);
+    let history = app.state.histories.remove(&journal_key).unwrap();
+    let encrypted_key = app.settings.get_journal_key().unwrap();
+    app.state.histories.insert(encrypted_key, history);
+    app.restore_history().unwrap();
+    assert!(app.state.histories.is_empty());
+    assert_eq!(app.undo().await.unwrap(), None);
+
+    // Persisted history is kept if it hasn't been restored due to failing to load the entries
+    let mut app = load_app(true, &journal_key).await;
+    app.update_state_history().unwrap();
+    assert!(!app.state.histories[&journal_key].undo.is_empty());
+
+    app.restore_history().unwrap();
+    app.history = HistoryManager::new(app.settings.history_limit);
+    app.update_state_history().unwrap();
+    assert!(app.state.histories[&journal_key].undo.is_empty());
+
+    // History of other journals isn't restored
+    let mut app = load_app(true, "Json:/other.json").await;
+    app.restore_history().unwrap();
+    assert_eq!(app.state.histories.len(), 1);
+    assert_eq!(app.undo().await.unwrap(), None);
+
+    // All changes are restored when the entries are unchanged
+    let mut app = load_app(true, &journal_key).await;
+    app.delete_entry(1).await.unwrap();
+    app.history = HistoryManager::new(app.settings.history_limit);
+    app.restore_history().unwrap();
+    let id = app.undo().await.unwrap().unwrap();
+    assert_eq!(app.get_entry(id).unwrap().title, "Title 2");
+    assert_eq!(app.undo().await.unwrap(), Some(0));
+    assert_eq!(app.get_entry(0).unwrap().title, "Title 1");
+    assert!(app.get_entry(0).unwrap().priority.is_none());
+
+    // Changes on deleted entries are dropped
+    let mut app = load_app(true, &journal_key).await;
+    app.delete_entry(1).await.unwrap();
+    app.delete_entry(0).await.unwrap();
+    app.history = HistoryManager::new(app.settings.history_limit);
+    app.restore_history().unwrap();
+    app.undo().await.unwrap();
+    assert_eq!(app.undo().await.unwrap(), None);
+    assert_eq!(app.get_active_entries().count(), 1);
+
+    // Changes on entries modified since or replaced by other entries with the same id are
+    // dropped
+    let mut app = load_app(true, &journal_key).await;
+    app.delete_entry(1).await.unwrap();
+    app.entries[0].updated_at = None;
+    app.history = HistoryManager::new(app.settings.history_limit);
+    app.restore_history().unwrap();
+    app.undo().await.unwrap();
+    assert_eq!(app.undo().await.unwrap(), None);
+}
+
+#[tokio::test]
+/// Test for describing the history and jumping through it
+async fn jump_history() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    app.current_entry_id = Some(0);
+    app.update_current_entry_content("Changed".into())
+        .await
+        .unwrap();
+    app.toggle_current_entry_pin().await.unwrap();
+    app.delete_entry(1).await.unwrap();
+
+    assert_eq!(
+        app.describe_history(HistoryStack::Undo),
+        [
+            "Restore 'Title 2'",
+            "Unpin 'Title 1'",
+            "Restore content of 'Title 1'"
+        ]
+    );
+
+    let id = app.jump_history(HistoryStack::Undo, 3).await.unwrap();
+    assert_eq!(id, Some(0));
+    assert_eq!(app.get_entry(0).unwrap().content, "Content 1");
+    assert!(!app.get_entry(0).unwrap().is_pinned());
+    assert_eq!(app.get_active_entries().count(), 2);
+    assert!(app.describe_history(HistoryStack::Undo).is_empty());
+    assert_eq!(app.describe_history(HistoryStack::Redo).len(), 3);
+
+    app.jump_history(HistoryStack::Redo, 2).await.unwrap();
+    assert_eq!(app.get_entry(0).unwrap().content, "Changed");
+    assert!(app.get_entry(0).unwrap().is_pinned());
+    assert_eq!(
+        app.describe_history(HistoryStack::Redo),
+        ["Delete 'Title 2'"]
+    );
+}
+
+#[tokio::test]
+/// Test for grouping consecutive content changes into one undo step
+async fn group_content_changes() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+
+    app.current_entry_id = Some(0);
+    for content in ["First", "Second", "Third"] {
+        app.update_current_entry_content(content.into())
+            .await
+            .unwrap();
+    }
+
+    // Undo restores the content before the first grouped change
+    app.undo().await.unwrap();
+    assert_eq!(app.get_entry(0).unwrap().content, "Content 1");
+    assert_eq!(app.undo().await.unwrap(), None);
+
+    // Changes after redo aren't grouped with it
+    app.redo().await.unwrap();
+    app.update_current_entry_content("Fourth".into())
+        .await
+        .unwrap();
+    app.undo().await.unwrap();
+    assert_eq!(app.get_entry(0).unwrap().content, "Third");
+
+    // Changes on other journals end the group
+    app.update_current_entry_content("Fifth".into())
+        .await
+        .unwrap();
+    app.current_entry_id = Some(1);
+    app.update_current_entry_content("Other".into())
+        .await
+        .unwrap();
+    app.current_entry_id = Some(0);
+    app.update_current_entry_content("Sixth".into())
+        .await
+        .unwrap();
+    app.undo().await.unwrap();
+    assert_eq!(app.get_entry(0).unwrap().content, "Fifth");
+
+    // Zero disables grouping
+    app.history.set_grouping_interval(Duration::ZERO);
+    app.update_current_entry_content("Seventh".into())
+        .await
+        .unwrap();
+    app.update_current_entry_content("Eighth".into())
+        .await
+        .unwrap();
+    app.undo().await.unwrap();
+    assert_eq!(app.get_entry(0).unwrap().content, "Seventh");
+}
+
+#[tokio::test]
+/// Test for batch changes taking one place on a full undo stack, even when saving fails
+async fn batch_with_full_stack() {
+    let mut app = create_default_app();
+    app.load_entries().await.unwrap();
+    app.set_history_limit(3);
+    app.current_entry_id = Some(0);
+
+    for title in ["Added 1", "Added 2", "Added 3"] {
+        app.add_entry(title.into(), DateTime::default(), vec![], None)
+            .await
+            .unwrap();
+    }
+
+    app.select_entry(0);
+    app.select_entry(1);
+    app.add_tag_to_selected(String::from("Batch"))
+        .await
+        .unwrap();
+
+    // Failed batch is rolled back without touching the history
+    app.data_provide.set_return_err(true);
+    assert!(app
+        .add_tag_to_selected(String::from("Failed"))
+        .await
+        .is_err());
+    app.data_provide.set_return_err(false);
+
+    // One undo reverts the whole batch
+    app.undo().await.unwrap();
+    assert_eq!(app.get_entry(0).unwrap().tags, vec!["Tag 1", "Tag 2"]);
+    assert!(app.get_entry(1).unwrap().tags.is_empty());
+
+    // Redoing the batch takes one place on the full redo stack too
+    app.redo().await.unwrap();
+    assert!(app.get_entry(1).unwrap().tags.contains(&"Batch".into()));
+    app.undo().await.unwrap();
+
+    // Only the oldest change is dropped from the stack
+    app.undo().await.unwrap();
+    app.undo().await.unwrap();
+    assert_eq!(app.undo().await.unwrap(), None);
+    assert_eq!(app.get_active_entries().count(), 3);
+}
+
+#[tokio::test]
+/// Test for deleting more selected entries than the history limit at once
+async fn delete_selected_over_limit() {
+    let mut app = create_default_app();
+    add_extra_entries_drafts(&mut app).await;
+    app.load_entries().await.unwrap();
+    app.set_history_limit(2);
+    let count_before = app.entries.len();
+
+    for title in ["Added 1", "Added 2"] {
+        app.add_entry(title.into(), DateTime::default(), vec![], None)
+            .await
+            .unwrap();
+    }
+
+    for entry_id in [0, 1, 2] {
+        app.select_entry(entry_id);
+    }
+    app.delete_selected_entries().await.unwrap();
+    assert_eq!(app.entries.len(), count_before - 1);
+
+    // One undo restores all the deleted entries
+    app.undo().await.unwrap();
+    assert_eq!(app.entries.len(), count_before + 2);
+    for title in ["Title 1", "Title 2", "Title 3"] {
+        assert!(app.entries.iter().any(|entry| entry.title == title));
+    }
+
+    // The batch takes one place only, dropping the oldest change
+    app.undo().await.unwrap();
+    assert!(app.entries.iter().all(|entry| entry.title != "Added 2"));
+    assert_eq!(app.undo().await.unwrap(), None);
+}
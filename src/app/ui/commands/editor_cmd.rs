@@ -21,7 +21,14 @@ pub async fn exec_save_entry_content<'a, D: DataProvider>(
     let entry_content = ui_components.editor.get_content();
     app.update_current_entry_content(entry_content).await?;
 
+    // Keep the current entry selected since the entries can be reordered after the change
+    let entry_index = app
+        .get_active_entries()
+        .position(|entry| Some(entry.id) == app.current_entry_id);
+    ui_components.entries_list.state.select(entry_index);
+
     ui_components.editor.refresh_has_unsaved(app);
+    ui_components.editor.mark_saved();
 
     Ok(HandleInputReturnType::Handled)
 }
@@ -1,7 +1,11 @@
 use std::{collections::HashMap, env};
 
-use crate::app::{external_editor, ui::*, App, UIComponents};
+use crate::{
+    app::{external_editor, ui::*, App, EntryExportFormat, UIComponents},
+    settings::SelectionAfterDelete,
+};
 
+use arboard::Clipboard;
 use backend::DataProvider;
 
 use scopeguard::defer;
@@ -38,13 +42,28 @@ fn select_prev_entry<D: DataProvider>(
             app.get_active_entries()
                 .nth(prev_index)
                 .map(|entry| entry.id)
-        });
+        })
+        .or_else(|| first_entry_if_cleared(ui_components, app));
 
     if prev_id.is_some() {
         ui_components.set_current_entry(prev_id, app);
     }
 }
 
+/// Returns the first active entry when the selection is cleared, so navigation can start again.
+fn first_entry_if_cleared<D: DataProvider>(
+    ui_components: &UIComponents,
+    app: &App<D>,
+) -> Option<u32> {
+    ui_components
+        .entries_list
+        .state
+        .selected()
+        .is_none()
+        .then(|| app.get_active_entries().next().map(|entry| entry.id))
+        .flatten()
+}
+
 pub async fn continue_select_prev_entry<'a, D: DataProvider>(
     ui_components: &mut UIComponents<'a>,
     app: &mut App<D>,
@@ -90,7 +109,8 @@ fn select_next_entry<D: DataProvider>(
                 .nth(next_index)
                 .or_else(|| app.get_active_entries().next_back())
                 .map(|entry| entry.id)
-        });
+        })
+        .or_else(|| first_entry_if_cleared(ui_components, app));
 
     if next_id.is_some() {
         ui_components.set_current_entry(next_id, app);
@@ -150,6 +170,39 @@ pub async fn continue_create_entry<'a, D: DataProvider>(
     Ok(HandleInputReturnType::Handled)
 }
 
+pub fn exec_create_quick_note(ui_components: &mut UIComponents) -> CmdResult {
+    if ui_components.has_unsaved() {
+        ui_components.show_unsaved_msg_box(Some(UICommand::CreateQuickNote));
+    } else {
+        create_quick_note(ui_components);
+    }
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+fn create_quick_note(ui_components: &mut UIComponents) {
+    ui_components
+        .popup_stack
+        .push(Popup::QuickNote(Box::new(QuickNotePopup::new())));
+}
+
+pub async fn continue_create_quick_note<'a, D: DataProvider>(
+    ui_components: &mut UIComponents<'a>,
+    app: &mut App<D>,
+    msg_box_result: MsgBoxResult,
+) -> CmdResult {
+    match msg_box_result {
+        MsgBoxResult::Ok | MsgBoxResult::Cancel => {}
+        MsgBoxResult::Yes => {
+            exec_save_entry_content(ui_components, app).await?;
+            create_quick_note(ui_components);
+        }
+        MsgBoxResult::No => create_quick_note(ui_components),
+    }
+
+    Ok(HandleInputReturnType::Handled)
+}
+
 pub fn exec_edit_current_entry<D: DataProvider>(
     ui_components: &mut UIComponents,
     app: &mut App<D>,
@@ -167,7 +220,10 @@ fn edit_current_entry<D: DataProvider>(ui_components: &mut UIComponents, app: &m
     if let Some(entry) = app.get_current_entry() {
         ui_components
             .popup_stack
-            .push(Popup::Entry(Box::new(EntryPopup::from_entry(entry))));
+            .push(Popup::Entry(Box::new(EntryPopup::from_entry(
+                entry,
+                &app.settings,
+            ))));
     }
 }
 
@@ -191,6 +247,66 @@ pub async fn continue_edit_current_entry<'a, D: DataProvider>(
     Ok(HandleInputReturnType::Handled)
 }
 
+pub fn exec_prompt_tags_new_entry(ui_components: &mut UIComponents) -> CmdResult {
+    let msg = MsgBoxType::Question(
+        "The new journal doesn't have any tags. Do you want to add tags to it?".into(),
+    );
+    let msg_actions = MsgBoxActions::YesNo;
+    ui_components.show_msg_box(msg, msg_actions, Some(UICommand::PromptTagsNewEntry));
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub fn continue_prompt_tags_new_entry<D: DataProvider>(
+    ui_components: &mut UIComponents,
+    app: &mut App<D>,
+    msg_box_result: MsgBoxResult,
+) -> CmdResult {
+    if msg_box_result == MsgBoxResult::Yes {
+        if let Some(entry) = app.get_current_entry() {
+            let entry_popup = EntryPopup::from_entry(entry, &app.settings).with_tags_focused();
+            ui_components
+                .popup_stack
+                .push(Popup::Entry(Box::new(entry_popup)));
+        }
+    }
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub fn exec_insert_tag_template(ui_components: &mut UIComponents) -> CmdResult {
+    let Some(tag) = ui_components.pending_template_tag.as_ref() else {
+        return Ok(HandleInputReturnType::Handled);
+    };
+
+    let msg = MsgBoxType::Question(format!(
+        "Tag '{tag}' has a content template. Do you want to insert it to the journal?"
+    ));
+    let msg_actions = MsgBoxActions::YesNo;
+    ui_components.show_msg_box(msg, msg_actions, Some(UICommand::InsertTagTemplate));
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub async fn continue_insert_tag_template<D: DataProvider>(
+    ui_components: &mut UIComponents<'_>,
+    app: &mut App<D>,
+    msg_box_result: MsgBoxResult,
+) -> CmdResult {
+    let Some(tag) = ui_components.pending_template_tag.take() else {
+        return Ok(HandleInputReturnType::Handled);
+    };
+
+    if msg_box_result == MsgBoxResult::Yes {
+        if let Some(entry_id) = app.current_entry_id {
+            app.insert_tag_template(entry_id, &tag).await?;
+            ui_components.set_current_entry(Some(entry_id), app);
+        }
+    }
+
+    Ok(HandleInputReturnType::Handled)
+}
+
 pub fn exec_delete_current_entry<D: DataProvider>(
     ui_components: &mut UIComponents,
     app: &App<D>,
@@ -204,17 +320,25 @@ pub fn exec_delete_current_entry<D: DataProvider>(
     Ok(HandleInputReturnType::Handled)
 }
 
-pub async fn continue_delete_current_entry<'a, D: DataProvider>(
+pub async fn continue_delete_current_entry<D: DataProvider>(
+    ui_components: &mut UIComponents<'_>,
     app: &mut App<D>,
     msg_box_result: MsgBoxResult,
 ) -> CmdResult {
     match msg_box_result {
         MsgBoxResult::Yes => {
-            app.delete_entry(
-                app.current_entry_id
-                    .expect("current entry must have a value"),
-            )
-            .await?;
+            let entry_id = app
+                .current_entry_id
+                .expect("current entry must have a value");
+            let next_selection = app.get_selection_after_delete(entry_id);
+
+            app.delete_entry(entry_id).await?;
+
+            if app.settings.selection_after_delete == SelectionAfterDelete::Clear {
+                ui_components.clear_current_entry(app);
+            } else {
+                ui_components.set_current_entry(next_selection, app);
+            }
         }
         MsgBoxResult::No => {}
         _ => unreachable!(
@@ -240,8 +364,22 @@ pub fn exec_export_entry_content<D: DataProvider>(
 }
 
 pub fn export_entry_content<D: DataProvider>(ui_components: &mut UIComponents, app: &App<D>) {
+    show_entry_export(EntryExportFormat::Content, ui_components, app);
+}
+
+/// Opens the export popup for the current entry with Markdown as the initial format, which
+/// includes the title and the metadata of the entry.
+pub fn export_entry_document<D: DataProvider>(ui_components: &mut UIComponents, app: &mut App<D>) {
+    show_entry_export(EntryExportFormat::Markdown, ui_components, app);
+}
+
+fn show_entry_export<D: DataProvider>(
+    format: EntryExportFormat,
+    ui_components: &mut UIComponents,
+    app: &App<D>,
+) {
     if let Some(entry) = app.get_current_entry() {
-        match ExportPopup::create_entry_content(entry, app) {
+        match ExportPopup::create_entry_content(entry, format, app) {
             Ok(popup) => ui_components
                 .popup_stack
                 .push(Popup::Export(Box::new(popup))),
@@ -293,22 +431,12 @@ pub async fn edit_in_external_editor<'a, D: DataProvider>(
     use tokio::fs;
 
     if let Some(entry) = app.get_current_entry() {
-        const TEMP_FILENAME: &str = "tui_journal";
-        let temp_extension = &app.settings.external_editor.temp_file_extension;
-
-        let file_name = if !temp_extension.is_empty() {
-            format!("{TEMP_FILENAME}.{temp_extension}")
-        } else {
-            String::from(TEMP_FILENAME)
-        };
-
-        let file_path = env::temp_dir().join(file_name);
-
-        if file_path.exists() {
-            fs::remove_file(&file_path).await?;
-        }
-
-        fs::write(&file_path, entry.content.as_str()).await?;
+        let file_path = external_editor::create_temp_file(
+            &env::temp_dir(),
+            &app.settings.external_editor.temp_file_extension,
+            entry.content.as_str(),
+        )
+        .await?;
 
         defer! {
         std::fs::remove_file(&file_path).expect("Temp File couldn't be deleted");
@@ -363,7 +491,7 @@ pub fn exec_show_filter<D: DataProvider>(
 }
 
 fn show_filter<D: DataProvider>(ui_components: &mut UIComponents, app: &mut App<D>) {
-    let tags = app.get_all_tags();
+    let tags = app.get_filter_tags();
     ui_components
         .popup_stack
         .push(Popup::Filter(Box::new(FilterPopup::new(
@@ -392,12 +520,217 @@ pub async fn continue_show_filter<'a, D: DataProvider>(
     Ok(HandleInputReturnType::Handled)
 }
 
+pub fn show_saved_filters<D: DataProvider>(ui_components: &mut UIComponents, app: &mut App<D>) {
+    let popup = SavedFiltersPopup::new(app);
+    ui_components
+        .popup_stack
+        .push(Popup::SavedFilters(Box::new(popup)));
+}
+
+pub fn show_search<D: DataProvider>(ui_components: &mut UIComponents, app: &mut App<D>) {
+    let popup = SearchPopup::new(app.get_search_query(), app.is_search_case_sensitive());
+    ui_components
+        .popup_stack
+        .push(Popup::Search(Box::new(popup)));
+}
+
+pub fn go_to_next_search_match<D: DataProvider>(
+    ui_components: &mut UIComponents,
+    app: &mut App<D>,
+) {
+    go_to_search_match(true, ui_components, app);
+}
+
+pub fn go_to_prev_search_match<D: DataProvider>(
+    ui_components: &mut UIComponents,
+    app: &mut App<D>,
+) {
+    go_to_search_match(false, ui_components, app);
+}
+
+/// Goes to the next or previous search match, opening the search popup if there is no query.
+fn go_to_search_match<D: DataProvider>(
+    next: bool,
+    ui_components: &mut UIComponents,
+    app: &mut App<D>,
+) {
+    if app.get_search_query().is_none() {
+        show_search(ui_components, app);
+        return;
+    }
+
+    match app.get_search_match(next) {
+        Some(entry_id) => ui_components.set_current_entry(Some(entry_id), app),
+        None => ui_components.show_msg_box(
+            MsgBoxType::Info(String::from("No journals match the search")),
+            MsgBoxActions::Ok,
+            None,
+        ),
+    }
+}
+
 pub fn exec_reset_filter<D: DataProvider>(app: &mut App<D>) -> CmdResult {
     app.apply_filter(None);
 
     Ok(HandleInputReturnType::Handled)
 }
 
+pub fn exec_toggle_focus_mode<D: DataProvider>(
+    ui_components: &mut UIComponents,
+    app: &mut App<D>,
+) -> CmdResult {
+    app.toggle_focus_mode();
+    // Keep the current entry selected after the other entries are hidden or shown again.
+    ui_components.set_current_entry(app.current_entry_id, app);
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub fn exec_copy_entries_markdown<D: DataProvider>(
+    ui_components: &mut UIComponents,
+    app: &App<D>,
+) -> CmdResult {
+    let Some(markdown) = app.get_entries_markdown() else {
+        let msg = MsgBoxType::Info("No journals to copy".into());
+        ui_components.show_msg_box(msg, MsgBoxActions::Ok, None);
+
+        return Ok(HandleInputReturnType::Handled);
+    };
+
+    let result = Clipboard::new().and_then(|mut clipboard| clipboard.set_text(markdown));
+
+    match result {
+        Ok(()) => {
+            if app.settings.export.show_confirmation {
+                let msg = MsgBoxType::Info("Journal(s) copied to the clipboard as Markdown".into());
+                ui_components.show_msg_box(msg, MsgBoxActions::Ok, None);
+            }
+        }
+        Err(err) => ui_components.show_err_msg(format!(
+            "Error while copying journal(s) to the clipboard. Err: {err}"
+        )),
+    }
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub fn exec_cycle_list_density<D: DataProvider>(app: &mut App<D>) -> CmdResult {
+    app.settings.list_density = app.settings.list_density.next();
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub fn exec_remove_entry_tags<D: DataProvider>(
+    ui_components: &mut UIComponents,
+    app: &App<D>,
+) -> CmdResult {
+    let has_tags = app
+        .get_current_entry()
+        .is_some_and(|entry| !entry.tags.is_empty());
+
+    if has_tags {
+        ui_components
+            .popup_stack
+            .push(Popup::RemoveTags(Box::new(RemoveTagsPopup::new(app))));
+    }
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub fn exec_set_entry_color<D: DataProvider>(
+    ui_components: &mut UIComponents,
+    app: &App<D>,
+) -> CmdResult {
+    if let Some(entry) = app.get_current_entry() {
+        let popup = EntryColorPopup::new(entry.title.to_owned(), entry.color.as_deref());
+        ui_components
+            .popup_stack
+            .push(Popup::EntryColor(Box::new(popup)));
+    }
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub async fn exec_bump_entry_priority<D: DataProvider>(
+    ui_components: &mut UIComponents<'_>,
+    app: &mut App<D>,
+) -> CmdResult {
+    let result = app.bump_current_entry_priority().await;
+    // Changing the priority can change the position of the entry in the sorted list.
+    ui_components.set_current_entry(app.current_entry_id, app);
+    result?;
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub async fn exec_lower_entry_priority<D: DataProvider>(
+    ui_components: &mut UIComponents<'_>,
+    app: &mut App<D>,
+) -> CmdResult {
+    let result = app.lower_current_entry_priority().await;
+    ui_components.set_current_entry(app.current_entry_id, app);
+    result?;
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub async fn exec_toggle_pin_entry<D: DataProvider>(
+    ui_components: &mut UIComponents<'_>,
+    app: &mut App<D>,
+) -> CmdResult {
+    let result = app.toggle_current_entry_pin().await;
+    // Pinned entries are moved above the other entries.
+    ui_components.set_current_entry(app.current_entry_id, app);
+    result?;
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub async fn exec_duplicate_current_entry<'a, D: DataProvider>(
+    ui_components: &mut UIComponents<'a>,
+    app: &mut App<D>,
+) -> CmdResult {
+    if ui_components.has_unsaved() {
+        ui_components.show_unsaved_msg_box(Some(UICommand::DuplicateEntry));
+    } else {
+        duplicate_current_entry(ui_components, app).await?;
+    }
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+async fn duplicate_current_entry<'a, D: DataProvider>(
+    ui_components: &mut UIComponents<'a>,
+    app: &mut App<D>,
+) -> anyhow::Result<()> {
+    if let Some(entry_id) = app.current_entry_id {
+        let new_id = app.duplicate_entry(entry_id).await?;
+        ui_components.set_current_entry(Some(new_id), app);
+    }
+
+    Ok(())
+}
+
+pub async fn continue_duplicate_current_entry<'a, D: DataProvider>(
+    ui_components: &mut UIComponents<'a>,
+    app: &mut App<D>,
+    msg_box_result: MsgBoxResult,
+) -> CmdResult {
+    match msg_box_result {
+        MsgBoxResult::Ok | MsgBoxResult::Cancel => {}
+        MsgBoxResult::Yes => {
+            exec_save_entry_content(ui_components, app).await?;
+            duplicate_current_entry(ui_components, app).await?;
+        }
+        MsgBoxResult::No => {
+            discard_current_content(ui_components, app);
+            duplicate_current_entry(ui_components, app).await?;
+        }
+    }
+
+    Ok(HandleInputReturnType::Handled)
+}
+
 pub fn exec_cycle_tag_filter<D: DataProvider>(
     ui_components: &mut UIComponents,
     app: &mut App<D>,
@@ -406,6 +739,7 @@ pub fn exec_cycle_tag_filter<D: DataProvider>(
         ui_components.show_unsaved_msg_box(Some(UICommand::CycleTagFilter));
     } else {
         app.cycle_tags_in_filter();
+        confirm_pending_filter(ui_components, app);
     }
 
     Ok(HandleInputReturnType::Handled)
@@ -421,16 +755,95 @@ pub async fn continue_cycle_tag_filter<'a, D: DataProvider>(
         MsgBoxResult::Yes => {
             exec_save_entry_content(ui_components, app).await?;
             app.cycle_tags_in_filter();
+            confirm_pending_filter(ui_components, app);
         }
         MsgBoxResult::No => {
             discard_current_content(ui_components, app);
             app.cycle_tags_in_filter();
+            confirm_pending_filter(ui_components, app);
         }
     }
 
     Ok(HandleInputReturnType::Handled)
 }
 
+pub fn exec_apply_filter_preset<D: DataProvider>(
+    ui_components: &mut UIComponents,
+    app: &mut App<D>,
+    key: u8,
+) -> CmdResult {
+    if ui_components.has_unsaved() {
+        ui_components.show_unsaved_msg_box(Some(UICommand::ApplyFilterPreset(key)));
+    } else {
+        app.toggle_filter_preset(key)?;
+        confirm_pending_filter(ui_components, app);
+    }
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub async fn continue_apply_filter_preset<'a, D: DataProvider>(
+    ui_components: &mut UIComponents<'a>,
+    app: &mut App<D>,
+    msg_box_result: MsgBoxResult,
+    key: u8,
+) -> CmdResult {
+    match msg_box_result {
+        MsgBoxResult::Ok | MsgBoxResult::Cancel => {}
+        MsgBoxResult::Yes => {
+            exec_save_entry_content(ui_components, app).await?;
+            app.toggle_filter_preset(key)?;
+            confirm_pending_filter(ui_components, app);
+        }
+        MsgBoxResult::No => {
+            discard_current_content(ui_components, app);
+            app.toggle_filter_preset(key)?;
+            confirm_pending_filter(ui_components, app);
+        }
+    }
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub fn exec_confirm_empty_filter<D: DataProvider>(
+    ui_components: &mut UIComponents,
+    app: &App<D>,
+) -> CmdResult {
+    confirm_pending_filter(ui_components, app);
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+/// Asks for confirmation if there is a filter hiding all journals waiting to be applied.
+pub fn confirm_pending_filter<D: DataProvider>(ui_components: &mut UIComponents, app: &App<D>) {
+    if app.has_pending_filter() {
+        let msg = MsgBoxType::Question(
+            "This filter hides all journals. Do you want to apply it anyway?".into(),
+        );
+        let msg_actions = MsgBoxActions::YesNo;
+        ui_components.show_msg_box(msg, msg_actions, Some(UICommand::ConfirmEmptyFilter));
+    }
+}
+
+pub fn continue_confirm_empty_filter<D: DataProvider>(
+    ui_components: &mut UIComponents,
+    app: &mut App<D>,
+    msg_box_result: MsgBoxResult,
+) -> CmdResult {
+    if msg_box_result == MsgBoxResult::Yes {
+        app.apply_pending_filter();
+        // The filter popup is kept open while confirming the filter it applies.
+        if matches!(ui_components.popup_stack.last(), Some(Popup::Filter(_))) {
+            ui_components.popup_stack.pop();
+        }
+        ui_components.set_current_entry(None, app);
+    } else {
+        app.discard_pending_filter();
+    }
+
+    Ok(HandleInputReturnType::Handled)
+}
+
 pub fn exec_show_fuzzy_find<D: DataProvider>(
     ui_components: &mut UIComponents,
     app: &mut App<D>,
@@ -451,7 +864,10 @@ fn show_fuzzy_find<D: DataProvider>(ui_components: &mut UIComponents, app: &mut
         .collect();
     ui_components
         .popup_stack
-        .push(Popup::FuzzFind(Box::new(FuzzFindPopup::new(entries))));
+        .push(Popup::FuzzFind(Box::new(FuzzFindPopup::new(
+            entries,
+            app.settings.fuzzy_find_suggestions,
+        ))));
 }
 
 pub async fn continue_fuzzy_find<'a, D: DataProvider>(
@@ -521,6 +937,17 @@ pub async fn continue_show_sort_options<'a, D: DataProvider>(
     Ok(HandleInputReturnType::Handled)
 }
 
+pub fn exec_show_stats<D: DataProvider>(
+    ui_components: &mut UIComponents,
+    app: &App<D>,
+) -> CmdResult {
+    ui_components
+        .popup_stack
+        .push(Popup::Stats(Box::new(StatsPopup::new(app))));
+
+    Ok(HandleInputReturnType::Handled)
+}
+
 pub fn go_to_top_entry<D: DataProvider>(ui_components: &mut UIComponents, app: &mut App<D>) {
     let top_id = app.get_active_entries().next().map(|entry| entry.id);
 
@@ -537,6 +964,21 @@ pub fn go_to_bottom_entry<D: DataProvider>(ui_components: &mut UIComponents, app
     }
 }
 
+pub fn go_back_viewed_entries<D: DataProvider>(ui_components: &mut UIComponents, app: &mut App<D>) {
+    if let Some(entry_id) = app.go_back_viewed_entries() {
+        ui_components.go_to_entry(entry_id, app);
+    }
+}
+
+pub fn go_forward_viewed_entries<D: DataProvider>(
+    ui_components: &mut UIComponents,
+    app: &mut App<D>,
+) {
+    if let Some(entry_id) = app.go_forward_viewed_entries() {
+        ui_components.go_to_entry(entry_id, app);
+    }
+}
+
 pub fn page_up_entries<D: DataProvider>(ui_components: &mut UIComponents, app: &mut App<D>) {
     let step = app.settings.get_scroll_per_page();
 
@@ -1,5 +1,6 @@
 use crate::app::{
-    ui::{help_popup::KeybindingsTabs, *},
+    history::HistoryStack,
+    ui::{help_popup::KeybindingsTabs, history_popup::HistoryJump, *},
     App, HandleInputReturnType, UIComponents,
 };
 
@@ -7,6 +8,11 @@ use backend::DataProvider;
 
 use super::{editor_cmd::exec_save_entry_content, CmdResult};
 
+const UNDO_PAST_SAVE_QUESTION: &str =
+    "Undo will revert the journal changes before the last save. Do you want to continue?";
+const REDO_PAST_SAVE_QUESTION: &str =
+    "Redo will reapply changes past the last save of the journals. Do you want to continue?";
+
 pub fn exec_quit(ui_components: &mut UIComponents) -> CmdResult {
     if ui_components.has_unsaved() {
         ui_components.show_unsaved_msg_box(Some(UICommand::Quit));
@@ -36,21 +42,47 @@ pub fn exec_show_help(ui_components: &mut UIComponents) -> CmdResult {
         ui_components.active_control,
         ui_components.entries_list.multi_select_mode,
     ) {
-        (ControlType::EntriesList, false) => KeybindingsTabs::Global,
-        (ControlType::EntriesList, true) => KeybindingsTabs::MultiSelect,
+        (ControlType::TagsSidebar | ControlType::EntriesList, false) => KeybindingsTabs::Global,
+        (ControlType::TagsSidebar | ControlType::EntriesList, true) => KeybindingsTabs::MultiSelect,
         (ControlType::EntryContentTxt, _) => KeybindingsTabs::Editor,
     };
 
+    let global_keymaps = ui_components
+        .global_keymaps
+        .iter()
+        .chain(ui_components.entries_list_keymaps.iter())
+        .chain(ui_components.editor_keymaps.iter())
+        .chain(ui_components.tags_sidebar_keymaps.iter());
+    let help_popup = HelpPopup::new(
+        start_tab,
+        global_keymaps,
+        ui_components.multi_select_keymaps.iter(),
+    );
+
+    ui_components
+        .popup_stack
+        .push(Popup::Help(Box::new(help_popup)));
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub fn exec_show_settings<D: DataProvider>(
+    ui_components: &mut UIComponents,
+    app: &App<D>,
+) -> CmdResult {
     ui_components
         .popup_stack
-        .push(Popup::Help(Box::new(HelpPopup::new(start_tab))));
+        .push(Popup::Settings(Box::new(SettingsPopup::new(&app.settings))));
 
     Ok(HandleInputReturnType::Handled)
 }
 
 pub fn exec_cycle_forward(ui_components: &mut UIComponents) -> CmdResult {
+    let sidebar_visible = ui_components.tags_sidebar.is_visible;
     let next_control = match ui_components.active_control {
+        ControlType::TagsSidebar => ControlType::EntriesList,
         ControlType::EntriesList => ControlType::EntryContentTxt,
+        ControlType::EntryContentTxt if sidebar_visible => ControlType::TagsSidebar,
         ControlType::EntryContentTxt => ControlType::EntriesList,
     };
 
@@ -59,7 +91,10 @@ pub fn exec_cycle_forward(ui_components: &mut UIComponents) -> CmdResult {
 }
 
 pub fn exec_cycle_backward(ui_components: &mut UIComponents) -> CmdResult {
+    let sidebar_visible = ui_components.tags_sidebar.is_visible;
     let prev_control = match ui_components.active_control {
+        ControlType::TagsSidebar => ControlType::EntryContentTxt,
+        ControlType::EntriesList if sidebar_visible => ControlType::TagsSidebar,
         ControlType::EntriesList => ControlType::EntryContentTxt,
         ControlType::EntryContentTxt => ControlType::EntriesList,
     };
@@ -131,9 +166,21 @@ pub async fn exec_undo<D: DataProvider>(
 async fn undo<D: DataProvider>(
     ui_components: &mut UIComponents<'_>,
     app: &mut App<D>,
+) -> anyhow::Result<()> {
+    if app.undo_needs_confirm() {
+        exec_undo_past_save(ui_components)?;
+        return Ok(());
+    }
+
+    apply_undo(ui_components, app).await
+}
+
+async fn apply_undo<D: DataProvider>(
+    ui_components: &mut UIComponents<'_>,
+    app: &mut App<D>,
 ) -> anyhow::Result<()> {
     if let Some(id) = app.undo().await? {
-        ui_components.set_current_entry(Some(id), app);
+        ui_components.go_to_entry(id, app);
     }
 
     Ok(())
@@ -172,9 +219,21 @@ pub async fn exec_redo<D: DataProvider>(
 async fn redo<D: DataProvider>(
     ui_components: &mut UIComponents<'_>,
     app: &mut App<D>,
+) -> anyhow::Result<()> {
+    if app.redo_needs_confirm() {
+        exec_redo_past_save(ui_components)?;
+        return Ok(());
+    }
+
+    apply_redo(ui_components, app).await
+}
+
+async fn apply_redo<D: DataProvider>(
+    ui_components: &mut UIComponents<'_>,
+    app: &mut App<D>,
 ) -> anyhow::Result<()> {
     if let Some(id) = app.redo().await? {
-        ui_components.set_current_entry(Some(id), app);
+        ui_components.go_to_entry(id, app);
     }
 
     Ok(())
@@ -196,3 +255,77 @@ pub async fn continue_redo<D: DataProvider>(
 
     Ok(HandleInputReturnType::Handled)
 }
+
+pub fn exec_undo_past_save(ui_components: &mut UIComponents) -> CmdResult {
+    let msg = MsgBoxType::Question(UNDO_PAST_SAVE_QUESTION.into());
+    ui_components.show_msg_box(msg, MsgBoxActions::YesNo, Some(UICommand::UndoPastSave));
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub async fn continue_undo_past_save<D: DataProvider>(
+    ui_components: &mut UIComponents<'_>,
+    app: &mut App<D>,
+    msg_box_result: MsgBoxResult,
+) -> CmdResult {
+    if msg_box_result == MsgBoxResult::Yes {
+        apply_undo(ui_components, app).await?;
+    }
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub fn exec_redo_past_save(ui_components: &mut UIComponents) -> CmdResult {
+    let msg = MsgBoxType::Question(REDO_PAST_SAVE_QUESTION.into());
+    ui_components.show_msg_box(msg, MsgBoxActions::YesNo, Some(UICommand::RedoPastSave));
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub async fn continue_redo_past_save<D: DataProvider>(
+    ui_components: &mut UIComponents<'_>,
+    app: &mut App<D>,
+    msg_box_result: MsgBoxResult,
+) -> CmdResult {
+    if msg_box_result == MsgBoxResult::Yes {
+        apply_redo(ui_components, app).await?;
+    }
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub fn show_history<D: DataProvider>(ui_components: &mut UIComponents, app: &mut App<D>) {
+    ui_components
+        .popup_stack
+        .push(Popup::History(Box::new(HistoryPopup::new(app))));
+}
+
+pub fn exec_jump_history_past_save(
+    ui_components: &mut UIComponents,
+    jump: HistoryJump,
+) -> CmdResult {
+    let question = match jump.target {
+        HistoryStack::Undo => UNDO_PAST_SAVE_QUESTION,
+        HistoryStack::Redo => REDO_PAST_SAVE_QUESTION,
+    };
+    ui_components.show_msg_box(
+        MsgBoxType::Question(question.into()),
+        MsgBoxActions::YesNo,
+        Some(UICommand::JumpHistoryPastSave(jump)),
+    );
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub async fn continue_jump_history_past_save<D: DataProvider>(
+    ui_components: &mut UIComponents<'_>,
+    app: &mut App<D>,
+    msg_box_result: MsgBoxResult,
+    jump: HistoryJump,
+) -> CmdResult {
+    if msg_box_result == MsgBoxResult::Yes {
+        ui_components.apply_history_jump(jump, app).await;
+    }
+
+    Ok(HandleInputReturnType::Handled)
+}
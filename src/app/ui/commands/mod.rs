@@ -3,16 +3,18 @@ use std::fmt::Debug;
 
 use multi_select_cmd::*;
 
-use super::{App, HandleInputReturnType, MsgBoxResult, UIComponents};
+use super::{history_popup::HistoryJump, App, HandleInputReturnType, MsgBoxResult, UIComponents};
 
 use editor_cmd::*;
 use entries_list_cmd::*;
 use global_cmd::*;
+use tags_sidebar_cmd::*;
 
 mod editor_cmd;
 mod entries_list_cmd;
 mod global_cmd;
 mod multi_select_cmd;
+mod tags_sidebar_cmd;
 
 type CmdResult = anyhow::Result<HandleInputReturnType>;
 
@@ -29,12 +31,16 @@ pub enum ClipboardOperation {
 pub enum UICommand {
     Quit,
     ShowHelp,
+    ShowSettings,
     CycleFocusedControlForward,
     CycleFocusedControlBack,
     SelectedNextEntry,
     SelectedPrevEntry,
     CreateEntry,
+    CreateQuickNote,
     EditCurrentEntry,
+    PromptTagsNewEntry,
+    InsertTagTemplate,
     DeleteCurrentEntry,
     StartEditEntryContent,
     BackEditorNormalMode,
@@ -42,6 +48,7 @@ pub enum UICommand {
     DiscardChangesEntryContent,
     ReloadAll,
     ExportEntryContent,
+    ExportEntryDocument,
     EditInExternalEditor,
     EnterMultiSelectMode,
     LeaveMultiSelectMode,
@@ -51,9 +58,24 @@ pub enum UICommand {
     MulSelInverSelection,
     MulSelDeleteEntries,
     MulSelExportEntries,
+    MulSelAddTag,
+    MulSelRemoveTag,
+    MulSelSetPriority,
+    CopyEntriesMarkdown,
     ShowFilter,
     ResetFilter,
     CycleTagFilter,
+    ConfirmEmptyFilter,
+    ApplyFilterPreset(u8),
+    ShowSavedFilters,
+    ShowSearch,
+    SearchNextMatch,
+    SearchPrevMatch,
+    ToggleTagsSidebar,
+    TagsSidebarToggleTag,
+    TagsSidebarSetColor,
+    TagsSidebarRenameTag,
+    TagsSidebarMergeTag,
     ShowFuzzyFind,
     ToggleEditorVisualMode,
     ToggleFullScreenMode,
@@ -61,12 +83,27 @@ pub enum UICommand {
     CutOsClipboard,
     PasteOsClipboard,
     ShowSortOptions,
+    ShowStats,
+    CycleListDensity,
+    RemoveEntryTags,
+    BumpEntryPriority,
+    LowerEntryPriority,
+    TogglePinEntry,
+    SetEntryColor,
+    DuplicateEntry,
+    ToggleFocusMode,
     GoToTopEntry,
     GoToBottomEntry,
     PageUpEntries,
     PageDownEntries,
+    GoBackViewedEntries,
+    GoForwardViewedEntries,
     Undo,
     Redo,
+    UndoPastSave,
+    RedoPastSave,
+    ShowHistory,
+    JumpHistoryPastSave(HistoryJump),
 }
 
 #[derive(Debug, Clone)]
@@ -89,6 +126,10 @@ impl UICommand {
         match self {
             UICommand::Quit => CommandInfo::new("Exit", "Exit the program"),
             UICommand::ShowHelp => CommandInfo::new("Show help", "Show keybindings overview"),
+            UICommand::ShowSettings => CommandInfo::new(
+                "Show settings",
+                "Open settings popup to search and change the options",
+            ),
             UICommand::CycleFocusedControlForward => {
                 CommandInfo::new("Cycle focus forward", "Move focus to the next control")
             }
@@ -107,10 +148,22 @@ impl UICommand {
                 "Create new journal",
                 "Opens dialog to add a new journal entry",
             ),
+            UICommand::CreateQuickNote => CommandInfo::new(
+                "Create quick note",
+                "Opens a single field prompt to create a journal with the note as its content",
+            ),
             UICommand::EditCurrentEntry => CommandInfo::new(
                 "Edit current journal",
                 "Open entry dialog to edit current journal entry if any",
             ),
+            UICommand::PromptTagsNewEntry => CommandInfo::new(
+                "Prompt tags for new journal",
+                "Ask to add tags to the newly created journal if it has no tags",
+            ),
+            UICommand::InsertTagTemplate => CommandInfo::new(
+                "Insert tag template",
+                "Ask to insert the content template of the tag assigned to the journal",
+            ),
             UICommand::DeleteCurrentEntry => {
                 CommandInfo::new("Delete journal", "Delete current journal entry if any")
             }
@@ -131,6 +184,10 @@ impl UICommand {
             UICommand::ExportEntryContent => {
                 CommandInfo::new("Export journal content", "Export current journal content")
             }
+            UICommand::ExportEntryDocument => CommandInfo::new(
+                "Export journal document",
+                "Export current journal with its title, date and tags as Markdown or HTML file",
+            ),
             UICommand::EditInExternalEditor => CommandInfo::new(
                 "Edit in external editor",
                 "Edit current journal content in external editor (The editor can be set in configurations file or via the environment variables VISUAL, EDITOR)",
@@ -167,6 +224,22 @@ impl UICommand {
                 "Export selection",
                 "Export selected journals to a transfer JSON file, which can be imported to other back-end files",
             ),
+            UICommand::MulSelAddTag => CommandInfo::new(
+                "Add tag to selection",
+                "Add a tag to all selected journals in multi selection mode",
+            ),
+            UICommand::MulSelRemoveTag => CommandInfo::new(
+                "Remove tag from selection",
+                "Pick a tag to remove from all selected journals in multi selection mode",
+            ),
+            UICommand::MulSelSetPriority => CommandInfo::new(
+                "Set priority of selection",
+                "Set or clear the priority of all selected journals in multi selection mode",
+            ),
+            UICommand::CopyEntriesMarkdown => CommandInfo::new(
+                "Copy journals as Markdown",
+                "Copy the selected journals or the current one as Markdown to the clipboard",
+            ),
             UICommand::ShowFilter => CommandInfo::new(
                 "Open filter",
                 "Open filter popup for journals",
@@ -179,6 +252,50 @@ impl UICommand {
                 "Cycle Tag Filter",
                 "Cycle through the tag filters",
             ),
+            UICommand::ConfirmEmptyFilter => CommandInfo::new(
+                "Confirm empty filter",
+                "Ask for confirmation before applying a filter which hides all journals",
+            ),
+            UICommand::ApplyFilterPreset(key) => CommandInfo::new(
+                &format!("Apply filter preset {key}"),
+                &format!("Apply the filter preset bound to the key {key} or reset it if applied"),
+            ),
+            UICommand::ShowSearch => CommandInfo::new(
+                "Search journals",
+                "Search for a text in the titles and contents of the shown journals",
+            ),
+            UICommand::SearchNextMatch => CommandInfo::new(
+                "Next search match",
+                "Go to the next journal matching the search",
+            ),
+            UICommand::SearchPrevMatch => CommandInfo::new(
+                "Previous search match",
+                "Go to the previous journal matching the search",
+            ),
+            UICommand::ShowSavedFilters => CommandInfo::new(
+                "Saved filters",
+                "Open the saved filters to apply or delete them or save the current filter by name",
+            ),
+            UICommand::ToggleTagsSidebar => CommandInfo::new(
+                "Toggle tags sidebar",
+                "Show or hide the sidebar listing all tags to filter the journals with",
+            ),
+            UICommand::TagsSidebarToggleTag => CommandInfo::new(
+                "Toggle tag in filter",
+                "Add or remove the selected tag in the sidebar to the applied filter",
+            ),
+            UICommand::TagsSidebarSetColor => CommandInfo::new(
+                "Set tag color",
+                "Open dialog to set the colors of the selected tag in the sidebar with live preview",
+            ),
+            UICommand::TagsSidebarRenameTag => CommandInfo::new(
+                "Rename tag",
+                "Rename the selected tag in the sidebar on all journals, merging it with an existing tag of the same name",
+            ),
+            UICommand::TagsSidebarMergeTag => CommandInfo::new(
+                "Merge tag",
+                "Pick a tag to merge the selected tag in the sidebar into on all journals",
+            ),
             UICommand::ShowFuzzyFind => CommandInfo::new(
                 "Fuzzy find",
                 "Open fuzzy find popup for journals",
@@ -207,6 +324,42 @@ impl UICommand {
                 "Open sort options",
                 "Open sort popup to set the sorting options of the journals",
             ),
+            UICommand::ShowStats => CommandInfo::new(
+                "Show statistics",
+                "Show statistics about the shown or all journals like their counts, words and most used tags",
+            ),
+            UICommand::CycleListDensity => CommandInfo::new(
+                "Cycle list density",
+                "Cycle through the compact, normal and detailed modes of the journals' list",
+            ),
+            UICommand::RemoveEntryTags => CommandInfo::new(
+                "Remove journal tags",
+                "Open a list of the current journal's tags to remove them one by one",
+            ),
+            UICommand::BumpEntryPriority => CommandInfo::new(
+                "Bump journal priority",
+                "Increment the priority of the currently selected journal",
+            ),
+            UICommand::LowerEntryPriority => CommandInfo::new(
+                "Lower journal priority",
+                "Decrement the priority of the currently selected journal",
+            ),
+            UICommand::TogglePinEntry => CommandInfo::new(
+                "Toggle journal pin",
+                "Pin or unpin the currently selected journal keeping pinned journals on top",
+            ),
+            UICommand::SetEntryColor => CommandInfo::new(
+                "Set journal color",
+                "Set the color of the currently selected journal title in the journals list",
+            ),
+            UICommand::DuplicateEntry => CommandInfo::new(
+                "Duplicate journal",
+                "Create a new journal dated today copying the title, tags, priority and content of the currently selected journal",
+            ),
+            UICommand::ToggleFocusMode => CommandInfo::new(
+                "Toggle focus mode",
+                "Hide all journals except the current one or exit focus mode restoring the previous view",
+            ),
             UICommand::GoToTopEntry => CommandInfo::new(
                 "Go to top journal",
                 "Go to the top entry in the journals' list",
@@ -223,26 +376,140 @@ impl UICommand {
                 "Page Down journals",
                 "Go one page down in the journals' list",
             ),
+            UICommand::GoBackViewedEntries => CommandInfo::new(
+                "Go back in viewed journals",
+                "Go back to the previously viewed journal",
+            ),
+            UICommand::GoForwardViewedEntries => CommandInfo::new(
+                "Go forward in viewed journals",
+                "Go forward to the next viewed journal after going back",
+            ),
             UICommand::Undo => CommandInfo::new("Undo", "Undo the latest change on journals"),
             UICommand::Redo => CommandInfo::new("Redo", "Redo the latest change on journals"),
+            UICommand::UndoPastSave => CommandInfo::new(
+                "Confirm undo past save",
+                "Ask for confirmation before undo reverts changes before the last save",
+            ),
+            UICommand::RedoPastSave => CommandInfo::new(
+                "Confirm redo past save",
+                "Ask for confirmation before redo reapplies changes past the last save",
+            ),
+            UICommand::ShowHistory => CommandInfo::new(
+                "Show history",
+                "List the undo and redo changes to undo or redo up to the picked change at once",
+            ),
+            UICommand::JumpHistoryPastSave(_) => CommandInfo::new(
+                "Jump in history past save",
+                "Apply the changes picked in the history past the last save",
+            ),
 
         }
     }
 
+    /// Checks if the command modifies the journals, which isn't allowed in read-only mode.
+    pub fn is_mutating(&self) -> bool {
+        match self {
+            UICommand::CreateEntry
+            | UICommand::CreateQuickNote
+            | UICommand::EditCurrentEntry
+            | UICommand::PromptTagsNewEntry
+            | UICommand::InsertTagTemplate
+            | UICommand::DeleteCurrentEntry
+            | UICommand::StartEditEntryContent
+            | UICommand::SaveEntryContent
+            | UICommand::EditInExternalEditor
+            | UICommand::MulSelDeleteEntries
+            | UICommand::MulSelAddTag
+            | UICommand::MulSelRemoveTag
+            | UICommand::MulSelSetPriority
+            | UICommand::TagsSidebarRenameTag
+            | UICommand::TagsSidebarMergeTag
+            | UICommand::CutOsClipboard
+            | UICommand::PasteOsClipboard
+            | UICommand::RemoveEntryTags
+            | UICommand::BumpEntryPriority
+            | UICommand::LowerEntryPriority
+            | UICommand::TogglePinEntry
+            | UICommand::SetEntryColor
+            | UICommand::DuplicateEntry
+            | UICommand::Undo
+            | UICommand::Redo
+            | UICommand::UndoPastSave
+            | UICommand::RedoPastSave
+            | UICommand::ShowHistory
+            | UICommand::JumpHistoryPastSave(_) => true,
+            UICommand::Quit
+            | UICommand::ShowHelp
+            | UICommand::ShowSettings
+            | UICommand::CycleFocusedControlForward
+            | UICommand::CycleFocusedControlBack
+            | UICommand::SelectedNextEntry
+            | UICommand::SelectedPrevEntry
+            | UICommand::BackEditorNormalMode
+            | UICommand::DiscardChangesEntryContent
+            | UICommand::ReloadAll
+            | UICommand::ExportEntryContent
+            | UICommand::ExportEntryDocument
+            | UICommand::EnterMultiSelectMode
+            | UICommand::LeaveMultiSelectMode
+            | UICommand::MulSelToggleSelected
+            | UICommand::MulSelSelectAll
+            | UICommand::MulSelSelectNone
+            | UICommand::MulSelInverSelection
+            | UICommand::MulSelExportEntries
+            | UICommand::CopyEntriesMarkdown
+            | UICommand::ShowFilter
+            | UICommand::ResetFilter
+            | UICommand::CycleTagFilter
+            | UICommand::ConfirmEmptyFilter
+            | UICommand::ApplyFilterPreset(_)
+            | UICommand::ShowSavedFilters
+            | UICommand::ShowSearch
+            | UICommand::SearchNextMatch
+            | UICommand::SearchPrevMatch
+            | UICommand::ToggleTagsSidebar
+            | UICommand::TagsSidebarToggleTag
+            | UICommand::TagsSidebarSetColor
+            | UICommand::ShowFuzzyFind
+            | UICommand::ToggleEditorVisualMode
+            | UICommand::ToggleFullScreenMode
+            | UICommand::CopyOsClipboard
+            | UICommand::ShowSortOptions
+            | UICommand::ShowStats
+            | UICommand::CycleListDensity
+            | UICommand::ToggleFocusMode
+            | UICommand::GoToTopEntry
+            | UICommand::GoToBottomEntry
+            | UICommand::PageUpEntries
+            | UICommand::PageDownEntries
+            | UICommand::GoBackViewedEntries
+            | UICommand::GoForwardViewedEntries => false,
+        }
+    }
+
     pub async fn execute<'a, D: DataProvider>(
         &self,
         ui_components: &mut UIComponents<'a>,
         app: &mut App<D>,
     ) -> CmdResult {
+        if app.settings.is_read_only() && self.is_mutating() {
+            ui_components.show_read_only_msg();
+            return Ok(HandleInputReturnType::Handled);
+        }
+
         match self {
             UICommand::Quit => exec_quit(ui_components),
             UICommand::ShowHelp => exec_show_help(ui_components),
+            UICommand::ShowSettings => exec_show_settings(ui_components, app),
             UICommand::CycleFocusedControlForward => exec_cycle_forward(ui_components),
             UICommand::CycleFocusedControlBack => exec_cycle_backward(ui_components),
             UICommand::SelectedNextEntry => exec_select_next_entry(ui_components, app),
             UICommand::SelectedPrevEntry => exec_select_prev_entry(ui_components, app),
             UICommand::CreateEntry => exec_create_entry(ui_components, app),
+            UICommand::CreateQuickNote => exec_create_quick_note(ui_components),
             UICommand::EditCurrentEntry => exec_edit_current_entry(ui_components, app),
+            UICommand::PromptTagsNewEntry => exec_prompt_tags_new_entry(ui_components),
+            UICommand::InsertTagTemplate => exec_insert_tag_template(ui_components),
             UICommand::DeleteCurrentEntry => exec_delete_current_entry(ui_components, app),
             UICommand::StartEditEntryContent => exec_start_edit_content(ui_components),
             UICommand::BackEditorNormalMode => exec_back_editor_to_normal_mode(ui_components),
@@ -250,6 +517,9 @@ impl UICommand {
             UICommand::DiscardChangesEntryContent => exec_discard_content(ui_components),
             UICommand::ReloadAll => exec_reload_all(ui_components, app).await,
             UICommand::ExportEntryContent => exec_export_entry_content(ui_components, app),
+            cmd @ UICommand::ExportEntryDocument => {
+                check_unsaved_then_exec_cmd(*cmd, export_entry_document, ui_components, app)
+            }
             UICommand::EditInExternalEditor => {
                 exec_edit_in_external_editor(ui_components, app).await
             }
@@ -261,9 +531,34 @@ impl UICommand {
             UICommand::MulSelInverSelection => exec_invert_selection(app),
             UICommand::MulSelDeleteEntries => exec_delete_selected_entries(ui_components, app),
             UICommand::MulSelExportEntries => exec_export_selected_entries(ui_components, app),
+            UICommand::MulSelAddTag => exec_add_tag_to_selected(ui_components, app),
+            UICommand::MulSelRemoveTag => exec_remove_tag_from_selected(ui_components, app),
+            UICommand::MulSelSetPriority => exec_set_priority_for_selected(ui_components, app),
+            UICommand::CopyEntriesMarkdown => exec_copy_entries_markdown(ui_components, app),
             UICommand::ShowFilter => exec_show_filter(ui_components, app),
             UICommand::ResetFilter => exec_reset_filter(app),
             UICommand::CycleTagFilter => exec_cycle_tag_filter(ui_components, app),
+            UICommand::ConfirmEmptyFilter => exec_confirm_empty_filter(ui_components, app),
+            UICommand::ApplyFilterPreset(key) => exec_apply_filter_preset(ui_components, app, *key),
+            cmd @ UICommand::ShowSavedFilters => {
+                check_unsaved_then_exec_cmd(*cmd, show_saved_filters, ui_components, app)
+            }
+            cmd @ UICommand::ShowSearch => {
+                check_unsaved_then_exec_cmd(*cmd, show_search, ui_components, app)
+            }
+            cmd @ UICommand::SearchNextMatch => {
+                check_unsaved_then_exec_cmd(*cmd, go_to_next_search_match, ui_components, app)
+            }
+            cmd @ UICommand::SearchPrevMatch => {
+                check_unsaved_then_exec_cmd(*cmd, go_to_prev_search_match, ui_components, app)
+            }
+            UICommand::ToggleTagsSidebar => exec_toggle_tags_sidebar(ui_components),
+            UICommand::TagsSidebarSetColor => exec_set_tag_color(ui_components, app),
+            UICommand::TagsSidebarRenameTag => exec_rename_tag(ui_components, app),
+            UICommand::TagsSidebarMergeTag => exec_merge_tag(ui_components, app),
+            cmd @ UICommand::TagsSidebarToggleTag => {
+                check_unsaved_then_exec_cmd(*cmd, toggle_selected_tag, ui_components, app)
+            }
             UICommand::ShowFuzzyFind => exec_show_fuzzy_find(ui_components, app),
             UICommand::ToggleEditorVisualMode => exec_toggle_editor_visual_mode(ui_components),
             UICommand::ToggleFullScreenMode => exec_toggle_full_screen_mode(app),
@@ -271,6 +566,15 @@ impl UICommand {
             UICommand::CutOsClipboard => exec_cut_os_clipboard(ui_components),
             UICommand::PasteOsClipboard => exec_paste_os_clipboard(ui_components),
             UICommand::ShowSortOptions => exec_show_sort_options(ui_components, app),
+            UICommand::ShowStats => exec_show_stats(ui_components, app),
+            UICommand::CycleListDensity => exec_cycle_list_density(app),
+            UICommand::RemoveEntryTags => exec_remove_entry_tags(ui_components, app),
+            UICommand::BumpEntryPriority => exec_bump_entry_priority(ui_components, app).await,
+            UICommand::LowerEntryPriority => exec_lower_entry_priority(ui_components, app).await,
+            UICommand::TogglePinEntry => exec_toggle_pin_entry(ui_components, app).await,
+            UICommand::SetEntryColor => exec_set_entry_color(ui_components, app),
+            UICommand::DuplicateEntry => exec_duplicate_current_entry(ui_components, app).await,
+            UICommand::ToggleFocusMode => exec_toggle_focus_mode(ui_components, app),
             cmd @ UICommand::GoToTopEntry => {
                 check_unsaved_then_exec_cmd(*cmd, go_to_top_entry, ui_components, app)
             }
@@ -283,8 +587,22 @@ impl UICommand {
             cmd @ UICommand::PageDownEntries => {
                 check_unsaved_then_exec_cmd(*cmd, page_down_entries, ui_components, app)
             }
+            cmd @ UICommand::GoBackViewedEntries => {
+                check_unsaved_then_exec_cmd(*cmd, go_back_viewed_entries, ui_components, app)
+            }
+            cmd @ UICommand::GoForwardViewedEntries => {
+                check_unsaved_then_exec_cmd(*cmd, go_forward_viewed_entries, ui_components, app)
+            }
             UICommand::Undo => exec_undo(ui_components, app).await,
             UICommand::Redo => exec_redo(ui_components, app).await,
+            UICommand::UndoPastSave => exec_undo_past_save(ui_components),
+            UICommand::RedoPastSave => exec_redo_past_save(ui_components),
+            UICommand::JumpHistoryPastSave(jump) => {
+                exec_jump_history_past_save(ui_components, *jump)
+            }
+            cmd @ UICommand::ShowHistory => {
+                check_unsaved_then_exec_cmd(*cmd, show_history, ui_components, app)
+            }
         }
     }
 
@@ -298,6 +616,7 @@ impl UICommand {
         match self {
             UICommand::Quit => continue_quit(ui_components, app, msg_box_result).await,
             UICommand::ShowHelp => not_implemented(),
+            UICommand::ShowSettings => not_implemented(),
             UICommand::CycleFocusedControlForward => not_implemented(),
             UICommand::CycleFocusedControlBack => not_implemented(),
             UICommand::SelectedNextEntry => {
@@ -309,11 +628,20 @@ impl UICommand {
             UICommand::CreateEntry => {
                 continue_create_entry(ui_components, app, msg_box_result).await
             }
+            UICommand::CreateQuickNote => {
+                continue_create_quick_note(ui_components, app, msg_box_result).await
+            }
             UICommand::EditCurrentEntry => {
                 continue_edit_current_entry(ui_components, app, msg_box_result).await
             }
+            UICommand::PromptTagsNewEntry => {
+                continue_prompt_tags_new_entry(ui_components, app, msg_box_result)
+            }
+            UICommand::InsertTagTemplate => {
+                continue_insert_tag_template(ui_components, app, msg_box_result).await
+            }
             UICommand::DeleteCurrentEntry => {
-                continue_delete_current_entry(app, msg_box_result).await
+                continue_delete_current_entry(ui_components, app, msg_box_result).await
             }
             UICommand::StartEditEntryContent => not_implemented(),
             UICommand::BackEditorNormalMode => not_implemented(),
@@ -325,6 +653,15 @@ impl UICommand {
             UICommand::ExportEntryContent => {
                 continue_export_entry_content(ui_components, app, msg_box_result).await
             }
+            UICommand::ExportEntryDocument => {
+                continue_cmd_after_check_unsaved(
+                    export_entry_document,
+                    ui_components,
+                    app,
+                    msg_box_result,
+                )
+                .await
+            }
             UICommand::EditInExternalEditor => {
                 continue_edit_in_external_editor(ui_components, app, msg_box_result).await
             }
@@ -337,14 +674,68 @@ impl UICommand {
             UICommand::MulSelSelectNone => not_implemented(),
             UICommand::MulSelInverSelection => not_implemented(),
             UICommand::MulSelDeleteEntries => {
-                continue_delete_selected_entries(app, msg_box_result).await
+                continue_delete_selected_entries(ui_components, app, msg_box_result).await
             }
             UICommand::MulSelExportEntries => not_implemented(),
+            UICommand::MulSelAddTag => not_implemented(),
+            UICommand::MulSelRemoveTag => not_implemented(),
+            UICommand::MulSelSetPriority => not_implemented(),
+            UICommand::CopyEntriesMarkdown => not_implemented(),
             UICommand::ShowFilter => continue_show_filter(ui_components, app, msg_box_result).await,
             UICommand::ResetFilter => not_implemented(),
             UICommand::CycleTagFilter => {
                 continue_cycle_tag_filter(ui_components, app, msg_box_result).await
             }
+            UICommand::ConfirmEmptyFilter => {
+                continue_confirm_empty_filter(ui_components, app, msg_box_result)
+            }
+            UICommand::ApplyFilterPreset(key) => {
+                continue_apply_filter_preset(ui_components, app, msg_box_result, *key).await
+            }
+            UICommand::ShowSavedFilters => {
+                continue_cmd_after_check_unsaved(
+                    show_saved_filters,
+                    ui_components,
+                    app,
+                    msg_box_result,
+                )
+                .await
+            }
+            UICommand::ShowSearch => {
+                continue_cmd_after_check_unsaved(show_search, ui_components, app, msg_box_result)
+                    .await
+            }
+            UICommand::SearchNextMatch => {
+                continue_cmd_after_check_unsaved(
+                    go_to_next_search_match,
+                    ui_components,
+                    app,
+                    msg_box_result,
+                )
+                .await
+            }
+            UICommand::SearchPrevMatch => {
+                continue_cmd_after_check_unsaved(
+                    go_to_prev_search_match,
+                    ui_components,
+                    app,
+                    msg_box_result,
+                )
+                .await
+            }
+            UICommand::ToggleTagsSidebar => not_implemented(),
+            UICommand::TagsSidebarSetColor => not_implemented(),
+            UICommand::TagsSidebarRenameTag => not_implemented(),
+            UICommand::TagsSidebarMergeTag => not_implemented(),
+            UICommand::TagsSidebarToggleTag => {
+                continue_cmd_after_check_unsaved(
+                    toggle_selected_tag,
+                    ui_components,
+                    app,
+                    msg_box_result,
+                )
+                .await
+            }
             UICommand::ShowFuzzyFind => {
                 continue_fuzzy_find(ui_components, app, msg_box_result).await
             }
@@ -356,6 +747,17 @@ impl UICommand {
             UICommand::ShowSortOptions => {
                 continue_show_sort_options(ui_components, app, msg_box_result).await
             }
+            UICommand::ShowStats => not_implemented(),
+            UICommand::CycleListDensity => not_implemented(),
+            UICommand::RemoveEntryTags => not_implemented(),
+            UICommand::BumpEntryPriority => not_implemented(),
+            UICommand::LowerEntryPriority => not_implemented(),
+            UICommand::TogglePinEntry => not_implemented(),
+            UICommand::SetEntryColor => not_implemented(),
+            UICommand::DuplicateEntry => {
+                continue_duplicate_current_entry(ui_components, app, msg_box_result).await
+            }
+            UICommand::ToggleFocusMode => not_implemented(),
             UICommand::GoToTopEntry => {
                 continue_cmd_after_check_unsaved(
                     go_to_top_entry,
@@ -392,8 +794,39 @@ impl UICommand {
                 )
                 .await
             }
+            UICommand::GoBackViewedEntries => {
+                continue_cmd_after_check_unsaved(
+                    go_back_viewed_entries,
+                    ui_components,
+                    app,
+                    msg_box_result,
+                )
+                .await
+            }
+            UICommand::GoForwardViewedEntries => {
+                continue_cmd_after_check_unsaved(
+                    go_forward_viewed_entries,
+                    ui_components,
+                    app,
+                    msg_box_result,
+                )
+                .await
+            }
             UICommand::Undo => continue_undo(ui_components, app, msg_box_result).await,
             UICommand::Redo => continue_redo(ui_components, app, msg_box_result).await,
+            UICommand::UndoPastSave => {
+                continue_undo_past_save(ui_components, app, msg_box_result).await
+            }
+            UICommand::JumpHistoryPastSave(jump) => {
+                continue_jump_history_past_save(ui_components, app, msg_box_result, *jump).await
+            }
+            UICommand::RedoPastSave => {
+                continue_redo_past_save(ui_components, app, msg_box_result).await
+            }
+            UICommand::ShowHistory => {
+                continue_cmd_after_check_unsaved(show_history, ui_components, app, msg_box_result)
+                    .await
+            }
         }
     }
 }
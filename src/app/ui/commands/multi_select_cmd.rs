@@ -4,6 +4,8 @@ use crate::app::{
     ui::{
         export_popup::ExportPopup,
         msg_box::{MsgBoxActions, MsgBoxType},
+        remove_tags_popup::RemoveTagsPopup,
+        selection_input_popup::{SelectionInputKind, SelectionInputPopup},
         MsgBoxResult, Popup,
     },
     App, HandleInputReturnType, UIComponents,
@@ -75,9 +77,9 @@ pub fn exec_toggle_selected<D: DataProvider>(app: &mut App<D>) -> CmdResult {
 }
 
 fn toggle_entry_selection<D: DataProvider>(entry_id: u32, app: &mut App<D>) {
-    if !app.selected_entries.insert(entry_id) {
+    if !app.select_entry(entry_id) {
         // entry was selected, then remove it
-        app.selected_entries.remove(&entry_id);
+        app.deselect_entry(entry_id);
     }
 }
 
@@ -85,14 +87,14 @@ pub fn exec_select_all<D: DataProvider>(app: &mut App<D>) -> CmdResult {
     let active_ids: Vec<u32> = app.get_active_entries().map(|entry| entry.id).collect();
 
     for id in active_ids {
-        app.selected_entries.insert(id);
+        app.select_entry(id);
     }
 
     Ok(HandleInputReturnType::Handled)
 }
 
 pub fn exec_select_none<D: DataProvider>(app: &mut App<D>) -> CmdResult {
-    app.selected_entries.clear();
+    app.clear_selection();
 
     Ok(HandleInputReturnType::Handled)
 }
@@ -128,17 +130,23 @@ pub fn exec_delete_selected_entries<D: DataProvider>(
     Ok(HandleInputReturnType::Handled)
 }
 
-pub async fn continue_delete_selected_entries<'a, D: DataProvider>(
+pub async fn continue_delete_selected_entries<D: DataProvider>(
+    ui_components: &mut UIComponents<'_>,
     app: &mut App<D>,
     msg_box_result: MsgBoxResult,
 ) -> CmdResult {
     match msg_box_result {
         MsgBoxResult::Yes => {
-            let delete_ids: Vec<u32> = app.selected_entries.iter().cloned().collect();
-            for entry_id in delete_ids {
-                app.delete_entry(entry_id).await?;
+            let result = app.delete_selected_entries().await;
+            ui_components.entries_list.multi_select_mode = false;
+
+            let current_exists = app.get_current_entry().is_some();
+            if !current_exists {
+                let first_id = app.get_active_entries().next().map(|entry| entry.id);
+                ui_components.set_current_entry(first_id, app);
             }
-            app.selected_entries.clear();
+
+            result?;
         }
         MsgBoxResult::No => {}
         _ => unreachable!(
@@ -177,3 +185,71 @@ pub fn exec_export_selected_entries<D: DataProvider>(
 
     Ok(HandleInputReturnType::Handled)
 }
+
+pub fn exec_add_tag_to_selected<D: DataProvider>(
+    ui_components: &mut UIComponents,
+    app: &mut App<D>,
+) -> CmdResult {
+    debug_assert!(ui_components.entries_list.multi_select_mode);
+    debug_assert!(!ui_components.has_unsaved());
+
+    show_selection_input(SelectionInputKind::Tag, ui_components, app);
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub fn exec_remove_tag_from_selected<D: DataProvider>(
+    ui_components: &mut UIComponents,
+    app: &mut App<D>,
+) -> CmdResult {
+    debug_assert!(ui_components.entries_list.multi_select_mode);
+    debug_assert!(!ui_components.has_unsaved());
+
+    if app.selected_entries.is_empty() {
+        let msg = MsgBoxType::Info("No items have been selected".into());
+        let msg_action = MsgBoxActions::Ok;
+        ui_components.show_msg_box(msg, msg_action, None);
+
+        return Ok(HandleInputReturnType::Handled);
+    }
+
+    let popup = RemoveTagsPopup::new_multi_select(app);
+    if !popup.is_empty() {
+        ui_components
+            .popup_stack
+            .push(Popup::RemoveTags(Box::new(popup)));
+    }
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub fn exec_set_priority_for_selected<D: DataProvider>(
+    ui_components: &mut UIComponents,
+    app: &mut App<D>,
+) -> CmdResult {
+    debug_assert!(ui_components.entries_list.multi_select_mode);
+    debug_assert!(!ui_components.has_unsaved());
+
+    show_selection_input(SelectionInputKind::Priority, ui_components, app);
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+/// Prompts for the value of the given kind to apply on the selected journals, informing the
+/// user instead if there is no selection.
+fn show_selection_input<D: DataProvider>(
+    kind: SelectionInputKind,
+    ui_components: &mut UIComponents,
+    app: &App<D>,
+) {
+    if app.selected_entries.is_empty() {
+        let msg = MsgBoxType::Info("No items have been selected".into());
+        ui_components.show_msg_box(msg, MsgBoxActions::Ok, None);
+        return;
+    }
+
+    let popup = SelectionInputPopup::new(kind, app.selected_entries.len());
+    ui_components
+        .popup_stack
+        .push(Popup::SelectionInput(Box::new(popup)));
+}
@@ -0,0 +1,105 @@
+use crate::app::{
+    ui::{
+        merge_tags_popup::MergeTagsPopup,
+        selection_input_popup::{SelectionInputKind, SelectionInputPopup},
+        tag_color_popup::TagColorPopup,
+        ControlType, Popup, UIComponents,
+    },
+    App, HandleInputReturnType,
+};
+
+use backend::DataProvider;
+
+use super::{entries_list_cmd::confirm_pending_filter, CmdResult};
+
+pub fn exec_toggle_tags_sidebar(ui_components: &mut UIComponents) -> CmdResult {
+    let sidebar = &mut ui_components.tags_sidebar;
+    sidebar.is_visible = !sidebar.is_visible;
+
+    if sidebar.is_visible {
+        ui_components.change_active_control(ControlType::TagsSidebar);
+    } else if ui_components.active_control == ControlType::TagsSidebar {
+        ui_components.change_active_control(ControlType::EntriesList);
+    }
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub fn toggle_selected_tag<D: DataProvider>(ui_components: &mut UIComponents, app: &mut App<D>) {
+    let Some(tag) = ui_components.tags_sidebar.selected_tag(app) else {
+        return;
+    };
+
+    app.toggle_tag_in_filter(&tag);
+
+    // Keep the current entry selected in the list if it's still visible after filtering.
+    ui_components.set_current_entry(app.current_entry_id, app);
+
+    confirm_pending_filter(ui_components, app);
+}
+
+pub fn exec_set_tag_color<D: DataProvider>(
+    ui_components: &mut UIComponents,
+    app: &mut App<D>,
+) -> CmdResult {
+    let Some(tag) = ui_components.tags_sidebar.selected_tag(app) else {
+        return Ok(HandleInputReturnType::Handled);
+    };
+
+    if !app.settings.colored_tags {
+        ui_components.show_err_msg("Colored tags are disabled in the settings".into());
+        return Ok(HandleInputReturnType::Handled);
+    }
+
+    let current_colors = app.get_color_for_tag(&tag);
+    ui_components
+        .popup_stack
+        .push(Popup::TagColor(Box::new(TagColorPopup::new(
+            tag,
+            current_colors,
+        ))));
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub fn exec_rename_tag<D: DataProvider>(
+    ui_components: &mut UIComponents,
+    app: &mut App<D>,
+) -> CmdResult {
+    let Some(tag) = ui_components.tags_sidebar.selected_tag(app) else {
+        return Ok(HandleInputReturnType::Handled);
+    };
+
+    let entries_count = app
+        .entries
+        .iter()
+        .filter(|entry| entry.tags.contains(&tag))
+        .count();
+
+    let popup = SelectionInputPopup::new(SelectionInputKind::TagRename(tag), entries_count);
+    ui_components
+        .popup_stack
+        .push(Popup::SelectionInput(Box::new(popup)));
+
+    Ok(HandleInputReturnType::Handled)
+}
+
+pub fn exec_merge_tag<D: DataProvider>(
+    ui_components: &mut UIComponents,
+    app: &mut App<D>,
+) -> CmdResult {
+    let Some(tag) = ui_components.tags_sidebar.selected_tag(app) else {
+        return Ok(HandleInputReturnType::Handled);
+    };
+
+    let popup = MergeTagsPopup::new(tag, app);
+    if popup.is_empty() {
+        ui_components.show_err_msg("There are no other tags to merge into".into());
+    } else {
+        ui_components
+            .popup_stack
+            .push(Popup::MergeTags(Box::new(popup)));
+    }
+
+    Ok(HandleInputReturnType::Handled)
+}
@@ -0,0 +1,163 @@
+use std::time::{Duration, Instant};
+
+/// Duration to keep showing that the content has been saved.
+const SAVED_DISPLAY_DURATION: Duration = Duration::from_secs(3);
+
+/// State of the autosave indicator rendered with the editor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutosaveStatus {
+    /// There are no unsaved changes.
+    Idle,
+    /// Unsaved changes will be saved after the remaining seconds.
+    Pending { remaining_secs: u64 },
+    /// The content has been saved recently.
+    Saved,
+}
+
+/// Tracks the time since the content of the editor got unsaved changes to save them
+/// automatically after the configured interval.
+#[derive(Debug, Clone)]
+pub struct AutosaveTimer {
+    interval: Duration,
+    dirty_since: Option<Instant>,
+    saved_at: Option<Instant>,
+}
+
+impl AutosaveTimer {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            dirty_since: None,
+            saved_at: None,
+        }
+    }
+
+    /// Starts the countdown once the content gets unsaved changes and stops it when the changes
+    /// are saved or discarded.
+    pub fn update(&mut self, has_unsaved: bool, now: Instant) {
+        match (has_unsaved, self.dirty_since) {
+            (true, None) => {
+                self.dirty_since = Some(now);
+                self.saved_at = None;
+            }
+            (false, Some(_)) => self.dirty_since = None,
+            _ => {}
+        }
+    }
+
+    /// Restarts the countdown of the unsaved changes, used when saving them fails.
+    pub fn restart(&mut self, now: Instant) {
+        if self.dirty_since.is_some() {
+            self.dirty_since = Some(now);
+        }
+    }
+
+    pub fn mark_saved(&mut self, now: Instant) {
+        self.dirty_since = None;
+        self.saved_at = Some(now);
+    }
+
+    /// Checks if the unsaved changes have waited for the whole interval.
+    pub fn is_due(&self, now: Instant) -> bool {
+        self.dirty_since
+            .is_some_and(|since| now.saturating_duration_since(since) >= self.interval)
+    }
+
+    pub fn status(&self, now: Instant) -> AutosaveStatus {
+        if let Some(since) = self.dirty_since {
+            let remaining = self
+                .interval
+                .saturating_sub(now.saturating_duration_since(since));
+            // Round up so the countdown reaches zero when the save is due.
+            let remaining_secs = remaining.as_millis().div_ceil(1000) as u64;
+            return AutosaveStatus::Pending { remaining_secs };
+        }
+
+        match self.saved_at {
+            Some(saved_at) if now.saturating_duration_since(saved_at) < SAVED_DISPLAY_DURATION => {
+                AutosaveStatus::Saved
+            }
+            _ => AutosaveStatus::Idle,
+        }
+    }
+}
+
+impl AutosaveStatus {
+    /// Text of the indicator for the status. Idle status isn't rendered.
+    pub fn indicator_text(&self) -> Option<String> {
+        match self {
+            AutosaveStatus::Idle => None,
+            AutosaveStatus::Pending { remaining_secs } => {
+                Some(format!("autosave in {remaining_secs}s"))
+            }
+            AutosaveStatus::Saved => Some(String::from("saved ✓")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn autosave_status_transitions() {
+        let start = Instant::now();
+        let secs = |secs: u64| start + Duration::from_secs(secs);
+        let mut timer = AutosaveTimer::new(Duration::from_secs(10));
+
+        assert_eq!(timer.status(start), AutosaveStatus::Idle);
+        assert!(!timer.is_due(start));
+
+        // Content gets unsaved changes
+        timer.update(true, start);
+        assert_eq!(
+            timer.status(start),
+            AutosaveStatus::Pending { remaining_secs: 10 }
+        );
+
+        // Further changes don't restart the countdown
+        timer.update(true, secs(4));
+        assert_eq!(
+            timer.status(secs(4) + Duration::from_millis(500)),
+            AutosaveStatus::Pending { remaining_secs: 6 }
+        );
+        assert!(!timer.is_due(secs(9)));
+        assert!(timer.is_due(secs(10)));
+        assert_eq!(
+            timer.status(secs(12)),
+            AutosaveStatus::Pending { remaining_secs: 0 }
+        );
+
+        timer.mark_saved(secs(12));
+        assert!(!timer.is_due(secs(12)));
+        assert_eq!(timer.status(secs(13)), AutosaveStatus::Saved);
+        assert_eq!(timer.status(secs(15)), AutosaveStatus::Idle);
+
+        // Discarding the changes stops the countdown without showing saved.
+        timer.update(true, secs(20));
+        timer.update(false, secs(22));
+        assert_eq!(timer.status(secs(22)), AutosaveStatus::Idle);
+        assert!(!timer.is_due(secs(40)));
+    }
+
+    #[test]
+    fn autosave_restart_on_failure() {
+        let start = Instant::now();
+        let mut timer = AutosaveTimer::new(Duration::from_secs(5));
+
+        // Restarting without unsaved changes has no effect
+        timer.restart(start);
+        assert_eq!(timer.status(start), AutosaveStatus::Idle);
+
+        timer.update(true, start);
+        let failed_at = start + Duration::from_secs(5);
+        assert!(timer.is_due(failed_at));
+
+        timer.restart(failed_at);
+        assert!(!timer.is_due(failed_at));
+        assert_eq!(
+            timer.status(failed_at),
+            AutosaveStatus::Pending { remaining_secs: 5 }
+        );
+    }
+}
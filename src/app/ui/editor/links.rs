@@ -0,0 +1,184 @@
+use std::ops::Range;
+
+use ratatui::{buffer::Buffer, layout::Rect, style::Style};
+
+const URL_SCHEMES: [&str; 2] = ["https://", "http://"];
+
+/// Characters which end an URL when they appear in the text.
+const URL_TERMINATORS: [char; 5] = ['<', '>', '"', '`', '|'];
+
+/// Punctuation which isn't considered part of an URL when it comes at its end.
+const TRAILING_PUNCTUATION: [char; 7] = ['.', ',', ';', ':', '!', '?', '\''];
+
+/// Finds the bare URLs in the given line returning their spans as ranges of char indices.
+/// Trailing punctuation and unbalanced closing brackets are excluded from the URLs.
+pub fn find_url_spans(line: &str) -> Vec<Range<usize>> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut spans = Vec::new();
+
+    let mut idx = 0;
+    while idx < chars.len() {
+        let at_word_start = idx == 0 || !chars[idx - 1].is_alphanumeric();
+        let scheme_len = URL_SCHEMES
+            .iter()
+            .find(|scheme| at_word_start && starts_with(&chars[idx..], scheme))
+            .map(|scheme| scheme.len());
+
+        let Some(scheme_len) = scheme_len else {
+            idx += 1;
+            continue;
+        };
+
+        let mut end = idx + scheme_len;
+        while end < chars.len()
+            && !chars[end].is_whitespace()
+            && !URL_TERMINATORS.contains(&chars[end])
+        {
+            end += 1;
+        }
+
+        end = trim_url_end(&chars[idx..end]) + idx;
+
+        if end > idx + scheme_len {
+            spans.push(idx..end);
+        }
+
+        idx = end.max(idx + scheme_len);
+    }
+
+    spans
+}
+
+fn starts_with(chars: &[char], pattern: &str) -> bool {
+    chars.len() >= pattern.len() && pattern.chars().zip(chars).all(|(p, c)| p == *c)
+}
+
+/// Returns the length of the given URL after removing trailing punctuation and closing brackets
+/// which don't have an opening counterpart inside the URL.
+fn trim_url_end(url: &[char]) -> usize {
+    let mut len = url.len();
+
+    while let Some(last) = url[..len].last() {
+        let unbalanced = match last {
+            ')' => is_unbalanced(&url[..len], '(', ')'),
+            ']' => is_unbalanced(&url[..len], '[', ']'),
+            '}' => is_unbalanced(&url[..len], '{', '}'),
+            c => TRAILING_PUNCTUATION.contains(c),
+        };
+
+        if !unbalanced {
+            break;
+        }
+
+        len -= 1;
+    }
+
+    len
+}
+
+fn is_unbalanced(url: &[char], open: char, close: char) -> bool {
+    let count = |ch: char| url.iter().filter(|c| **c == ch).count();
+    count(close) > count(open)
+}
+
+/// Applies the given style on the URLs found in the rendered lines within the given area of
+/// the buffer. This changes the displayed text only, keeping the content itself untouched.
+pub fn highlight_urls(buf: &mut Buffer, area: Rect, style: Style) {
+    let area = area.intersection(buf.area);
+
+    for y in area.top()..area.bottom() {
+        let mut line = String::new();
+        // Maps each char in the line to the x position of its cell
+        let mut chars_x = Vec::new();
+        for x in area.left()..area.right() {
+            let symbol = buf[(x, y)].symbol();
+            line.push_str(symbol);
+            chars_x.extend(symbol.chars().map(|_| x));
+        }
+
+        for span in find_url_spans(&line) {
+            for x in chars_x[span.start]..=chars_x[span.end - 1] {
+                buf[(x, y)].set_style(style);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ratatui::style::Modifier;
+
+    use super::*;
+
+    fn urls(line: &str) -> Vec<String> {
+        find_url_spans(line)
+            .into_iter()
+            .map(|span| line.chars().skip(span.start).take(span.len()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn detect_urls() {
+        assert!(urls("No links here").is_empty());
+        assert!(urls("Scheme only https:// isn't a link").is_empty());
+        assert!(urls("Not at word start xhttps://example.com").is_empty());
+
+        assert_eq!(urls("https://example.com"), vec!["https://example.com"]);
+        assert_eq!(
+            urls("Visit http://example.com/path?q=1&b=2#top for more"),
+            vec!["http://example.com/path?q=1&b=2#top"]
+        );
+        assert_eq!(
+            urls("Two links https://a.org and https://b.org/x"),
+            vec!["https://a.org", "https://b.org/x"]
+        );
+        assert_eq!(
+            urls("Unicode before ü https://example.com/ä"),
+            vec!["https://example.com/ä"]
+        );
+    }
+
+    #[test]
+    fn detect_urls_trailing_punctuation() {
+        assert_eq!(
+            urls("See https://example.com."),
+            vec!["https://example.com"]
+        );
+        assert_eq!(
+            urls("Links: https://a.org, https://b.org; https://c.org!?"),
+            vec!["https://a.org", "https://b.org", "https://c.org"]
+        );
+        assert_eq!(
+            urls("(see https://example.com/page)."),
+            vec!["https://example.com/page"]
+        );
+        assert_eq!(
+            urls("https://en.wikipedia.org/wiki/Rust_(programming_language)."),
+            vec!["https://en.wikipedia.org/wiki/Rust_(programming_language)"]
+        );
+        assert_eq!(
+            urls("<https://example.com/a.b>"),
+            vec!["https://example.com/a.b"]
+        );
+    }
+
+    #[test]
+    fn highlight_rendered_urls() {
+        let area = Rect::new(0, 0, 30, 2);
+        let mut buf = Buffer::with_lines(["Go to https://a.org.", "No links"]);
+        let style = Style::default().add_modifier(Modifier::UNDERLINED);
+
+        highlight_urls(&mut buf, area, style);
+
+        let underlined: Vec<(u16, u16)> = buf
+            .content()
+            .iter()
+            .enumerate()
+            .filter(|(_, cell)| cell.modifier.contains(Modifier::UNDERLINED))
+            .map(|(idx, _)| buf.pos_of(idx))
+            .collect();
+
+        let expected: Vec<(u16, u16)> = (6..19).map(|x| (x, 0)).collect();
+        assert_eq!(underlined, expected);
+    }
+}
@@ -0,0 +1,147 @@
+/// Snapshot for the state of the editor content used to restore it on undo & redo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EditorSnapshot {
+    pub lines: Vec<String>,
+    pub cursor: (usize, usize),
+}
+
+impl EditorSnapshot {
+    pub fn new(lines: Vec<String>, cursor: (usize, usize)) -> Self {
+        Self { lines, cursor }
+    }
+}
+
+/// Local undo & redo stacks for the in-progress edits in the content editor.
+/// Consecutive edits are grouped together until a word is finished or the editing is interrupted,
+/// so undoing reverts the last edit group instead of single characters or the whole content.
+/// This history is independent from the app history which tracks the saved content changes only.
+#[derive(Debug, Default)]
+pub struct LocalHistory {
+    undo_stack: Vec<EditorSnapshot>,
+    redo_stack: Vec<EditorSnapshot>,
+    group_open: bool,
+}
+
+impl LocalHistory {
+    /// Registers an input handled by the editor providing the snapshot from before applying it.
+    /// `changed` indicates if the input changed the content, while `closes_group` indicates
+    /// if the input finishes the current edit group, like typing white-spaces.
+    pub fn register_input(&mut self, before: EditorSnapshot, changed: bool, closes_group: bool) {
+        if !changed {
+            self.close_group();
+            return;
+        }
+
+        if !self.group_open {
+            self.undo_stack.push(before);
+            self.redo_stack.clear();
+            self.group_open = true;
+        }
+
+        if closes_group {
+            self.close_group();
+        }
+    }
+
+    #[inline]
+    pub fn close_group(&mut self) {
+        self.group_open = false;
+    }
+
+    /// Returns the snapshot to restore for undo, saving the current one for redo.
+    pub fn undo(&mut self, current: EditorSnapshot) -> Option<EditorSnapshot> {
+        self.close_group();
+        let snapshot = self.undo_stack.pop()?;
+        self.redo_stack.push(current);
+
+        Some(snapshot)
+    }
+
+    /// Returns the snapshot to restore for redo, saving the current one for undo.
+    pub fn redo(&mut self, current: EditorSnapshot) -> Option<EditorSnapshot> {
+        self.close_group();
+        let snapshot = self.redo_stack.pop()?;
+        self.undo_stack.push(current);
+
+        Some(snapshot)
+    }
+
+    pub fn clear(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.close_group();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn snapshot(text: &str) -> EditorSnapshot {
+        EditorSnapshot::new(vec![text.to_owned()], (0, text.len()))
+    }
+
+    /// Simulates typing the given text char by char, registering each input in the history.
+    fn type_text(history: &mut LocalHistory, current: &mut String, text: &str) {
+        for ch in text.chars() {
+            let before = snapshot(current);
+            current.push(ch);
+            history.register_input(before, true, ch.is_whitespace());
+        }
+    }
+
+    #[test]
+    fn undo_redo_word_groups() {
+        let mut history = LocalHistory::default();
+        let mut current = String::new();
+
+        type_text(&mut history, &mut current, "hello brave world");
+        assert_eq!(current, "hello brave world");
+
+        let restored = history.undo(snapshot(&current)).unwrap();
+        assert_eq!(restored, snapshot("hello brave "));
+
+        let restored = history.undo(restored).unwrap();
+        assert_eq!(restored, snapshot("hello "));
+
+        let restored = history.redo(restored).unwrap();
+        assert_eq!(restored, snapshot("hello brave "));
+
+        let restored = history.redo(restored).unwrap();
+        assert_eq!(restored, snapshot("hello brave world"));
+
+        assert!(history.redo(restored).is_none());
+    }
+
+    #[test]
+    fn interrupted_edits_start_new_group() {
+        let mut history = LocalHistory::default();
+        let mut current = String::new();
+
+        type_text(&mut history, &mut current, "abc");
+        // Cursor movement without changes
+        history.register_input(snapshot(&current), false, false);
+        type_text(&mut history, &mut current, "def");
+
+        let restored = history.undo(snapshot(&current)).unwrap();
+        assert_eq!(restored, snapshot("abc"));
+
+        let restored = history.undo(restored).unwrap();
+        assert_eq!(restored, snapshot(""));
+
+        assert!(history.undo(restored).is_none());
+    }
+
+    #[test]
+    fn new_edit_clears_redo() {
+        let mut history = LocalHistory::default();
+        let mut current = String::new();
+
+        type_text(&mut history, &mut current, "one two");
+        let restored = history.undo(snapshot(&current)).unwrap();
+        current = restored.lines[0].clone();
+
+        type_text(&mut history, &mut current, "three");
+        assert!(history.redo(snapshot(&current)).is_none());
+    }
+}
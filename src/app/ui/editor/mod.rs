@@ -1,23 +1,35 @@
+use std::time::{Duration, Instant};
+
 use anyhow::{anyhow, bail};
 use arboard::Clipboard;
 use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
 use ratatui::{
     layout::Rect,
     prelude::Margin,
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     symbols,
+    text::Line,
     widgets::{Block, Borders, Scrollbar, ScrollbarOrientation, ScrollbarState},
     Frame,
 };
 
 use crate::app::{keymap::Input, runner::HandleInputReturnType, App};
 
-use backend::DataProvider;
+use backend::{count_chars, count_words, DataProvider};
 use tui_textarea::{CursorMove, Scrolling, TextArea};
 
 use super::commands::ClipboardOperation;
 use super::Styles;
 
+use autosave::AutosaveTimer;
+use local_history::{EditorSnapshot, LocalHistory};
+
+pub use autosave::AutosaveStatus;
+
+mod autosave;
+mod links;
+mod local_history;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EditorMode {
     Normal,
@@ -31,6 +43,12 @@ pub struct Editor<'a> {
     is_active: bool,
     is_dirty: bool,
     has_unsaved: bool,
+    local_history: LocalHistory,
+    autosave: Option<AutosaveTimer>,
+    /// Headings of the content sections exceeding the limits of their templates.
+    exceeded_sections: Vec<String>,
+    /// Pattern of the full-text search query to highlight its matches in the content.
+    search_pattern: Option<String>,
 }
 
 impl From<&Input> for KeyEvent {
@@ -46,7 +64,7 @@ impl From<&Input> for KeyEvent {
 
 impl<'a> Editor<'a> {
     pub fn new() -> Editor<'a> {
-        let text_area = TextArea::default();
+        let text_area = create_text_area(Vec::new());
 
         Editor {
             text_area,
@@ -54,6 +72,10 @@ impl<'a> Editor<'a> {
             is_active: false,
             is_dirty: false,
             has_unsaved: false,
+            local_history: LocalHistory::default(),
+            autosave: None,
+            exceeded_sections: Vec::new(),
+            search_pattern: None,
         }
     }
 
@@ -78,18 +100,16 @@ impl<'a> Editor<'a> {
                 if let Some(entry) = app.get_entry(id) {
                     self.is_dirty = false;
                     let lines = entry.content.lines().map(|line| line.to_owned()).collect();
-                    let mut text_area = TextArea::new(lines);
-                    text_area.move_cursor(tui_textarea::CursorMove::Bottom);
-                    text_area.move_cursor(tui_textarea::CursorMove::End);
-                    text_area
+                    create_text_area(lines)
                 } else {
-                    TextArea::default()
+                    create_text_area(Vec::new())
                 }
             }
-            None => TextArea::default(),
+            None => create_text_area(Vec::new()),
         };
 
         self.text_area = text_area;
+        self.local_history.clear();
 
         self.refresh_has_unsaved(app);
     }
@@ -126,9 +146,34 @@ impl<'a> Editor<'a> {
                 }
             }
 
+            // Undo & Redo are handled by the local history of the editor
+            if input.modifiers.contains(KeyModifiers::CONTROL) {
+                match input.key_code {
+                    KeyCode::Char('u') => {
+                        self.undo_local(app);
+                        return Ok(HandleInputReturnType::Handled);
+                    }
+                    KeyCode::Char('r') => {
+                        self.redo_local(app);
+                        return Ok(HandleInputReturnType::Handled);
+                    }
+                    _ => {}
+                }
+            }
+
             // give the input to the editor
+            let before = self.take_snapshot();
             let key_event = KeyEvent::from(input);
-            if self.text_area.input(key_event) {
+            let changed = self.text_area.input(key_event);
+            let closes_group = match input.key_code {
+                KeyCode::Char(ch) => ch.is_whitespace(),
+                KeyCode::Enter | KeyCode::Tab => true,
+                _ => false,
+            };
+            self.local_history
+                .register_input(before, changed, closes_group);
+
+            if changed {
                 self.is_dirty = true;
                 self.refresh_has_unsaved(app);
             }
@@ -139,6 +184,8 @@ impl<'a> Editor<'a> {
         Ok(HandleInputReturnType::NotFound)
     }
 
+    /// Handles the input in normal and visual modes. Inputs editing the content are rejected in
+    /// read-only mode returning [`HandleInputReturnType::NotFound`].
     pub fn handle_input<D: DataProvider>(
         &mut self,
         input: &Input,
@@ -152,15 +199,42 @@ impl<'a> Editor<'a> {
 
         let sync_os_clipboard = app.settings.sync_os_clipboard;
 
+        let before = self.take_snapshot();
+
         if is_default_navigation(input) {
             let key_event = KeyEvent::from(input);
             self.text_area.input(key_event);
         } else if !self.is_visual_mode()
             || !self.handle_input_visual_only(input, sync_os_clipboard)?
         {
-            self.handle_vim_motions(input, sync_os_clipboard)?;
+            match (
+                input.key_code,
+                input.modifiers.contains(KeyModifiers::CONTROL),
+            ) {
+                (KeyCode::Char('u'), false) => {
+                    self.undo_local(app);
+                    return Ok(HandleInputReturnType::Handled);
+                }
+                (KeyCode::Char('r'), true) => {
+                    self.redo_local(app);
+                    return Ok(HandleInputReturnType::Handled);
+                }
+                _ => self.handle_vim_motions(input, sync_os_clipboard)?,
+            }
+        }
+
+        let changed = before.lines != self.text_area.lines();
+
+        // Reject edits in read-only mode, restoring the editor to its state before the input.
+        if app.settings.is_read_only() && (changed || self.is_insert_mode()) {
+            self.restore_snapshot(before);
+            self.set_editor_mode(EditorMode::Normal);
+            return Ok(HandleInputReturnType::NotFound);
         }
 
+        // Edits in normal & visual modes are registered each as separate group
+        self.local_history.register_input(before, changed, true);
+
         // Check if the input led the editor to leave the visual mode and make the corresponding UI changes
         if !self.text_area.is_selecting() && self.is_visual_mode() {
             self.set_editor_mode(EditorMode::Normal);
@@ -185,7 +259,7 @@ impl<'a> Editor<'a> {
         match input.key_code {
             KeyCode::Char('d') => {
                 if sync_os_clipboard {
-                    self.exec_os_clipboard(ClipboardOperation::Cut)?;
+                    self.exec_os_clipboard_intern(ClipboardOperation::Cut)?;
                 } else {
                     self.text_area.cut();
                 }
@@ -193,7 +267,7 @@ impl<'a> Editor<'a> {
             }
             KeyCode::Char('y') => {
                 if sync_os_clipboard {
-                    self.exec_os_clipboard(ClipboardOperation::Copy)?;
+                    self.exec_os_clipboard_intern(ClipboardOperation::Copy)?;
                 } else {
                     self.text_area.copy();
                 }
@@ -202,7 +276,7 @@ impl<'a> Editor<'a> {
             }
             KeyCode::Char('c') => {
                 if sync_os_clipboard {
-                    self.exec_os_clipboard(ClipboardOperation::Copy)?;
+                    self.exec_os_clipboard_intern(ClipboardOperation::Copy)?;
                 } else {
                     self.text_area.cut();
                 }
@@ -243,29 +317,23 @@ impl<'a> Editor<'a> {
             }
             (KeyCode::Char('D'), false) => {
                 self.text_area.delete_line_by_end();
-                self.exec_os_clipboard(ClipboardOperation::Copy)?;
+                self.exec_os_clipboard_intern(ClipboardOperation::Copy)?;
             }
             (KeyCode::Char('C'), false) => {
                 self.text_area.delete_line_by_end();
-                self.exec_os_clipboard(ClipboardOperation::Copy)?;
+                self.exec_os_clipboard_intern(ClipboardOperation::Copy)?;
                 self.mode = EditorMode::Insert;
             }
             (KeyCode::Char('p'), false) => {
                 if sync_os_clipboard {
-                    self.exec_os_clipboard(ClipboardOperation::Paste)?;
+                    self.exec_os_clipboard_intern(ClipboardOperation::Paste)?;
                 } else {
                     self.text_area.paste();
                 }
             }
-            (KeyCode::Char('u'), false) => {
-                self.text_area.undo();
-            }
-            (KeyCode::Char('r'), true) => {
-                self.text_area.redo();
-            }
             (KeyCode::Char('x'), false) => {
                 self.text_area.delete_next_char();
-                self.exec_os_clipboard(ClipboardOperation::Copy)?;
+                self.exec_os_clipboard_intern(ClipboardOperation::Copy)?;
             }
             (KeyCode::Char('i'), false) => self.mode = EditorMode::Insert,
             (KeyCode::Char('a'), false) => {
@@ -327,6 +395,18 @@ impl<'a> Editor<'a> {
         self.mode = mode;
     }
 
+    /// Sets the query of the full-text search to be highlighted in the content.
+    pub fn set_search_query(&mut self, query: Option<&str>, case_sensitive: bool) {
+        self.search_pattern = query.map(|query| {
+            let escaped = regex::escape(query);
+            if case_sensitive {
+                escaped
+            } else {
+                format!("(?i){escaped}")
+            }
+        });
+    }
+
     pub fn render_widget(&mut self, frame: &mut Frame, area: Rect, styles: &Styles) {
         let mut title = "Content".to_owned();
         if self.is_active {
@@ -341,6 +421,13 @@ impl<'a> Editor<'a> {
             title.push_str(" *");
         }
 
+        // The indicator is styled with modifiers only to stay readable without colors.
+        let autosave_indicator = self.autosave_status().indicator_text().map(|text| {
+            Line::from(format!(" {text} "))
+                .style(Style::default().add_modifier(Modifier::DIM | Modifier::ITALIC))
+                .right_aligned()
+        });
+
         let estyles = &styles.editor;
 
         let text_block_style = match (self.mode, self.is_active) {
@@ -350,12 +437,29 @@ impl<'a> Editor<'a> {
             (EditorMode::Normal, false) => estyles.block_normal_inactive,
         };
 
-        self.text_area.set_block(
-            Block::default()
-                .borders(Borders::ALL)
-                .style(text_block_style)
-                .title(title),
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .style(text_block_style)
+            .title(title);
+        if let Some(indicator) = autosave_indicator {
+            block = block.title(indicator);
+        }
+        // Counts are taken from the text area to stay live while typing.
+        let content = self.get_content();
+        let counts = format!(
+            " {} words | {} chars ",
+            count_words(&content),
+            count_chars(&content)
         );
+        block = block.title_bottom(Line::from(counts).right_aligned());
+        if !self.exceeded_sections.is_empty() {
+            let warning = format!(" Over limit: {} ", self.exceeded_sections.join(", "));
+            block = block.title_bottom(
+                Line::from(warning).style(Style::default().fg(styles.msgbox.warning)),
+            );
+        }
+
+        self.text_area.set_block(block);
 
         let cursor_style = if self.is_active {
             let s = match self.mode {
@@ -376,8 +480,27 @@ impl<'a> Editor<'a> {
         self.text_area
             .set_selection_style(Style::default().bg(Color::White).fg(Color::Black));
 
+        // Text area is recreated on content changes, losing its search pattern.
+        let search_pattern = self.search_pattern.as_deref().unwrap_or_default();
+        let current_pattern = self
+            .text_area
+            .search_pattern()
+            .map(|regex| regex.as_str())
+            .unwrap_or_default();
+        if current_pattern != search_pattern {
+            // Pattern is built from an escaped query, so it's always valid.
+            _ = self.text_area.set_search_pattern(search_pattern);
+        }
+        self.text_area
+            .set_search_style(Style::from(estyles.search_match));
+
         frame.render_widget(&self.text_area, area);
 
+        if self.mode == EditorMode::Normal {
+            let text_area = area.inner(Margin::new(1, 1));
+            links::highlight_urls(frame.buffer_mut(), text_area, Style::from(estyles.link));
+        }
+
         self.render_vertical_scrollbar(frame, area);
         self.render_horizontal_scrollbar(frame, area);
     }
@@ -470,24 +593,114 @@ impl<'a> Editor<'a> {
                 }
             }
             false => false,
+        };
+
+        if let Some(autosave) = self.autosave.as_mut() {
+            autosave.update(self.has_unsaved, Instant::now());
+        }
+
+        self.exceeded_sections = if app.settings.tag_template_limits.is_empty() {
+            Vec::new()
+        } else {
+            app.get_exceeded_sections(&self.get_content())
+        };
+    }
+
+    /// Enables saving the unsaved changes automatically after the given interval, or disables it
+    /// if no interval is provided.
+    pub fn set_autosave_interval(&mut self, interval: Option<Duration>) {
+        self.autosave = interval.map(AutosaveTimer::new);
+    }
+
+    pub fn autosave_status(&self) -> AutosaveStatus {
+        self.autosave
+            .as_ref()
+            .map_or(AutosaveStatus::Idle, |autosave| {
+                autosave.status(Instant::now())
+            })
+    }
+
+    pub fn is_autosave_due(&self) -> bool {
+        self.autosave
+            .as_ref()
+            .is_some_and(|autosave| autosave.is_due(Instant::now()))
+    }
+
+    /// Marks the content as saved to be shown in the autosave indicator.
+    pub fn mark_saved(&mut self) {
+        if let Some(autosave) = self.autosave.as_mut() {
+            autosave.mark_saved(Instant::now());
+        }
+    }
+
+    /// Restarts the autosave countdown after saving the changes has failed.
+    pub fn restart_autosave(&mut self) {
+        if let Some(autosave) = self.autosave.as_mut() {
+            autosave.restart(Instant::now());
         }
     }
 
     pub fn set_entry_content<D: DataProvider>(&mut self, entry_content: &str, app: &App<D>) {
         self.is_dirty = true;
+        let before = self.take_snapshot();
         let lines = entry_content.lines().map(|line| line.to_owned()).collect();
-        let mut text_area = TextArea::new(lines);
-        text_area.move_cursor(tui_textarea::CursorMove::Bottom);
-        text_area.move_cursor(tui_textarea::CursorMove::End);
 
-        self.text_area = text_area;
+        self.text_area = create_text_area(lines);
+
+        let changed = before.lines != self.text_area.lines();
+        self.local_history.register_input(before, changed, true);
 
         self.refresh_has_unsaved(app);
     }
 
+    fn take_snapshot(&self) -> EditorSnapshot {
+        EditorSnapshot::new(self.text_area.lines().to_vec(), self.text_area.cursor())
+    }
+
+    fn restore_snapshot(&mut self, snapshot: EditorSnapshot) {
+        let yank_text = self.text_area.yank_text();
+        let EditorSnapshot { lines, cursor } = snapshot;
+
+        self.text_area = create_text_area(lines);
+        self.text_area.set_yank_text(yank_text);
+        self.text_area
+            .move_cursor(CursorMove::Jump(cursor.0 as u16, cursor.1 as u16));
+    }
+
+    /// Reverts the last edit group from the local history of the editor.
+    pub fn undo_local<D: DataProvider>(&mut self, app: &App<D>) {
+        if let Some(snapshot) = self.local_history.undo(self.take_snapshot()) {
+            self.restore_snapshot(snapshot);
+            self.is_dirty = true;
+            self.refresh_has_unsaved(app);
+        }
+    }
+
+    /// Reapplies the last reverted edit group from the local history of the editor.
+    pub fn redo_local<D: DataProvider>(&mut self, app: &App<D>) {
+        if let Some(snapshot) = self.local_history.redo(self.take_snapshot()) {
+            self.restore_snapshot(snapshot);
+            self.is_dirty = true;
+            self.refresh_has_unsaved(app);
+        }
+    }
+
     pub fn exec_os_clipboard(
         &mut self,
         operation: ClipboardOperation,
+    ) -> anyhow::Result<HandleInputReturnType> {
+        let before = self.take_snapshot();
+        let result = self.exec_os_clipboard_intern(operation);
+
+        let changed = before.lines != self.text_area.lines();
+        self.local_history.register_input(before, changed, true);
+
+        result
+    }
+
+    fn exec_os_clipboard_intern(
+        &mut self,
+        operation: ClipboardOperation,
     ) -> anyhow::Result<HandleInputReturnType> {
         let mut clipboard = Clipboard::new().map_err(map_clipboard_error)?;
 
@@ -527,6 +740,17 @@ impl<'a> Editor<'a> {
     }
 }
 
+/// Creates a text area with the given lines placing the cursor at the end of its content.
+/// The builtin history of the text area is disabled since the editor has its own local history.
+fn create_text_area<'a>(lines: Vec<String>) -> TextArea<'a> {
+    let mut text_area = TextArea::new(lines);
+    text_area.set_max_histories(0);
+    text_area.move_cursor(CursorMove::Bottom);
+    text_area.move_cursor(CursorMove::End);
+
+    text_area
+}
+
 fn is_default_navigation(input: &Input) -> bool {
     let has_control = input.modifiers.contains(KeyModifiers::CONTROL);
     let has_alt = input.modifiers.contains(KeyModifiers::ALT);
@@ -0,0 +1,149 @@
+use backend::{DataProvider, Entry};
+use ratatui::{
+    layout::Rect,
+    text::Line,
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{
+    app::{keymap::Keymap, App},
+    settings::EmptyContentView,
+};
+
+use super::{footer::get_priority_counts_text, Styles, UICommand};
+
+/// Commands listed in the help shown in the content area.
+const HELP_COMMANDS: [UICommand; 6] = [
+    UICommand::CreateEntry,
+    UICommand::CreateQuickNote,
+    UICommand::ShowFuzzyFind,
+    UICommand::ShowFilter,
+    UICommand::ResetFilter,
+    UICommand::ShowHelp,
+];
+
+/// Content shown in the content area instead of the editor when no journal is selected.
+#[derive(Debug, PartialEq, Eq)]
+pub enum EmptyContent<'a> {
+    Help,
+    Stats,
+    RecentEntry(&'a Entry),
+}
+
+/// Returns the content to show instead of the editor according to the settings, or `None` if
+/// the editor should be shown.
+pub fn get_empty_content<D: DataProvider>(app: &App<D>) -> Option<EmptyContent<'_>> {
+    if app.current_entry_id.is_some() {
+        return None;
+    }
+
+    match app.settings.empty_content_view {
+        EmptyContentView::Blank => None,
+        EmptyContentView::Help => Some(EmptyContent::Help),
+        EmptyContentView::Stats => Some(EmptyContent::Stats),
+        EmptyContentView::RecentEntry => app
+            .entries
+            .iter()
+            .max_by_key(|entry| entry.last_modified())
+            .map(EmptyContent::RecentEntry),
+    }
+}
+
+pub fn render_empty_content<'k, D: DataProvider>(
+    frame: &mut Frame,
+    area: Rect,
+    content: EmptyContent,
+    app: &App<D>,
+    keymaps: impl Iterator<Item = &'k Keymap> + Clone,
+    styles: &Styles,
+) {
+    let (title, lines) = match content {
+        EmptyContent::Help => (String::from("Content - Help"), get_help_lines(keymaps)),
+        EmptyContent::Stats => (String::from("Content - Stats"), get_stats_lines(app)),
+        EmptyContent::RecentEntry(entry) => (
+            format!("Content - Most recent: {}", entry.title),
+            entry
+                .content
+                .lines()
+                .map(|line| Line::from(line.to_owned()))
+                .collect(),
+        ),
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .style(styles.editor.block_normal_inactive)
+        .title(title);
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+
+    frame.render_widget(paragraph, area);
+}
+
+fn get_help_lines<'k>(keymaps: impl Iterator<Item = &'k Keymap> + Clone) -> Vec<Line<'static>> {
+    let mut lines = vec![Line::from("No journal is selected."), Line::default()];
+
+    lines.extend(HELP_COMMANDS.iter().filter_map(|command| {
+        let keys: Vec<String> = keymaps
+            .clone()
+            .filter(|keymap| keymap.command == *command)
+            .map(|keymap| format!("'{}'", keymap.key))
+            .collect();
+
+        (!keys.is_empty())
+            .then(|| Line::from(format!("{}: {}", command.get_info().name, keys.join(","))))
+    }));
+
+    lines
+}
+
+fn get_stats_lines<D: DataProvider>(app: &App<D>) -> Vec<Line<'static>> {
+    let pinned_count = app.entries.iter().filter(|entry| entry.is_pinned()).count();
+
+    [
+        format!("Journals: {}", app.entries.len()),
+        format!("Shown journals: {}", app.get_active_entries().count()),
+        format!("Pinned journals: {pinned_count}"),
+        format!("Tags: {}", app.get_tag_counts().len()),
+        format!("Priorities: {}", get_priority_counts_text(app)),
+    ]
+    .into_iter()
+    .map(Line::from)
+    .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{app::test::mock::MockDataProvider, settings::Settings};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn empty_content_by_settings() {
+        let mut app = App::new(MockDataProvider::new_with_data(), Settings::default());
+        app.load_entries().await.unwrap();
+
+        let mut get_content_for = |view| {
+            app.settings.empty_content_view = view;
+            get_empty_content(&app).map(|content| match content {
+                EmptyContent::RecentEntry(entry) => format!("Recent: {}", entry.title),
+                content => format!("{content:?}"),
+            })
+        };
+
+        assert_eq!(get_content_for(EmptyContentView::Blank), None);
+        assert_eq!(get_content_for(EmptyContentView::Help).unwrap(), "Help");
+        assert_eq!(get_content_for(EmptyContentView::Stats).unwrap(), "Stats");
+        assert_eq!(
+            get_content_for(EmptyContentView::RecentEntry).unwrap(),
+            "Recent: Title 2"
+        );
+
+        // Editor is shown when there is a current entry
+        app.current_entry_id = Some(0);
+        assert_eq!(get_empty_content(&app), None);
+    }
+}
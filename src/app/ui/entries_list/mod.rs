@@ -1,9 +1,11 @@
+use std::{collections::BTreeMap, str::FromStr};
+
 use chrono::Datelike;
 
 use ratatui::{
     layout::{Alignment, Rect},
     prelude::Margin,
-    style::Style,
+    style::{Color, Style},
     symbols,
     text::{Line, Span},
     widgets::{
@@ -13,15 +15,27 @@ use ratatui::{
     Frame,
 };
 
-use backend::DataProvider;
+use backend::{DataProvider, Entry};
 
-use crate::app::App;
-use crate::{app::keymap::Keymap, settings::DatumVisibility};
+use crate::app::{filter::CriteriaRelation, App};
+use crate::{
+    app::keymap::Keymap,
+    settings::{DatePriorityOrder, DatumVisibility, ListDensity, ScrollbarVisibility, Settings},
+};
 
 use super::{Styles, UICommand};
 
 const LIST_INNER_MARGIN: usize = 5;
 
+/// State of the applied filter shown in the title of the journals list.
+#[derive(Debug, Clone, Copy)]
+struct FilterSummary {
+    /// Count of the journals hidden by the filter.
+    hidden: usize,
+    /// Relation between the criteria, set only when the filter has many criteria.
+    relation: Option<CriteriaRelation>,
+}
+
 #[derive(Debug)]
 pub struct EntriesList {
     pub state: ListState,
@@ -49,123 +63,24 @@ impl EntriesList {
 
         let mut lines_count = 0;
 
+        let tag_counts = app.settings.show_tag_counts.then(|| app.get_tag_counts());
+
         let items: Vec<ListItem> = app
             .get_active_entries()
             .map(|entry| {
-                let highlight_selected =
-                    self.multi_select_mode && app.selected_entries.contains(&entry.id);
-
-                // *** Title ***
-                let mut title = entry.title.to_string();
-
-                if highlight_selected {
-                    title.insert_str(0, "* ");
-                }
-
-                // Text wrapping
-                let title_lines = textwrap::wrap(&title, area.width as usize - LIST_INNER_MARGIN);
-
-                // tilte lines
-                lines_count += title_lines.len();
-
-                let title_style = match (self.is_active, highlight_selected) {
-                    (_, true) => jstyles.title_selected,
-                    (true, _) => jstyles.title_active,
-                    (false, _) => jstyles.title_inactive,
-                };
-
-                let mut spans: Vec<Line> = title_lines
-                    .iter()
-                    .map(|line| Line::from(Span::styled(line.to_string(), title_style)))
-                    .collect();
-
-                // *** Date & Priority ***
-                let date_priority_lines = match (app.settings.datum_visibility, entry.priority) {
-                    (DatumVisibility::Show, Some(prio)) => {
-                        let one_liner = format!(
-                            "{},{},{} | Priority: {}",
-                            entry.date.day(),
-                            entry.date.month(),
-                            entry.date.year(),
-                            prio
-                        );
-
-                        if one_liner.len() > area.width as usize - LIST_INNER_MARGIN {
-                            vec![
-                                format!(
-                                    "{},{},{}",
-                                    entry.date.day(),
-                                    entry.date.month(),
-                                    entry.date.year()
-                                ),
-                                format!("Priority: {prio}"),
-                            ]
-                        } else {
-                            vec![one_liner]
-                        }
-                    }
-                    (DatumVisibility::Show, None) => {
-                        vec![format!(
-                            "{},{},{}",
-                            entry.date.day(),
-                            entry.date.month(),
-                            entry.date.year()
-                        )]
-                    }
-                    (DatumVisibility::Hide, None) => Vec::new(),
-                    (DatumVisibility::EmptyLine, None) => vec![String::new()],
-                    (_, Some(prio)) => {
-                        vec![format!("Priority: {}", prio)]
-                    }
-                };
-
-                let date_lines = date_priority_lines
-                    .iter()
-                    .map(|line| Line::from(Span::styled(line.to_string(), jstyles.date_priority)));
-                spans.extend(date_lines);
-
-                // date & priority lines
-                lines_count += date_priority_lines.len();
-
-                // *** Tags ***
-                if !entry.tags.is_empty() {
-                    const TAGS_SEPARATOR: &str = " | ";
-                    let tags_default_style: Style = jstyles.tags_default.into();
-
-                    let mut added_lines = 1;
-                    spans.push(Line::default());
-
-                    for tag in entry.tags.iter() {
-                        let mut last_line = spans.last_mut().unwrap();
-                        let allowd_width = area.width as usize - LIST_INNER_MARGIN;
-                        if !last_line.spans.is_empty() {
-                            if last_line.width() + TAGS_SEPARATOR.len() > allowd_width {
-                                added_lines += 1;
-                                spans.push(Line::default());
-                                last_line = spans.last_mut().unwrap();
-                            }
-                            last_line.push_span(Span::styled(TAGS_SEPARATOR, tags_default_style))
-                        }
-
-                        let style = app
-                            .get_color_for_tag(tag)
-                            .map(|c| Style::default().bg(c.background).fg(c.foreground))
-                            .unwrap_or(tags_default_style);
-                        let span_to_add = Span::styled(tag.to_owned(), style);
-
-                        if last_line.width() + tag.len() < allowd_width {
-                            last_line.push_span(span_to_add);
-                        } else {
-                            added_lines += 1;
-                            let line = Line::from(span_to_add);
-                            spans.push(line);
-                        }
-                    }
-
-                    lines_count += added_lines;
-                }
-
-                ListItem::new(spans)
+                let lines = self.get_entry_lines(
+                    entry,
+                    app,
+                    tag_counts.as_ref(),
+                    (area.width as usize)
+                        .saturating_sub(LIST_INNER_MARGIN)
+                        .max(1),
+                    styles,
+                );
+
+                lines_count += lines.len();
+
+                ListItem::new(lines)
             })
             .collect();
 
@@ -178,43 +93,185 @@ impl EntriesList {
         };
 
         let list = List::new(items)
-            .block(self.get_list_block(app.filter.is_some(), Some(items_count), styles))
+            .block(self.get_list_block(
+                app.is_focus_mode(),
+                get_filter_summary(app),
+                Some(items_count),
+                styles,
+            ))
             .highlight_style(highlight_style)
             .highlight_symbol("> ");
 
         frame.render_stateful_widget(list, area, &mut self.state);
 
         let lines_count = lines_count;
+        let overflows = lines_count > area.height as usize - 2;
 
-        if lines_count > area.height as usize - 2 {
-            let avg_item_height = lines_count / items_count;
+        let show_scrollbar = match app.settings.list_scrollbar {
+            ScrollbarVisibility::Auto => overflows,
+            ScrollbarVisibility::Always => true,
+            ScrollbarVisibility::Never => false,
+        };
 
-            self.render_scrollbar(
-                frame,
-                area,
-                self.state.selected().unwrap_or(0),
-                items_count,
-                avg_item_height,
-            );
+        if show_scrollbar {
+            let state = if overflows {
+                const VIEWPORT_ADJUST: u16 = 4;
+                let avg_item_height = lines_count / items_count;
+                let viewport_len =
+                    (area.height / avg_item_height as u16).saturating_sub(VIEWPORT_ADJUST);
+
+                ScrollbarState::default()
+                    .content_length(items_count)
+                    .viewport_content_length(viewport_len as usize)
+                    .position(self.state.selected().unwrap_or(0))
+            } else {
+                // A single position covering the viewport fills the scrollbar with the thumb.
+                ScrollbarState::default()
+                    .content_length(1)
+                    .viewport_content_length(1)
+            };
+
+            self.render_scrollbar(frame, area, state);
         }
     }
 
-    fn render_scrollbar(
-        &mut self,
-        frame: &mut Frame,
-        area: Rect,
-        pos: usize,
-        items_count: usize,
-        avg_item_height: usize,
-    ) {
-        const VIEWPORT_ADJUST: u16 = 4;
-        let viewport_len = (area.height / avg_item_height as u16).saturating_sub(VIEWPORT_ADJUST);
+    /// Builds the lines of the given entry in the list according to the density mode in
+    /// the settings, showing the given counts next to the tags if provided.
+    fn get_entry_lines<D: DataProvider>(
+        &self,
+        entry: &Entry,
+        app: &App<D>,
+        tag_counts: Option<&BTreeMap<String, usize>>,
+        allowed_width: usize,
+        styles: &Styles,
+    ) -> Vec<Line<'static>> {
+        let jstyles = &styles.journals_list;
+        let density = app.settings.list_density;
+
+        let highlight_selected = self.multi_select_mode && app.selected_entries.contains(&entry.id);
+
+        // *** Title ***
+        let mut title = entry.title.to_string();
+
+        if app.is_entry_unsynced(entry.id) {
+            title.insert_str(0, "[Unsynced] ");
+        }
+
+        if entry.is_pinned() {
+            title.insert_str(0, "[Pinned] ");
+        }
+
+        if highlight_selected {
+            title.insert_str(0, "* ");
+        }
+
+        // Text wrapping
+        let title_lines = textwrap::wrap(&title, allowed_width);
+
+        let mut title_style = Style::from(match (self.is_active, highlight_selected) {
+            (_, true) => jstyles.title_selected,
+            (true, _) => jstyles.title_active,
+            (false, _) => jstyles.title_inactive,
+        });
+
+        // The color of the entry overrides the default styling unless it's selected.
+        if let Some(color) = get_entry_color(entry).filter(|_| !highlight_selected) {
+            title_style = title_style.fg(color);
+        }
+
+        let mut spans: Vec<Line> = title_lines
+            .iter()
+            .map(|line| Line::from(Span::styled(line.to_string(), title_style)))
+            .collect();
+
+        if density == ListDensity::Compact {
+            return spans;
+        }
+
+        // *** Content Preview ***
+        if density == ListDensity::Detailed {
+            const PREVIEW_MAX_LINES: usize = 2;
+            if let Some(preview) = entry.content.lines().map(str::trim).find(|l| !l.is_empty()) {
+                let preview_lines = textwrap::wrap(preview, allowed_width);
+                spans.extend(
+                    preview_lines
+                        .iter()
+                        .take(PREVIEW_MAX_LINES)
+                        .map(|line| Line::from(line.to_string())),
+                );
+            }
+        }
+
+        // *** Date & Priority ***
+        let date_priority_lines = get_date_priority_lines(entry, &app.settings, allowed_width);
+
+        let date_lines = date_priority_lines
+            .into_iter()
+            .map(|line| Line::from(Span::styled(line, jstyles.date_priority)));
+        spans.extend(date_lines);
+
+        if density == ListDensity::Detailed {
+            if let Some(updated_at) = entry.updated_at {
+                let modified = format!(
+                    "Modified: {},{},{}",
+                    updated_at.day(),
+                    updated_at.month(),
+                    updated_at.year()
+                );
+                spans.push(Line::from(Span::styled(modified, jstyles.date_priority)));
+            }
+        }
+
+        // *** Tags ***
+        if !entry.tags.is_empty() {
+            const TAGS_SEPARATOR: &str = " | ";
+            let tags_default_style: Style = jstyles.tags_default.into();
+
+            spans.push(Line::default());
+
+            for tag in entry.tags.iter() {
+                let mut last_line = spans.last_mut().unwrap();
+                if !last_line.spans.is_empty() {
+                    if last_line.width() + TAGS_SEPARATOR.len() > allowed_width {
+                        spans.push(Line::default());
+                        last_line = spans.last_mut().unwrap();
+                    }
+                    last_line.push_span(Span::styled(TAGS_SEPARATOR, tags_default_style))
+                }
+
+                let style = app
+                    .get_color_for_tag(tag)
+                    .map(|c| Style::default().bg(c.background).fg(c.foreground))
+                    .unwrap_or(tags_default_style);
+                let tag_text = match tag_counts.and_then(|counts| counts.get(tag)) {
+                    Some(count) => format!("{tag} ({count})"),
+                    None => tag.to_owned(),
+                };
+                let span_to_add = Span::styled(tag_text, style);
+                let tag_width = span_to_add.width();
+
+                if last_line.width() + tag_width < allowed_width {
+                    last_line.push_span(span_to_add);
+                } else if tag_width <= allowed_width {
+                    let line = Line::from(span_to_add);
+                    spans.push(line);
+                } else {
+                    // Tags wider than the list are hard-wrapped, starting on the empty line if any.
+                    if last_line.spans.is_empty() {
+                        spans.pop();
+                    }
+                    let tag_lines = textwrap::wrap(&span_to_add.content, allowed_width)
+                        .into_iter()
+                        .map(|part| Line::from(Span::styled(part.into_owned(), style)));
+                    spans.extend(tag_lines);
+                }
+            }
+        }
 
-        let mut state = ScrollbarState::default()
-            .content_length(items_count)
-            .viewport_content_length(viewport_len as usize)
-            .position(pos);
+        spans
+    }
 
+    fn render_scrollbar(&mut self, frame: &mut Frame, area: Rect, mut state: ScrollbarState) {
         let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
             .begin_symbol(Some("▲"))
             .end_symbol(Some("▼"))
@@ -234,7 +291,7 @@ impl EntriesList {
         frame: &mut Frame,
         area: Rect,
         list_keymaps: &[Keymap],
-        has_filter: bool,
+        filter_summary: Option<FilterSummary>,
         styles: &Styles,
     ) {
         let keys_text: Vec<String> = list_keymaps
@@ -252,23 +309,19 @@ impl EntriesList {
         let place_holder = Paragraph::new(place_holder_text)
             .wrap(Wrap { trim: false })
             .alignment(Alignment::Center)
-            .block(self.get_list_block(has_filter, None, styles));
+            .block(self.get_list_block(false, filter_summary, None, styles));
 
         frame.render_widget(place_holder, area);
     }
 
     fn get_list_block<'a>(
         &self,
-        has_filter: bool,
+        focus_mode: bool,
+        filter_summary: Option<FilterSummary>,
         entries_len: Option<usize>,
         styles: &Styles,
     ) -> Block<'a> {
-        let title = match (self.multi_select_mode, has_filter) {
-            (true, true) => "Journals - Multi-Select - Filtered",
-            (true, false) => "Journals - Multi-Select",
-            (false, true) => "Journals - Filtered",
-            (false, false) => "Journals",
-        };
+        let title = get_list_title(self.multi_select_mode, focus_mode, filter_summary);
 
         let border_style = match (self.is_active, self.multi_select_mode) {
             (_, true) => styles.journals_list.block_multi_select,
@@ -298,7 +351,7 @@ impl EntriesList {
         styles: &Styles,
     ) {
         if app.get_active_entries().next().is_none() {
-            self.render_place_holder(frame, area, list_keymaps, app.filter.is_some(), styles);
+            self.render_place_holder(frame, area, list_keymaps, get_filter_summary(app), styles);
         } else {
             self.render_list(frame, app, area, styles);
         }
@@ -308,3 +361,375 @@ impl EntriesList {
         self.is_active = active;
     }
 }
+
+/// Returns the count of the entries hidden by the filter with the relation between its criteria
+/// if a filter is applied.
+fn get_filter_summary<D: DataProvider>(app: &App<D>) -> Option<FilterSummary> {
+    app.filter.as_ref().map(|filter| FilterSummary {
+        hidden: app.filtered_out_entries.len(),
+        relation: (filter.criteria.len() > 1).then_some(filter.relation),
+    })
+}
+
+/// Returns the color of the given entry if it has a valid one.
+fn get_entry_color(entry: &Entry) -> Option<Color> {
+    let color = entry.color.as_deref()?;
+
+    Color::from_str(color)
+        .inspect_err(|_| log::warn!("Invalid color '{color}' of journal {}", entry.id))
+        .ok()
+}
+
+/// Builds the title of the entries list block, including the count of the hidden entries by
+/// the filter if any is applied, and the relation between its criteria if it has many of them.
+/// The filter isn't mentioned in focus mode since it's overridden.
+fn get_list_title(
+    multi_select_mode: bool,
+    focus_mode: bool,
+    filter_summary: Option<FilterSummary>,
+) -> String {
+    let mode = if multi_select_mode {
+        "Journals - Multi-Select"
+    } else {
+        "Journals"
+    };
+
+    if focus_mode {
+        return format!("{mode} - Focus Mode");
+    }
+
+    match filter_summary {
+        Some(FilterSummary {
+            hidden,
+            relation: None,
+        }) => format!("{mode} - Filtered ({hidden} hidden)"),
+        Some(FilterSummary {
+            hidden,
+            relation: Some(relation),
+        }) => {
+            let relation = match relation {
+                CriteriaRelation::And => "all",
+                CriteriaRelation::Or => "any",
+            };
+            format!("{mode} - Filtered ({relation}, {hidden} hidden)")
+        }
+        None => mode.to_owned(),
+    }
+}
+
+/// Builds the lines of the date and priority block for the given entry, respecting the order and
+/// layout options in the settings and wrapping into separate lines if the width isn't enough.
+fn get_date_priority_lines(
+    entry: &Entry,
+    settings: &Settings,
+    allowed_width: usize,
+) -> Vec<String> {
+    let date_text = format!(
+        "{},{},{}",
+        entry.date.day(),
+        entry.date.month(),
+        entry.date.year()
+    );
+
+    match (settings.datum_visibility, entry.priority) {
+        (DatumVisibility::Show, Some(prio)) => {
+            let priority_text = format!("Priority: {prio}");
+
+            let (first, second) = match settings.date_priority_order {
+                DatePriorityOrder::DateFirst => (date_text, priority_text),
+                DatePriorityOrder::PriorityFirst => (priority_text, date_text),
+            };
+
+            let one_liner = format!("{first} | {second}");
+
+            if settings.priority_own_line || one_liner.len() > allowed_width {
+                vec![first, second]
+            } else {
+                vec![one_liner]
+            }
+        }
+        (DatumVisibility::Show, None) => vec![date_text],
+        (DatumVisibility::Hide, None) => Vec::new(),
+        (DatumVisibility::EmptyLine, None) => vec![String::new()],
+        (_, Some(prio)) => vec![format!("Priority: {prio}")],
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{TimeZone, Utc};
+    use ratatui::{backend::TestBackend, Terminal};
+
+    use crate::app::{
+        filter::{Filter, FilterCriterion},
+        test::mock::MockDataProvider,
+    };
+
+    use super::*;
+
+    const WIDE: usize = 100;
+
+    fn get_entry() -> Entry {
+        Entry::new(
+            0,
+            Utc.with_ymd_and_hms(2023, 10, 12, 11, 22, 33).unwrap(),
+            String::from("Title"),
+            String::from("Content"),
+            Vec::new(),
+            Some(2),
+        )
+    }
+
+    #[test]
+    fn date_priority_default_order() {
+        let settings = Settings::default();
+        let lines = get_date_priority_lines(&get_entry(), &settings, WIDE);
+
+        assert_eq!(lines, vec![String::from("12,10,2023 | Priority: 2")]);
+    }
+
+    #[test]
+    fn date_priority_priority_first() {
+        let settings = Settings {
+            date_priority_order: DatePriorityOrder::PriorityFirst,
+            ..Default::default()
+        };
+        let lines = get_date_priority_lines(&get_entry(), &settings, WIDE);
+
+        assert_eq!(lines, vec![String::from("Priority: 2 | 12,10,2023")]);
+    }
+
+    #[test]
+    fn date_priority_own_line() {
+        let mut settings = Settings {
+            priority_own_line: true,
+            ..Default::default()
+        };
+        let lines = get_date_priority_lines(&get_entry(), &settings, WIDE);
+        assert_eq!(
+            lines,
+            vec![String::from("12,10,2023"), String::from("Priority: 2")]
+        );
+
+        settings.date_priority_order = DatePriorityOrder::PriorityFirst;
+        let lines = get_date_priority_lines(&get_entry(), &settings, WIDE);
+        assert_eq!(
+            lines,
+            vec![String::from("Priority: 2"), String::from("12,10,2023")]
+        );
+    }
+
+    #[test]
+    fn date_priority_wrap_narrow() {
+        let settings = Settings {
+            date_priority_order: DatePriorityOrder::PriorityFirst,
+            ..Default::default()
+        };
+        let lines = get_date_priority_lines(&get_entry(), &settings, 15);
+
+        assert_eq!(
+            lines,
+            vec![String::from("Priority: 2"), String::from("12,10,2023")]
+        );
+    }
+
+    fn summary(hidden: usize, relation: Option<CriteriaRelation>) -> Option<FilterSummary> {
+        Some(FilterSummary { hidden, relation })
+    }
+
+    #[test]
+    fn list_title_hidden_count() {
+        assert_eq!(get_list_title(false, false, None), "Journals");
+        assert_eq!(
+            get_list_title(false, false, summary(3, None)),
+            "Journals - Filtered (3 hidden)"
+        );
+        assert_eq!(
+            get_list_title(true, false, summary(0, None)),
+            "Journals - Multi-Select - Filtered (0 hidden)"
+        );
+        assert_eq!(
+            get_list_title(false, true, summary(3, None)),
+            "Journals - Focus Mode"
+        );
+    }
+
+    #[test]
+    fn list_title_criteria_relation() {
+        assert_eq!(
+            get_list_title(false, false, summary(3, Some(CriteriaRelation::And))),
+            "Journals - Filtered (all, 3 hidden)"
+        );
+        assert_eq!(
+            get_list_title(false, false, summary(1, Some(CriteriaRelation::Or))),
+            "Journals - Filtered (any, 1 hidden)"
+        );
+    }
+
+    #[tokio::test]
+    async fn render_hidden_count() {
+        let mut app = App::new(MockDataProvider::default(), Settings::default());
+        app.load_entries().await.unwrap();
+        for idx in 0..10 {
+            let priority = if idx < 3 { 2 } else { 1 };
+            app.add_entry(
+                format!("Title {idx}"),
+                Utc::now(),
+                Vec::new(),
+                Some(priority),
+            )
+            .await
+            .unwrap();
+        }
+
+        let mut filter = Filter::default();
+        filter.criteria.push(FilterCriterion::Priority(1));
+        app.apply_filter(Some(filter));
+
+        let mut entries_list = EntriesList::new();
+        let styles = Styles::default();
+        let mut terminal = Terminal::new(TestBackend::new(60, 40)).unwrap();
+        terminal
+            .draw(|frame| entries_list.render_widget(frame, frame.area(), &app, &[], &styles))
+            .unwrap();
+
+        let first_row: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .take(60)
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(
+            first_row.contains("Journals - Filtered (3 hidden)"),
+            "Invalid title row: {first_row}"
+        );
+    }
+
+    #[tokio::test]
+    async fn render_entry_color() {
+        let mut app = App::new(MockDataProvider::new_with_data(), Settings::default());
+        app.load_entries().await.unwrap();
+        app.current_entry_id = Some(0);
+        app.set_current_entry_color(Some(Color::Rgb(16, 32, 48)))
+            .await
+            .unwrap();
+
+        let mut entries_list = EntriesList::new();
+        entries_list.set_active(true);
+        let styles = Styles::default();
+        let mut terminal = Terminal::new(TestBackend::new(60, 20)).unwrap();
+        terminal
+            .draw(|frame| entries_list.render_widget(frame, frame.area(), &app, &[], &styles))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let get_title_color = |title: &str| {
+            let width = buffer.area.width as usize;
+            let rows: Vec<String> = buffer
+                .content()
+                .chunks(width)
+                .map(|row| row.iter().map(|cell| cell.symbol()).collect())
+                .collect();
+            let (row_idx, col_idx) = rows
+                .iter()
+                .enumerate()
+                .find_map(|(idx, row)| row.find(title).map(|col| (idx, col)))
+                .expect("Title must be rendered");
+            buffer.content()[row_idx * width + col_idx].fg
+        };
+
+        assert_eq!(get_title_color("Title 1"), Color::Rgb(16, 32, 48));
+        assert_eq!(
+            get_title_color("Title 2"),
+            Style::from(styles.journals_list.title_active)
+                .fg
+                .unwrap_or(Color::Reset)
+        );
+    }
+
+    #[tokio::test]
+    async fn render_scrollbar_when_fits() {
+        let mut app = App::new(MockDataProvider::new_with_data(), Settings::default());
+        app.load_entries().await.unwrap();
+
+        // Returns the count of the scrollbar thumb cells
+        let mut get_thumb_len = |visibility| {
+            app.settings.list_scrollbar = visibility;
+            let mut entries_list = EntriesList::new();
+            let styles = Styles::default();
+            let mut terminal = Terminal::new(TestBackend::new(60, 40)).unwrap();
+            terminal
+                .draw(|frame| entries_list.render_widget(frame, frame.area(), &app, &[], &styles))
+                .unwrap();
+
+            terminal
+                .backend()
+                .buffer()
+                .content()
+                .iter()
+                .filter(|cell| cell.symbol() == symbols::block::FULL)
+                .count()
+        };
+
+        assert_eq!(get_thumb_len(ScrollbarVisibility::Auto), 0);
+        // Thumb fills the height without the borders and the arrows
+        assert_eq!(get_thumb_len(ScrollbarVisibility::Always), 36);
+        assert_eq!(get_thumb_len(ScrollbarVisibility::Never), 0);
+    }
+
+    #[tokio::test]
+    async fn density_lines_count() {
+        let mut app = App::new(MockDataProvider::new_with_data(), Settings::default());
+        app.load_entries().await.unwrap();
+
+        let entries_list = EntriesList::new();
+        let styles = Styles::default();
+        let entry = app.get_entry(0).unwrap().clone();
+
+        let mut get_lines_count = |density| {
+            app.settings.list_density = density;
+            entries_list
+                .get_entry_lines(&entry, &app, None, WIDE, &styles)
+                .len()
+        };
+
+        let compact = get_lines_count(ListDensity::Compact);
+        let normal = get_lines_count(ListDensity::Normal);
+        let detailed = get_lines_count(ListDensity::Detailed);
+
+        assert_eq!(compact, 1);
+        assert!(compact < normal, "compact: {compact}, normal: {normal}");
+        assert!(normal < detailed, "normal: {normal}, detailed: {detailed}");
+    }
+
+    #[tokio::test]
+    async fn wrap_long_words() {
+        let mut app = App::new(MockDataProvider::default(), Settings::default());
+        app.load_entries().await.unwrap();
+
+        let title = "a".repeat(200);
+        let tag = "b".repeat(70);
+        let id = app
+            .add_entry(title.clone(), Utc::now(), vec![tag.clone()], None)
+            .await
+            .unwrap();
+        let entry = app.get_entry(id).unwrap().clone();
+
+        let entries_list = EntriesList::new();
+        let styles = Styles::default();
+        let allowed_width = 30;
+        let lines = entries_list.get_entry_lines(&entry, &app, None, allowed_width, &styles);
+
+        for line in lines.iter() {
+            assert!(line.width() <= allowed_width, "Line overflows: {line}");
+        }
+
+        let rendered: String = lines.iter().map(|line| line.to_string()).collect();
+        assert!(rendered.starts_with(&title));
+        assert!(rendered.ends_with(&tag));
+        // Title lines fill the width
+        assert_eq!(lines[0].width(), allowed_width);
+    }
+}
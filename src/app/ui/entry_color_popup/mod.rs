@@ -0,0 +1,134 @@
+use std::str::FromStr;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use tui_textarea::{CursorMove, TextArea};
+
+use crate::app::keymap::Input;
+
+use super::{ui_functions::centered_rect_exact_height, PopupReturn, Styles};
+
+type EntryColorPopupReturn = PopupReturn<Option<Color>>;
+
+const FOOTER_TEXT: &str = "Enter: confirm | Esc or <Ctrl-c>: Cancel | Empty color: Remove it";
+const FOOTER_MARGIN: u16 = 8;
+
+/// Popup to set the color of the title of a journal, overriding the default styling.
+pub struct EntryColorPopup<'a> {
+    title: String,
+    color_txt: TextArea<'a>,
+    err_msg: String,
+}
+
+impl EntryColorPopup<'_> {
+    pub fn new(title: String, current_color: Option<&str>) -> Self {
+        let mut color_txt = TextArea::new(vec![current_color.unwrap_or_default().to_owned()]);
+        color_txt.move_cursor(CursorMove::End);
+
+        Self {
+            title,
+            color_txt,
+            err_msg: String::default(),
+        }
+    }
+
+    /// Parses the entered color, where empty text removes the color. Sets the error message
+    /// if the color is invalid.
+    fn parse_color(&mut self) -> Result<Option<Color>, ()> {
+        let text = self.color_txt.lines()[0].trim();
+        if text.is_empty() {
+            return Ok(None);
+        }
+
+        Color::from_str(text).map(Some).map_err(|_| {
+            self.err_msg = "Invalid color. Use a color name or hex '#RRGGBB'".into();
+        })
+    }
+
+    pub fn render_widget(&mut self, frame: &mut Frame, area: Rect, styles: &Styles) {
+        let mut area = centered_rect_exact_height(60, 10, area);
+
+        if area.width < FOOTER_TEXT.len() as u16 + FOOTER_MARGIN {
+            area.height += 1;
+        }
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("Journal Color");
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .horizontal_margin(4)
+            .vertical_margin(2)
+            .constraints(
+                [
+                    Constraint::Length(1),
+                    Constraint::Length(3),
+                    Constraint::Min(1),
+                ]
+                .as_ref(),
+            )
+            .split(area);
+
+        let journal_paragraph = Paragraph::new(format!("Journal: {}", self.title));
+        frame.render_widget(journal_paragraph, chunks[0]);
+
+        let (block_style, cursor_style, title) = if self.err_msg.is_empty() {
+            (
+                styles.general.input_block_active,
+                styles.general.input_corsur_active,
+                String::from("Color"),
+            )
+        } else {
+            (
+                styles.general.input_block_invalid,
+                styles.general.input_corsur_invalid,
+                format!("Color : {}", self.err_msg),
+            )
+        };
+
+        self.color_txt.set_style(Style::from(block_style));
+        self.color_txt.set_cursor_style(Style::from(cursor_style));
+        self.color_txt.set_cursor_line_style(Style::default());
+        self.color_txt.set_block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::from(block_style))
+                .title(title),
+        );
+
+        frame.render_widget(&self.color_txt, chunks[1]);
+
+        let footer = Paragraph::new(FOOTER_TEXT)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(footer, chunks[2]);
+    }
+
+    pub fn handle_input(&mut self, input: &Input) -> EntryColorPopupReturn {
+        let has_ctrl = input.modifiers.contains(KeyModifiers::CONTROL);
+        match input.key_code {
+            KeyCode::Esc => EntryColorPopupReturn::Cancel,
+            KeyCode::Char('c') if has_ctrl => EntryColorPopupReturn::Cancel,
+            KeyCode::Enter => match self.parse_color() {
+                Ok(color) => EntryColorPopupReturn::Apply(color),
+                Err(()) => EntryColorPopupReturn::KeepPopup,
+            },
+            _ => {
+                if self.color_txt.input(KeyEvent::from(input)) {
+                    self.err_msg.clear();
+                }
+                EntryColorPopupReturn::KeepPopup
+            }
+        }
+    }
+}
@@ -3,7 +3,8 @@ use chrono::{Datelike, Local, NaiveDate, TimeZone, Utc};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph, Wrap},
     Frame,
 };
@@ -11,25 +12,39 @@ use tui_textarea::{CursorMove, TextArea};
 
 use crate::{
     app::{keymap::Input, App},
-    settings::Settings,
+    settings::{EmptyTitleBehavior, Settings},
 };
 
 use backend::{DataProvider, Entry};
 
-use self::tags::{TagsPopup, TagsPopupReturn};
+use self::{
+    tag_chips::TagChips,
+    tag_suggestions::TagSuggestions,
+    tags::{TagsPopup, TagsPopupReturn},
+};
 
 use super::{ui_functions::centered_rect_exact_height, Styles};
 
+mod tag_chips;
+mod tag_suggestions;
 mod tags;
 
 const FOOTER_TEXT: &str =
     "Enter or <Ctrl-m>: confirm | Esc or <Ctrl-c>: Cancel | Tab: Change focused control | <Ctrl-Space> or <Ctrl-t>: Open tags";
+const DISCARD_FOOTER_TEXT: &str =
+    "Discard changes? Esc or <Ctrl-c>: Discard | Any other key: Continue editing";
 const FOOTER_MARGIN: u16 = 15;
+const TAGS_ACTIVE_TITLE: &str =
+    "Tags - Enter: Add typed tags | Tab: Complete tag | Backspace on empty input: Remove last tag";
 
 pub struct EntryPopup<'a> {
     title_txt: TextArea<'a>,
     date_txt: TextArea<'a>,
+    /// Input for the tags which aren't committed as chips yet.
     tags_txt: TextArea<'a>,
+    tag_chips: TagChips,
+    /// Autocomplete suggestions for the tag being typed in the tags input.
+    tag_suggestions: TagSuggestions,
     priority_txt: TextArea<'a>,
     is_edit_entry: bool,
     active_txt: ActiveText,
@@ -38,6 +53,13 @@ pub struct EntryPopup<'a> {
     tags_err_msg: String,
     priority_err_msg: String,
     tags_popup: Option<TagsPopup>,
+    /// Values of the text boxes when the popup has been opened to check for changes.
+    initial_values: [String; 4],
+    /// Indicates that cancel has been requested while having changes and it's waiting for
+    /// confirmation.
+    confirm_discard: bool,
+    /// Indicates if empty titles must be rejected according to the settings.
+    reject_empty_title: bool,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -77,10 +99,12 @@ impl<'a> EntryPopup<'a> {
             TextArea::default()
         };
 
-        Self {
+        let mut entry_popup = Self {
             title_txt,
             date_txt,
             tags_txt,
+            tag_chips: TagChips::default(),
+            tag_suggestions: TagSuggestions::default(),
             priority_txt,
             is_edit_entry: false,
             active_txt: ActiveText::Title,
@@ -89,10 +113,24 @@ impl<'a> EntryPopup<'a> {
             tags_err_msg: String::default(),
             priority_err_msg: String::default(),
             tags_popup: None,
-        }
+            initial_values: Default::default(),
+            confirm_discard: false,
+            reject_empty_title: settings.empty_title == EmptyTitleBehavior::Reject,
+        };
+
+        entry_popup.initial_values = entry_popup.current_values();
+
+        entry_popup
     }
 
-    pub fn from_entry(entry: &Entry) -> Self {
+    /// Activates the tags text box of the popup.
+    #[must_use]
+    pub fn with_tags_focused(mut self) -> Self {
+        self.active_txt = ActiveText::Tags;
+        self
+    }
+
+    pub fn from_entry(entry: &Entry, settings: &Settings) -> Self {
         let mut title_txt = TextArea::new(vec![entry.title.to_owned()]);
         title_txt.move_cursor(CursorMove::End);
 
@@ -103,10 +141,8 @@ impl<'a> EntryPopup<'a> {
             entry.date.year()
         )]);
 
-        let tags = tags_to_text(&entry.tags);
-
-        let mut tags_txt = TextArea::new(vec![tags]);
-        tags_txt.move_cursor(CursorMove::End);
+        let tags_txt = TextArea::default();
+        let tag_chips = TagChips::new(&entry.tags);
 
         let prio = entry.priority.map(|pr| pr.to_string()).unwrap_or_default();
 
@@ -117,6 +153,8 @@ impl<'a> EntryPopup<'a> {
             title_txt,
             date_txt,
             tags_txt,
+            tag_chips,
+            tag_suggestions: TagSuggestions::default(),
             priority_txt,
             is_edit_entry: true,
             active_txt: ActiveText::Title,
@@ -125,15 +163,44 @@ impl<'a> EntryPopup<'a> {
             tags_err_msg: String::default(),
             priority_err_msg: String::default(),
             tags_popup: None,
+            initial_values: Default::default(),
+            confirm_discard: false,
+            reject_empty_title: settings.empty_title == EmptyTitleBehavior::Reject,
         };
 
         entry_popup.validate_all();
+        entry_popup.initial_values = entry_popup.current_values();
 
         entry_popup
     }
 
+    fn current_values(&self) -> [String; 4] {
+        [
+            self.title_txt.lines()[0].to_owned(),
+            self.date_txt.lines()[0].to_owned(),
+            tags_to_text(&self.current_tags()),
+            self.priority_txt.lines()[0].to_owned(),
+        ]
+    }
+
+    /// Gets the tags of the chips including the text in the tags input which isn't committed yet.
+    fn current_tags(&self) -> Vec<String> {
+        let pending_text = self
+            .tags_txt
+            .lines()
+            .first()
+            .expect("Tags TextBox have one line");
+
+        self.tag_chips.with_pending(pending_text)
+    }
+
+    /// Checks if the values of the text boxes have been changed since opening the popup.
+    pub fn has_changes(&self) -> bool {
+        self.current_values() != self.initial_values
+    }
+
     pub fn render_widget(&mut self, frame: &mut Frame, area: Rect, styles: &Styles) {
-        let mut area = centered_rect_exact_height(70, 17, area);
+        let mut area = centered_rect_exact_height(70, 18, area);
 
         const FOOTER_LEN: u16 = FOOTER_TEXT.len() as u16 + FOOTER_MARGIN;
 
@@ -161,7 +228,7 @@ impl<'a> EntryPopup<'a> {
                     Constraint::Length(3),
                     Constraint::Length(3),
                     Constraint::Length(3),
-                    Constraint::Length(3),
+                    Constraint::Length(4),
                     Constraint::Min(1),
                 ]
                 .as_ref(),
@@ -242,23 +309,17 @@ impl<'a> EntryPopup<'a> {
             );
         }
 
-        if self.tags_err_msg.is_empty() {
+        let tags_block = if self.tags_err_msg.is_empty() {
             let (block, cursor, title) = match self.active_txt {
-                ActiveText::Tags => (
-                    active_block_style,
-                    active_cursor_style,
-                    "Tags - A comma-separated list",
-                ),
+                ActiveText::Tags => (active_block_style, active_cursor_style, TAGS_ACTIVE_TITLE),
                 _ => (reset_style, deactivate_cursor_style, "Tags"),
             };
             self.tags_txt.set_style(block);
             self.tags_txt.set_cursor_style(cursor);
-            self.tags_txt.set_block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .style(block)
-                    .title(title),
-            );
+            Block::default()
+                .borders(Borders::ALL)
+                .style(block)
+                .title(title)
         } else {
             let cursor = if self.active_txt == ActiveText::Tags {
                 invalid_cursor_style
@@ -267,13 +328,11 @@ impl<'a> EntryPopup<'a> {
             };
             self.tags_txt.set_style(invalid_block_style);
             self.tags_txt.set_cursor_style(cursor);
-            self.tags_txt.set_block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .style(invalid_block_style)
-                    .title(format!("Tags : {}", self.date_err_msg)),
-            );
-        }
+            Block::default()
+                .borders(Borders::ALL)
+                .style(invalid_block_style)
+                .title(format!("Tags : {}", self.tags_err_msg))
+        };
 
         if self.priority_err_msg.is_empty() {
             let (block, cursor) = match self.active_txt {
@@ -307,9 +366,15 @@ impl<'a> EntryPopup<'a> {
         frame.render_widget(&self.title_txt, chunks[0]);
         frame.render_widget(&self.date_txt, chunks[1]);
         frame.render_widget(&self.priority_txt, chunks[2]);
-        frame.render_widget(&self.tags_txt, chunks[3]);
+        self.render_tags(frame, chunks[3], tags_block);
+
+        let footer_text = if self.confirm_discard {
+            DISCARD_FOOTER_TEXT
+        } else {
+            FOOTER_TEXT
+        };
 
-        let footer = Paragraph::new(FOOTER_TEXT)
+        let footer = Paragraph::new(footer_text)
             .alignment(Alignment::Center)
             .wrap(Wrap { trim: false })
             .block(
@@ -320,11 +385,46 @@ impl<'a> EntryPopup<'a> {
 
         frame.render_widget(footer, chunks[4]);
 
+        if self.active_txt == ActiveText::Tags {
+            self.tag_suggestions.render_widget(frame, chunks[3], styles);
+        }
+
         if let Some(tags_popup) = self.tags_popup.as_mut() {
             tags_popup.render_widget(frame, area, styles)
         }
     }
 
+    /// Renders the committed tags as chips in the first line and the tags input in the second.
+    fn render_tags(&self, frame: &mut Frame, area: Rect, block: Block) {
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Length(1)].as_ref())
+            .split(inner);
+
+        // Chips are styled with modifiers only to stay readable without colors.
+        let chip_style = Style::default().add_modifier(Modifier::REVERSED);
+        let chips_line = if self.tag_chips.tags().is_empty() {
+            Line::from(Span::styled(
+                "No tags",
+                Style::default().add_modifier(Modifier::DIM | Modifier::ITALIC),
+            ))
+        } else {
+            let spans: Vec<Span> = self
+                .tag_chips
+                .tags()
+                .iter()
+                .flat_map(|tag| [Span::styled(format!(" {tag} "), chip_style), Span::raw(" ")])
+                .collect();
+            Line::from(spans)
+        };
+
+        frame.render_widget(Paragraph::new(chips_line), rows[0]);
+        frame.render_widget(&self.tags_txt, rows[1]);
+    }
+
     pub fn is_input_valid(&self) -> bool {
         self.title_err_msg.is_empty()
             && self.date_err_msg.is_empty()
@@ -340,7 +440,7 @@ impl<'a> EntryPopup<'a> {
     }
 
     fn validate_title(&mut self) {
-        if self.title_txt.lines()[0].is_empty() {
+        if self.reject_empty_title && self.title_txt.lines()[0].trim().is_empty() {
             self.title_err_msg = "Title can't be empty".into();
         } else {
             self.title_err_msg.clear();
@@ -356,12 +456,7 @@ impl<'a> EntryPopup<'a> {
     }
 
     fn validate_tags(&mut self) {
-        let tags = text_to_tags(
-            self.tags_txt
-                .lines()
-                .first()
-                .expect("Tags TextBox have one line"),
-        );
+        let tags = self.current_tags();
         if tags.iter().any(|tag| tag.contains(',')) {
             self.tags_err_msg = "Tags are invalid".into();
         } else {
@@ -389,11 +484,33 @@ impl<'a> EntryPopup<'a> {
             return Ok(EntryPopupInputReturn::KeepPopup);
         }
 
+        if self.active_txt == ActiveText::Tags
+            && self.tag_suggestions.is_visible()
+            && self.handle_tag_suggestions_input(input)
+        {
+            return Ok(EntryPopupInputReturn::KeepPopup);
+        }
+
         let has_ctrl = input.modifiers.contains(KeyModifiers::CONTROL);
 
+        let is_cancel = match input.key_code {
+            KeyCode::Esc => true,
+            KeyCode::Char('c') => has_ctrl,
+            _ => false,
+        };
+
+        if is_cancel {
+            return Ok(self.handle_cancel(app.settings.confirm_discard_entry_form));
+        }
+
+        // Any other input dismisses the discard confirmation
+        self.confirm_discard = false;
+
+        if self.active_txt == ActiveText::Tags && self.handle_tag_chips_input(input) {
+            return Ok(EntryPopupInputReturn::KeepPopup);
+        }
+
         match input.key_code {
-            KeyCode::Esc => Ok(EntryPopupInputReturn::Cancel),
-            KeyCode::Char('c') if has_ctrl => Ok(EntryPopupInputReturn::Cancel),
             KeyCode::Enter => self.handle_confirm(app).await,
             KeyCode::Tab | KeyCode::Down => {
                 self.active_txt = match self.active_txt {
@@ -417,13 +534,9 @@ impl<'a> EntryPopup<'a> {
                 debug_assert!(self.tags_popup.is_none());
 
                 let tags = app.get_all_tags();
-                let tags_text = self
-                    .tags_txt
-                    .lines()
-                    .first()
-                    .expect("Tags text box has one line");
+                let tags_text = tags_to_text(&self.current_tags());
 
-                self.tags_popup = Some(TagsPopup::new(tags_text, tags));
+                self.tags_popup = Some(TagsPopup::new(&tags_text, tags));
 
                 Ok(EntryPopupInputReturn::KeepPopup)
             }
@@ -442,6 +555,11 @@ impl<'a> EntryPopup<'a> {
                     ActiveText::Tags => {
                         if self.tags_txt.input(KeyEvent::from(input)) {
                             self.validate_tags();
+                            self.tag_suggestions.update(
+                                &self.tags_txt.lines()[0],
+                                &app.get_all_tags(),
+                                self.tag_chips.tags(),
+                            );
                         }
                     }
                     ActiveText::Priority => {
@@ -455,6 +573,94 @@ impl<'a> EntryPopup<'a> {
         }
     }
 
+    /// Handles cancel requests, asking for confirmation first if enabled and the values have been
+    /// changed.
+    fn handle_cancel(&mut self, confirm_enabled: bool) -> EntryPopupInputReturn {
+        if confirm_enabled && !self.confirm_discard && self.has_changes() {
+            self.confirm_discard = true;
+            EntryPopupInputReturn::KeepPopup
+        } else {
+            EntryPopupInputReturn::Cancel
+        }
+    }
+
+    /// Commits the typed tags as chips on enter and removes the last chip on backspace while the
+    /// tags input is empty. Returns if the input has been handled.
+    fn handle_tag_chips_input(&mut self, input: &Input) -> bool {
+        if !input.modifiers.is_empty() {
+            return false;
+        }
+
+        let pending_text = self.tags_txt.lines()[0].to_owned();
+        match input.key_code {
+            // Enter confirms the popup when there are no typed tags to commit
+            KeyCode::Enter if !pending_text.trim().is_empty() => {
+                self.tag_chips.commit(&pending_text);
+                self.tags_txt = TextArea::default();
+            }
+            KeyCode::Backspace if pending_text.is_empty() => {
+                self.tag_chips.remove_last();
+            }
+            _ => return false,
+        }
+
+        self.validate_tags();
+        true
+    }
+
+    /// Handles navigating, accepting and dismissing the tag suggestions while they are shown.
+    /// Returns if the input has been handled.
+    fn handle_tag_suggestions_input(&mut self, input: &Input) -> bool {
+        let has_ctrl = input.modifiers.contains(KeyModifiers::CONTROL);
+        let suggestion = match input.key_code {
+            KeyCode::Esc => {
+                self.tag_suggestions.dismiss();
+                return true;
+            }
+            KeyCode::Down => {
+                self.tag_suggestions.cycle_selection(true);
+                return true;
+            }
+            KeyCode::Up => {
+                self.tag_suggestions.cycle_selection(false);
+                return true;
+            }
+            KeyCode::Char('n') if has_ctrl => {
+                self.tag_suggestions.cycle_selection(true);
+                return true;
+            }
+            KeyCode::Char('p') if has_ctrl => {
+                self.tag_suggestions.cycle_selection(false);
+                return true;
+            }
+            KeyCode::Tab => self.tag_suggestions.selected_or_first(),
+            // Enter commits the typed text as it is if no suggestion is highlighted.
+            KeyCode::Enter if !has_ctrl => self.tag_suggestions.selected(),
+            _ => None,
+        };
+
+        let Some(suggestion) = suggestion.map(str::to_owned) else {
+            return false;
+        };
+
+        self.accept_tag_suggestion(&suggestion);
+        true
+    }
+
+    /// Replaces the tag being typed with the given suggestion, committing the typed tags as chips.
+    fn accept_tag_suggestion(&mut self, suggestion: &str) {
+        let pending_text = self.tags_txt.lines()[0].to_owned();
+        let committed_text = match pending_text.rsplit_once(',') {
+            Some((before, _)) => format!("{before},{suggestion}"),
+            None => suggestion.to_owned(),
+        };
+
+        self.tag_chips.commit(&committed_text);
+        self.tags_txt = TextArea::default();
+        self.tag_suggestions = TagSuggestions::default();
+        self.validate_tags();
+    }
+
     pub fn handle_tags_popup_input(&mut self, input: &Input) {
         let tags_popup = self
             .tags_popup
@@ -465,8 +671,8 @@ impl<'a> EntryPopup<'a> {
             TagsPopupReturn::Keep => {}
             TagsPopupReturn::Cancel => self.tags_popup = None,
             TagsPopupReturn::Apply(tags_text) => {
-                self.tags_txt = TextArea::new(vec![tags_text]);
-                self.tags_txt.move_cursor(CursorMove::End);
+                self.tag_chips = TagChips::new(&text_to_tags(&tags_text));
+                self.tags_txt = TextArea::default();
                 self.active_txt = ActiveText::Tags;
                 self.tags_popup = None;
             }
@@ -491,12 +697,11 @@ impl<'a> EntryPopup<'a> {
             .with_ymd_and_hms(date.year(), date.month(), date.day(), 0, 0, 0)
             .unwrap();
 
-        let tags = text_to_tags(
-            self.tags_txt
-                .lines()
-                .first()
-                .expect("Tags TextBox have one line"),
-        );
+        // Duplicated tags are passed on to be removed by the app, which notifies about them if
+        // configured in the settings.
+        let tags = self
+            .tag_chips
+            .with_pending_duplicates(&self.tags_txt.lines()[0]);
 
         let priority = match self.priority_txt.lines().first().unwrap() {
             num if num.is_empty() => None,
@@ -523,3 +728,117 @@ fn text_to_tags(text: &str) -> Vec<String> {
         .map(|tag| String::from(tag.trim()))
         .collect()
 }
+
+#[cfg(test)]
+mod test {
+    use crate::app::test::mock::MockDataProvider;
+
+    use super::*;
+
+    #[test]
+    fn cancel_confirm_with_changes() {
+        let mut entry_popup = EntryPopup::new_entry(&Settings::default());
+        entry_popup.title_txt.insert_str("Title");
+        assert!(entry_popup.has_changes());
+
+        assert_eq!(
+            entry_popup.handle_cancel(true),
+            EntryPopupInputReturn::KeepPopup
+        );
+        assert!(entry_popup.confirm_discard);
+
+        // Second cancel discards the changes
+        assert_eq!(
+            entry_popup.handle_cancel(true),
+            EntryPopupInputReturn::Cancel
+        );
+    }
+
+    #[test]
+    fn cancel_without_changes() {
+        let entry = Entry::new(
+            0,
+            Utc::now(),
+            String::from("Title"),
+            String::new(),
+            vec![String::from("Tag")],
+            Some(1),
+        );
+        let mut entry_popup = EntryPopup::from_entry(&entry, &Settings::default());
+        assert!(!entry_popup.has_changes());
+
+        assert_eq!(
+            entry_popup.handle_cancel(true),
+            EntryPopupInputReturn::Cancel
+        );
+        assert!(!entry_popup.confirm_discard);
+
+        // Changes reverted to the initial values
+        entry_popup.title_txt.insert_char('s');
+        entry_popup.title_txt.delete_char();
+        assert!(!entry_popup.has_changes());
+    }
+
+    #[test]
+    fn cancel_confirm_disabled() {
+        let mut entry_popup = EntryPopup::new_entry(&Settings::default());
+        entry_popup.title_txt.insert_str("Title");
+
+        assert_eq!(
+            entry_popup.handle_cancel(false),
+            EntryPopupInputReturn::Cancel
+        );
+        assert!(!entry_popup.confirm_discard);
+    }
+
+    #[test]
+    fn empty_title_validation() {
+        let mut entry_popup = EntryPopup::new_entry(&Settings::default());
+        entry_popup.validate_title();
+        assert!(entry_popup.title_err_msg.is_empty());
+
+        let settings = Settings {
+            empty_title: EmptyTitleBehavior::Reject,
+            ..Default::default()
+        };
+        let mut entry_popup = EntryPopup::new_entry(&settings);
+        entry_popup.title_txt.insert_str("  ");
+        entry_popup.validate_title();
+        assert!(!entry_popup.title_err_msg.is_empty());
+    }
+
+    #[tokio::test]
+    async fn accept_tag_suggestion() {
+        let mut app = App::new(MockDataProvider::new_with_data(), Settings::default());
+        app.load_entries().await.unwrap();
+
+        let mut entry_popup = EntryPopup::new_entry(&app.settings).with_tags_focused();
+
+        for ch in "New, tag".chars() {
+            let input = Input::new(KeyCode::Char(ch), KeyModifiers::NONE);
+            entry_popup.handle_input(&input, &mut app).await.unwrap();
+        }
+        assert!(entry_popup.tag_suggestions.is_visible());
+
+        // Esc dismisses the suggestions without canceling the popup
+        let esc = Input::new(KeyCode::Esc, KeyModifiers::NONE);
+        let result = entry_popup.handle_input(&esc, &mut app).await.unwrap();
+        assert_eq!(result, EntryPopupInputReturn::KeepPopup);
+        assert!(!entry_popup.tag_suggestions.is_visible());
+
+        let input = Input::new(KeyCode::Char(' '), KeyModifiers::NONE);
+        entry_popup.handle_input(&input, &mut app).await.unwrap();
+
+        let down = Input::new(KeyCode::Down, KeyModifiers::NONE);
+        entry_popup.handle_input(&down, &mut app).await.unwrap();
+        entry_popup.handle_input(&down, &mut app).await.unwrap();
+
+        let enter = Input::new(KeyCode::Enter, KeyModifiers::NONE);
+        let result = entry_popup.handle_input(&enter, &mut app).await.unwrap();
+        assert_eq!(result, EntryPopupInputReturn::KeepPopup);
+
+        assert_eq!(entry_popup.tag_chips.tags(), ["New", "Tag 2"]);
+        assert!(entry_popup.tags_txt.lines()[0].is_empty());
+        assert!(!entry_popup.tag_suggestions.is_visible());
+    }
+}
@@ -0,0 +1,128 @@
+use super::text_to_tags;
+
+/// Tags of the journal in the entry popup, rendered as chips and added by committing the text
+/// typed in the tags input.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TagChips {
+    tags: Vec<String>,
+    /// Duplicated tags skipped while adding the chips, kept to notify about them on saving.
+    duplicates: Vec<String>,
+}
+
+impl TagChips {
+    pub fn new(tags: &[String]) -> Self {
+        let mut chips = Self::default();
+        tags.iter().for_each(|tag| {
+            chips.add(tag);
+        });
+
+        chips
+    }
+
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Adds the tags in the given text as chips, skipping the empty and the duplicated ones.
+    /// Returns if any tag has been added.
+    pub fn commit(&mut self, text: &str) -> bool {
+        let mut added = false;
+        for tag in text_to_tags(text) {
+            added |= self.add(&tag);
+        }
+
+        added
+    }
+
+    /// Removes the last chip returning its tag.
+    pub fn remove_last(&mut self) -> Option<String> {
+        let tag = self.tags.pop()?;
+        self.duplicates.retain(|duplicate| *duplicate != tag);
+
+        Some(tag)
+    }
+
+    /// Gets the tags of the chips combined with the tags in the given text which isn't committed
+    /// yet.
+    pub fn with_pending(&self, text: &str) -> Vec<String> {
+        let mut chips = self.clone();
+        chips.commit(text);
+        chips.tags
+    }
+
+    /// Gets the same tags as [`Self::with_pending()`] followed by the skipped duplicates, so they
+    /// can be removed and reported while saving the journal.
+    pub fn with_pending_duplicates(&self, text: &str) -> Vec<String> {
+        let mut chips = self.clone();
+        chips.commit(text);
+        chips.tags.extend(chips.duplicates);
+        chips.tags
+    }
+
+    fn add(&mut self, tag: &str) -> bool {
+        let tag = tag.trim();
+        if tag.is_empty() {
+            return false;
+        }
+
+        if self.tags.iter().any(|t| t == tag) {
+            self.duplicates.push(tag.to_owned());
+            return false;
+        }
+
+        self.tags.push(tag.to_owned());
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn add_remove_chips() {
+        let mut chips = TagChips::new(&[String::from("Work"), String::from("Work")]);
+        assert_eq!(chips.tags(), ["Work"]);
+
+        assert!(chips.commit("  Home "));
+        assert_eq!(chips.tags(), ["Work", "Home"]);
+
+        // Empty input and duplicates aren't added
+        assert!(!chips.commit(""));
+        assert!(!chips.commit("   "));
+        assert!(!chips.commit("Home"));
+        assert_eq!(chips.tags(), ["Work", "Home"]);
+
+        // Comma-separated text is split by the tag parser
+        assert!(chips.commit("Travel, Work,Books,"));
+        assert_eq!(chips.tags(), ["Work", "Home", "Travel", "Books"]);
+
+        assert_eq!(chips.remove_last().as_deref(), Some("Books"));
+        assert_eq!(chips.remove_last().as_deref(), Some("Travel"));
+        assert_eq!(chips.tags(), ["Work", "Home"]);
+
+        assert_eq!(chips.with_pending("Ideas"), ["Work", "Home", "Ideas"]);
+        assert_eq!(chips.tags(), ["Work", "Home"]);
+
+        chips.remove_last();
+        chips.remove_last();
+        assert!(chips.remove_last().is_none());
+        assert!(chips.tags().is_empty());
+    }
+
+    #[test]
+    fn keep_skipped_duplicates() {
+        let mut chips = TagChips::new(&[String::from("Work")]);
+        chips.commit("Home, Work");
+        assert_eq!(chips.tags(), ["Work", "Home"]);
+        assert_eq!(
+            chips.with_pending_duplicates("Home"),
+            ["Work", "Home", "Work", "Home"]
+        );
+
+        // Duplicates of removed chips aren't kept
+        chips.remove_last();
+        chips.remove_last();
+        assert!(chips.with_pending_duplicates("").is_empty());
+    }
+}
@@ -0,0 +1,157 @@
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+use crate::app::ui::Styles;
+
+/// Maximum number of suggestions shown at once in the dropdown.
+const MAX_VISIBLE: u16 = 5;
+const MAX_WIDTH: u16 = 30;
+
+/// Autocomplete suggestions for the tag being typed in the tags input of the entry popup, drawn
+/// from the existing tags and rendered as a dropdown below the input.
+#[derive(Debug, Default)]
+pub struct TagSuggestions {
+    matches: Vec<String>,
+    state: ListState,
+    /// Suggestions are hidden until the typed text changes again.
+    dismissed: bool,
+}
+
+impl TagSuggestions {
+    /// Updates the suggestions with the tags starting with the last tag in the given text,
+    /// ignoring the case and skipping the tags which are added already.
+    pub fn update(&mut self, text: &str, all_tags: &[String], added_tags: &[String]) {
+        let prefix = text.rsplit(',').next().unwrap_or_default().trim();
+        let prefix = prefix.to_lowercase();
+
+        self.matches = if prefix.is_empty() {
+            Vec::new()
+        } else {
+            all_tags
+                .iter()
+                .filter(|tag| tag.to_lowercase().starts_with(&prefix))
+                .filter(|tag| !added_tags.contains(tag))
+                .cloned()
+                .collect()
+        };
+
+        self.state.select(None);
+        self.dismissed = false;
+    }
+
+    /// Checks if the dropdown is shown.
+    pub fn is_visible(&self) -> bool {
+        !self.dismissed && !self.matches.is_empty()
+    }
+
+    /// Hides the suggestions until the typed text changes again.
+    pub fn dismiss(&mut self) {
+        self.dismissed = true;
+    }
+
+    /// Gets the highlighted suggestion if any.
+    pub fn selected(&self) -> Option<&str> {
+        self.state
+            .selected()
+            .and_then(|idx| self.matches.get(idx))
+            .map(String::as_str)
+    }
+
+    /// Gets the highlighted suggestion falling back to the first one.
+    pub fn selected_or_first(&self) -> Option<&str> {
+        self.selected()
+            .or_else(|| self.matches.first().map(String::as_str))
+    }
+
+    pub fn cycle_selection(&mut self, next: bool) {
+        if self.matches.is_empty() {
+            return;
+        }
+
+        let last_index = self.matches.len() - 1;
+        let new_index = match (self.state.selected(), next) {
+            (Some(idx), true) if idx < last_index => idx + 1,
+            (Some(idx), false) if idx > 0 => idx - 1,
+            (_, true) => 0,
+            (_, false) => last_index,
+        };
+
+        self.state.select(Some(new_index));
+    }
+
+    /// Renders the dropdown below the given area of the tags input, within the bounds of the
+    /// given frame area.
+    pub fn render_widget(&mut self, frame: &mut Frame, input_area: Rect, styles: &Styles) {
+        if !self.is_visible() {
+            return;
+        }
+
+        let frame_area = frame.area();
+        let y = input_area.bottom().saturating_sub(1);
+        let height = (self.matches.len() as u16).min(MAX_VISIBLE) + 2;
+        let height = height.min(frame_area.bottom().saturating_sub(y));
+        let width = MAX_WIDTH.min(input_area.width.saturating_sub(2));
+        if height < 3 || width == 0 {
+            return;
+        }
+
+        let area = Rect::new(input_area.x + 1, y, width, height);
+
+        let items: Vec<ListItem> = self
+            .matches
+            .iter()
+            .map(|tag| ListItem::new(tag.as_str()).style(Style::reset()))
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .style(Style::from(styles.general.input_block_active))
+                    .title("Suggestions"),
+            )
+            .highlight_style(Style::from(styles.general.list_highlight_active))
+            .highlight_symbol(">> ");
+
+        frame.render_widget(Clear, area);
+        frame.render_stateful_widget(list, area, &mut self.state);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn filter_by_prefix() {
+        let all_tags = vec![
+            String::from("Work"),
+            String::from("workout"),
+            String::from("Home"),
+        ];
+
+        let mut suggestions = TagSuggestions::default();
+        suggestions.update("Home, wo", &all_tags, &[]);
+        assert_eq!(suggestions.matches, ["Work", "workout"]);
+        assert!(suggestions.is_visible());
+        assert_eq!(suggestions.selected(), None);
+        assert_eq!(suggestions.selected_or_first(), Some("Work"));
+
+        suggestions.cycle_selection(false);
+        assert_eq!(suggestions.selected(), Some("workout"));
+
+        // Added tags aren't suggested
+        suggestions.update("wo", &all_tags, &[String::from("Work")]);
+        assert_eq!(suggestions.matches, ["workout"]);
+
+        suggestions.dismiss();
+        assert!(!suggestions.is_visible());
+
+        suggestions.update("", &all_tags, &[]);
+        assert!(suggestions.matches.is_empty());
+    }
+}
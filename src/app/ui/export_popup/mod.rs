@@ -1,6 +1,7 @@
 use std::{env, path::PathBuf};
 
 use backend::{DataProvider, Entry};
+use chrono::{Local, NaiveDate};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -10,28 +11,131 @@ use ratatui::{
 };
 use tui_textarea::{CursorMove, TextArea};
 
-use crate::app::{keymap::Input, App};
+use crate::{
+    app::{keymap::Input, App, EntryExportFormat},
+    settings::PreviewLongLines,
+};
 
 use super::{ui_functions::centered_rect_exact_height, PopupReturn, Styles};
 
-type ExportPopupInputReturn = PopupReturn<(PathBuf, Option<u32>)>;
+use preview::{ExportPreview, PreviewReturn};
+
+mod preview;
+
+type ExportPopupInputReturn = PopupReturn<ExportRequest>;
 
-const FOOTER_TEXT: &str = "Enter: confirm | Esc or <Ctrl-c>: Cancel";
+const FOOTER_TEXT: &str =
+    "Enter: confirm | <Ctrl-p>: Preview | <Ctrl-t>: Toggle format | Esc or <Ctrl-c>: Cancel";
+const MULTI_SELECT_FOOTER_TEXT: &str = "Enter: confirm | <Ctrl-p>: Preview | <Ctrl-t>: Toggle format | <Ctrl-e>: Markdown files | Esc or <Ctrl-c>: Cancel";
 const FOOTER_MARGINE: u16 = 8;
 const DEFAULT_FILE_NAME: &str = "tjournal_export.json";
+const MULTI_SELECT_JOURNAL_NAME: &str = "journals";
 
 pub struct ExportPopup<'a> {
     path_txt: TextArea<'a>,
     path_err_msg: String,
     entry_id: Option<u32>,
     paragraph_text: String,
+    preview_long_lines: PreviewLongLines,
+    preview: Option<ExportPreview>,
+    format: ExportFormat,
+}
+
+/// Format of the exported file, where the current entry can be exported as content, Markdown
+/// or HTML and the selected entries as JSON, Markdown or CSV metadata.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExportFormat {
+    Content,
+    Json,
+    Markdown,
+    Html,
+    Csv,
+}
+
+impl ExportFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Content => "txt",
+            ExportFormat::Json => "json",
+            ExportFormat::Markdown => "md",
+            ExportFormat::Html => "html",
+            ExportFormat::Csv => "csv",
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            ExportFormat::Content => "Content",
+            ExportFormat::Json => "JSON",
+            ExportFormat::Markdown => "Markdown",
+            ExportFormat::Html => "HTML",
+            ExportFormat::Csv => "CSV (Metadata only)",
+        }
+    }
+
+    /// Returns the format following this one among the formats of the given export mode.
+    fn next(self, multi_select: bool) -> Self {
+        match (self, multi_select) {
+            (ExportFormat::Json, true) => ExportFormat::Markdown,
+            (ExportFormat::Markdown, true) => ExportFormat::Csv,
+            (_, true) => ExportFormat::Json,
+            (ExportFormat::Content, false) => ExportFormat::Markdown,
+            (ExportFormat::Markdown, false) => ExportFormat::Html,
+            (_, false) => ExportFormat::Content,
+        }
+    }
+
+    fn entry_format(self) -> EntryExportFormat {
+        match self {
+            ExportFormat::Markdown => EntryExportFormat::Markdown,
+            ExportFormat::Html => EntryExportFormat::Html,
+            ExportFormat::Content | ExportFormat::Json | ExportFormat::Csv => {
+                EntryExportFormat::Content
+            }
+        }
+    }
+}
+
+impl From<EntryExportFormat> for ExportFormat {
+    fn from(format: EntryExportFormat) -> Self {
+        match format {
+            EntryExportFormat::Content => ExportFormat::Content,
+            EntryExportFormat::Markdown => ExportFormat::Markdown,
+            EntryExportFormat::Html => ExportFormat::Html,
+        }
+    }
+}
+
+/// Requests of the export popup which need the app to be fulfilled.
+pub enum ExportRequest {
+    /// Write the export of the given entry in the format to the path.
+    WriteEntry(PathBuf, u32, EntryExportFormat),
+    /// Show a preview of the export of the given entry in the format.
+    PreviewEntry(u32, EntryExportFormat),
+    /// Write the selected entries as transfer JSON to the path.
+    Write(PathBuf),
+    /// Show a preview of the export of the selected entries as transfer JSON.
+    Preview,
+    /// Write the selected entries as sections of a single Markdown file to the path.
+    WriteMarkdown(PathBuf),
+    /// Show a preview of the export of the selected entries as a single Markdown file.
+    PreviewMarkdown,
+    /// Write the metadata of the selected entries as CSV to the path.
+    WriteCsv(PathBuf),
+    /// Show a preview of the CSV metadata of the selected entries.
+    PreviewCsv,
+    /// Write the selected entries as Markdown files with an index into the directory.
+    MarkdownFiles(PathBuf),
 }
 
 impl ExportPopup<'_> {
     pub fn create_entry_content<D: DataProvider>(
         entry: &Entry,
+        format: EntryExportFormat,
         app: &App<D>,
     ) -> anyhow::Result<Self> {
+        let format = ExportFormat::from(format);
+
         let mut default_path = if let Some(path) = &app.settings.export.default_path {
             path.clone()
         } else {
@@ -40,7 +144,16 @@ impl ExportPopup<'_> {
 
         // Add filename if it's not already defined
         if default_path.extension().is_none() {
-            default_path.push(format!("{}.txt", entry.title.as_str()));
+            let file_name = match &app.settings.export.file_name_pattern {
+                Some(pattern) => expand_file_name_pattern(
+                    pattern,
+                    Local::now().date_naive(),
+                    format.extension(),
+                    &entry.title,
+                ),
+                None => format!("{}.{}", entry.title.as_str(), format.extension()),
+            };
+            default_path.push(file_name);
         }
 
         let mut path_txt = TextArea::new(vec![default_path.to_string_lossy().to_string()]);
@@ -53,6 +166,9 @@ impl ExportPopup<'_> {
             path_err_msg: String::default(),
             entry_id: Some(entry.id),
             paragraph_text,
+            preview_long_lines: app.settings.export.preview_long_lines,
+            preview: None,
+            format,
         };
 
         export_popup.validate_path();
@@ -69,7 +185,16 @@ impl ExportPopup<'_> {
 
         // Add filename if it's not already defined
         if default_path.extension().is_none() {
-            default_path.push(DEFAULT_FILE_NAME);
+            let file_name = match &app.settings.export.file_name_pattern {
+                Some(pattern) => expand_file_name_pattern(
+                    pattern,
+                    Local::now().date_naive(),
+                    "json",
+                    MULTI_SELECT_JOURNAL_NAME,
+                ),
+                None => DEFAULT_FILE_NAME.into(),
+            };
+            default_path.push(file_name);
         }
 
         let mut path_txt = TextArea::new(vec![default_path.to_string_lossy().to_string()]);
@@ -85,6 +210,9 @@ impl ExportPopup<'_> {
             path_err_msg: String::default(),
             entry_id: None,
             paragraph_text,
+            preview_long_lines: app.settings.export.preview_long_lines,
+            preview: None,
+            format: ExportFormat::Json,
         };
 
         export_popup.validate_path();
@@ -114,17 +242,28 @@ impl ExportPopup<'_> {
         self.entry_id.is_none()
     }
 
+    /// Shows the preview of the export with the given content which will be written to the file.
+    pub fn show_preview(&mut self, content: String) {
+        self.preview = Some(ExportPreview::new(content, self.preview_long_lines));
+    }
+
     pub fn render_widget(&mut self, frame: &mut Frame, area: Rect, styles: &Styles) {
         let mut area = centered_rect_exact_height(70, 11, area);
 
-        if area.width < FOOTER_TEXT.len() as u16 + FOOTER_MARGINE {
+        let footer_text = if self.is_multi_select_mode() {
+            MULTI_SELECT_FOOTER_TEXT
+        } else {
+            FOOTER_TEXT
+        };
+
+        if area.width < footer_text.len() as u16 + FOOTER_MARGINE {
             area.height += 1;
         }
 
         let title = if self.is_multi_select_mode() {
             "Export journals"
         } else {
-            "Export journal content"
+            "Export journal"
         };
 
         let block = Block::default().borders(Borders::ALL).title(title);
@@ -147,8 +286,8 @@ impl ExportPopup<'_> {
             )
             .split(area);
 
-        let journal_paragraph =
-            Paragraph::new(self.paragraph_text.as_str()).wrap(Wrap { trim: false });
+        let paragraph_text = format!("{}\nFormat: {}", self.paragraph_text, self.format.name());
+        let journal_paragraph = Paragraph::new(paragraph_text).wrap(Wrap { trim: false });
         frame.render_widget(journal_paragraph, chunks[0]);
 
         if self.path_err_msg.is_empty() {
@@ -179,18 +318,52 @@ impl ExportPopup<'_> {
 
         frame.render_widget(&self.path_txt, chunks[1]);
 
-        let footer = Paragraph::new(FOOTER_TEXT)
+        let footer = Paragraph::new(footer_text)
             .alignment(Alignment::Center)
             .wrap(Wrap { trim: false });
 
         frame.render_widget(footer, chunks[3]);
+
+        if let Some(preview) = self.preview.as_mut() {
+            preview.render_widget(frame, frame.area());
+        }
     }
 
     pub fn handle_input(&mut self, input: &Input) -> ExportPopupInputReturn {
+        if let Some(preview) = self.preview.as_mut() {
+            return match preview.handle_input(input) {
+                PreviewReturn::Keep => ExportPopupInputReturn::KeepPopup,
+                PreviewReturn::Close => {
+                    self.preview = None;
+                    ExportPopupInputReturn::KeepPopup
+                }
+                PreviewReturn::Confirm => {
+                    self.preview = None;
+                    self.handle_confirm()
+                }
+            };
+        }
+
         let has_ctrl = input.modifiers.contains(KeyModifiers::CONTROL);
         match input.key_code {
             KeyCode::Esc => ExportPopupInputReturn::Cancel,
             KeyCode::Char('c') if has_ctrl => ExportPopupInputReturn::Cancel,
+            KeyCode::Char('p') if has_ctrl => {
+                let request = match (self.entry_id, self.format) {
+                    (Some(id), format) => ExportRequest::PreviewEntry(id, format.entry_format()),
+                    (None, ExportFormat::Markdown) => ExportRequest::PreviewMarkdown,
+                    (None, ExportFormat::Csv) => ExportRequest::PreviewCsv,
+                    (None, _) => ExportRequest::Preview,
+                };
+                ExportPopupInputReturn::Apply(request)
+            }
+            KeyCode::Char('t') if has_ctrl => {
+                self.toggle_format();
+                ExportPopupInputReturn::KeepPopup
+            }
+            KeyCode::Char('e') if has_ctrl && self.is_multi_select_mode() => {
+                self.handle_markdown_files()
+            }
             KeyCode::Enter => self.handle_confirm(),
             _ => {
                 if self.path_txt.input(KeyEvent::from(input)) {
@@ -201,20 +374,94 @@ impl ExportPopup<'_> {
         }
     }
 
+    fn get_path(&self) -> PathBuf {
+        self.path_txt
+            .lines()
+            .first()
+            .expect("Path Textbox should always have one line")
+            .parse()
+            .expect("PathBuf from string should never fail")
+    }
+
     fn handle_confirm(&mut self) -> ExportPopupInputReturn {
         self.validate_path();
         if !self.is_input_valid() {
             return ExportPopupInputReturn::KeepPopup;
         }
 
-        let path: PathBuf = self
-            .path_txt
-            .lines()
-            .first()
-            .expect("Path Textbox should always have one line")
-            .parse()
-            .expect("PathBuf from string should never fail");
+        let path = self.get_path();
+        let request = match (self.entry_id, self.format) {
+            (Some(id), format) => ExportRequest::WriteEntry(path, id, format.entry_format()),
+            (None, ExportFormat::Markdown) => ExportRequest::WriteMarkdown(path),
+            (None, ExportFormat::Csv) => ExportRequest::WriteCsv(path),
+            (None, _) => ExportRequest::Write(path),
+        };
+
+        ExportPopupInputReturn::Apply(request)
+    }
+
+    /// Switches between the export formats, replacing the extension of the path if it's the
+    /// one of the previous format.
+    fn toggle_format(&mut self) {
+        let new_format = self.format.next(self.is_multi_select_mode());
+
+        let path = self.get_path();
+        if path
+            .extension()
+            .is_some_and(|ext| ext == self.format.extension())
+        {
+            let new_path = path.with_extension(new_format.extension());
+            self.path_txt = TextArea::new(vec![new_path.to_string_lossy().to_string()]);
+            self.path_txt.move_cursor(CursorMove::End);
+        }
 
-        ExportPopupInputReturn::Apply((path, self.entry_id))
+        self.format = new_format;
+    }
+
+    /// Requests writing the Markdown files into the directory in the path, where the extension
+    /// of the path is dropped to export next to the suggested file.
+    fn handle_markdown_files(&mut self) -> ExportPopupInputReturn {
+        self.validate_path();
+        if !self.is_input_valid() {
+            return ExportPopupInputReturn::KeepPopup;
+        }
+
+        let dir = self.get_path().with_extension("");
+
+        ExportPopupInputReturn::Apply(ExportRequest::MarkdownFiles(dir))
+    }
+}
+
+/// Expands the placeholders in the given file name pattern:
+/// - `{date}`: The given date in the format `YYYY-MM-DD`.
+/// - `{format}`: The extension of the exported file.
+/// - `{journal}`: The title of the exported journal.
+fn expand_file_name_pattern(pattern: &str, date: NaiveDate, format: &str, journal: &str) -> String {
+    pattern
+        .replace("{date}", &date.format("%Y-%m-%d").to_string())
+        .replace("{format}", format)
+        .replace("{journal}", journal)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn file_name_pattern_expansion() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 7).unwrap();
+
+        assert_eq!(
+            expand_file_name_pattern("{date}_{journal}.{format}", date, "txt", "Work"),
+            "2024-03-07_Work.txt"
+        );
+        assert_eq!(
+            expand_file_name_pattern("{journal}-{journal}_{date}.{format}", date, "json", "all"),
+            "all-all_2024-03-07.json"
+        );
+        assert_eq!(
+            expand_file_name_pattern("export.md", date, "txt", "Work"),
+            "export.md"
+        );
     }
 }
@@ -0,0 +1,156 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{app::keymap::Input, settings::PreviewLongLines};
+
+use super::super::ui_functions::centered_rect;
+
+const FOOTER_TEXT: &str = "j/k: Scroll | h/l: Scroll sideways | Enter: Export | Esc or q: Back";
+const FOOTER_MARGIN: u16 = 4;
+
+pub enum PreviewReturn {
+    Keep,
+    Close,
+    Confirm,
+}
+
+/// Read-only preview of the text which will be written to the export file.
+pub struct ExportPreview {
+    content: String,
+    long_lines: PreviewLongLines,
+    scroll_y: u16,
+    scroll_x: u16,
+    /// Width of the rendered content, used to count the wrapped lines.
+    content_width: u16,
+}
+
+impl ExportPreview {
+    pub fn new(content: String, long_lines: PreviewLongLines) -> Self {
+        Self {
+            content,
+            long_lines,
+            scroll_y: 0,
+            scroll_x: 0,
+            content_width: 0,
+        }
+    }
+
+    /// Counts the lines of the content as rendered, taking the wrapped lines into account.
+    fn rendered_lines_count(&self) -> usize {
+        match self.long_lines {
+            PreviewLongLines::Wrap if self.content_width > 0 => self
+                .content
+                .lines()
+                .map(|line| textwrap::wrap(line, self.content_width as usize).len())
+                .sum(),
+            PreviewLongLines::Wrap | PreviewLongLines::Scroll => self.content.lines().count(),
+        }
+    }
+
+    pub fn render_widget(&mut self, frame: &mut Frame, area: Rect) {
+        let area = centered_rect(80, 80, area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title("Export Preview");
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        let footer_height = if area.width < FOOTER_TEXT.len() as u16 + FOOTER_MARGIN {
+            3
+        } else {
+            2
+        };
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .horizontal_margin(1)
+            .vertical_margin(1)
+            .constraints([Constraint::Min(3), Constraint::Length(footer_height)].as_ref())
+            .split(area);
+
+        let mut preview = Paragraph::new(self.content.as_str());
+        preview = match self.long_lines {
+            PreviewLongLines::Wrap => preview
+                .wrap(Wrap { trim: false })
+                .scroll((self.scroll_y, 0)),
+            PreviewLongLines::Scroll => preview.scroll((self.scroll_y, self.scroll_x)),
+        };
+
+        self.content_width = chunks[0].width;
+        frame.render_widget(preview, chunks[0]);
+
+        let footer = Paragraph::new(FOOTER_TEXT)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(Borders::TOP));
+
+        frame.render_widget(footer, chunks[1]);
+    }
+
+    pub fn handle_input(&mut self, input: &Input) -> PreviewReturn {
+        let has_ctrl = input.modifiers.contains(KeyModifiers::CONTROL);
+        match input.key_code {
+            KeyCode::Esc | KeyCode::Char('q') => PreviewReturn::Close,
+            KeyCode::Char('c') if has_ctrl => PreviewReturn::Close,
+            KeyCode::Enter => PreviewReturn::Confirm,
+            KeyCode::Char('m') if has_ctrl => PreviewReturn::Confirm,
+            KeyCode::Down | KeyCode::Char('j') => {
+                let max_scroll = self.rendered_lines_count().saturating_sub(1) as u16;
+                self.scroll_y = (self.scroll_y + 1).min(max_scroll);
+                PreviewReturn::Keep
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.scroll_y = self.scroll_y.saturating_sub(1);
+                PreviewReturn::Keep
+            }
+            KeyCode::Right | KeyCode::Char('l') if self.long_lines == PreviewLongLines::Scroll => {
+                let max_scroll = self
+                    .content
+                    .lines()
+                    .map(|line| line.chars().count())
+                    .max()
+                    .unwrap_or_default()
+                    .saturating_sub(1) as u16;
+                self.scroll_x = (self.scroll_x + 1).min(max_scroll);
+                PreviewReturn::Keep
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.scroll_x = self.scroll_x.saturating_sub(1);
+                PreviewReturn::Keep
+            }
+            _ => PreviewReturn::Keep,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn scroll_to_end(preview: &mut ExportPreview) -> u16 {
+        for _ in 0..20 {
+            preview.handle_input(&Input::new(KeyCode::Down, KeyModifiers::NONE));
+        }
+        preview.scroll_y
+    }
+
+    #[test]
+    fn scroll_wrapped_lines() {
+        let content = format!("{}\nShort", "word ".repeat(10));
+
+        let mut preview = ExportPreview::new(content.clone(), PreviewLongLines::Wrap);
+        preview.content_width = 10;
+        assert_eq!(scroll_to_end(&mut preview), 5);
+
+        let mut preview = ExportPreview::new(content, PreviewLongLines::Scroll);
+        preview.content_width = 10;
+        assert_eq!(scroll_to_end(&mut preview), 1);
+    }
+}
@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -10,7 +11,7 @@ use ratatui::{
 use tui_textarea::{CursorMove, TextArea};
 
 use crate::app::{
-    filter::{CriteriaRelation, Filter, FilterCriterion},
+    filter::{CriteriaRelation, Filter, FilterCriterion, RegexPattern},
     keymap::Input,
 };
 
@@ -18,8 +19,13 @@ use super::{ui_functions::centered_rect, PopupReturn, Styles};
 
 type FilterPopupReturn = PopupReturn<Option<Filter>>;
 
-const FOOTER_TEXT: &str = r"Tab: Change focused control | Enter or <Ctrl-m>: Confirm | Esc or <Ctrl-c>: Cancel | <Ctrl-r>: Change Matching Logic | <Space>: Tags Toggle Selected";
+const FOOTER_TEXT: &str = r"Tab: Change focused control | Enter or <Ctrl-m>: Confirm | Esc or <Ctrl-c>: Cancel | <Ctrl-r>: Change Matching Logic | <Ctrl-g>: Toggle Regex | <Ctrl-t>: Toggle Regex Case Sensitivity | <Ctrl-n>: Toggle Negation | <Space>: Tags Toggle Selected";
 const FOOTER_MARGIN: usize = 8;
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const DATE_RANGE_SEPARATOR: &str = "..";
+
+/// Inclusive bounds of a date range, where a missing bound leaves the range open.
+type DateRangeBounds = (Option<DateTime<Utc>>, Option<DateTime<Utc>>);
 
 pub struct FilterPopup<'a> {
     active_control: FilterControl,
@@ -27,17 +33,32 @@ pub struct FilterPopup<'a> {
     tags: Vec<String>,
     relation: CriteriaRelation,
     selected_tags: HashSet<String>,
+    /// Selected tags which journals must not have.
+    negated_tags: HashSet<String>,
+    /// Text boxes with negated criteria, matching the journals which don't meet them.
+    negated_controls: HashSet<FilterControl>,
     title_txt: TextArea<'a>,
+    title_err_msg: String,
     content_txt: TextArea<'a>,
+    content_err_msg: String,
+    /// Matches title and content with regular expressions instead of plain text.
+    regex_mode: bool,
+    /// Matches the regular expressions case-sensitively.
+    case_sensitive: bool,
     priority_txt: TextArea<'a>,
     priority_err_msg: String,
+    date_range_txt: TextArea<'a>,
+    date_range_err_msg: String,
+    /// Criteria which can't be edited in the popup and are kept as they are, like pinned ids.
+    kept_criteria: Vec<FilterCriterion>,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 enum FilterControl {
     TitleTxt,
     ContentTxt,
     PriorityTxt,
+    DateRangeTxt,
     TagsList,
 }
 
@@ -48,18 +69,72 @@ impl FilterPopup<'_> {
         let relation = filter.relation;
 
         let mut selected_tags = HashSet::new();
+        let mut negated_tags = HashSet::new();
+        let mut negated_controls = HashSet::new();
         let mut title_text = String::default();
         let mut content_text = String::default();
+        let mut regex_mode = false;
+        let mut case_sensitive = false;
         let mut priority_text = String::default();
+        let mut date_range_text = String::default();
+        let mut kept_criteria = Vec::new();
+
+        for criterion in filter.criteria {
+            let (criterion, negate) = match criterion {
+                FilterCriterion::Not(inner)
+                    if !matches!(*inner, FilterCriterion::Ids(_) | FilterCriterion::Not(_)) =>
+                {
+                    (*inner, true)
+                }
+                criterion => (criterion, false),
+            };
+
+            let control = match criterion {
+                FilterCriterion::Tag(tag) => {
+                    if negate {
+                        negated_tags.insert(tag.clone());
+                    }
+                    selected_tags.insert(tag);
+                    None
+                }
+                FilterCriterion::Title(title_search) => {
+                    title_text = title_search;
+                    Some(FilterControl::TitleTxt)
+                }
+                FilterCriterion::Content(content_search) => {
+                    content_text = content_search;
+                    Some(FilterControl::ContentTxt)
+                }
+                FilterCriterion::TitleRegex(pattern) => {
+                    regex_mode = true;
+                    case_sensitive = pattern.case_sensitive;
+                    title_text = pattern.pattern;
+                    Some(FilterControl::TitleTxt)
+                }
+                FilterCriterion::ContentRegex(pattern) => {
+                    regex_mode = true;
+                    case_sensitive = pattern.case_sensitive;
+                    content_text = pattern.pattern;
+                    Some(FilterControl::ContentTxt)
+                }
+                FilterCriterion::Priority(prio) => {
+                    priority_text = prio.to_string();
+                    Some(FilterControl::PriorityTxt)
+                }
+                FilterCriterion::DateRange { from, to } => {
+                    date_range_text = format_date_range(from, to);
+                    Some(FilterControl::DateRangeTxt)
+                }
+                criterion @ (FilterCriterion::Ids(_) | FilterCriterion::Not(_)) => {
+                    kept_criteria.push(criterion);
+                    None
+                }
+            };
 
-        filter.criteria.into_iter().for_each(|cr| match cr {
-            FilterCriterion::Tag(tag) => {
-                selected_tags.insert(tag);
+            if let Some(control) = control.filter(|_| negate) {
+                negated_controls.insert(control);
             }
-            FilterCriterion::Title(title_search) => title_text = title_search,
-            FilterCriterion::Content(content_search) => content_text = content_search,
-            FilterCriterion::Priority(prio) => priority_text = prio.to_string(),
-        });
+        }
 
         let mut title_txt = TextArea::new(vec![title_text]);
         title_txt.move_cursor(CursorMove::End);
@@ -70,6 +145,9 @@ impl FilterPopup<'_> {
         let mut priority_txt = TextArea::new(vec![priority_text]);
         priority_txt.move_cursor(CursorMove::End);
 
+        let mut date_range_txt = TextArea::new(vec![date_range_text]);
+        date_range_txt.move_cursor(CursorMove::End);
+
         let active_control = FilterControl::TitleTxt;
 
         let mut filter_popup = FilterPopup {
@@ -78,15 +156,26 @@ impl FilterPopup<'_> {
             tags,
             relation,
             selected_tags,
+            negated_tags,
+            negated_controls,
             title_txt,
+            title_err_msg: String::default(),
             content_txt,
+            content_err_msg: String::default(),
+            regex_mode,
+            case_sensitive,
             priority_txt,
             priority_err_msg: String::default(),
+            date_range_txt,
+            date_range_err_msg: String::default(),
+            kept_criteria,
         };
 
         filter_popup.cycle_next_tag();
 
         filter_popup.validate_priority();
+        filter_popup.validate_date_range();
+        filter_popup.validate_regex();
 
         filter_popup
     }
@@ -112,6 +201,7 @@ impl FilterPopup<'_> {
                     Constraint::Length(3),
                     Constraint::Length(3),
                     Constraint::Length(3),
+                    Constraint::Length(3),
                     Constraint::Min(4),
                     Constraint::Length(footer_height.try_into().unwrap()),
                 ]
@@ -121,15 +211,15 @@ impl FilterPopup<'_> {
 
         self.render_relations(frame, chunks[0]);
 
-        self.render_text_boxes(frame, chunks[1], chunks[2], chunks[3], styles);
+        self.render_text_boxes(frame, &chunks[1..5], styles);
 
         if self.tags.is_empty() {
-            self.render_tags_place_holder(frame, chunks[4], styles);
+            self.render_tags_place_holder(frame, chunks[5], styles);
         } else {
-            self.render_tags_list(frame, chunks[4], styles);
+            self.render_tags_list(frame, chunks[5], styles);
         }
 
-        self.render_footer(frame, chunks[5]);
+        self.render_footer(frame, chunks[6]);
     }
 
     fn render_relations(&mut self, frame: &mut Frame, area: Rect) {
@@ -150,71 +240,83 @@ impl FilterPopup<'_> {
         frame.render_widget(relation, area);
     }
 
-    fn render_text_boxes(
-        &mut self,
-        frame: &mut Frame,
-        title_area: Rect,
-        content_area: Rect,
-        priority_area: Rect,
-        styles: &Styles,
-    ) {
+    /// Renders the text boxes of title, content, priority and date range in the given areas.
+    fn render_text_boxes(&mut self, frame: &mut Frame, areas: &[Rect], styles: &Styles) {
         let gstyles = &styles.general;
         let active_cursor_style: Style = gstyles.input_corsur_active.into();
         let invalid_cursor_style: Style = gstyles.input_corsur_invalid.into();
         let deactivate_cursor_style = Style::default().bg(Color::Reset);
 
-        let mut title_txt_block = Block::default().title("Title").borders(Borders::ALL);
-        let mut content_txt_block = Block::default().title("Content").borders(Borders::ALL);
-        let mut priority_txt_block = if self.priority_err_msg.is_empty() {
-            Block::default().title("Priority").borders(Borders::ALL)
-        } else {
-            Block::default()
-                .title(format!("Priority : {}", self.priority_err_msg))
-                .borders(Borders::ALL)
-                .style(gstyles.input_block_invalid)
+        let (title_title, content_title) = match (self.regex_mode, self.case_sensitive) {
+            (false, _) => ("Title", "Content"),
+            (true, false) => ("Title (Regex)", "Content (Regex)"),
+            (true, true) => (
+                "Title (Regex, Case-sensitive)",
+                "Content (Regex, Case-sensitive)",
+            ),
         };
 
-        match self.active_control {
-            FilterControl::TitleTxt => {
-                self.title_txt.set_cursor_style(active_cursor_style);
-                self.content_txt.set_cursor_style(deactivate_cursor_style);
-                self.priority_txt.set_cursor_style(deactivate_cursor_style);
-                title_txt_block = title_txt_block.style(gstyles.input_block_active);
-            }
-            FilterControl::ContentTxt => {
-                self.title_txt.set_cursor_style(deactivate_cursor_style);
-                self.content_txt.set_cursor_style(active_cursor_style);
-                self.priority_txt.set_cursor_style(deactivate_cursor_style);
-                content_txt_block = content_txt_block.style(gstyles.input_block_active);
-            }
-            FilterControl::TagsList => {
-                self.title_txt.set_cursor_style(deactivate_cursor_style);
-                self.content_txt.set_cursor_style(deactivate_cursor_style);
-                self.priority_txt.set_cursor_style(deactivate_cursor_style);
-            }
-            FilterControl::PriorityTxt => {
-                self.title_txt.set_cursor_style(deactivate_cursor_style);
-                self.content_txt.set_cursor_style(deactivate_cursor_style);
-                if self.priority_err_msg.is_empty() {
-                    self.priority_txt.set_cursor_style(active_cursor_style);
-                    priority_txt_block = priority_txt_block.style(gstyles.input_block_active);
+        let text_boxes = [
+            (
+                FilterControl::TitleTxt,
+                &mut self.title_txt,
+                title_title,
+                self.title_err_msg.as_str(),
+            ),
+            (
+                FilterControl::ContentTxt,
+                &mut self.content_txt,
+                content_title,
+                self.content_err_msg.as_str(),
+            ),
+            (
+                FilterControl::PriorityTxt,
+                &mut self.priority_txt,
+                "Priority",
+                self.priority_err_msg.as_str(),
+            ),
+            (
+                FilterControl::DateRangeTxt,
+                &mut self.date_range_txt,
+                "Date range (YYYY-MM-DD..YYYY-MM-DD)",
+                self.date_range_err_msg.as_str(),
+            ),
+        ];
+
+        for ((control, text_box, title, err_msg), area) in text_boxes.into_iter().zip(areas) {
+            let is_active = self.active_control == control;
+            let title = if self.negated_controls.contains(&control) {
+                format!("Not {title}")
+            } else {
+                title.to_owned()
+            };
+
+            let block = if err_msg.is_empty() {
+                let block = Block::default().title(title).borders(Borders::ALL);
+                if is_active {
+                    block.style(gstyles.input_block_active)
                 } else {
-                    self.priority_txt.set_cursor_style(invalid_cursor_style);
+                    block
                 }
-            }
-        }
+            } else {
+                Block::default()
+                    .title(format!("{title} : {err_msg}"))
+                    .borders(Borders::ALL)
+                    .style(gstyles.input_block_invalid)
+            };
 
-        self.title_txt.set_cursor_line_style(Style::default());
-        self.content_txt.set_cursor_line_style(Style::default());
-        self.priority_txt.set_cursor_line_style(Style::default());
+            let cursor_style = match (is_active, err_msg.is_empty()) {
+                (false, _) => deactivate_cursor_style,
+                (true, true) => active_cursor_style,
+                (true, false) => invalid_cursor_style,
+            };
 
-        self.title_txt.set_block(title_txt_block);
-        self.content_txt.set_block(content_txt_block);
-        self.priority_txt.set_block(priority_txt_block);
+            text_box.set_cursor_style(cursor_style);
+            text_box.set_cursor_line_style(Style::default());
+            text_box.set_block(block);
 
-        frame.render_widget(&self.title_txt, title_area);
-        frame.render_widget(&self.content_txt, content_area);
-        frame.render_widget(&self.priority_txt, priority_area);
+            frame.render_widget(&*text_box, *area);
+        }
     }
 
     fn render_tags_list(&mut self, frame: &mut Frame, area: Rect, styles: &Styles) {
@@ -226,7 +328,9 @@ impl FilterPopup<'_> {
             .map(|tag| {
                 let is_selected = self.selected_tags.contains(tag);
 
-                let (tag_text, style) = if is_selected {
+                let (tag_text, style) = if is_selected && self.negated_tags.contains(tag) {
+                    (format!("* NOT {tag}"), selected_style)
+                } else if is_selected {
                     (format!("* {tag}"), selected_style)
                 } else {
                     (tag.to_owned(), Style::reset())
@@ -299,17 +403,40 @@ impl FilterPopup<'_> {
                     self.change_relation();
                     FilterPopupReturn::KeepPopup
                 }
+                KeyCode::Char('g') if has_control => {
+                    self.toggle_regex_mode();
+                    FilterPopupReturn::KeepPopup
+                }
+                KeyCode::Char('t') if has_control => {
+                    self.toggle_case_sensitive();
+                    FilterPopupReturn::KeepPopup
+                }
+                KeyCode::Char('n') if has_control => {
+                    self.toggle_negation();
+                    FilterPopupReturn::KeepPopup
+                }
                 _ => {
                     match self.active_control {
-                        FilterControl::TitleTxt => _ = self.title_txt.input(KeyEvent::from(input)),
+                        FilterControl::TitleTxt => {
+                            if self.title_txt.input(KeyEvent::from(input)) {
+                                self.validate_regex();
+                            }
+                        }
                         FilterControl::ContentTxt => {
-                            _ = self.content_txt.input(KeyEvent::from(input))
+                            if self.content_txt.input(KeyEvent::from(input)) {
+                                self.validate_regex();
+                            }
                         }
                         FilterControl::PriorityTxt => {
                             if self.priority_txt.input(KeyEvent::from(input)) {
                                 self.validate_priority();
                             }
                         }
+                        FilterControl::DateRangeTxt => {
+                            if self.date_range_txt.input(KeyEvent::from(input)) {
+                                self.validate_date_range();
+                            }
+                        }
                         FilterControl::TagsList => unreachable!("Tags List is unreachable here"),
                     };
                     FilterPopupReturn::KeepPopup
@@ -334,6 +461,18 @@ impl FilterPopup<'_> {
                     self.change_relation();
                     FilterPopupReturn::KeepPopup
                 }
+                KeyCode::Char('g') if has_control => {
+                    self.toggle_regex_mode();
+                    FilterPopupReturn::KeepPopup
+                }
+                KeyCode::Char('t') if has_control => {
+                    self.toggle_case_sensitive();
+                    FilterPopupReturn::KeepPopup
+                }
+                KeyCode::Char('n') => {
+                    self.toggle_negation();
+                    FilterPopupReturn::KeepPopup
+                }
                 KeyCode::Esc | KeyCode::Char('q') => FilterPopupReturn::Cancel,
                 KeyCode::Char('c') if has_control => FilterPopupReturn::Cancel,
                 KeyCode::Enter => self.confirm(),
@@ -347,7 +486,8 @@ impl FilterPopup<'_> {
         self.active_control = match self.active_control {
             FilterControl::TitleTxt => FilterControl::ContentTxt,
             FilterControl::ContentTxt => FilterControl::PriorityTxt,
-            FilterControl::PriorityTxt => FilterControl::TagsList,
+            FilterControl::PriorityTxt => FilterControl::DateRangeTxt,
+            FilterControl::DateRangeTxt => FilterControl::TagsList,
             FilterControl::TagsList => FilterControl::TitleTxt,
         };
 
@@ -391,6 +531,39 @@ impl FilterPopup<'_> {
         }
     }
 
+    fn toggle_regex_mode(&mut self) {
+        self.regex_mode = !self.regex_mode;
+        self.validate_regex();
+    }
+
+    fn toggle_case_sensitive(&mut self) {
+        self.case_sensitive = !self.case_sensitive;
+    }
+
+    /// Toggles the negation of the criterion in the active text box or of the highlighted tag,
+    /// selecting the tag if it isn't selected yet.
+    fn toggle_negation(&mut self) {
+        if self.active_control != FilterControl::TagsList {
+            if !self.negated_controls.remove(&self.active_control) {
+                self.negated_controls.insert(self.active_control);
+            }
+            return;
+        }
+
+        let Some(tag) = self
+            .tags_state
+            .selected()
+            .and_then(|idx| self.tags.get(idx))
+        else {
+            return;
+        };
+
+        if !self.negated_tags.remove(tag) {
+            self.negated_tags.insert(tag.to_owned());
+            self.selected_tags.insert(tag.to_owned());
+        }
+    }
+
     fn toggle_selected(&mut self) {
         if let Some(idx) = self.tags_state.selected() {
             let tag = self
@@ -400,6 +573,7 @@ impl FilterPopup<'_> {
 
             if self.selected_tags.contains(tag) {
                 self.selected_tags.remove(tag);
+                self.negated_tags.remove(tag);
             } else {
                 self.selected_tags.insert(tag.to_owned());
             }
@@ -415,12 +589,63 @@ impl FilterPopup<'_> {
         }
     }
 
+    /// Validates the title and content as regular expressions if the regex mode is active.
+    fn validate_regex(&mut self) {
+        let regex_mode = self.regex_mode;
+        let validate = |text_box: &TextArea, err_msg: &mut String| {
+            err_msg.clear();
+            if !regex_mode {
+                return;
+            }
+
+            let pattern = RegexPattern::new(text_box.lines()[0].to_owned(), false);
+            if pattern.validate().is_err() {
+                *err_msg = String::from("Invalid regular expression");
+            }
+        };
+
+        validate(&self.title_txt, &mut self.title_err_msg);
+        validate(&self.content_txt, &mut self.content_err_msg);
+    }
+
+    fn validate_date_range(&mut self) {
+        let text = self.date_range_txt.lines().first().unwrap();
+        match parse_date_range(text) {
+            Ok(_) => self.date_range_err_msg.clear(),
+            Err(err) => self.date_range_err_msg = err,
+        }
+    }
+
     fn is_valid_input(&self) -> bool {
         self.priority_err_msg.is_empty()
+            && self.date_range_err_msg.is_empty()
+            && self.title_err_msg.is_empty()
+            && self.content_err_msg.is_empty()
+    }
+
+    fn is_negated(&self, control: FilterControl) -> bool {
+        self.negated_controls.contains(&control)
+    }
+
+    /// Creates the criterion for the given text as plain text or regular expression according
+    /// to the regex mode.
+    fn text_criterion(
+        &self,
+        text: &str,
+        plain: fn(String) -> FilterCriterion,
+        regex: fn(RegexPattern) -> FilterCriterion,
+    ) -> FilterCriterion {
+        if self.regex_mode {
+            regex(RegexPattern::new(text.to_owned(), self.case_sensitive))
+        } else {
+            plain(text.to_owned())
+        }
     }
 
     fn confirm(&mut self) -> FilterPopupReturn {
         self.validate_priority();
+        self.validate_date_range();
+        self.validate_regex();
         if !self.is_valid_input() {
             return FilterPopupReturn::KeepPopup;
         }
@@ -428,7 +653,7 @@ impl FilterPopup<'_> {
         let mut critria: Vec<_> = self
             .selected_tags
             .iter()
-            .map(|tag| FilterCriterion::Tag(tag.into()))
+            .map(|tag| FilterCriterion::Tag(tag.into()).negate_if(self.negated_tags.contains(tag)))
             .collect();
 
         let title_filter = self
@@ -438,7 +663,14 @@ impl FilterPopup<'_> {
             .expect("Title TextBox has one line");
 
         if !title_filter.is_empty() {
-            critria.push(FilterCriterion::Title(title_filter.to_owned()));
+            critria.push(
+                self.text_criterion(
+                    title_filter,
+                    FilterCriterion::Title,
+                    FilterCriterion::TitleRegex,
+                )
+                .negate_if(self.is_negated(FilterControl::TitleTxt)),
+            );
         }
 
         let content_filter = self
@@ -448,7 +680,14 @@ impl FilterPopup<'_> {
             .expect("Content TextBox has one line");
 
         if !content_filter.is_empty() {
-            critria.push(FilterCriterion::Content(content_filter.to_owned()));
+            critria.push(
+                self.text_criterion(
+                    content_filter,
+                    FilterCriterion::Content,
+                    FilterCriterion::ContentRegex,
+                )
+                .negate_if(self.is_negated(FilterControl::ContentTxt)),
+            );
         }
 
         let priority_filter = self
@@ -460,9 +699,28 @@ impl FilterPopup<'_> {
             let prio = priority_filter
                 .parse()
                 .expect("Priority text is validated at this point");
-            critria.push(FilterCriterion::Priority(prio));
+            critria.push(
+                FilterCriterion::Priority(prio)
+                    .negate_if(self.is_negated(FilterControl::PriorityTxt)),
+            );
         }
 
+        let date_range = self
+            .date_range_txt
+            .lines()
+            .first()
+            .expect("Date range text box has one line");
+        if let Some((from, to)) =
+            parse_date_range(date_range).expect("Date range text is validated at this point")
+        {
+            critria.push(
+                FilterCriterion::DateRange { from, to }
+                    .negate_if(self.is_negated(FilterControl::DateRangeTxt)),
+            );
+        }
+
+        critria.extend(self.kept_criteria.iter().cloned());
+
         if critria.is_empty() {
             FilterPopupReturn::Apply(None)
         } else {
@@ -475,3 +733,168 @@ impl FilterPopup<'_> {
         }
     }
 }
+
+/// Parses the date range in the format `from..to` with dates as `YYYY-MM-DD`, where one of the
+/// dates can be omitted to leave the range open. A single date matches that day only.
+/// Returns `None` for empty text, and the bounds covering the whole days otherwise.
+fn parse_date_range(text: &str) -> Result<Option<DateRangeBounds>, String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(None);
+    }
+
+    let (from_text, to_text) = text
+        .split_once(DATE_RANGE_SEPARATOR)
+        .unwrap_or((text, text));
+
+    let parse_date = |date_text: &str| -> Result<Option<NaiveDate>, String> {
+        let date_text = date_text.trim();
+        if date_text.is_empty() {
+            return Ok(None);
+        }
+
+        NaiveDate::parse_from_str(date_text, DATE_FORMAT)
+            .map(Some)
+            .map_err(|_| format!("Invalid date '{date_text}'"))
+    };
+
+    let from = parse_date(from_text)?;
+    let to = parse_date(to_text)?;
+
+    if from.is_none() && to.is_none() {
+        return Err(String::from("At least one date is required"));
+    }
+
+    if from.zip(to).is_some_and(|(from, to)| from > to) {
+        return Err(String::from("Start date is after end date"));
+    }
+
+    let from = from.map(|date| date.and_time(NaiveTime::MIN).and_utc());
+    let to = to.map(|date| {
+        date.and_hms_opt(23, 59, 59)
+            .expect("End of day is a valid time")
+            .and_utc()
+    });
+
+    Ok(Some((from, to)))
+}
+
+/// Formats the given date range bounds in the format accepted by [`parse_date_range`].
+fn format_date_range(from: Option<DateTime<Utc>>, to: Option<DateTime<Utc>>) -> String {
+    let format = |date: Option<DateTime<Utc>>| {
+        date.map(|date| date.format(DATE_FORMAT).to_string())
+            .unwrap_or_default()
+    };
+
+    format!("{}{DATE_RANGE_SEPARATOR}{}", format(from), format(to))
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn regex_mode_criteria() {
+        let mut popup = FilterPopup::new(Vec::new(), None);
+        for ch in "todo(".chars() {
+            popup.handle_input(&Input::new(KeyCode::Char(ch), KeyModifiers::NONE));
+        }
+
+        popup.handle_input(&Input::new(KeyCode::Char('g'), KeyModifiers::CONTROL));
+        assert!(!popup.title_err_msg.is_empty());
+        assert!(matches!(
+            popup.handle_input(&Input::new(KeyCode::Enter, KeyModifiers::NONE)),
+            FilterPopupReturn::KeepPopup
+        ));
+
+        popup.handle_input(&Input::new(KeyCode::Backspace, KeyModifiers::NONE));
+        popup.handle_input(&Input::new(KeyCode::Char('t'), KeyModifiers::CONTROL));
+        let FilterPopupReturn::Apply(Some(filter)) =
+            popup.handle_input(&Input::new(KeyCode::Enter, KeyModifiers::NONE))
+        else {
+            panic!("Filter with valid regex must be applied");
+        };
+
+        assert_eq!(
+            filter.criteria,
+            vec![FilterCriterion::TitleRegex(RegexPattern::new(
+                String::from("todo"),
+                true
+            ))]
+        );
+
+        // Regex mode is restored from the filter
+        let popup = FilterPopup::new(Vec::new(), Some(filter));
+        assert!(popup.regex_mode);
+        assert!(popup.case_sensitive);
+    }
+
+    #[test]
+    fn negated_criteria() {
+        let tags = vec![String::from("Work"), String::from("Home")];
+        let mut popup = FilterPopup::new(tags.clone(), None);
+        popup.handle_input(&Input::new(KeyCode::Char('x'), KeyModifiers::NONE));
+        popup.handle_input(&Input::new(KeyCode::Char('n'), KeyModifiers::CONTROL));
+
+        // Negating the highlighted tag selects it
+        popup.active_control = FilterControl::TagsList;
+        popup.handle_input(&Input::new(KeyCode::Char('n'), KeyModifiers::NONE));
+
+        let FilterPopupReturn::Apply(Some(filter)) =
+            popup.handle_input(&Input::new(KeyCode::Enter, KeyModifiers::NONE))
+        else {
+            panic!("Filter must be applied");
+        };
+
+        assert_eq!(
+            filter.criteria,
+            vec![
+                FilterCriterion::Tag(String::from("Work")).negate_if(true),
+                FilterCriterion::Title(String::from("x")).negate_if(true),
+            ]
+        );
+
+        // Negation is restored from the filter
+        let popup = FilterPopup::new(tags, Some(filter));
+        assert!(popup.negated_tags.contains("Work"));
+        assert!(popup.is_negated(FilterControl::TitleTxt));
+        assert!(!popup.is_negated(FilterControl::ContentTxt));
+    }
+
+    #[test]
+    fn date_range_parsing() {
+        let (from, to) = parse_date_range("2024-01-01..2024-01-31").unwrap().unwrap();
+        assert_eq!(
+            from,
+            Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            to,
+            Some(Utc.with_ymd_and_hms(2024, 1, 31, 23, 59, 59).unwrap())
+        );
+        assert_eq!(format_date_range(from, to), "2024-01-01..2024-01-31");
+
+        let (from, to) = parse_date_range(" ..2024-01-31").unwrap().unwrap();
+        assert_eq!(from, None);
+        assert!(to.is_some());
+        assert_eq!(format_date_range(from, to), "..2024-01-31");
+
+        // Single date covers the whole day
+        let (from, to) = parse_date_range("2024-02-29").unwrap().unwrap();
+        assert_eq!(
+            from,
+            Some(Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            to,
+            Some(Utc.with_ymd_and_hms(2024, 2, 29, 23, 59, 59).unwrap())
+        );
+
+        assert_eq!(parse_date_range("  "), Ok(None));
+        assert!(parse_date_range("..").is_err());
+        assert!(parse_date_range("2024-13-01..").is_err());
+        assert!(parse_date_range("2024-02-01..2024-01-01").is_err());
+    }
+}
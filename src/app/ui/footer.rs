@@ -45,11 +45,31 @@ fn get_footer_text<D: DataProvider>(ui_components: &UIComponents, app: &App<D>)
         ui_components.editor.is_insert_mode(),
         ui_components.entries_list.multi_select_mode,
     );
-    match (edior_mode, multi_select_mode) {
+    let mut footer_text = match (edior_mode, multi_select_mode) {
         (true, false) => get_editor_mode_text(ui_components),
         (false, true) => get_multi_select_text(ui_components),
         _ => get_standard_text(ui_components, app),
+    };
+
+    if app.settings.priority_counts {
+        footer_text.push_str(SEPARATOR);
+        footer_text.push_str(&get_priority_counts_text(app));
     }
+
+    footer_text
+}
+
+pub fn get_priority_counts_text<D: DataProvider>(app: &App<D>) -> String {
+    let counts = app.get_priority_counts();
+
+    let mut parts: Vec<String> = counts
+        .priorities
+        .iter()
+        .map(|(priority, count)| format!("P{priority}: {count}"))
+        .collect();
+    parts.push(format!("No priority: {}", counts.no_priority));
+
+    parts.join(" ")
 }
 
 fn get_editor_mode_text(ui_components: &UIComponents) -> String {
@@ -79,10 +99,13 @@ fn get_standard_text<D: DataProvider>(ui_components: &UIComponents, app: &App<D>
         .filter(|keymap| keymap.command == UICommand::StartEditEntryContent)
         .collect();
 
-    let mut footer_parts = vec![
-        get_keymap_text(close_keymap),
-        get_keymap_text(enter_editor_keymap),
-    ];
+    let mut footer_parts = vec![get_keymap_text(close_keymap)];
+
+    if app.settings.is_read_only() {
+        footer_parts.push(String::from("Read-only mode"));
+    } else {
+        footer_parts.push(get_keymap_text(enter_editor_keymap));
+    }
 
     if ui_components.active_control == ControlType::EntriesList {
         if app.filter.is_none() {
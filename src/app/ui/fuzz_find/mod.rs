@@ -25,6 +25,9 @@ pub struct FuzzFindPopup<'a> {
     filtered_entries: Vec<FilteredEntry>,
     list_state: ListState,
     matcher: SkimMatcherV2,
+    /// Suggest the closest title when the search query has no matches.
+    suggestions_enabled: bool,
+    suggestion: Option<String>,
 }
 
 pub enum FuzzFindReturn {
@@ -45,11 +48,9 @@ impl FilteredEntry {
 }
 
 impl FuzzFindPopup<'_> {
-    pub fn new(entries: HashMap<u32, String>) -> Self {
+    pub fn new(entries: HashMap<u32, String>, suggestions_enabled: bool) -> Self {
         let mut query_text_box = TextArea::default();
-        let block = Block::default().title("Search Query").borders(Borders::ALL);
         query_text_box.set_cursor_line_style(Style::default());
-        query_text_box.set_block(block);
 
         Self {
             query_text_box,
@@ -58,6 +59,8 @@ impl FuzzFindPopup<'_> {
             filtered_entries: Vec::new(),
             list_state: ListState::default(),
             matcher: SkimMatcherV2::default().smart_case(),
+            suggestions_enabled,
+            suggestion: None,
         }
     }
 
@@ -90,6 +93,13 @@ impl FuzzFindPopup<'_> {
             )
             .split(area);
 
+        let query_title = match self.suggestion.as_ref() {
+            Some(suggestion) => format!("Search Query - Did you mean: {suggestion}?"),
+            None => String::from("Search Query"),
+        };
+        self.query_text_box
+            .set_block(Block::default().title(query_title).borders(Borders::ALL));
+
         frame.render_widget(&self.query_text_box, chunks[0]);
 
         self.render_entries_list(frame, chunks[1], styles);
@@ -231,9 +241,17 @@ impl FuzzFindPopup<'_> {
                 })
                 .collect();
 
-            self.filtered_entries.sort_by(|a, b| b.score.cmp(&a.score));
+            self.filtered_entries
+                .sort_by_key(|entry| std::cmp::Reverse(entry.score));
         }
 
+        self.suggestion = match self.search_query.as_ref() {
+            Some(query) if self.suggestions_enabled && self.filtered_entries.is_empty() => {
+                self.find_suggestion(query)
+            }
+            _ => None,
+        };
+
         if self.filtered_entries.is_empty() {
             self.list_state.select(None);
         } else {
@@ -241,4 +259,80 @@ impl FuzzFindPopup<'_> {
             self.list_state.select(Some(0));
         }
     }
+
+    /// Finds the title which matches the given query best when one character of the query is
+    /// left out, tolerating typos like a wrong or swapped character.
+    fn find_suggestion(&self, query: &str) -> Option<String> {
+        let chars: Vec<char> = query.chars().collect();
+        if chars.len() < 2 {
+            return None;
+        }
+
+        (0..chars.len())
+            .map(|skip_idx| {
+                chars
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(idx, ch)| (idx != skip_idx).then_some(ch))
+                    .collect::<String>()
+            })
+            .flat_map(|variant| {
+                self.entries.values().filter_map(move |title| {
+                    self.matcher
+                        .fuzzy_match(title, &variant)
+                        .map(|score| (score, title))
+                })
+            })
+            // Prefer the alphabetically first title on equal scores to be deterministic.
+            .max_by(|(score_a, title_a), (score_b, title_b)| {
+                score_a.cmp(score_b).then_with(|| title_b.cmp(title_a))
+            })
+            .map(|(_, title)| title.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn type_query(popup: &mut FuzzFindPopup, query: &str) {
+        for ch in query.chars() {
+            popup.handle_input(&Input::new(KeyCode::Char(ch), KeyModifiers::NONE));
+        }
+    }
+
+    fn get_popup(suggestions_enabled: bool) -> FuzzFindPopup<'static> {
+        let entries = HashMap::from([
+            (0, String::from("Weekly meeting")),
+            (1, String::from("Shopping list")),
+            (2, String::from("Book notes")),
+        ]);
+
+        FuzzFindPopup::new(entries, suggestions_enabled)
+    }
+
+    #[test]
+    fn suggest_closest_title() {
+        let mut popup = get_popup(true);
+        type_query(&mut popup, "meetnig");
+
+        assert!(popup.filtered_entries.is_empty());
+        assert_eq!(popup.suggestion.as_deref(), Some("Weekly meeting"));
+
+        // Exact match shows no suggestion
+        let mut popup = get_popup(true);
+        type_query(&mut popup, "meeting");
+
+        assert_eq!(popup.filtered_entries.len(), 1);
+        assert!(popup.suggestion.is_none());
+    }
+
+    #[test]
+    fn suggestions_disabled() {
+        let mut popup = get_popup(false);
+        type_query(&mut popup, "meetnig");
+
+        assert!(popup.filtered_entries.is_empty());
+        assert!(popup.suggestion.is_none());
+    }
 }
@@ -3,9 +3,7 @@ use std::collections::BTreeMap;
 use ratatui::widgets::TableState;
 
 use crate::app::{
-    keymap::{
-        get_editor_mode_keymaps, get_entries_list_keymaps, get_global_keymaps, Input, Keymap,
-    },
+    keymap::{Input, Keymap},
     ui::UICommand,
 };
 
@@ -18,12 +16,12 @@ pub struct GlobalBindings {
 }
 
 impl GlobalBindings {
-    pub fn new() -> Self {
+    pub fn new<'a>(keymaps: impl Iterator<Item = &'a Keymap>) -> Self {
         let state = TableState::default();
 
         let mut bindings_map: BTreeMap<UICommand, Vec<Input>> = BTreeMap::new();
 
-        get_all_keymaps().for_each(|keymap| {
+        keymaps.for_each(|keymap| {
             bindings_map
                 .entry(keymap.command)
                 .and_modify(|keys| keys.push(keymap.key))
@@ -37,14 +35,6 @@ impl GlobalBindings {
     }
 }
 
-fn get_all_keymaps() -> impl Iterator<Item = Keymap> {
-    let global_maps = get_global_keymaps().into_iter();
-    let list_maps = get_entries_list_keymaps().into_iter();
-    let editor_maps = get_editor_mode_keymaps().into_iter();
-
-    global_maps.chain(list_maps).chain(editor_maps)
-}
-
 impl KeybindingsTable for GlobalBindings {
     fn get_state_mut(&mut self) -> &mut TableState {
         &mut self.state
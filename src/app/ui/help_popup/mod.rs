@@ -12,7 +12,7 @@ use ratatui::{
     Frame,
 };
 
-use crate::app::keymap::Input;
+use crate::app::keymap::{Input, Keymap};
 
 use self::{
     global_bindings::GlobalBindings, keybindings_table::KeybindingsTable,
@@ -107,9 +107,15 @@ pub struct HelpPopup {
 }
 
 impl HelpPopup {
-    pub fn new(selected_tab: KeybindingsTabs) -> Self {
-        let global_bindings = GlobalBindings::new();
-        let multi_select_bindings = MultiSelectBindings::new();
+    /// Creates the help popup showing the given keymaps, where global keymaps include the
+    /// keymaps of all controls.
+    pub fn new<'a>(
+        selected_tab: KeybindingsTabs,
+        global_keymaps: impl Iterator<Item = &'a Keymap>,
+        multi_select_keymaps: impl Iterator<Item = &'a Keymap>,
+    ) -> Self {
+        let global_bindings = GlobalBindings::new(global_keymaps);
+        let multi_select_bindings = MultiSelectBindings::new(multi_select_keymaps);
         Self {
             selected_tab,
             global_bindings,
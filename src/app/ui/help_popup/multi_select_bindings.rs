@@ -3,7 +3,7 @@ use std::collections::BTreeMap;
 use ratatui::widgets::TableState;
 
 use crate::app::{
-    keymap::{get_multi_select_keymaps, Input},
+    keymap::{Input, Keymap},
     ui::UICommand,
 };
 
@@ -16,11 +16,11 @@ pub struct MultiSelectBindings {
 }
 
 impl MultiSelectBindings {
-    pub fn new() -> Self {
+    pub fn new<'a>(keymaps: impl Iterator<Item = &'a Keymap>) -> Self {
         let state = TableState::default();
         let mut bingings_map: BTreeMap<UICommand, Vec<Input>> = BTreeMap::new();
 
-        get_multi_select_keymaps().into_iter().for_each(|keymap| {
+        keymaps.for_each(|keymap| {
             bingings_map
                 .entry(keymap.command)
                 .and_modify(|keys| keys.push(keymap.key))
@@ -0,0 +1,226 @@
+use backend::DataProvider;
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::{history::HistoryStack, keymap::Input, App};
+
+use super::{ui_functions::centered_rect, PopupReturn, Styles};
+
+type HistoryPopupReturn = PopupReturn<HistoryJump>;
+
+const FOOTER_TEXT: &str =
+    "Enter: Undo or redo up to the highlighted change | Esc, q or <Ctrl-c>: Close";
+const FOOTER_MARGIN: u16 = 8;
+const CURRENT_STATE_TEXT: &str = "-- Current state --";
+
+/// Jump in the history requested via the history popup, applying undo or redo the given count
+/// of times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HistoryJump {
+    pub target: HistoryStack,
+    pub count: usize,
+}
+
+struct HistoryItem {
+    text: String,
+    /// Jump to the state after applying the change, which is `None` for the current state.
+    jump: Option<HistoryJump>,
+}
+
+/// Popup listing the changes of the redo and undo stacks around the current state, to undo or
+/// redo up to the picked change at once.
+pub struct HistoryPopup {
+    items: Vec<HistoryItem>,
+    state: ListState,
+}
+
+impl HistoryPopup {
+    pub fn new<D: DataProvider>(app: &App<D>) -> Self {
+        let redo = app.describe_history(HistoryStack::Redo);
+        let undo = app.describe_history(HistoryStack::Undo);
+
+        // Redo changes are listed above the current state starting with the farthest one.
+        let redo_items = redo
+            .into_iter()
+            .enumerate()
+            .rev()
+            .map(|(idx, text)| HistoryItem {
+                text: format!("Redo: {text}"),
+                jump: Some(HistoryJump {
+                    target: HistoryStack::Redo,
+                    count: idx + 1,
+                }),
+            });
+
+        let current_item = HistoryItem {
+            text: CURRENT_STATE_TEXT.into(),
+            jump: None,
+        };
+
+        let undo_items = undo.into_iter().enumerate().map(|(idx, text)| HistoryItem {
+            text: format!("Undo: {text}"),
+            jump: Some(HistoryJump {
+                target: HistoryStack::Undo,
+                count: idx + 1,
+            }),
+        });
+
+        let items: Vec<HistoryItem> = redo_items
+            .chain(std::iter::once(current_item))
+            .chain(undo_items)
+            .collect();
+
+        let current_idx = items.iter().position(|item| item.jump.is_none());
+        let mut state = ListState::default();
+        state.select(current_idx);
+
+        Self { items, state }
+    }
+
+    pub fn render_widget(&mut self, frame: &mut Frame, area: Rect, styles: &Styles) {
+        let area = centered_rect(60, 60, area);
+
+        let block = Block::default().borders(Borders::ALL).title("History");
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        let footer_height = if area.width < FOOTER_TEXT.len() as u16 + FOOTER_MARGIN {
+            2
+        } else {
+            1
+        };
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .horizontal_margin(4)
+            .vertical_margin(2)
+            .constraints([Constraint::Min(3), Constraint::Length(footer_height)].as_ref())
+            .split(area);
+
+        let items: Vec<ListItem> = self
+            .items
+            .iter()
+            .map(|item| {
+                let style = match item.jump {
+                    None => Style::reset().add_modifier(Modifier::BOLD),
+                    Some(jump) if jump.target == HistoryStack::Redo => {
+                        Style::reset().add_modifier(Modifier::DIM)
+                    }
+                    Some(_) => Style::reset(),
+                };
+                ListItem::new(item.text.as_str()).style(style)
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .style(Style::from(styles.general.input_block_active))
+                    .title("Changes"),
+            )
+            .highlight_style(Style::from(styles.general.list_highlight_active))
+            .highlight_symbol(">> ");
+
+        frame.render_stateful_widget(list, chunks[0], &mut self.state);
+
+        let footer = Paragraph::new(FOOTER_TEXT)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(footer, chunks[1]);
+    }
+
+    pub fn handle_input(&mut self, input: &Input) -> HistoryPopupReturn {
+        let has_ctrl = input.modifiers.contains(KeyModifiers::CONTROL);
+        let last_index = self.items.len().saturating_sub(1);
+        let selected = self.state.selected().unwrap_or_default();
+
+        match input.key_code {
+            KeyCode::Esc | KeyCode::Char('q') => HistoryPopupReturn::Cancel,
+            KeyCode::Char('c') if has_ctrl => HistoryPopupReturn::Cancel,
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.state.select(Some(selected.saturating_sub(1)));
+                HistoryPopupReturn::KeepPopup
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.state.select(Some((selected + 1).min(last_index)));
+                HistoryPopupReturn::KeepPopup
+            }
+            KeyCode::Enter => match self.items.get(selected).and_then(|item| item.jump) {
+                Some(jump) => HistoryPopupReturn::Apply(jump),
+                None => HistoryPopupReturn::Cancel,
+            },
+            _ => HistoryPopupReturn::KeepPopup,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::DateTime;
+
+    use crate::{app::test::mock::MockDataProvider, settings::Settings};
+
+    use super::*;
+
+    fn press(popup: &mut HistoryPopup, key_code: KeyCode) -> HistoryPopupReturn {
+        popup.handle_input(&Input::new(key_code, KeyModifiers::NONE))
+    }
+
+    #[tokio::test]
+    async fn pick_history_change() {
+        let mut app = App::new(MockDataProvider::new_with_data(), Settings::default());
+        app.load_entries().await.unwrap();
+
+        app.add_entry("Added".into(), DateTime::default(), vec![], None)
+            .await
+            .unwrap();
+        app.delete_entry(1).await.unwrap();
+        app.undo().await.unwrap();
+
+        let mut popup = HistoryPopup::new(&app);
+        let texts: Vec<&str> = popup.items.iter().map(|item| item.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            [
+                "Redo: Delete 'Title 2'",
+                CURRENT_STATE_TEXT,
+                "Undo: Delete 'Added'"
+            ]
+        );
+
+        // Current state is selected initially and closes the popup.
+        assert!(matches!(
+            press(&mut popup, KeyCode::Enter),
+            HistoryPopupReturn::Cancel
+        ));
+
+        press(&mut popup, KeyCode::Down);
+        press(&mut popup, KeyCode::Down);
+        assert!(matches!(
+            press(&mut popup, KeyCode::Enter),
+            HistoryPopupReturn::Apply(HistoryJump {
+                target: HistoryStack::Undo,
+                count: 1
+            })
+        ));
+
+        press(&mut popup, KeyCode::Up);
+        press(&mut popup, KeyCode::Up);
+        press(&mut popup, KeyCode::Up);
+        assert!(matches!(
+            press(&mut popup, KeyCode::Enter),
+            HistoryPopupReturn::Apply(HistoryJump {
+                target: HistoryStack::Redo,
+                count: 1
+            })
+        ));
+    }
+}
@@ -0,0 +1,142 @@
+use backend::DataProvider;
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::{keymap::Input, App};
+
+use super::{ui_functions::centered_rect, PopupReturn, Styles};
+
+type MergeTagsReturn = PopupReturn<MergeTagsRequest>;
+
+const FOOTER_TEXT: &str = "Enter: Merge into highlighted tag | Esc, q or <Ctrl-c>: Cancel";
+const FOOTER_MARGIN: u16 = 8;
+
+/// Merge of the source tag into the target tag requested via the merge tags popup.
+pub struct MergeTagsRequest {
+    pub from: String,
+    pub into: String,
+}
+
+/// Popup listing all tags except the source tag to pick the tag to merge the source tag into.
+pub struct MergeTagsPopup {
+    source_tag: String,
+    tags: Vec<String>,
+    state: ListState,
+}
+
+impl MergeTagsPopup {
+    pub fn new<D: DataProvider>(source_tag: String, app: &App<D>) -> Self {
+        let tags: Vec<String> = app
+            .get_all_tags()
+            .into_iter()
+            .filter(|tag| *tag != source_tag)
+            .collect();
+
+        let mut state = ListState::default();
+        state.select((!tags.is_empty()).then_some(0));
+
+        Self {
+            source_tag,
+            tags,
+            state,
+        }
+    }
+
+    /// Checks if there are no tags to merge into.
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    pub fn render_widget(&mut self, frame: &mut Frame, area: Rect, styles: &Styles) {
+        let area = centered_rect(50, 50, area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Merge Tag '{}'", self.source_tag));
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        let footer_height = if area.width < FOOTER_TEXT.len() as u16 + FOOTER_MARGIN {
+            2
+        } else {
+            1
+        };
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .horizontal_margin(4)
+            .vertical_margin(2)
+            .constraints([Constraint::Min(3), Constraint::Length(footer_height)].as_ref())
+            .split(area);
+
+        let items: Vec<ListItem> = self
+            .tags
+            .iter()
+            .map(|tag| ListItem::new(tag.as_str()).style(Style::reset()))
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .style(Style::from(styles.general.input_block_active))
+                    .title("Merge into"),
+            )
+            .highlight_style(Style::from(styles.general.list_highlight_active))
+            .highlight_symbol(">> ");
+
+        frame.render_stateful_widget(list, chunks[0], &mut self.state);
+
+        let footer = Paragraph::new(FOOTER_TEXT)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(footer, chunks[1]);
+    }
+
+    pub fn handle_input(&mut self, input: &Input) -> MergeTagsReturn {
+        let has_ctrl = input.modifiers.contains(KeyModifiers::CONTROL);
+        match input.key_code {
+            KeyCode::Esc | KeyCode::Char('q') => MergeTagsReturn::Cancel,
+            KeyCode::Char('c') if has_ctrl => MergeTagsReturn::Cancel,
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.cycle_selection(false);
+                MergeTagsReturn::KeepPopup
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.cycle_selection(true);
+                MergeTagsReturn::KeepPopup
+            }
+            KeyCode::Enter => match self.state.selected().and_then(|idx| self.tags.get(idx)) {
+                Some(tag) => MergeTagsReturn::Apply(MergeTagsRequest {
+                    from: self.source_tag.clone(),
+                    into: tag.to_owned(),
+                }),
+                None => MergeTagsReturn::KeepPopup,
+            },
+            _ => MergeTagsReturn::KeepPopup,
+        }
+    }
+
+    fn cycle_selection(&mut self, next: bool) {
+        if self.tags.is_empty() {
+            return;
+        }
+
+        let last_index = self.tags.len() - 1;
+        let new_index = match (self.state.selected(), next) {
+            (Some(idx), true) if idx < last_index => idx + 1,
+            (Some(idx), false) if idx > 0 => idx - 1,
+            (_, true) => 0,
+            (_, false) => last_index,
+        };
+
+        self.state.select(Some(new_index));
+    }
+}
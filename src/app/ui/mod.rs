@@ -1,4 +1,7 @@
-use std::path::PathBuf;
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
 
 use backend::DataProvider;
 pub use themes::Styles;
@@ -6,50 +9,81 @@ pub use themes::Styles;
 use self::{
     editor::{Editor, EditorMode},
     entries_list::EntriesList,
+    entry_color_popup::EntryColorPopup,
     entry_popup::{EntryPopup, EntryPopupInputReturn},
-    export_popup::ExportPopup,
+    export_popup::{ExportPopup, ExportRequest},
     filter_popup::FilterPopup,
     footer::{get_footer_heigh, render_footer},
     fuzz_find::FuzzFindPopup,
     help_popup::{HelpInputInputReturn, HelpPopup},
+    history_popup::{HistoryJump, HistoryPopup},
+    merge_tags_popup::MergeTagsPopup,
     msg_box::{MsgBox, MsgBoxActions, MsgBoxType},
+    quick_note_popup::QuickNotePopup,
+    remove_tags_popup::RemoveTagsPopup,
+    saved_filters_popup::SavedFiltersPopup,
+    search_popup::{SearchPopup, SearchRequest},
+    selection_input_popup::{SelectionInput, SelectionInputPopup},
+    settings_popup::{SettingsPopup, SettingsPopupReturn},
     sort_popup::SortPopup,
+    stats_popup::StatsPopup,
+    tag_color_popup::TagColorPopup,
+    tags_sidebar::TagsSidebar,
+    whats_new_popup::WhatsNewPopup,
 };
 
 use super::{
+    changelog::ReleaseNotes,
     keymap::{
-        get_editor_mode_keymaps, get_entries_list_keymaps, get_global_keymaps,
-        get_multi_select_keymaps, Input, Keymap,
+        apply_keybinding_overrides, get_editor_mode_keymaps, get_entries_list_keymaps,
+        get_global_keymaps, get_multi_select_keymaps, get_tags_sidebar_keymaps, Input, Keymap,
     },
     runner::HandleInputReturnType,
     App,
 };
+use crate::settings::{Settings, SingleEntryFilter};
 use anyhow::Result;
 
 use ratatui::{
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     Frame,
 };
 
 mod commands;
 mod editor;
+mod empty_content;
 mod entries_list;
+mod entry_color_popup;
 mod entry_popup;
 mod export_popup;
 mod filter_popup;
 mod footer;
 mod fuzz_find;
 mod help_popup;
+mod history_popup;
+mod merge_tags_popup;
 mod msg_box;
+mod quick_note_popup;
+mod remove_tags_popup;
+mod saved_filters_popup;
+mod search_popup;
+mod selection_input_popup;
+mod settings_popup;
 mod sort_popup;
+mod stats_popup;
+mod tag_color_popup;
+mod tags_sidebar;
 pub mod themes;
 pub mod ui_functions;
+mod whats_new_popup;
 
 pub use commands::UICommand;
+pub use editor::AutosaveStatus;
 pub use msg_box::MsgBoxResult;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ControlType {
+    TagsSidebar,
     EntriesList,
     EntryContentTxt,
 }
@@ -62,6 +96,18 @@ pub enum Popup<'a> {
     Filter(Box<FilterPopup<'a>>),
     FuzzFind(Box<FuzzFindPopup<'a>>),
     Sort(Box<SortPopup>),
+    TagColor(Box<TagColorPopup<'a>>),
+    RemoveTags(Box<RemoveTagsPopup>),
+    WhatsNew(Box<WhatsNewPopup>),
+    Settings(Box<SettingsPopup<'a>>),
+    QuickNote(Box<QuickNotePopup<'a>>),
+    EntryColor(Box<EntryColorPopup<'a>>),
+    SavedFilters(Box<SavedFiltersPopup<'a>>),
+    Search(Box<SearchPopup<'a>>),
+    SelectionInput(Box<SelectionInputPopup<'a>>),
+    MergeTags(Box<MergeTagsPopup>),
+    Stats(Box<StatsPopup>),
+    History(Box<HistoryPopup>),
 }
 
 #[derive(Debug, Clone)]
@@ -77,11 +123,19 @@ pub struct UIComponents<'a> {
     entries_list_keymaps: Vec<Keymap>,
     editor_keymaps: Vec<Keymap>,
     multi_select_keymaps: Vec<Keymap>,
+    tags_sidebar_keymaps: Vec<Keymap>,
+    tags_sidebar: TagsSidebar,
     entries_list: EntriesList,
     editor: Editor<'a>,
     popup_stack: Vec<Popup<'a>>,
     pub active_control: ControlType,
     pending_command: Option<UICommand>,
+    /// Tag which its content template is offered to be inserted in the current entry.
+    pending_template_tag: Option<String>,
+    /// Current entry has been cleared on purpose and shouldn't be replaced automatically.
+    current_entry_cleared: bool,
+    /// Timeout to dismiss unanswered confirmation prompts after.
+    confirmation_timeout: Option<Duration>,
 }
 
 impl<'a, 'b> UIComponents<'a> {
@@ -90,6 +144,8 @@ impl<'a, 'b> UIComponents<'a> {
         let entries_list_keymaps = get_entries_list_keymaps();
         let editor_keymaps = get_editor_mode_keymaps();
         let multi_select_keymaps = get_multi_select_keymaps();
+        let tags_sidebar_keymaps = get_tags_sidebar_keymaps();
+        let tags_sidebar = TagsSidebar::new();
         let mut entries_list = EntriesList::new();
         let editor = Editor::new();
 
@@ -102,12 +158,45 @@ impl<'a, 'b> UIComponents<'a> {
             entries_list_keymaps,
             editor_keymaps,
             multi_select_keymaps,
+            tags_sidebar_keymaps,
+            tags_sidebar,
             entries_list,
             editor,
             popup_stack: Vec::new(),
             active_control,
             pending_command: None,
+            pending_template_tag: None,
+            current_entry_cleared: false,
+            confirmation_timeout: None,
+        }
+    }
+
+    /// Overrides the default keys of the commands with the keybindings from the settings,
+    /// showing a warning about the keybindings which couldn't be applied.
+    pub fn apply_keybindings(&mut self, settings: &Settings) -> Result<()> {
+        let mut other_keymaps = [
+            &mut self.entries_list_keymaps,
+            &mut self.editor_keymaps,
+            &mut self.multi_select_keymaps,
+            &mut self.tags_sidebar_keymaps,
+        ];
+
+        let warnings = apply_keybinding_overrides(
+            &mut self.global_keymaps,
+            &mut other_keymaps,
+            &settings.keybindings,
+            settings.keybinding_conflicts,
+        )?;
+
+        if !warnings.is_empty() {
+            let msg = format!(
+                "Keybindings kept with their default keys:\n{}",
+                warnings.join("\n")
+            );
+            self.show_msg_box(MsgBoxType::Warning(msg), MsgBoxActions::Ok, None);
         }
+
+        Ok(())
     }
 
     pub fn has_popup(&self) -> bool {
@@ -116,7 +205,9 @@ impl<'a, 'b> UIComponents<'a> {
 
     pub fn set_current_entry<D: DataProvider>(&mut self, entry_id: Option<u32>, app: &mut App<D>) {
         app.current_entry_id = entry_id;
+        self.current_entry_cleared = false;
         if let Some(id) = entry_id {
+            app.record_viewed_entry(id);
             let entry_index = app.get_active_entries().position(|entry| entry.id == id);
             self.entries_list.state.select(entry_index);
         }
@@ -124,10 +215,33 @@ impl<'a, 'b> UIComponents<'a> {
         self.editor.set_current_entry(entry_id, app);
     }
 
+    /// Navigates to the entry with the given id, handling the entries hidden by the filter
+    /// according to the settings.
+    pub fn go_to_entry<D: DataProvider>(&mut self, entry_id: u32, app: &mut App<D>) {
+        if app.reveal_entry(entry_id) {
+            self.set_current_entry(Some(entry_id), app);
+        } else {
+            self.show_msg_box(
+                MsgBoxType::Info(String::from("The journal is hidden by the current filter")),
+                MsgBoxActions::Ok,
+                None,
+            );
+        }
+    }
+
     pub fn render_ui<D>(&mut self, f: &mut Frame, app: &'b App<D>)
     where
         D: DataProvider,
     {
+        let (min_width, min_height) = (
+            app.settings.min_terminal_width,
+            app.settings.min_terminal_height,
+        );
+        if f.area().width < min_width || f.area().height < min_height {
+            ui_functions::render_terminal_too_small(f, min_width, min_height);
+            return;
+        }
+
         let footer_height = get_footer_heigh(f.area().width, self, app);
 
         let chunks = Layout::default()
@@ -138,6 +252,10 @@ impl<'a, 'b> UIComponents<'a> {
         render_footer(f, chunks[1], self, app);
         if app.state.full_screen {
             match self.active_control {
+                ControlType::TagsSidebar => {
+                    self.tags_sidebar
+                        .render_widget(f, chunks[0], app, &self.styles);
+                }
                 ControlType::EntriesList => {
                     self.entries_list.render_widget(
                         f,
@@ -148,9 +266,31 @@ impl<'a, 'b> UIComponents<'a> {
                     );
                 }
                 ControlType::EntryContentTxt => {
-                    self.editor.render_widget(f, chunks[0], &self.styles);
+                    self.render_content(f, chunks[0], app);
                 }
             }
+        } else if self.tags_sidebar.is_visible {
+            let entries_chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints(
+                    [
+                        Constraint::Percentage(20),
+                        Constraint::Percentage(25),
+                        Constraint::Percentage(55),
+                    ]
+                    .as_ref(),
+                )
+                .split(chunks[0]);
+            self.tags_sidebar
+                .render_widget(f, entries_chunks[0], app, &self.styles);
+            self.entries_list.render_widget(
+                f,
+                entries_chunks[1],
+                app,
+                &self.entries_list_keymaps,
+                &self.styles,
+            );
+            self.render_content(f, entries_chunks[2], app);
         } else {
             let entries_chunks = Layout::default()
                 .direction(Direction::Horizontal)
@@ -163,13 +303,27 @@ impl<'a, 'b> UIComponents<'a> {
                 &self.entries_list_keymaps,
                 &self.styles,
             );
-            self.editor
-                .render_widget(f, entries_chunks[1], &self.styles);
+            self.render_content(f, entries_chunks[1], app);
         }
 
         self.render_popup(f);
     }
 
+    /// Renders the editor in the content area, or the configured content instead of it when no
+    /// journal is selected.
+    fn render_content<D: DataProvider>(&mut self, f: &mut Frame, area: Rect, app: &App<D>) {
+        match empty_content::get_empty_content(app) {
+            Some(content) => {
+                let keymaps = self
+                    .global_keymaps
+                    .iter()
+                    .chain(self.entries_list_keymaps.iter());
+                empty_content::render_empty_content(f, area, content, app, keymaps, &self.styles);
+            }
+            None => self.editor.render_widget(f, area, &self.styles),
+        }
+    }
+
     pub fn render_popup(&mut self, f: &mut Frame) {
         if let Some(popup) = self.popup_stack.last_mut() {
             match popup {
@@ -184,6 +338,38 @@ impl<'a, 'b> UIComponents<'a> {
                 }
                 Popup::FuzzFind(fuzz_find) => fuzz_find.render_widget(f, f.area(), &self.styles),
                 Popup::Sort(sort_popup) => sort_popup.render_widget(f, f.area(), &self.styles),
+                Popup::TagColor(tag_color_popup) => {
+                    tag_color_popup.render_widget(f, f.area(), &self.styles)
+                }
+                Popup::RemoveTags(remove_tags_popup) => {
+                    remove_tags_popup.render_widget(f, f.area(), &self.styles)
+                }
+                Popup::WhatsNew(whats_new_popup) => whats_new_popup.render_widget(f, f.area()),
+                Popup::Settings(settings_popup) => {
+                    settings_popup.render_widget(f, f.area(), &self.styles)
+                }
+                Popup::QuickNote(quick_note_popup) => {
+                    quick_note_popup.render_widget(f, f.area(), &self.styles)
+                }
+                Popup::EntryColor(entry_color_popup) => {
+                    entry_color_popup.render_widget(f, f.area(), &self.styles)
+                }
+                Popup::SavedFilters(saved_filters_popup) => {
+                    saved_filters_popup.render_widget(f, f.area(), &self.styles)
+                }
+                Popup::Search(search_popup) => {
+                    search_popup.render_widget(f, f.area(), &self.styles)
+                }
+                Popup::SelectionInput(selection_input_popup) => {
+                    selection_input_popup.render_widget(f, f.area(), &self.styles)
+                }
+                Popup::MergeTags(merge_tags_popup) => {
+                    merge_tags_popup.render_widget(f, f.area(), &self.styles)
+                }
+                Popup::Stats(stats_popup) => stats_popup.render_widget(f, f.area()),
+                Popup::History(history_popup) => {
+                    history_popup.render_widget(f, f.area(), &self.styles)
+                }
             }
         }
     }
@@ -217,6 +403,13 @@ impl<'a, 'b> UIComponents<'a> {
             return Ok(HandleInputReturnType::Handled);
         }
 
+        // Tags sidebar keys take precedence over the global ones, like entering the tags.
+        if self.active_control == ControlType::TagsSidebar {
+            if let Some(key) = self.tags_sidebar_keymaps.iter().find(|c| &c.key == input) {
+                return key.command.clone().execute(self, app).await;
+            }
+        }
+
         if let Some(cmd) = self
             .global_keymaps
             .iter()
@@ -226,6 +419,7 @@ impl<'a, 'b> UIComponents<'a> {
             cmd.execute(self, app).await
         } else {
             match self.active_control {
+                ControlType::TagsSidebar => Ok(self.tags_sidebar.handle_input(input, app)),
                 ControlType::EntriesList => {
                     if let Some(key) = self.entries_list_keymaps.iter().find(|c| &c.key == input) {
                         key.command.clone().execute(self, app).await
@@ -237,7 +431,16 @@ impl<'a, 'b> UIComponents<'a> {
                     if let Some(key) = self.editor_keymaps.iter().find(|c| &c.key == input) {
                         key.command.clone().execute(self, app).await
                     } else {
-                        self.editor.handle_input(input, app)
+                        let result = self.editor.handle_input(input, app)?;
+                        // The editor rejects the inputs which edit the content in read-only mode.
+                        if app.settings.is_read_only()
+                            && matches!(result, HandleInputReturnType::NotFound)
+                        {
+                            self.show_read_only_msg();
+                            return Ok(HandleInputReturnType::Handled);
+                        }
+
+                        Ok(result)
                     }
                 }
             }
@@ -257,15 +460,26 @@ impl<'a, 'b> UIComponents<'a> {
                     }
                 }
                 Popup::Entry(entry_popup) => {
+                    let mut prompt_tags = false;
+                    let mut template_tag = None;
+                    let previous_tags = app
+                        .get_current_entry()
+                        .map(|entry| entry.tags.clone())
+                        .unwrap_or_default();
                     let close_popup = match entry_popup.handle_input(input, app).await? {
                         EntryPopupInputReturn::Cancel => true,
                         EntryPopupInputReturn::KeepPopup => false,
                         EntryPopupInputReturn::AddEntry(entry_id) => {
                             self.set_current_entry(Some(entry_id), app);
+                            prompt_tags = app.needs_tags_prompt(entry_id);
+                            template_tag = app.get_new_tag_template(entry_id, &[]);
                             true
                         }
                         EntryPopupInputReturn::UpdateCurrentEntry => {
                             self.set_current_entry(app.current_entry_id, app);
+                            template_tag = app.current_entry_id.and_then(|entry_id| {
+                                app.get_new_tag_template(entry_id, &previous_tags)
+                            });
                             true
                         }
                     };
@@ -273,6 +487,15 @@ impl<'a, 'b> UIComponents<'a> {
                     if close_popup {
                         self.popup_stack.pop().expect("popup stack isn't empty");
                     }
+
+                    if prompt_tags {
+                        return UICommand::PromptTagsNewEntry.execute(self, app).await;
+                    }
+
+                    if template_tag.is_some() {
+                        self.pending_template_tag = template_tag;
+                        return UICommand::InsertTagTemplate.execute(self, app).await;
+                    }
                 }
                 Popup::MsgBox(msg_box) => match msg_box.handle_input(input) {
                     msg_box::MsgBoxInputResult::Keep => {}
@@ -289,8 +512,66 @@ impl<'a, 'b> UIComponents<'a> {
                         PopupReturn::Cancel => {
                             self.popup_stack.pop().expect("popup stack isn't empty");
                         }
-                        PopupReturn::Apply((path, entry_id)) => {
-                            self.handle_export_popup_return(path, entry_id, app).await;
+                        PopupReturn::Apply(ExportRequest::WriteEntry(path, entry_id, format)) => {
+                            let result = app.export_entry_content(entry_id, path.clone(), format);
+                            let msg = format!("Journal exported to file {}", path.display());
+                            self.handle_export_result(result.await, msg, app);
+                        }
+                        PopupReturn::Apply(ExportRequest::Write(path)) => {
+                            let result = app.export_entries(path.clone()).await;
+                            let msg = format!("Journal(s)  exported to file {}", path.display());
+                            self.handle_export_result(result, msg, app);
+                        }
+                        PopupReturn::Apply(ExportRequest::WriteMarkdown(path)) => {
+                            let result = app.export_entries_markdown(path.clone()).await;
+                            let msg = format!(
+                                "Journal(s) exported as Markdown to file {}",
+                                path.display()
+                            );
+                            self.handle_export_result(result, msg, app);
+                        }
+                        PopupReturn::Apply(ExportRequest::WriteCsv(path)) => {
+                            let result = app.export_metadata_csv(path.clone()).await;
+                            let msg = format!(
+                                "Journal(s) metadata exported as CSV to file {}",
+                                path.display()
+                            );
+                            self.handle_export_result(result, msg, app);
+                        }
+                        PopupReturn::Apply(ExportRequest::PreviewCsv) => {
+                            match app.get_metadata_csv() {
+                                Ok(content) => export_popup.show_preview(content),
+                                Err(err) => self.show_err_msg(format!(
+                                    "Error while creating export preview. Err: {err}"
+                                )),
+                            }
+                        }
+                        PopupReturn::Apply(ExportRequest::PreviewMarkdown) => {
+                            match app.get_markdown_export_content() {
+                                Ok(content) => export_popup.show_preview(content),
+                                Err(err) => self.show_err_msg(format!(
+                                    "Error while creating export preview. Err: {err}"
+                                )),
+                            }
+                        }
+                        PopupReturn::Apply(ExportRequest::MarkdownFiles(dir)) => {
+                            self.handle_markdown_files_export(dir, app).await;
+                        }
+                        PopupReturn::Apply(ExportRequest::PreviewEntry(entry_id, format)) => {
+                            match app.get_entry_export_content(entry_id, format) {
+                                Ok(content) => export_popup.show_preview(content),
+                                Err(err) => self.show_err_msg(format!(
+                                    "Error while creating export preview. Err: {err}"
+                                )),
+                            }
+                        }
+                        PopupReturn::Apply(ExportRequest::Preview) => {
+                            match app.get_export_content().await {
+                                Ok(content) => export_popup.show_preview(content),
+                                Err(err) => self.show_err_msg(format!(
+                                    "Error while creating export preview. Err: {err}"
+                                )),
+                            }
                         }
                     };
                 }
@@ -301,6 +582,13 @@ impl<'a, 'b> UIComponents<'a> {
                     }
                     PopupReturn::Apply(filter) => {
                         app.apply_filter(filter);
+
+                        // The popup stays open with the edits until the filter hiding all journals
+                        // is confirmed, so they aren't lost if it's declined.
+                        if app.has_pending_filter() {
+                            return UICommand::ConfirmEmptyFilter.execute(self, app).await;
+                        }
+
                         self.popup_stack.pop().expect("popup stack isn't empty");
 
                         // This fixes the bug: Entry will not be highlighted when the result of the filter is one entry only
@@ -336,6 +624,181 @@ impl<'a, 'b> UIComponents<'a> {
                         self.set_current_entry(current_entry_id, app);
                     }
                 },
+                Popup::TagColor(tag_color_popup) => {
+                    match tag_color_popup.handle_input(input, app) {
+                        PopupReturn::KeepPopup => {}
+                        PopupReturn::Cancel => {
+                            self.popup_stack.pop().expect("popup stack isn't empty");
+                        }
+                        PopupReturn::Apply(tag) => {
+                            self.popup_stack.pop().expect("popup stack isn't empty");
+                            if let Err(err) = app.persist_tag_color(&tag).await {
+                                self.show_err_msg(format!(
+                                    "Error while saving tag colors. Err: {err}"
+                                ));
+                            }
+                        }
+                    }
+                }
+                Popup::RemoveTags(remove_tags_popup) => {
+                    match remove_tags_popup.handle_input(input, app).await? {
+                        PopupReturn::KeepPopup => {}
+                        PopupReturn::Cancel | PopupReturn::Apply(()) => {
+                            self.popup_stack.pop().expect("popup stack isn't empty");
+                            // Removing tags can filter the current entry out.
+                            self.set_current_entry(app.current_entry_id, app);
+                        }
+                    }
+                }
+                Popup::WhatsNew(whats_new_popup) => match whats_new_popup.handle_input(input) {
+                    PopupReturn::KeepPopup => {}
+                    PopupReturn::Cancel | PopupReturn::Apply(()) => {
+                        self.popup_stack.pop().expect("popup stack isn't empty");
+                    }
+                },
+                Popup::Settings(settings_popup) => match settings_popup.handle_input(input) {
+                    SettingsPopupReturn::KeepPopup => {}
+                    SettingsPopupReturn::Close => {
+                        self.popup_stack.pop().expect("popup stack isn't empty");
+                    }
+                    SettingsPopupReturn::Change(key, value) => {
+                        let result = app.change_setting(key, value).await;
+                        settings_popup.update_values(&app.settings);
+
+                        if key == "autosave_interval" {
+                            let interval = app.settings.autosave_interval.map(Duration::from_secs);
+                            self.set_autosave_interval(interval);
+                        }
+
+                        if key == "confirmation_timeout" {
+                            let timeout =
+                                app.settings.confirmation_timeout.map(Duration::from_secs);
+                            self.set_confirmation_timeout(timeout);
+                        }
+
+                        if let Err(err) = result {
+                            self.show_err_msg(format!("Error while changing settings. Err: {err}"));
+                        }
+                    }
+                },
+                Popup::QuickNote(quick_note_popup) => match quick_note_popup.handle_input(input) {
+                    PopupReturn::KeepPopup => {}
+                    PopupReturn::Cancel => {
+                        self.popup_stack.pop().expect("popup stack isn't empty");
+                    }
+                    PopupReturn::Apply(note) => {
+                        self.popup_stack.pop().expect("popup stack isn't empty");
+                        let entry_id = app.add_quick_note(note).await?;
+                        self.set_current_entry(Some(entry_id), app);
+                    }
+                },
+                Popup::EntryColor(entry_color_popup) => {
+                    match entry_color_popup.handle_input(input) {
+                        PopupReturn::KeepPopup => {}
+                        PopupReturn::Cancel => {
+                            self.popup_stack.pop().expect("popup stack isn't empty");
+                        }
+                        PopupReturn::Apply(color) => {
+                            self.popup_stack.pop().expect("popup stack isn't empty");
+                            app.set_current_entry_color(color).await?;
+                        }
+                    }
+                }
+                Popup::SavedFilters(saved_filters_popup) => {
+                    match saved_filters_popup.handle_input(input, app) {
+                        PopupReturn::KeepPopup => {}
+                        PopupReturn::Cancel => {
+                            self.popup_stack.pop().expect("popup stack isn't empty");
+                        }
+                        PopupReturn::Apply(()) => {
+                            self.popup_stack.pop().expect("popup stack isn't empty");
+
+                            if app.has_pending_filter() {
+                                return UICommand::ConfirmEmptyFilter.execute(self, app).await;
+                            }
+
+                            if app.get_active_entries().count() == 1 {
+                                let entry_id =
+                                    app.get_active_entries().next().map(|entry| entry.id);
+                                self.set_current_entry(entry_id, app);
+                            }
+                        }
+                    }
+                }
+                Popup::Search(search_popup) => match search_popup.handle_input(input) {
+                    PopupReturn::KeepPopup => {}
+                    PopupReturn::Cancel => {
+                        self.popup_stack.pop().expect("popup stack isn't empty");
+                    }
+                    PopupReturn::Apply(request) => {
+                        self.popup_stack.pop().expect("popup stack isn't empty");
+                        self.apply_search(request, app);
+                    }
+                },
+                Popup::SelectionInput(selection_input_popup) => {
+                    match selection_input_popup.handle_input(input) {
+                        PopupReturn::KeepPopup => {}
+                        PopupReturn::Cancel => {
+                            self.popup_stack.pop().expect("popup stack isn't empty");
+                        }
+                        PopupReturn::Apply(value) => {
+                            self.popup_stack.pop().expect("popup stack isn't empty");
+                            let result = match value {
+                                SelectionInput::Tag(tag) => app.add_tag_to_selected(tag).await,
+                                SelectionInput::Priority(priority) => {
+                                    app.set_priority_for_selected(priority).await
+                                }
+                                SelectionInput::TagRename { old, new } => {
+                                    app.rename_tag(&old, &new).await
+                                }
+                            };
+                            // Changing the journals can filter the current entry out.
+                            self.set_current_entry(app.current_entry_id, app);
+                            if let Err(err) = result {
+                                self.show_err_msg(format!(
+                                    "Error while changing journals. Err: {err}"
+                                ));
+                            }
+                        }
+                    }
+                }
+                Popup::MergeTags(merge_tags_popup) => match merge_tags_popup.handle_input(input) {
+                    PopupReturn::KeepPopup => {}
+                    PopupReturn::Cancel => {
+                        self.popup_stack.pop().expect("popup stack isn't empty");
+                    }
+                    PopupReturn::Apply(request) => {
+                        self.popup_stack.pop().expect("popup stack isn't empty");
+                        let result = app.merge_tags(&request.from, &request.into).await;
+                        self.set_current_entry(app.current_entry_id, app);
+                        if let Err(err) = result {
+                            self.show_err_msg(format!("Error while merging tags. Err: {err}"));
+                        }
+                    }
+                },
+                Popup::Stats(stats_popup) => match stats_popup.handle_input(input, app) {
+                    PopupReturn::KeepPopup => {}
+                    PopupReturn::Cancel | PopupReturn::Apply(()) => {
+                        self.popup_stack.pop().expect("popup stack isn't empty");
+                    }
+                },
+                Popup::History(history_popup) => match history_popup.handle_input(input) {
+                    PopupReturn::KeepPopup => {}
+                    PopupReturn::Cancel => {
+                        self.popup_stack.pop().expect("popup stack isn't empty");
+                    }
+                    PopupReturn::Apply(jump) => {
+                        self.popup_stack.pop().expect("popup stack isn't empty");
+
+                        if app.history_jump_needs_confirm(jump.target, jump.count) {
+                            return UICommand::JumpHistoryPastSave(jump)
+                                .execute(self, app)
+                                .await;
+                        }
+
+                        self.apply_history_jump(jump, app).await;
+                    }
+                },
             }
             Ok(HandleInputReturnType::Handled)
         } else {
@@ -343,25 +806,51 @@ impl<'a, 'b> UIComponents<'a> {
         }
     }
 
-    async fn handle_export_popup_return<D: DataProvider>(
-        &mut self,
-        path: PathBuf,
-        entry_id: Option<u32>,
-        app: &mut App<D>,
-    ) {
-        let (result, confirmation_msg) = if self.entries_list.multi_select_mode {
-            let result = app.export_entries(path.clone()).await;
-            let msg = format!("Journal(s)  exported to file {}", path.display());
+    /// Undoes or redoes the changes according to the given jump picked in the history popup,
+    /// going to the entry of the last applied change.
+    async fn apply_history_jump<D: DataProvider>(&mut self, jump: HistoryJump, app: &mut App<D>) {
+        match app.jump_history(jump.target, jump.count).await {
+            Ok(Some(id)) => self.go_to_entry(id, app),
+            Ok(None) => {}
+            Err(err) => {
+                self.show_err_msg(format!("Error while applying history changes. Err: {err}"))
+            }
+        }
+    }
 
-            (result, msg)
-        } else {
-            let entry_id = entry_id.expect("entry id must have a value in normal mode");
-            let result = app.export_entry_content(entry_id, path.clone()).await;
-            let msg = format!("Journal content exported to file {}", path.display());
+    /// Applies the given full-text search, highlighting its matches in the editor and jumping
+    /// to the next match if the current entry doesn't match.
+    fn apply_search<D: DataProvider>(&mut self, request: SearchRequest, app: &mut App<D>) {
+        app.set_search(request.query, request.case_sensitive);
+        self.editor
+            .set_search_query(app.get_search_query(), app.is_search_case_sensitive());
 
-            (result, msg)
+        let Some(query) = app.get_search_query() else {
+            return;
         };
 
+        let matches = app.search(query);
+        if matches.is_empty() {
+            self.show_msg_box(
+                MsgBoxType::Info(String::from("No journals match the search")),
+                MsgBoxActions::Ok,
+                None,
+            );
+        } else if !app
+            .current_entry_id
+            .is_some_and(|current_id| matches.contains(&current_id))
+        {
+            let entry_id = app.get_search_match(true);
+            self.set_current_entry(entry_id, app);
+        }
+    }
+
+    fn handle_export_result<D: DataProvider>(
+        &mut self,
+        result: anyhow::Result<()>,
+        confirmation_msg: String,
+        app: &App<D>,
+    ) {
         match result {
             Ok(_) => {
                 self.popup_stack.pop().expect("popup stack isn't empty");
@@ -376,8 +865,32 @@ impl<'a, 'b> UIComponents<'a> {
         };
     }
 
+    async fn handle_markdown_files_export<D: DataProvider>(
+        &mut self,
+        dir: PathBuf,
+        app: &mut App<D>,
+    ) {
+        match app.export_markdown_files(dir).await {
+            Ok(index_path) => {
+                self.popup_stack.pop().expect("popup stack isn't empty");
+
+                if app.settings.export.show_confirmation {
+                    let msg = format!(
+                        "Journal(s) exported as Markdown files with the index {}",
+                        index_path.display()
+                    );
+                    self.show_msg_box(MsgBoxType::Info(msg), MsgBoxActions::Ok, None);
+                }
+            }
+            Err(err) => {
+                self.show_err_msg(format!("Error while exporting journal(s). Err: {err}",));
+            }
+        }
+    }
+
     fn set_control_is_active(&mut self, control: ControlType, is_active: bool) {
         match control {
+            ControlType::TagsSidebar => self.tags_sidebar.set_active(is_active),
             ControlType::EntriesList => self.entries_list.set_active(is_active),
             ControlType::EntryContentTxt => self.editor.set_active(is_active),
         }
@@ -413,7 +926,11 @@ impl<'a, 'b> UIComponents<'a> {
         pending_cmd: Option<UICommand>,
     ) {
         self.pending_command = pending_cmd;
-        let msg_box = MsgBox::new(msg, msg_actions);
+        let is_question = matches!(msg, MsgBoxType::Question(_));
+        let mut msg_box = MsgBox::new(msg, msg_actions);
+        if is_question {
+            msg_box = self.apply_confirmation_timeout(msg_box);
+        }
 
         self.popup_stack.push(Popup::MsgBox(Box::new(msg_box)));
     }
@@ -423,24 +940,530 @@ impl<'a, 'b> UIComponents<'a> {
         let msg =
             MsgBoxType::Question("Do you want to save the changes on the current journal?".into());
         let msg_actions = MsgBoxActions::YesNoCancel;
-        let msg_box = MsgBox::new(msg, msg_actions);
+        let msg_box = self.apply_confirmation_timeout(MsgBox::new(msg, msg_actions));
 
         self.popup_stack.push(Popup::MsgBox(Box::new(msg_box)));
     }
 
+    fn apply_confirmation_timeout(&self, msg_box: MsgBox) -> MsgBox {
+        match self.confirmation_timeout {
+            Some(timeout) => msg_box.with_timeout(timeout),
+            None => msg_box,
+        }
+    }
+
+    /// Sets the timeout of the confirmation prompts. A zero timeout disables it like `None`.
+    pub fn set_confirmation_timeout(&mut self, timeout: Option<Duration>) {
+        self.confirmation_timeout = timeout.filter(|timeout| !timeout.is_zero());
+    }
+
+    /// Closes the message box on top of the popups if its timeout has elapsed at the given
+    /// instant, continuing the pending command with the result of the timeout.
+    pub async fn dismiss_timed_out_msg_box<D: DataProvider>(
+        &mut self,
+        app: &mut App<D>,
+        now: Instant,
+    ) -> Result<HandleInputReturnType> {
+        let Some(Popup::MsgBox(msg_box)) = self.popup_stack.last() else {
+            return Ok(HandleInputReturnType::Ignore);
+        };
+
+        let Some(msg_box_result) = msg_box.check_timeout(now) else {
+            return Ok(HandleInputReturnType::Ignore);
+        };
+
+        self.popup_stack.pop().expect("popup stack isn't empty");
+        if let Some(cmd) = self.pending_command.take() {
+            return cmd.continue_executing(self, app, msg_box_result).await;
+        }
+
+        Ok(HandleInputReturnType::Handled)
+    }
+
     #[inline]
     pub fn has_unsaved(&self) -> bool {
         self.editor.has_unsaved()
     }
 
+    pub fn set_autosave_interval(&mut self, interval: Option<Duration>) {
+        self.editor.set_autosave_interval(interval);
+    }
+
+    pub fn autosave_status(&self) -> AutosaveStatus {
+        self.editor.autosave_status()
+    }
+
+    /// Saves the content of the current journal once the autosave interval of its unsaved
+    /// changes has elapsed. Saving waits while popups are open since they can still decide
+    /// about the unsaved changes, like discarding them.
+    pub async fn autosave_if_due<D: DataProvider>(&mut self, app: &mut App<D>) -> Result<()> {
+        if self.has_popup() || !self.editor.is_autosave_due() {
+            return Ok(());
+        }
+
+        if let Err(err) = UICommand::SaveEntryContent.execute(self, app).await {
+            // Avoid retrying on each tick while the error is shown
+            self.editor.restart_autosave();
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
     pub fn show_err_msg(&mut self, err_txt: String) {
         self.show_msg_box(MsgBoxType::Error(err_txt), MsgBoxActions::Ok, None);
     }
 
+    pub fn show_info_msg(&mut self, info_txt: String) {
+        self.show_msg_box(MsgBoxType::Info(info_txt), MsgBoxActions::Ok, None);
+    }
+
+    pub fn show_read_only_msg(&mut self) {
+        self.show_msg_box(
+            MsgBoxType::Info(String::from("Journals are opened in read-only mode")),
+            MsgBoxActions::Ok,
+            None,
+        );
+    }
+
+    pub fn show_whats_new(&mut self, notes: ReleaseNotes) {
+        let popup = WhatsNewPopup::new(notes);
+        self.popup_stack.push(Popup::WhatsNew(Box::new(popup)));
+    }
+
+    /// Clears the current entry and the selection in entries list, keeping them cleared until
+    /// another entry is selected.
+    pub fn clear_current_entry<D: DataProvider>(&mut self, app: &mut App<D>) {
+        self.set_current_entry(None, app);
+        self.entries_list.state.select(None);
+        self.current_entry_cleared = true;
+    }
+
+    /// Shows a notification about the tags dropped from the filter if any, waiting for the open
+    /// popups to be closed first.
+    pub fn notify_dropped_filter_tags<D: DataProvider>(&mut self, app: &mut App<D>) {
+        if self.has_popup() {
+            return;
+        }
+
+        let dropped_tags = app.take_dropped_filter_tags();
+        if dropped_tags.is_empty() {
+            return;
+        }
+
+        let msg = format!(
+            "Tag(s) removed from the filter since no journals have them anymore: {}",
+            dropped_tags.join(", ")
+        );
+        self.show_msg_box(MsgBoxType::Info(msg), MsgBoxActions::Ok, None);
+    }
+
+    /// Shows a notification about the duplicated tags removed from journals if any, waiting for
+    /// the open popups to be closed first.
+    pub fn notify_duplicate_tags<D: DataProvider>(&mut self, app: &mut App<D>) {
+        if self.has_popup() {
+            return;
+        }
+
+        let duplicate_tags = app.take_duplicate_tags();
+        if duplicate_tags.is_empty() {
+            return;
+        }
+
+        let msg = format!(
+            "Duplicated tag(s) removed from the journal: {}",
+            duplicate_tags.join(", ")
+        );
+        self.show_msg_box(MsgBoxType::Info(msg), MsgBoxActions::Ok, None);
+    }
+
     pub fn update_current_entry<D: DataProvider>(&mut self, app: &mut App<D>) {
-        if app.get_current_entry().is_none() {
+        if let Some(entry_id) = app.take_single_active_entry() {
+            if !self.has_unsaved() && !self.has_popup() {
+                self.set_current_entry(Some(entry_id), app);
+                if app.settings.single_entry_filter == SingleEntryFilter::Open {
+                    self.change_active_control(ControlType::EntryContentTxt);
+                }
+                return;
+            }
+        }
+
+        if app.get_current_entry().is_none() && !self.current_entry_cleared {
             let first_entry = app.get_active_entries().next().map(|entry| entry.id);
             self.set_current_entry(first_entry, app);
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use ratatui::{backend::TestBackend, Terminal};
+
+    use crate::app::{
+        filter::{Filter, FilterCriterion},
+        sorter::{SortCriteria, SortOrder},
+        test::mock::MockDataProvider,
+    };
+
+    use super::*;
+
+    fn render_to_text(width: u16, height: u16) -> String {
+        let app = App::new(MockDataProvider::default(), Settings::default());
+        let mut ui_components = UIComponents::new(Styles::default());
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal
+            .draw(|frame| ui_components.render_ui(frame, &app))
+            .unwrap();
+
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn render_terminal_too_small() {
+        let settings = Settings::default();
+        let (min_width, min_height) = (settings.min_terminal_width, settings.min_terminal_height);
+
+        let text = render_to_text(min_width - 1, min_height);
+        assert!(text.contains("Terminal too small"), "{text}");
+        assert!(!text.contains("Journals"));
+
+        let text = render_to_text(min_width, min_height - 1);
+        assert!(text.contains("Terminal too small"), "{text}");
+
+        // Tiny terminals don't panic
+        render_to_text(1, 1);
+        render_to_text(0, 0);
+
+        let text = render_to_text(min_width, min_height);
+        assert!(!text.contains("Terminal too small"));
+        assert!(text.contains("Journals"));
+    }
+
+    #[tokio::test]
+    async fn priority_keeps_list_selection() {
+        let mut app = App::new(MockDataProvider::new_with_data(), Settings::default());
+        app.load_entries().await.unwrap();
+        app.apply_sort(vec![SortCriteria::Priority], SortOrder::Descending);
+        let mut ui_components = UIComponents::new(Styles::default());
+        ui_components.set_current_entry(Some(0), &mut app);
+        assert_eq!(ui_components.entries_list.state.selected(), Some(1));
+
+        // Changing the priority moves the entry when the entries are sorted by it
+        UICommand::BumpEntryPriority
+            .execute(&mut ui_components, &mut app)
+            .await
+            .unwrap();
+        assert_eq!(ui_components.entries_list.state.selected(), Some(0));
+    }
+
+    #[tokio::test]
+    async fn pin_keeps_list_selection() {
+        let mut app = App::new(MockDataProvider::new_with_data(), Settings::default());
+        app.load_entries().await.unwrap();
+        let mut ui_components = UIComponents::new(Styles::default());
+        ui_components.set_current_entry(Some(0), &mut app);
+        assert_eq!(ui_components.entries_list.state.selected(), Some(1));
+
+        UICommand::TogglePinEntry
+            .execute(&mut ui_components, &mut app)
+            .await
+            .unwrap();
+        assert_eq!(ui_components.entries_list.state.selected(), Some(0));
+
+        UICommand::TogglePinEntry
+            .execute(&mut ui_components, &mut app)
+            .await
+            .unwrap();
+        assert_eq!(ui_components.entries_list.state.selected(), Some(1));
+    }
+
+    #[tokio::test]
+    async fn focus_mode_keeps_list_selection() {
+        let mut app = App::new(MockDataProvider::new_with_data(), Settings::default());
+        app.load_entries().await.unwrap();
+        let mut ui_components = UIComponents::new(Styles::default());
+        ui_components.set_current_entry(Some(0), &mut app);
+        assert_eq!(ui_components.entries_list.state.selected(), Some(1));
+
+        // Focus mode hides the entries above the current one
+        UICommand::ToggleFocusMode
+            .execute(&mut ui_components, &mut app)
+            .await
+            .unwrap();
+        assert_eq!(ui_components.entries_list.state.selected(), Some(0));
+
+        UICommand::ToggleFocusMode
+            .execute(&mut ui_components, &mut app)
+            .await
+            .unwrap();
+        assert_eq!(ui_components.entries_list.state.selected(), Some(1));
+    }
+
+    #[tokio::test]
+    async fn tags_sidebar_enter_toggles_tag() {
+        let mut app = App::new(MockDataProvider::new_with_data(), Settings::default());
+        app.load_entries().await.unwrap();
+        let mut ui_components = UIComponents::new(Styles::default());
+        let press = |key_code| Input::new(key_code, KeyModifiers::NONE);
+
+        UICommand::ToggleTagsSidebar
+            .execute(&mut ui_components, &mut app)
+            .await
+            .unwrap();
+        ui_components
+            .handle_input(&press(KeyCode::Home), &mut app)
+            .await
+            .unwrap();
+        let tag = ui_components.tags_sidebar.selected_tag(&app).unwrap();
+
+        // Enter toggles the tag in the sidebar instead of starting to edit the journal.
+        ui_components
+            .handle_input(&press(KeyCode::Enter), &mut app)
+            .await
+            .unwrap();
+        assert_eq!(ui_components.active_control, ControlType::TagsSidebar);
+        assert_eq!(
+            app.filter.as_ref().unwrap().criteria,
+            [FilterCriterion::Tag(tag)]
+        );
+
+        ui_components
+            .handle_input(&press(KeyCode::Enter), &mut app)
+            .await
+            .unwrap();
+        assert!(app.filter.is_none());
+    }
+
+    #[tokio::test]
+    async fn tags_sidebar_rename_without_current_entry() {
+        let mut app = App::new(MockDataProvider::new_with_data(), Settings::default());
+        app.load_entries().await.unwrap();
+        let mut ui_components = UIComponents::new(Styles::default());
+        ui_components.set_current_entry(None, &mut app);
+        let press = |key_code| Input::new(key_code, KeyModifiers::NONE);
+
+        UICommand::ToggleTagsSidebar
+            .execute(&mut ui_components, &mut app)
+            .await
+            .unwrap();
+        ui_components
+            .handle_input(&press(KeyCode::Home), &mut app)
+            .await
+            .unwrap();
+        assert_eq!(
+            ui_components.tags_sidebar.selected_tag(&app).as_deref(),
+            Some("Tag 1")
+        );
+
+        UICommand::TagsSidebarRenameTag
+            .execute(&mut ui_components, &mut app)
+            .await
+            .unwrap();
+        for key_code in [KeyCode::Char('!'), KeyCode::Enter] {
+            ui_components
+                .handle_input(&press(key_code), &mut app)
+                .await
+                .unwrap();
+        }
+
+        assert!(!ui_components.has_popup());
+        assert!(app.current_entry_id.is_none());
+        assert_eq!(app.get_entry(0).unwrap().tags, ["Tag 1!", "Tag 2"]);
+    }
+
+    #[tokio::test]
+    async fn autosave_waits_for_popups() {
+        let mut app = App::new(MockDataProvider::new_with_data(), Settings::default());
+        app.load_entries().await.unwrap();
+        let mut ui_components = UIComponents::new(Styles::default());
+        ui_components.set_autosave_interval(Some(Duration::ZERO));
+        ui_components.set_current_entry(Some(0), &mut app);
+        let original_content = app.get_entry(0).unwrap().content.clone();
+
+        ui_components.change_active_control(ControlType::EntryContentTxt);
+        for ch in ['i', 'a'] {
+            ui_components
+                .handle_input(&Input::new(KeyCode::Char(ch), KeyModifiers::NONE), &mut app)
+                .await
+                .unwrap();
+        }
+        assert!(ui_components.has_unsaved());
+
+        ui_components.show_unsaved_msg_box(Some(UICommand::Quit));
+        ui_components.autosave_if_due(&mut app).await.unwrap();
+        assert_eq!(app.get_entry(0).unwrap().content, original_content);
+        assert!(ui_components.has_unsaved());
+
+        ui_components.popup_stack.pop();
+        ui_components.autosave_if_due(&mut app).await.unwrap();
+        assert_ne!(app.get_entry(0).unwrap().content, original_content);
+        assert!(!ui_components.has_unsaved());
+    }
+
+    #[tokio::test]
+    async fn entry_popup_warns_duplicate_tags() {
+        let settings = Settings {
+            duplicate_tags: crate::settings::DuplicateTagsBehavior::Warn,
+            ..Default::default()
+        };
+        let mut app = App::new(MockDataProvider::new_with_data(), settings);
+        app.load_entries().await.unwrap();
+        let mut ui_components = UIComponents::new(Styles::default());
+        ui_components.set_current_entry(Some(0), &mut app);
+
+        let entry_popup = EntryPopup::from_entry(app.get_entry(0).unwrap(), &app.settings);
+        ui_components
+            .popup_stack
+            .push(Popup::Entry(Box::new(entry_popup.with_tags_focused())));
+
+        // Commit the existing tag as chip then save the journal
+        for ch in "Tag 1".chars() {
+            let input = Input::new(KeyCode::Char(ch), KeyModifiers::NONE);
+            ui_components.handle_input(&input, &mut app).await.unwrap();
+        }
+        let enter = Input::new(KeyCode::Enter, KeyModifiers::NONE);
+        ui_components.handle_input(&enter, &mut app).await.unwrap();
+        ui_components.handle_input(&enter, &mut app).await.unwrap();
+        assert!(!ui_components.has_popup());
+        assert_eq!(app.get_entry(0).unwrap().tags, ["Tag 1", "Tag 2"]);
+
+        ui_components.notify_duplicate_tags(&mut app);
+        assert!(matches!(
+            ui_components.popup_stack.last(),
+            Some(Popup::MsgBox(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn confirmation_timeout_cancels_prompt() {
+        let mut app = App::new(MockDataProvider::new_with_data(), Settings::default());
+        app.load_entries().await.unwrap();
+        let mut ui_components = UIComponents::new(Styles::default());
+        ui_components.set_confirmation_timeout(Some(Duration::from_secs(5)));
+        ui_components.set_current_entry(Some(0), &mut app);
+
+        UICommand::DeleteCurrentEntry
+            .execute(&mut ui_components, &mut app)
+            .await
+            .unwrap();
+        assert!(ui_components.has_popup());
+
+        let result = ui_components
+            .dismiss_timed_out_msg_box(&mut app, Instant::now())
+            .await
+            .unwrap();
+        assert_eq!(result, HandleInputReturnType::Ignore);
+        assert!(ui_components.has_popup());
+
+        let elapsed = Instant::now() + Duration::from_secs(6);
+        let result = ui_components
+            .dismiss_timed_out_msg_box(&mut app, elapsed)
+            .await
+            .unwrap();
+        assert_eq!(result, HandleInputReturnType::Handled);
+        assert!(!ui_components.has_popup());
+        assert_eq!(app.entries.len(), 2);
+        assert_eq!(app.current_entry_id, Some(0));
+
+        // A zero timeout disables dismissing the prompts
+        ui_components.set_confirmation_timeout(Some(Duration::ZERO));
+        UICommand::DeleteCurrentEntry
+            .execute(&mut ui_components, &mut app)
+            .await
+            .unwrap();
+        let result = ui_components
+            .dismiss_timed_out_msg_box(&mut app, elapsed)
+            .await
+            .unwrap();
+        assert_eq!(result, HandleInputReturnType::Ignore);
+        assert!(ui_components.has_popup());
+    }
+
+    #[tokio::test]
+    async fn filter_popup_kept_until_empty_filter_confirmed() {
+        let settings = Settings {
+            confirm_empty_filter: true,
+            ..Default::default()
+        };
+        let mut app = App::new(MockDataProvider::new_with_data(), settings);
+        app.load_entries().await.unwrap();
+        let mut ui_components = UIComponents::new(Styles::default());
+        let press = |key_code| Input::new(key_code, KeyModifiers::NONE);
+
+        let mut filter = Filter::default();
+        filter
+            .criteria
+            .push(FilterCriterion::Title(String::from("Not existing")));
+        let filter_popup = FilterPopup::new(app.get_all_tags(), Some(filter));
+        ui_components
+            .popup_stack
+            .push(Popup::Filter(Box::new(filter_popup)));
+
+        // Declining keeps the popup with its edits open
+        for key_code in [KeyCode::Enter, KeyCode::Char('n')] {
+            ui_components
+                .handle_input(&press(key_code), &mut app)
+                .await
+                .unwrap();
+        }
+        assert!(matches!(
+            ui_components.popup_stack.as_slice(),
+            [Popup::Filter(_)]
+        ));
+        assert!(!app.has_pending_filter());
+        assert_eq!(app.get_active_entries().count(), 2);
+
+        // Confirming closes the popup applying the filter
+        for key_code in [KeyCode::Enter, KeyCode::Char('y')] {
+            ui_components
+                .handle_input(&press(key_code), &mut app)
+                .await
+                .unwrap();
+        }
+        assert!(!ui_components.has_popup());
+        assert_eq!(app.get_active_entries().count(), 0);
+    }
+
+    #[tokio::test]
+    async fn select_single_filtered_entry() {
+        let title_filter = || {
+            let mut filter = Filter::default();
+            filter
+                .criteria
+                .push(FilterCriterion::Title(String::from("Title 1")));
+            Some(filter)
+        };
+
+        let mut app = App::new(MockDataProvider::new_with_data(), Settings::default());
+        app.load_entries().await.unwrap();
+        let mut ui_components = UIComponents::new(Styles::default());
+
+        // Cleared selection is kept by default
+        ui_components.clear_current_entry(&mut app);
+        app.apply_filter(title_filter());
+        ui_components.update_current_entry(&mut app);
+        assert_eq!(app.current_entry_id, None);
+
+        app.settings.single_entry_filter = SingleEntryFilter::Select;
+        app.apply_filter(None);
+        app.apply_filter(title_filter());
+        ui_components.update_current_entry(&mut app);
+        assert_eq!(app.current_entry_id, Some(0));
+        assert_eq!(ui_components.active_control, ControlType::EntriesList);
+
+        app.settings.single_entry_filter = SingleEntryFilter::Open;
+        ui_components.clear_current_entry(&mut app);
+        app.apply_filter(None);
+        app.apply_filter(title_filter());
+        ui_components.update_current_entry(&mut app);
+        assert_eq!(app.current_entry_id, Some(0));
+        assert_eq!(ui_components.active_control, ControlType::EntryContentTxt);
+    }
+}
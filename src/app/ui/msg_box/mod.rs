@@ -1,3 +1,5 @@
+use std::time::{Duration, Instant};
+
 use crossterm::event::KeyCode;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -49,11 +51,41 @@ pub enum MsgBoxInputResult {
 pub struct MsgBox {
     msg_type: MsgBoxType,
     actions: MsgBoxActions,
+    /// Instant after which the message box is dismissed without an answer.
+    deadline: Option<Instant>,
 }
 
 impl MsgBox {
     pub fn new(msg_type: MsgBoxType, actions: MsgBoxActions) -> Self {
-        Self { msg_type, actions }
+        Self {
+            msg_type,
+            actions,
+            deadline: None,
+        }
+    }
+
+    /// Dismisses the message box once the given timeout elapses without an answer.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.deadline = Some(Instant::now() + timeout);
+        self
+    }
+
+    /// Returns the result to close the message box with if its timeout has elapsed at the
+    /// given instant.
+    pub fn check_timeout(&self, now: Instant) -> Option<MsgBoxResult> {
+        self.deadline
+            .is_some_and(|deadline| now >= deadline)
+            .then(|| self.timeout_result())
+    }
+
+    /// Result of dismissing the message box without an answer, which is cancel or the closest
+    /// action to it.
+    fn timeout_result(&self) -> MsgBoxResult {
+        match self.actions {
+            MsgBoxActions::Ok => MsgBoxResult::Ok,
+            MsgBoxActions::YesNo => MsgBoxResult::No,
+            MsgBoxActions::OkCancel | MsgBoxActions::YesNoCancel => MsgBoxResult::Cancel,
+        }
     }
 
     pub fn render_widget(&mut self, frame: &mut Frame, area: Rect, styles: &Styles) {
@@ -131,3 +163,38 @@ impl MsgBox {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn question(actions: MsgBoxActions) -> MsgBox {
+        MsgBox::new(MsgBoxType::Question("Question".into()), actions)
+    }
+
+    #[test]
+    fn no_timeout() {
+        let msg_box = question(MsgBoxActions::YesNoCancel);
+
+        let later = Instant::now() + Duration::from_secs(3600);
+        assert_eq!(msg_box.check_timeout(later), None);
+    }
+
+    #[test]
+    fn timeout_resolves_to_cancel() {
+        let msg_box = question(MsgBoxActions::YesNoCancel).with_timeout(Duration::from_secs(5));
+
+        let now = Instant::now();
+        assert_eq!(msg_box.check_timeout(now), None);
+
+        let elapsed = now + Duration::from_secs(6);
+        assert_eq!(msg_box.check_timeout(elapsed), Some(MsgBoxResult::Cancel));
+
+        let msg_box = question(MsgBoxActions::OkCancel).with_timeout(Duration::from_secs(5));
+        assert_eq!(msg_box.check_timeout(elapsed), Some(MsgBoxResult::Cancel));
+
+        // Message boxes without cancel action resolve to their negative answer.
+        let msg_box = question(MsgBoxActions::YesNo).with_timeout(Duration::from_secs(5));
+        assert_eq!(msg_box.check_timeout(elapsed), Some(MsgBoxResult::No));
+    }
+}
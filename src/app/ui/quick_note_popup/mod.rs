@@ -0,0 +1,110 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use tui_textarea::TextArea;
+
+use crate::app::keymap::Input;
+
+use super::{ui_functions::centered_rect_exact_height, PopupReturn, Styles};
+
+type QuickNotePopupReturn = PopupReturn<String>;
+
+const FOOTER_TEXT: &str = "Enter: Create journal | Esc or <Ctrl-c>: Cancel";
+const FOOTER_MARGIN: u16 = 8;
+
+/// Minimal popup to capture a note in one field, creating a journal from it on confirm.
+pub struct QuickNotePopup<'a> {
+    note_txt: TextArea<'a>,
+    err_msg: String,
+}
+
+impl QuickNotePopup<'_> {
+    pub fn new() -> Self {
+        Self {
+            note_txt: TextArea::default(),
+            err_msg: String::default(),
+        }
+    }
+
+    pub fn render_widget(&mut self, frame: &mut Frame, area: Rect, styles: &Styles) {
+        let mut area = centered_rect_exact_height(60, 8, area);
+
+        if area.width < FOOTER_TEXT.len() as u16 + FOOTER_MARGIN {
+            area.height += 1;
+        }
+
+        let block = Block::default().borders(Borders::ALL).title("Quick Note");
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .horizontal_margin(4)
+            .vertical_margin(2)
+            .constraints([Constraint::Length(3), Constraint::Min(1)].as_ref())
+            .split(area);
+
+        let (block_style, cursor_style, title) = if self.err_msg.is_empty() {
+            (
+                styles.general.input_block_active,
+                styles.general.input_corsur_active,
+                String::from("Note"),
+            )
+        } else {
+            (
+                styles.general.input_block_invalid,
+                styles.general.input_corsur_invalid,
+                format!("Note : {}", self.err_msg),
+            )
+        };
+
+        self.note_txt.set_style(Style::from(block_style));
+        self.note_txt.set_cursor_style(Style::from(cursor_style));
+        self.note_txt.set_cursor_line_style(Style::default());
+        self.note_txt.set_block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::from(block_style))
+                .title(title),
+        );
+
+        frame.render_widget(&self.note_txt, chunks[0]);
+
+        let footer = Paragraph::new(FOOTER_TEXT)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(footer, chunks[1]);
+    }
+
+    pub fn handle_input(&mut self, input: &Input) -> QuickNotePopupReturn {
+        let has_ctrl = input.modifiers.contains(KeyModifiers::CONTROL);
+        match input.key_code {
+            KeyCode::Esc => QuickNotePopupReturn::Cancel,
+            KeyCode::Char('c') if has_ctrl => QuickNotePopupReturn::Cancel,
+            KeyCode::Enter => self.handle_confirm(),
+            KeyCode::Char('m') if has_ctrl => self.handle_confirm(),
+            _ => {
+                if self.note_txt.input(KeyEvent::from(input)) {
+                    self.err_msg.clear();
+                }
+                QuickNotePopupReturn::KeepPopup
+            }
+        }
+    }
+
+    fn handle_confirm(&mut self) -> QuickNotePopupReturn {
+        let note = self.note_txt.lines()[0].trim();
+        if note.is_empty() {
+            self.err_msg = "Note can't be empty".into();
+            return QuickNotePopupReturn::KeepPopup;
+        }
+
+        QuickNotePopupReturn::Apply(note.to_owned())
+    }
+}
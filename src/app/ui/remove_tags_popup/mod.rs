@@ -0,0 +1,264 @@
+use backend::DataProvider;
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::{keymap::Input, App};
+
+use super::{ui_functions::centered_rect, PopupReturn, Styles};
+
+type RemoveTagsReturn = PopupReturn<()>;
+
+const FOOTER_TEXT: &str = "d or Delete: Remove highlighted tag | Esc, q or Enter: Close";
+const FOOTER_MARGIN: u16 = 8;
+
+/// Defines which journals the tags are removed from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RemoveTarget {
+    /// The tags of the current journal.
+    CurrentEntry,
+    /// All tags, removed from the selected journals in multi-select mode.
+    SelectedEntries,
+}
+
+/// Popup listing the tags of the current journal, where the highlighted tag can be removed
+/// directly without editing the whole tags field.
+/// In multi-select mode it lists all tags to remove them from the selected journals at once.
+pub struct RemoveTagsPopup {
+    tags: Vec<String>,
+    state: ListState,
+    target: RemoveTarget,
+}
+
+impl RemoveTagsPopup {
+    pub fn new<D: DataProvider>(app: &App<D>) -> Self {
+        Self::with_target(RemoveTarget::CurrentEntry, app)
+    }
+
+    pub fn new_multi_select<D: DataProvider>(app: &App<D>) -> Self {
+        Self::with_target(RemoveTarget::SelectedEntries, app)
+    }
+
+    fn with_target<D: DataProvider>(target: RemoveTarget, app: &App<D>) -> Self {
+        let mut popup = Self {
+            tags: Vec::new(),
+            state: ListState::default(),
+            target,
+        };
+
+        popup.load_tags(app);
+
+        popup
+    }
+
+    /// Checks if there are no tags to be removed.
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    /// Loads the tags of the target keeping the selection in bounds.
+    fn load_tags<D: DataProvider>(&mut self, app: &App<D>) {
+        self.tags = match self.target {
+            RemoveTarget::CurrentEntry => app
+                .get_current_entry()
+                .map(|entry| entry.tags.clone())
+                .unwrap_or_default(),
+            RemoveTarget::SelectedEntries => app.get_all_tags(),
+        };
+
+        let selected = match self.state.selected() {
+            _ if self.tags.is_empty() => None,
+            Some(idx) => Some(idx.min(self.tags.len() - 1)),
+            None => Some(0),
+        };
+
+        self.state.select(selected);
+    }
+
+    pub fn render_widget(&mut self, frame: &mut Frame, area: Rect, styles: &Styles) {
+        let area = centered_rect(50, 50, area);
+
+        let (title, list_title) = match self.target {
+            RemoveTarget::CurrentEntry => ("Remove Tags", "Journal Tags"),
+            RemoveTarget::SelectedEntries => ("Remove Tags from Selected Journals", "All Tags"),
+        };
+
+        let block = Block::default().borders(Borders::ALL).title(title);
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        let footer_height = if area.width < FOOTER_TEXT.len() as u16 + FOOTER_MARGIN {
+            2
+        } else {
+            1
+        };
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .horizontal_margin(4)
+            .vertical_margin(2)
+            .constraints([Constraint::Min(3), Constraint::Length(footer_height)].as_ref())
+            .split(area);
+
+        let items: Vec<ListItem> = self
+            .tags
+            .iter()
+            .map(|tag| ListItem::new(tag.as_str()).style(Style::reset()))
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .style(Style::from(styles.general.input_block_active))
+                    .title(list_title),
+            )
+            .highlight_style(Style::from(styles.general.list_highlight_active))
+            .highlight_symbol(">> ");
+
+        frame.render_stateful_widget(list, chunks[0], &mut self.state);
+
+        let footer = Paragraph::new(FOOTER_TEXT)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(footer, chunks[1]);
+    }
+
+    pub async fn handle_input<D: DataProvider>(
+        &mut self,
+        input: &Input,
+        app: &mut App<D>,
+    ) -> anyhow::Result<RemoveTagsReturn> {
+        let has_ctrl = input.modifiers.contains(KeyModifiers::CONTROL);
+        match input.key_code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => Ok(RemoveTagsReturn::Cancel),
+            KeyCode::Char('c') if has_ctrl => Ok(RemoveTagsReturn::Cancel),
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.cycle_selection(false);
+                Ok(RemoveTagsReturn::KeepPopup)
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.cycle_selection(true);
+                Ok(RemoveTagsReturn::KeepPopup)
+            }
+            KeyCode::Delete | KeyCode::Char('d') => self.remove_selected_tag(app).await,
+            _ => Ok(RemoveTagsReturn::KeepPopup),
+        }
+    }
+
+    fn cycle_selection(&mut self, next: bool) {
+        if self.tags.is_empty() {
+            return;
+        }
+
+        let last_index = self.tags.len() - 1;
+        let new_index = match (self.state.selected(), next) {
+            (Some(idx), true) if idx < last_index => idx + 1,
+            (Some(idx), false) if idx > 0 => idx - 1,
+            (_, true) => 0,
+            (_, false) => last_index,
+        };
+
+        self.state.select(Some(new_index));
+    }
+
+    async fn remove_selected_tag<D: DataProvider>(
+        &mut self,
+        app: &mut App<D>,
+    ) -> anyhow::Result<RemoveTagsReturn> {
+        let Some(tag) = self.state.selected().and_then(|idx| self.tags.get(idx)) else {
+            return Ok(RemoveTagsReturn::KeepPopup);
+        };
+
+        match self.target {
+            RemoveTarget::CurrentEntry => {
+                app.remove_tag_from_current_entry(&tag.to_owned()).await?
+            }
+            RemoveTarget::SelectedEntries => app.remove_tag_from_selected(tag.to_owned()).await?,
+        }
+
+        self.load_tags(app);
+
+        if self.tags.is_empty() {
+            Ok(RemoveTagsReturn::Apply(()))
+        } else {
+            Ok(RemoveTagsReturn::KeepPopup)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{app::test::mock::MockDataProvider, settings::Settings};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn remove_highlighted_tag() {
+        let mut app = App::new(MockDataProvider::new_with_data(), Settings::default());
+        app.load_entries().await.unwrap();
+        app.current_entry_id = Some(0);
+
+        let mut popup = RemoveTagsPopup::new(&app);
+        assert_eq!(
+            popup.tags,
+            vec![String::from("Tag 1"), String::from("Tag 2")]
+        );
+
+        let down = Input::new(KeyCode::Char('j'), KeyModifiers::NONE);
+        let delete = Input::new(KeyCode::Char('d'), KeyModifiers::NONE);
+        popup.handle_input(&down, &mut app).await.unwrap();
+        assert!(matches!(
+            popup.handle_input(&delete, &mut app).await.unwrap(),
+            RemoveTagsReturn::KeepPopup
+        ));
+
+        assert_eq!(app.get_entry(0).unwrap().tags, vec![String::from("Tag 1")]);
+        assert_eq!(popup.state.selected(), Some(0));
+
+        // Removing the last tag closes the popup
+        assert!(matches!(
+            popup.handle_input(&delete, &mut app).await.unwrap(),
+            RemoveTagsReturn::Apply(())
+        ));
+        assert!(app.get_entry(0).unwrap().tags.is_empty());
+
+        // Each removal is registered in the history
+        app.undo().await.unwrap();
+        assert_eq!(app.get_entry(0).unwrap().tags, vec![String::from("Tag 1")]);
+        app.undo().await.unwrap();
+        assert_eq!(
+            app.get_entry(0).unwrap().tags,
+            vec![String::from("Tag 1"), String::from("Tag 2")]
+        );
+    }
+
+    #[tokio::test]
+    async fn remove_tag_from_selected() {
+        let mut app = App::new(MockDataProvider::new_with_data(), Settings::default());
+        app.load_entries().await.unwrap();
+        app.current_entry_id = Some(1);
+        app.select_entry(0);
+
+        let mut popup = RemoveTagsPopup::new_multi_select(&app);
+        assert_eq!(popup.tags, app.get_all_tags());
+
+        let tag_idx = popup.tags.iter().position(|tag| tag == "Tag 2");
+        popup.state.select(tag_idx);
+
+        let delete = Input::new(KeyCode::Char('d'), KeyModifiers::NONE);
+        assert!(matches!(
+            popup.handle_input(&delete, &mut app).await.unwrap(),
+            RemoveTagsReturn::KeepPopup
+        ));
+
+        assert_eq!(app.get_entry(0).unwrap().tags, vec![String::from("Tag 1")]);
+        assert_eq!(popup.tags, vec![String::from("Tag 1")]);
+    }
+}
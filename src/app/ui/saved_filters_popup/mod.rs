@@ -0,0 +1,286 @@
+use backend::DataProvider;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+use tui_textarea::TextArea;
+
+use crate::app::{keymap::Input, App};
+
+use super::{ui_functions::centered_rect, PopupReturn, Styles};
+
+type SavedFiltersReturn = PopupReturn<()>;
+
+const FOOTER_TEXT: &str =
+    "Enter: Apply | d or Delete: Delete | Tab: Switch to name | Esc, q or <Ctrl-c>: Close";
+const FOOTER_MARGIN: u16 = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ActiveControl {
+    List,
+    NameTxt,
+}
+
+/// Popup listing the saved filters by their names to apply or delete them, with an input to
+/// save the currently applied filter under a name.
+pub struct SavedFiltersPopup<'a> {
+    names: Vec<String>,
+    state: ListState,
+    name_txt: TextArea<'a>,
+    active_control: ActiveControl,
+    err_msg: String,
+}
+
+impl SavedFiltersPopup<'_> {
+    pub fn new<D: DataProvider>(app: &App<D>) -> Self {
+        let mut popup = Self {
+            names: Vec::new(),
+            state: ListState::default(),
+            name_txt: TextArea::default(),
+            active_control: ActiveControl::List,
+            err_msg: String::default(),
+        };
+
+        popup.load_names(app);
+
+        popup
+    }
+
+    /// Loads the names of the saved filters keeping the selection in bounds.
+    fn load_names<D: DataProvider>(&mut self, app: &App<D>) {
+        self.names = app
+            .get_saved_filters()
+            .iter()
+            .map(|(name, _)| name.to_owned())
+            .collect();
+
+        let selected = match self.state.selected() {
+            _ if self.names.is_empty() => None,
+            Some(idx) => Some(idx.min(self.names.len() - 1)),
+            None => Some(0),
+        };
+
+        self.state.select(selected);
+    }
+
+    pub fn render_widget(&mut self, frame: &mut Frame, area: Rect, styles: &Styles) {
+        let area = centered_rect(50, 50, area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("Saved Filters");
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        let footer_height = if area.width < FOOTER_TEXT.len() as u16 + FOOTER_MARGIN {
+            2
+        } else {
+            1
+        };
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .horizontal_margin(4)
+            .vertical_margin(2)
+            .constraints(
+                [
+                    Constraint::Min(3),
+                    Constraint::Length(3),
+                    Constraint::Length(footer_height),
+                ]
+                .as_ref(),
+            )
+            .split(area);
+
+        self.render_list(frame, chunks[0], styles);
+        self.render_name_txt(frame, chunks[1], styles);
+
+        let footer = Paragraph::new(FOOTER_TEXT)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(footer, chunks[2]);
+    }
+
+    fn render_list(&mut self, frame: &mut Frame, area: Rect, styles: &Styles) {
+        let is_active = self.active_control == ActiveControl::List;
+
+        let items: Vec<ListItem> = self
+            .names
+            .iter()
+            .map(|name| ListItem::new(name.as_str()).style(Style::reset()))
+            .collect();
+
+        let block = if is_active && !self.err_msg.is_empty() {
+            Block::default()
+                .title(format!("Filters : {}", self.err_msg))
+                .style(styles.general.input_block_invalid)
+        } else if is_active {
+            Block::default()
+                .title("Filters")
+                .style(styles.general.input_block_active)
+        } else {
+            Block::default().title("Filters")
+        };
+
+        let highlight_style = if is_active {
+            styles.general.list_highlight_active
+        } else {
+            styles.general.list_highlight_inactive
+        };
+
+        let list = List::new(items)
+            .block(block.borders(Borders::ALL))
+            .highlight_style(Style::from(highlight_style))
+            .highlight_symbol(">> ");
+
+        frame.render_stateful_widget(list, area, &mut self.state);
+    }
+
+    fn render_name_txt(&mut self, frame: &mut Frame, area: Rect, styles: &Styles) {
+        let is_active = self.active_control == ActiveControl::NameTxt;
+        let title = "Save current filter as";
+
+        let (block, cursor_style) = match (is_active, self.err_msg.is_empty()) {
+            (true, true) => (
+                Block::default()
+                    .title(title)
+                    .style(styles.general.input_block_active),
+                Style::from(styles.general.input_corsur_active),
+            ),
+            (true, false) => (
+                Block::default()
+                    .title(format!("{title} : {}", self.err_msg))
+                    .style(styles.general.input_block_invalid),
+                Style::from(styles.general.input_corsur_invalid),
+            ),
+            (false, _) => (
+                Block::default().title(title),
+                Style::default().bg(Color::Reset),
+            ),
+        };
+
+        self.name_txt.set_cursor_style(cursor_style);
+        self.name_txt.set_cursor_line_style(Style::default());
+        self.name_txt.set_block(block.borders(Borders::ALL));
+
+        frame.render_widget(&self.name_txt, area);
+    }
+
+    pub fn handle_input<D: DataProvider>(
+        &mut self,
+        input: &Input,
+        app: &mut App<D>,
+    ) -> SavedFiltersReturn {
+        let has_ctrl = input.modifiers.contains(KeyModifiers::CONTROL);
+        match input.key_code {
+            KeyCode::Esc => return SavedFiltersReturn::Cancel,
+            KeyCode::Char('c') if has_ctrl => return SavedFiltersReturn::Cancel,
+            KeyCode::Tab | KeyCode::BackTab => {
+                self.active_control = match self.active_control {
+                    ActiveControl::List => ActiveControl::NameTxt,
+                    ActiveControl::NameTxt => ActiveControl::List,
+                };
+                self.err_msg.clear();
+                return SavedFiltersReturn::KeepPopup;
+            }
+            _ => {}
+        }
+
+        match self.active_control {
+            ActiveControl::List => self.handle_list_input(input, app),
+            ActiveControl::NameTxt => self.handle_name_input(input, app),
+        }
+    }
+
+    fn handle_list_input<D: DataProvider>(
+        &mut self,
+        input: &Input,
+        app: &mut App<D>,
+    ) -> SavedFiltersReturn {
+        match input.key_code {
+            KeyCode::Char('q') => SavedFiltersReturn::Cancel,
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.cycle_selection(false);
+                SavedFiltersReturn::KeepPopup
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.cycle_selection(true);
+                SavedFiltersReturn::KeepPopup
+            }
+            KeyCode::Enter => {
+                let Some(name) = self.selected_name() else {
+                    return SavedFiltersReturn::KeepPopup;
+                };
+
+                match app.apply_saved_filter(&name) {
+                    Ok(()) => SavedFiltersReturn::Apply(()),
+                    Err(err) => {
+                        self.err_msg = err.to_string();
+                        SavedFiltersReturn::KeepPopup
+                    }
+                }
+            }
+            KeyCode::Delete | KeyCode::Char('d') => {
+                if let Some(name) = self.selected_name() {
+                    app.delete_saved_filter(&name);
+                    self.err_msg.clear();
+                    self.load_names(app);
+                }
+                SavedFiltersReturn::KeepPopup
+            }
+            _ => SavedFiltersReturn::KeepPopup,
+        }
+    }
+
+    fn handle_name_input<D: DataProvider>(
+        &mut self,
+        input: &Input,
+        app: &mut App<D>,
+    ) -> SavedFiltersReturn {
+        if input.key_code == KeyCode::Enter {
+            let name = self.name_txt.lines()[0].trim().to_owned();
+            match app.save_current_filter(&name) {
+                Ok(()) => {
+                    self.load_names(app);
+                    let saved_idx = self.names.iter().position(|saved| *saved == name);
+                    self.state.select(saved_idx);
+                    self.name_txt = TextArea::default();
+                    self.active_control = ActiveControl::List;
+                }
+                Err(err) => self.err_msg = err.to_string(),
+            }
+        } else if self.name_txt.input(KeyEvent::from(input)) {
+            self.err_msg.clear();
+        }
+
+        SavedFiltersReturn::KeepPopup
+    }
+
+    fn selected_name(&self) -> Option<String> {
+        self.state
+            .selected()
+            .and_then(|idx| self.names.get(idx))
+            .cloned()
+    }
+
+    fn cycle_selection(&mut self, next: bool) {
+        if self.names.is_empty() {
+            return;
+        }
+
+        let last_index = self.names.len() - 1;
+        let new_index = match (self.state.selected(), next) {
+            (Some(idx), true) if idx < last_index => idx + 1,
+            (Some(idx), false) if idx > 0 => idx - 1,
+            (_, true) => 0,
+            (_, false) => last_index,
+        };
+
+        self.state.select(Some(new_index));
+    }
+}
@@ -0,0 +1,112 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use tui_textarea::{CursorMove, TextArea};
+
+use crate::app::keymap::Input;
+
+use super::{ui_functions::centered_rect_exact_height, PopupReturn, Styles};
+
+type SearchPopupReturn = PopupReturn<SearchRequest>;
+
+const FOOTER_TEXT: &str =
+    "Enter: Search | <Ctrl-t>: Toggle case-sensitive | Esc or <Ctrl-c>: Cancel | Empty query: Clear search";
+const FOOTER_MARGIN: u16 = 8;
+
+/// Full-text search requested via the search popup.
+pub struct SearchRequest {
+    /// Query to search for, where `None` clears the search.
+    pub query: Option<String>,
+    pub case_sensitive: bool,
+}
+
+/// Popup to enter the query of the full-text search across the journals.
+pub struct SearchPopup<'a> {
+    query_txt: TextArea<'a>,
+    case_sensitive: bool,
+}
+
+impl SearchPopup<'_> {
+    pub fn new(query: Option<&str>, case_sensitive: bool) -> Self {
+        let mut query_txt = TextArea::new(vec![query.unwrap_or_default().to_owned()]);
+        query_txt.move_cursor(CursorMove::End);
+
+        Self {
+            query_txt,
+            case_sensitive,
+        }
+    }
+
+    pub fn render_widget(&mut self, frame: &mut Frame, area: Rect, styles: &Styles) {
+        let mut area = centered_rect_exact_height(60, 9, area);
+
+        if area.width < FOOTER_TEXT.len() as u16 + FOOTER_MARGIN {
+            area.height += 1;
+        }
+
+        let block = Block::default().borders(Borders::ALL).title("Search");
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .horizontal_margin(4)
+            .vertical_margin(2)
+            .constraints([Constraint::Length(3), Constraint::Min(1)].as_ref())
+            .split(area);
+
+        let title = if self.case_sensitive {
+            "Query (Case-sensitive)"
+        } else {
+            "Query"
+        };
+
+        self.query_txt
+            .set_style(Style::from(styles.general.input_block_active));
+        self.query_txt
+            .set_cursor_style(Style::from(styles.general.input_corsur_active));
+        self.query_txt.set_cursor_line_style(Style::default());
+        self.query_txt.set_block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::from(styles.general.input_block_active))
+                .title(title),
+        );
+
+        frame.render_widget(&self.query_txt, chunks[0]);
+
+        let footer = Paragraph::new(FOOTER_TEXT)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(footer, chunks[1]);
+    }
+
+    pub fn handle_input(&mut self, input: &Input) -> SearchPopupReturn {
+        let has_ctrl = input.modifiers.contains(KeyModifiers::CONTROL);
+        match input.key_code {
+            KeyCode::Esc => SearchPopupReturn::Cancel,
+            KeyCode::Char('c') if has_ctrl => SearchPopupReturn::Cancel,
+            KeyCode::Char('t') if has_ctrl => {
+                self.case_sensitive = !self.case_sensitive;
+                SearchPopupReturn::KeepPopup
+            }
+            KeyCode::Enter => {
+                let query = self.query_txt.lines()[0].trim();
+                SearchPopupReturn::Apply(SearchRequest {
+                    query: (!query.is_empty()).then(|| query.to_owned()),
+                    case_sensitive: self.case_sensitive,
+                })
+            }
+            _ => {
+                _ = self.query_txt.input(KeyEvent::from(input));
+                SearchPopupReturn::KeepPopup
+            }
+        }
+    }
+}
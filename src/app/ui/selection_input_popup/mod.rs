@@ -0,0 +1,181 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use tui_textarea::{CursorMove, TextArea};
+
+use crate::app::keymap::Input;
+
+use super::{ui_functions::centered_rect_exact_height, PopupReturn, Styles};
+
+type SelectionInputReturn = PopupReturn<SelectionInput>;
+
+const FOOTER_MARGIN: u16 = 8;
+
+/// Defines which value the popup asks for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectionInputKind {
+    Tag,
+    Priority,
+    /// New name of the given tag, applied on all journals having it.
+    TagRename(String),
+}
+
+/// Value entered to be applied on the selected journals.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectionInput {
+    Tag(String),
+    /// Priority to set where `None` clears the priority.
+    Priority(Option<u32>),
+    TagRename {
+        old: String,
+        new: String,
+    },
+}
+
+/// Popup to enter a value to be applied on all the selected journals in multi-select mode, or
+/// on all journals having a tag when renaming it.
+pub struct SelectionInputPopup<'a> {
+    kind: SelectionInputKind,
+    input_txt: TextArea<'a>,
+    selected_count: usize,
+    err_msg: String,
+}
+
+impl SelectionInputPopup<'_> {
+    pub fn new(kind: SelectionInputKind, selected_count: usize) -> Self {
+        let mut input_txt = match &kind {
+            SelectionInputKind::TagRename(tag) => TextArea::new(vec![tag.to_owned()]),
+            SelectionInputKind::Tag | SelectionInputKind::Priority => TextArea::default(),
+        };
+        input_txt.move_cursor(CursorMove::End);
+
+        Self {
+            kind,
+            input_txt,
+            selected_count,
+            err_msg: String::default(),
+        }
+    }
+
+    fn footer_text(&self) -> &'static str {
+        match self.kind {
+            SelectionInputKind::Tag => "Enter: Add tag | Esc or <Ctrl-c>: Cancel",
+            SelectionInputKind::Priority => {
+                "Enter: Set priority | Empty priority: Clear priority | Esc or <Ctrl-c>: Cancel"
+            }
+            SelectionInputKind::TagRename(_) => "Enter: Rename tag | Esc or <Ctrl-c>: Cancel",
+        }
+    }
+
+    pub fn render_widget(&mut self, frame: &mut Frame, area: Rect, styles: &Styles) {
+        let mut area = centered_rect_exact_height(60, 9, area);
+
+        let footer_text = self.footer_text();
+        if area.width < footer_text.len() as u16 + FOOTER_MARGIN {
+            area.height += 1;
+        }
+
+        let (popup_title, input_title) = match &self.kind {
+            SelectionInputKind::Tag => (
+                format!("Add Tag to {} Journals", self.selected_count),
+                "Tag",
+            ),
+            SelectionInputKind::Priority => (
+                format!("Set Priority of {} Journals", self.selected_count),
+                "Priority",
+            ),
+            SelectionInputKind::TagRename(tag) => (
+                format!("Rename Tag '{tag}' in {} Journals", self.selected_count),
+                "New name",
+            ),
+        };
+
+        let block = Block::default().borders(Borders::ALL).title(popup_title);
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .horizontal_margin(4)
+            .vertical_margin(2)
+            .constraints([Constraint::Length(3), Constraint::Min(1)].as_ref())
+            .split(area);
+
+        let (title, block_style, cursor_style) = if self.err_msg.is_empty() {
+            (
+                input_title.to_owned(),
+                styles.general.input_block_active,
+                styles.general.input_corsur_active,
+            )
+        } else {
+            (
+                format!("{input_title} : {}", self.err_msg),
+                styles.general.input_block_invalid,
+                styles.general.input_corsur_invalid,
+            )
+        };
+
+        self.input_txt.set_style(Style::from(block_style));
+        self.input_txt.set_cursor_style(Style::from(cursor_style));
+        self.input_txt.set_cursor_line_style(Style::default());
+        self.input_txt.set_block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::from(block_style))
+                .title(title),
+        );
+
+        frame.render_widget(&self.input_txt, chunks[0]);
+
+        let footer = Paragraph::new(footer_text)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(footer, chunks[1]);
+    }
+
+    pub fn handle_input(&mut self, input: &Input) -> SelectionInputReturn {
+        let has_ctrl = input.modifiers.contains(KeyModifiers::CONTROL);
+        match input.key_code {
+            KeyCode::Esc => SelectionInputReturn::Cancel,
+            KeyCode::Char('c') if has_ctrl => SelectionInputReturn::Cancel,
+            KeyCode::Enter => match self.parse_input() {
+                Ok(value) => SelectionInputReturn::Apply(value),
+                Err(err_msg) => {
+                    self.err_msg = err_msg;
+                    SelectionInputReturn::KeepPopup
+                }
+            },
+            _ => {
+                if self.input_txt.input(KeyEvent::from(input)) {
+                    self.err_msg.clear();
+                }
+                SelectionInputReturn::KeepPopup
+            }
+        }
+    }
+
+    fn parse_input(&self) -> Result<SelectionInput, String> {
+        let text = self.input_txt.lines()[0].trim();
+        match &self.kind {
+            SelectionInputKind::Tag | SelectionInputKind::TagRename(_) if text.is_empty() => {
+                Err(String::from("Tag can't be empty"))
+            }
+            SelectionInputKind::Tag => Ok(SelectionInput::Tag(text.to_owned())),
+            SelectionInputKind::Priority if text.is_empty() => Ok(SelectionInput::Priority(None)),
+            SelectionInputKind::Priority => text
+                .parse()
+                .map(|priority| SelectionInput::Priority(Some(priority)))
+                .map_err(|_| String::from("Priority must be a positive number")),
+            SelectionInputKind::TagRename(old) => Ok(SelectionInput::TagRename {
+                old: old.to_owned(),
+                new: text.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,354 @@
+use std::collections::HashMap;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+use tui_textarea::{CursorMove, TextArea};
+
+use crate::{
+    app::keymap::Input,
+    settings::{search_options, OptionKind, SettingOption, Settings, SETTING_OPTIONS},
+};
+
+use super::{ui_functions::centered_rect, Styles};
+
+const FOOTER_TEXT: &str =
+    "Enter: Toggle, cycle or edit option | Up, Down, <Ctrl-n>, <Ctrl-p>: Cycle through options | Esc, <Ctrl-c>: Close";
+const EDIT_FOOTER_TEXT: &str = "Enter: Confirm value | Esc, <Ctrl-c>: Cancel editing";
+const FOOTER_MARGINE: usize = 8;
+
+pub enum SettingsPopupReturn {
+    KeepPopup,
+    Close,
+    /// Change the value of the option with the given key, where `None` unsets the value.
+    Change(&'static str, Option<toml::Value>),
+}
+
+/// Popup to search the options of the settings and change them.
+pub struct SettingsPopup<'a> {
+    query_txt: TextArea<'a>,
+    filtered_options: Vec<&'static SettingOption>,
+    values: HashMap<&'static str, Option<toml::Value>>,
+    list_state: ListState,
+    /// Text box for editing the value of the selected number option.
+    number_txt: Option<TextArea<'a>>,
+    number_err_msg: String,
+}
+
+impl SettingsPopup<'_> {
+    pub fn new(settings: &Settings) -> Self {
+        let mut query_txt = TextArea::default();
+        query_txt.set_cursor_line_style(Style::default());
+        query_txt.set_block(Block::default().title("Search").borders(Borders::ALL));
+
+        let mut settings_popup = Self {
+            query_txt,
+            filtered_options: search_options(""),
+            values: HashMap::new(),
+            list_state: ListState::default().with_selected(Some(0)),
+            number_txt: None,
+            number_err_msg: String::default(),
+        };
+
+        settings_popup.update_values(settings);
+
+        settings_popup
+    }
+
+    /// Reloads the values of the options from the given settings.
+    pub fn update_values(&mut self, settings: &Settings) {
+        self.values = SETTING_OPTIONS
+            .iter()
+            .map(|option| {
+                let value = settings.get_option(option.key).unwrap_or_else(|err| {
+                    log::error!("Getting value of option {} failed: {err}", option.key);
+                    None
+                });
+                (option.key, value)
+            })
+            .collect();
+    }
+
+    fn selected_option(&self) -> Option<&'static SettingOption> {
+        self.list_state
+            .selected()
+            .and_then(|idx| self.filtered_options.get(idx).copied())
+    }
+
+    fn value_text(&self, key: &str) -> String {
+        match self.values.get(key).and_then(Option::as_ref) {
+            Some(toml::Value::String(text)) => text.to_owned(),
+            Some(value) => value.to_string(),
+            None => String::from("not set"),
+        }
+    }
+
+    pub fn render_widget(&mut self, frame: &mut Frame, area: Rect, styles: &Styles) {
+        let area = centered_rect(70, 80, area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title("Settings");
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        let footer_text = if self.number_txt.is_some() {
+            EDIT_FOOTER_TEXT
+        } else {
+            FOOTER_TEXT
+        };
+
+        let footer_height = textwrap::fill(footer_text, (area.width as usize) - FOOTER_MARGINE)
+            .lines()
+            .count();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .horizontal_margin(2)
+            .vertical_margin(1)
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Min(4),
+                    Constraint::Length(4),
+                    Constraint::Length(footer_height.try_into().unwrap()),
+                ]
+                .as_ref(),
+            )
+            .split(area);
+
+        frame.render_widget(&self.query_txt, chunks[0]);
+
+        self.render_options_list(frame, chunks[1], styles);
+
+        match self.number_txt.as_mut() {
+            Some(number_txt) => {
+                let (block_style, cursor_style, title) = if self.number_err_msg.is_empty() {
+                    (
+                        styles.general.input_block_active,
+                        styles.general.input_corsur_active,
+                        String::from("Value"),
+                    )
+                } else {
+                    (
+                        styles.general.input_block_invalid,
+                        styles.general.input_corsur_invalid,
+                        format!("Value : {}", self.number_err_msg),
+                    )
+                };
+                number_txt.set_style(Style::from(block_style));
+                number_txt.set_cursor_style(Style::from(cursor_style));
+                number_txt.set_cursor_line_style(Style::default());
+                number_txt.set_block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .style(Style::from(block_style))
+                        .title(title),
+                );
+
+                frame.render_widget(&*number_txt, chunks[2]);
+            }
+            None => {
+                let description = self
+                    .selected_option()
+                    .map(|option| option.description)
+                    .unwrap_or_default();
+                let description = Paragraph::new(description)
+                    .wrap(Wrap { trim: false })
+                    .block(Block::default().title("Description").borders(Borders::ALL));
+
+                frame.render_widget(description, chunks[2]);
+            }
+        }
+
+        let footer = Paragraph::new(footer_text)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(footer, chunks[3]);
+    }
+
+    fn render_options_list(&mut self, frame: &mut Frame, area: Rect, styles: &Styles) {
+        let name_width = self
+            .filtered_options
+            .iter()
+            .map(|option| option.key.len())
+            .max()
+            .unwrap_or_default();
+
+        let items: Vec<ListItem> = self
+            .filtered_options
+            .iter()
+            .map(|option| {
+                let name = format!("{:<name_width$}  ", option.name());
+                let value = Span::styled(
+                    self.value_text(option.key),
+                    Style::default().add_modifier(Modifier::BOLD),
+                );
+                ListItem::new(Line::from(vec![Span::raw(name), value]))
+            })
+            .collect();
+
+        let block_title = format!("Options: {}", self.filtered_options.len());
+
+        let list = List::new(items)
+            .block(Block::default().title(block_title).borders(Borders::ALL))
+            .highlight_style(styles.general.list_highlight_active)
+            .highlight_symbol(">> ");
+
+        frame.render_stateful_widget(list, area, &mut self.list_state);
+    }
+
+    pub fn handle_input(&mut self, input: &Input) -> SettingsPopupReturn {
+        if self.number_txt.is_some() {
+            return self.handle_number_input(input);
+        }
+
+        let has_control = input.modifiers.contains(KeyModifiers::CONTROL);
+
+        match input.key_code {
+            KeyCode::Esc => SettingsPopupReturn::Close,
+            KeyCode::Char('c') if has_control => SettingsPopupReturn::Close,
+            KeyCode::Up => self.cycle_prev_option(),
+            KeyCode::Char('p') if has_control => self.cycle_prev_option(),
+            KeyCode::Down => self.cycle_next_option(),
+            KeyCode::Char('n') if has_control => self.cycle_next_option(),
+            KeyCode::Enter => self.change_selected_option(),
+            KeyCode::Char('m') if has_control => self.change_selected_option(),
+            _ => {
+                if self.query_txt.input(KeyEvent::from(input)) {
+                    self.update_search_query();
+                }
+                SettingsPopupReturn::KeepPopup
+            }
+        }
+    }
+
+    fn cycle_next_option(&mut self) -> SettingsPopupReturn {
+        if !self.filtered_options.is_empty() {
+            let new_index = self
+                .list_state
+                .selected()
+                .map_or(0, |idx| (idx + 1).min(self.filtered_options.len() - 1));
+            self.list_state.select(Some(new_index));
+        }
+
+        SettingsPopupReturn::KeepPopup
+    }
+
+    fn cycle_prev_option(&mut self) -> SettingsPopupReturn {
+        if !self.filtered_options.is_empty() {
+            let new_index = self
+                .list_state
+                .selected()
+                .map_or(0, |idx| idx.saturating_sub(1));
+            self.list_state.select(Some(new_index));
+        }
+
+        SettingsPopupReturn::KeepPopup
+    }
+
+    fn update_search_query(&mut self) {
+        let query = self
+            .query_txt
+            .lines()
+            .first()
+            .expect("Query text box has one line");
+
+        self.filtered_options = search_options(query);
+
+        let selected = (!self.filtered_options.is_empty()).then_some(0);
+        self.list_state.select(selected);
+    }
+
+    /// Toggles bool options, cycles through the values of choice options and starts editing
+    /// number options.
+    fn change_selected_option(&mut self) -> SettingsPopupReturn {
+        let Some(option) = self.selected_option() else {
+            return SettingsPopupReturn::KeepPopup;
+        };
+
+        let current = self.values.get(option.key).cloned().flatten();
+
+        match option.kind {
+            OptionKind::Bool => {
+                let value = current.and_then(|v| v.as_bool()).unwrap_or_default();
+                SettingsPopupReturn::Change(option.key, Some(toml::Value::Boolean(!value)))
+            }
+            OptionKind::Choice(choices) => {
+                let next_idx = current
+                    .as_ref()
+                    .and_then(|v| v.as_str())
+                    .and_then(|value| choices.iter().position(|choice| *choice == value))
+                    .map_or(0, |idx| (idx + 1) % choices.len());
+                let next = toml::Value::String(choices[next_idx].into());
+                SettingsPopupReturn::Change(option.key, Some(next))
+            }
+            OptionKind::Number { .. } => {
+                let text = current.map(|v| v.to_string()).unwrap_or_default();
+                let mut number_txt = TextArea::new(vec![text]);
+                number_txt.move_cursor(CursorMove::End);
+                self.number_txt = Some(number_txt);
+                self.number_err_msg.clear();
+                SettingsPopupReturn::KeepPopup
+            }
+        }
+    }
+
+    fn handle_number_input(&mut self, input: &Input) -> SettingsPopupReturn {
+        let has_control = input.modifiers.contains(KeyModifiers::CONTROL);
+
+        match input.key_code {
+            KeyCode::Esc => self.number_txt = None,
+            KeyCode::Char('c') if has_control => self.number_txt = None,
+            KeyCode::Enter => return self.confirm_number(),
+            KeyCode::Char('m') if has_control => return self.confirm_number(),
+            _ => {
+                let number_txt = self.number_txt.as_mut().expect("Number is being edited");
+                if number_txt.input(KeyEvent::from(input)) {
+                    self.number_err_msg.clear();
+                }
+            }
+        }
+
+        SettingsPopupReturn::KeepPopup
+    }
+
+    fn confirm_number(&mut self) -> SettingsPopupReturn {
+        let Some(option) = self.selected_option() else {
+            self.number_txt = None;
+            return SettingsPopupReturn::KeepPopup;
+        };
+
+        let text = self
+            .number_txt
+            .as_ref()
+            .and_then(|txt| txt.lines().first())
+            .expect("Number text box has one line")
+            .trim();
+
+        let value = match (text.parse::<u32>(), option.kind) {
+            (Ok(number), _) => Some(toml::Value::Integer(number.into())),
+            (Err(_), OptionKind::Number { optional: true }) if text.is_empty() => None,
+            (Err(_), OptionKind::Number { optional: true }) => {
+                self.number_err_msg = "Enter a positive number or leave empty to unset".into();
+                return SettingsPopupReturn::KeepPopup;
+            }
+            (Err(_), _) => {
+                self.number_err_msg = "Enter a positive number".into();
+                return SettingsPopupReturn::KeepPopup;
+            }
+        };
+
+        self.number_txt = None;
+
+        SettingsPopupReturn::Change(option.key, value)
+    }
+}
@@ -0,0 +1,148 @@
+use backend::DataProvider;
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::{keymap::Input, App, JournalStats};
+
+use super::{ui_functions::centered_rect, PopupReturn};
+
+type StatsPopupReturn = PopupReturn<()>;
+
+const FOOTER_TEXT: &str =
+    "<Ctrl-t>: Toggle shown/all journals | j/k or Up/Down: Scroll | Esc, q or Enter: Close";
+const FOOTER_MARGIN: u16 = 8;
+/// Count of the most used tags to show.
+const TOP_TAGS_COUNT: usize = 5;
+/// Width of the bar of the month with the most journals in the histogram.
+const MAX_BAR_WIDTH: usize = 30;
+
+/// Popup showing aggregated statistics about the shown or all journals.
+pub struct StatsPopup {
+    stats: JournalStats,
+    only_active: bool,
+    scroll: u16,
+}
+
+impl StatsPopup {
+    pub fn new<D: DataProvider>(app: &App<D>) -> Self {
+        let only_active = true;
+
+        Self {
+            stats: app.compute_stats(only_active),
+            only_active,
+            scroll: 0,
+        }
+    }
+
+    fn get_lines(&self) -> Vec<Line<'_>> {
+        let stats = &self.stats;
+        let header_style = Style::default().add_modifier(Modifier::BOLD);
+
+        let mut lines = vec![
+            Line::from(format!("Journals: {}", stats.total)),
+            Line::from(format!("This week: {}", stats.this_week)),
+            Line::from(format!("This month: {}", stats.this_month)),
+            Line::from(format!(
+                "Average words per journal: {:.1}",
+                stats.average_words()
+            )),
+            Line::default(),
+            Line::from(Span::styled("Most used tags", header_style)),
+        ];
+
+        let top_tags = stats.top_tags(TOP_TAGS_COUNT);
+        if top_tags.is_empty() {
+            lines.push(Line::from(" No tags"));
+        }
+        lines.extend(
+            top_tags
+                .into_iter()
+                .map(|(tag, count)| Line::from(format!(" - {tag}: {count}"))),
+        );
+
+        lines.push(Line::default());
+        lines.push(Line::from(Span::styled("Journals per month", header_style)));
+
+        let max_count = stats.per_month.values().copied().max().unwrap_or_default();
+        lines.extend(stats.per_month.iter().map(|((year, month), count)| {
+            let width = (count * MAX_BAR_WIDTH).div_ceil(max_count);
+            Line::from(format!(" {year}-{month:02} {} {count}", "█".repeat(width)))
+        }));
+
+        lines
+    }
+
+    pub fn render_widget(&mut self, frame: &mut Frame, area: Rect) {
+        let area = centered_rect(60, 70, area);
+
+        let title = if self.only_active {
+            "Statistics - Shown journals"
+        } else {
+            "Statistics - All journals"
+        };
+
+        let block = Block::default().borders(Borders::ALL).title(title);
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        let footer_height = if area.width < FOOTER_TEXT.len() as u16 + FOOTER_MARGIN {
+            2
+        } else {
+            1
+        };
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .horizontal_margin(3)
+            .vertical_margin(2)
+            .constraints([Constraint::Min(3), Constraint::Length(footer_height)].as_ref())
+            .split(area);
+
+        let stats = Paragraph::new(self.get_lines())
+            .wrap(Wrap { trim: false })
+            .scroll((self.scroll, 0));
+
+        frame.render_widget(stats, chunks[0]);
+
+        let footer = Paragraph::new(FOOTER_TEXT)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(footer, chunks[1]);
+    }
+
+    pub fn handle_input<D: DataProvider>(
+        &mut self,
+        input: &Input,
+        app: &App<D>,
+    ) -> StatsPopupReturn {
+        let has_ctrl = input.modifiers.contains(KeyModifiers::CONTROL);
+        match input.key_code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => StatsPopupReturn::Cancel,
+            KeyCode::Char('c') if has_ctrl => StatsPopupReturn::Cancel,
+            KeyCode::Char('t') if has_ctrl => {
+                self.only_active = !self.only_active;
+                self.stats = app.compute_stats(self.only_active);
+                self.scroll = 0;
+                StatsPopupReturn::KeepPopup
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                let max_scroll = self.get_lines().len().saturating_sub(1) as u16;
+                self.scroll = (self.scroll + 1).min(max_scroll);
+                StatsPopupReturn::KeepPopup
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.scroll = self.scroll.saturating_sub(1);
+                StatsPopupReturn::KeepPopup
+            }
+            _ => StatsPopupReturn::KeepPopup,
+        }
+    }
+}
@@ -0,0 +1,323 @@
+use std::str::FromStr;
+
+use backend::DataProvider;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use tui_textarea::{CursorMove, TextArea};
+
+use crate::app::{keymap::Input, App, TagColors, TAG_COLORS};
+
+use super::{ui_functions::centered_rect_exact_height, PopupReturn, Styles};
+
+/// Returns the tag which colors are confirmed on apply.
+type TagColorPopupReturn = PopupReturn<String>;
+
+const FOOTER_TEXT: &str = "Enter: confirm | Esc or <Ctrl-c>: Cancel | Tab: Change focused control | <Ctrl-n>: Next palette colors";
+const FOOTER_MARGIN: u16 = 8;
+
+/// Popup to set the colors of a tag, previewing the entered colors live while typing.
+/// The colors are assigned to the tag on confirm only and the preview is dropped on cancel.
+pub struct TagColorPopup<'a> {
+    tag: String,
+    foreground_txt: TextArea<'a>,
+    background_txt: TextArea<'a>,
+    active_txt: ActiveText,
+    err_msg: String,
+    /// Index of the colors picked from the palette.
+    palette_idx: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum ActiveText {
+    Foreground,
+    Background,
+}
+
+impl TagColorPopup<'_> {
+    pub fn new(tag: String, current_colors: Option<TagColors>) -> Self {
+        let colors = current_colors.unwrap_or_default();
+
+        let mut foreground_txt = TextArea::new(vec![colors.foreground.to_string()]);
+        foreground_txt.move_cursor(CursorMove::End);
+
+        let mut background_txt = TextArea::new(vec![colors.background.to_string()]);
+        background_txt.move_cursor(CursorMove::End);
+
+        Self {
+            tag,
+            foreground_txt,
+            background_txt,
+            active_txt: ActiveText::Foreground,
+            err_msg: String::default(),
+            palette_idx: None,
+        }
+    }
+
+    /// Fills the text boxes with the next colors in the palette and previews them.
+    fn pick_next_palette_colors<D: DataProvider>(&mut self, app: &mut App<D>) {
+        let idx = self
+            .palette_idx
+            .map_or(0, |idx| (idx + 1) % TAG_COLORS.len());
+        self.palette_idx = Some(idx);
+
+        let colors = TAG_COLORS[idx];
+        self.foreground_txt = TextArea::new(vec![colors.foreground.to_string()]);
+        self.foreground_txt.move_cursor(CursorMove::End);
+        self.background_txt = TextArea::new(vec![colors.background.to_string()]);
+        self.background_txt.move_cursor(CursorMove::End);
+
+        self.err_msg.clear();
+        app.preview_tag_color(&self.tag, colors);
+    }
+
+    /// Parses the colors in the text boxes, setting the error message if any is invalid.
+    fn parse_colors(&mut self) -> Option<TagColors> {
+        let parse = |txt: &TextArea, name: &str| {
+            Color::from_str(txt.lines()[0].trim())
+                .map_err(|_| format!("Invalid {name} color. Use a color name or hex '#RRGGBB'"))
+        };
+
+        match (
+            parse(&self.foreground_txt, "foreground"),
+            parse(&self.background_txt, "background"),
+        ) {
+            (Ok(foreground), Ok(background)) => {
+                self.err_msg.clear();
+                Some(TagColors::new(foreground, background))
+            }
+            (Err(err), _) | (_, Err(err)) => {
+                self.err_msg = err;
+                None
+            }
+        }
+    }
+
+    pub fn render_widget(&mut self, frame: &mut Frame, area: Rect, styles: &Styles) {
+        let mut area = centered_rect_exact_height(60, 14, area);
+
+        if area.width < FOOTER_TEXT.len() as u16 + FOOTER_MARGIN {
+            area.height += 1;
+        }
+
+        let block = Block::default().borders(Borders::ALL).title("Tag Color");
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .horizontal_margin(4)
+            .vertical_margin(2)
+            .constraints(
+                [
+                    Constraint::Length(2),
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                    Constraint::Min(1),
+                ]
+                .as_ref(),
+            )
+            .split(area);
+
+        let mut tag_line = vec![Span::raw("Tag: "), Span::raw(self.tag.as_str())];
+        if !self.err_msg.is_empty() {
+            tag_line.push(Span::styled(
+                format!(" - {}", self.err_msg),
+                Style::from(styles.general.input_block_invalid),
+            ));
+        }
+        let tag_paragraph = Paragraph::new(Line::from(tag_line)).wrap(Wrap { trim: false });
+        frame.render_widget(tag_paragraph, chunks[0]);
+
+        let active_block_style = Style::from(styles.general.input_block_active);
+        let active_cursor_style = Style::from(styles.general.input_corsur_active);
+        let reset_style = Style::reset();
+        let deactivate_cursor_style = Style::default().bg(Color::Reset);
+
+        for (txt, active, title) in [
+            (
+                &mut self.foreground_txt,
+                self.active_txt == ActiveText::Foreground,
+                "Foreground",
+            ),
+            (
+                &mut self.background_txt,
+                self.active_txt == ActiveText::Background,
+                "Background",
+            ),
+        ] {
+            let (block, cursor) = if active {
+                (active_block_style, active_cursor_style)
+            } else {
+                (reset_style, deactivate_cursor_style)
+            };
+            txt.set_style(block);
+            txt.set_cursor_style(cursor);
+            txt.set_cursor_line_style(Style::default());
+            txt.set_block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .style(block)
+                    .title(title),
+            );
+        }
+
+        frame.render_widget(&self.foreground_txt, chunks[1]);
+        frame.render_widget(&self.background_txt, chunks[2]);
+
+        let footer = Paragraph::new(FOOTER_TEXT)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(footer, chunks[3]);
+    }
+
+    pub fn handle_input<D: DataProvider>(
+        &mut self,
+        input: &Input,
+        app: &mut App<D>,
+    ) -> TagColorPopupReturn {
+        let has_ctrl = input.modifiers.contains(KeyModifiers::CONTROL);
+        match input.key_code {
+            KeyCode::Esc => self.handle_cancel(app),
+            KeyCode::Char('c') if has_ctrl => self.handle_cancel(app),
+            KeyCode::Enter => self.handle_confirm(app),
+            KeyCode::Char('n') if has_ctrl => {
+                self.pick_next_palette_colors(app);
+                TagColorPopupReturn::KeepPopup
+            }
+            KeyCode::Tab | KeyCode::BackTab => {
+                self.active_txt = match self.active_txt {
+                    ActiveText::Foreground => ActiveText::Background,
+                    ActiveText::Background => ActiveText::Foreground,
+                };
+                TagColorPopupReturn::KeepPopup
+            }
+            _ => {
+                let txt = match self.active_txt {
+                    ActiveText::Foreground => &mut self.foreground_txt,
+                    ActiveText::Background => &mut self.background_txt,
+                };
+                if txt.input(KeyEvent::from(input)) {
+                    if let Some(colors) = self.parse_colors() {
+                        app.preview_tag_color(&self.tag, colors);
+                    }
+                }
+                TagColorPopupReturn::KeepPopup
+            }
+        }
+    }
+
+    fn handle_cancel<D: DataProvider>(&mut self, app: &mut App<D>) -> TagColorPopupReturn {
+        app.cancel_tag_color_preview();
+        TagColorPopupReturn::Cancel
+    }
+
+    fn handle_confirm<D: DataProvider>(&mut self, app: &mut App<D>) -> TagColorPopupReturn {
+        let Some(colors) = self.parse_colors() else {
+            return TagColorPopupReturn::KeepPopup;
+        };
+
+        app.preview_tag_color(&self.tag, colors);
+        app.confirm_tag_color_preview();
+
+        TagColorPopupReturn::Apply(self.tag.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{app::test::mock::MockDataProvider, settings::Settings};
+
+    use super::*;
+
+    fn type_text<D: DataProvider>(popup: &mut TagColorPopup, app: &mut App<D>, text: &str) {
+        for ch in text.chars() {
+            let input = Input::new(KeyCode::Char(ch), KeyModifiers::NONE);
+            popup.handle_input(&input, app);
+        }
+    }
+
+    #[tokio::test]
+    async fn cancel_keeps_tag_color() {
+        let mut app = App::new(MockDataProvider::new_with_data(), Settings::default());
+        app.load_entries().await.unwrap();
+
+        let tag = String::from("Tag 1");
+        let stored_colors = app.get_color_for_tag(&tag).unwrap();
+        let mut popup = TagColorPopup::new(tag.clone(), Some(stored_colors));
+
+        // Replace background color with a new valid one
+        let backspace = Input::new(KeyCode::Backspace, KeyModifiers::NONE);
+        popup.active_txt = ActiveText::Background;
+        for _ in 0..popup.background_txt.lines()[0].len() {
+            popup.handle_input(&backspace, &mut app);
+        }
+        type_text(&mut popup, &mut app, "#102030");
+
+        // Color is previewed while typing
+        let preview = app.get_color_for_tag(&tag).unwrap();
+        assert_eq!(preview.background, Color::Rgb(0x10, 0x20, 0x30));
+
+        let esc = Input::new(KeyCode::Esc, KeyModifiers::NONE);
+        assert!(matches!(
+            popup.handle_input(&esc, &mut app),
+            TagColorPopupReturn::Cancel
+        ));
+
+        assert_eq!(app.get_color_for_tag(&tag).unwrap(), stored_colors);
+    }
+
+    #[tokio::test]
+    async fn confirm_assigns_tag_color() {
+        let mut app = App::new(MockDataProvider::new_with_data(), Settings::default());
+        app.load_entries().await.unwrap();
+
+        let tag = String::from("Tag 2");
+        let mut popup = TagColorPopup::new(tag.clone(), None);
+        popup.foreground_txt = TextArea::new(vec![String::from("black")]);
+        popup.background_txt = TextArea::new(vec![String::from("#AABBCC")]);
+
+        let enter = Input::new(KeyCode::Enter, KeyModifiers::NONE);
+        assert!(matches!(
+            popup.handle_input(&enter, &mut app),
+            TagColorPopupReturn::Apply(confirmed) if confirmed == tag
+        ));
+
+        assert_eq!(
+            app.get_color_for_tag(&tag).unwrap(),
+            TagColors::new(Color::Black, Color::Rgb(0xAA, 0xBB, 0xCC))
+        );
+        assert_eq!(
+            app.settings.tag_colors.get(&tag).copied(),
+            Some(TagColors::new(Color::Black, Color::Rgb(0xAA, 0xBB, 0xCC)))
+        );
+    }
+
+    #[tokio::test]
+    async fn pick_palette_colors() {
+        let mut app = App::new(MockDataProvider::new_with_data(), Settings::default());
+        app.load_entries().await.unwrap();
+
+        let tag = String::from("Tag 1");
+        let mut popup = TagColorPopup::new(tag.clone(), app.get_color_for_tag(&tag));
+
+        let next = Input::new(KeyCode::Char('n'), KeyModifiers::CONTROL);
+        popup.handle_input(&next, &mut app);
+        popup.handle_input(&next, &mut app);
+        assert_eq!(app.get_color_for_tag(&tag).unwrap(), TAG_COLORS[1]);
+
+        let enter = Input::new(KeyCode::Enter, KeyModifiers::NONE);
+        popup.handle_input(&enter, &mut app);
+        assert_eq!(
+            app.settings.tag_colors.get(&tag).copied(),
+            Some(TAG_COLORS[1])
+        );
+    }
+}
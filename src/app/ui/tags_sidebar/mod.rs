@@ -0,0 +1,164 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+use backend::DataProvider;
+
+use crate::app::{keymap::Input, runner::HandleInputReturnType, App};
+
+use super::Styles;
+
+/// Collapsible sidebar listing all the tags with their counts, where each tag can be
+/// toggled to be added to or removed from the applied filter.
+#[derive(Debug)]
+pub struct TagsSidebar {
+    pub state: ListState,
+    pub is_visible: bool,
+    is_active: bool,
+}
+
+impl TagsSidebar {
+    pub fn new() -> Self {
+        Self {
+            state: ListState::default(),
+            is_visible: false,
+            is_active: false,
+        }
+    }
+
+    pub fn render_widget<D: DataProvider>(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        app: &App<D>,
+        styles: &Styles,
+    ) {
+        let tags = app.get_tags_with_counts();
+
+        if tags.is_empty() {
+            self.state.select(None);
+            let place_holder = Paragraph::new("\nNo tags")
+                .wrap(Wrap { trim: false })
+                .alignment(Alignment::Center)
+                .block(self.get_block(styles));
+
+            frame.render_widget(place_holder, area);
+            return;
+        }
+
+        // Keep the selection in bounds since tags can change between renders.
+        match self.state.selected() {
+            Some(idx) if idx >= tags.len() => self.state.select(Some(tags.len() - 1)),
+            None => self.state.select(Some(0)),
+            _ => {}
+        }
+
+        let jstyles = &styles.journals_list;
+        let tags_default_style: Style = jstyles.tags_default.into();
+
+        let items: Vec<ListItem> = tags
+            .iter()
+            .map(|(tag, count)| {
+                let check_mark = if app.is_tag_in_filter(tag) {
+                    "[x] "
+                } else {
+                    "[ ] "
+                };
+
+                let tag_style = app
+                    .get_color_for_tag(tag)
+                    .map(|c| Style::default().bg(c.background).fg(c.foreground))
+                    .unwrap_or(tags_default_style);
+
+                // Render hierarchical tags as a tree showing their own names only.
+                let (indent, name) = if app.settings.tag_hierarchy {
+                    let depth = tag.matches('/').count();
+                    let name = tag.rsplit('/').find(|n| !n.is_empty()).unwrap_or(tag);
+                    ("  ".repeat(depth), name)
+                } else {
+                    (String::new(), tag.as_str())
+                };
+
+                let line = Line::from(vec![
+                    Span::raw(check_mark),
+                    Span::raw(indent),
+                    Span::styled(name.to_owned(), tag_style),
+                    Span::raw(format!(" ({count})")),
+                ]);
+
+                ListItem::new(line)
+            })
+            .collect();
+
+        let highlight_style = if self.is_active {
+            jstyles.highlight_active
+        } else {
+            jstyles.highlight_inactive
+        };
+
+        let list = List::new(items)
+            .block(self.get_block(styles))
+            .highlight_style(highlight_style)
+            .highlight_symbol("> ");
+
+        frame.render_stateful_widget(list, area, &mut self.state);
+    }
+
+    fn get_block<'a>(&self, styles: &Styles) -> Block<'a> {
+        let border_style = if self.is_active {
+            styles.journals_list.block_active
+        } else {
+            styles.journals_list.block_inactive
+        };
+
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Tags")
+            .border_style(border_style)
+    }
+
+    pub fn handle_input<D: DataProvider>(
+        &mut self,
+        input: &Input,
+        app: &App<D>,
+    ) -> HandleInputReturnType {
+        let tags_count = app.get_tags_with_counts().len();
+        if tags_count == 0 {
+            return HandleInputReturnType::NotFound;
+        }
+
+        let selected = self.state.selected().unwrap_or_default();
+
+        match input.key_code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.state.select(Some(selected.saturating_sub(1)));
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.state.select(Some((selected + 1).min(tags_count - 1)));
+            }
+            KeyCode::Home => self.state.select(Some(0)),
+            KeyCode::End => self.state.select(Some(tags_count - 1)),
+            _ => return HandleInputReturnType::NotFound,
+        }
+
+        HandleInputReturnType::Handled
+    }
+
+    /// Returns the tag currently selected in the sidebar if any
+    pub fn selected_tag<D: DataProvider>(&self, app: &App<D>) -> Option<String> {
+        let idx = self.state.selected()?;
+        app.get_tags_with_counts()
+            .into_iter()
+            .nth(idx)
+            .map(|(tag, _)| tag)
+    }
+
+    pub fn set_active(&mut self, active: bool) {
+        self.is_active = active;
+    }
+}
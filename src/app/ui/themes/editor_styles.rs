@@ -21,6 +21,10 @@ pub struct EditorStyles {
     pub cursor_visual: Style,
     #[serde(default = "selection_style")]
     pub selection_style: Style,
+    #[serde(default = "link")]
+    pub link: Style,
+    #[serde(default = "search_match")]
+    pub search_match: Style,
 }
 
 impl Default for EditorStyles {
@@ -34,6 +38,8 @@ impl Default for EditorStyles {
             cursor_insert: cursor_insert(),
             cursor_visual: cursor_visual(),
             selection_style: selection_style(),
+            link: link(),
+            search_match: search_match(),
         }
     }
 }
@@ -108,3 +114,21 @@ fn selection_style() -> Style {
         ..Default::default()
     }
 }
+
+#[inline]
+fn link() -> Style {
+    Style {
+        fg: Some(Color::LightBlue),
+        modifiers: Modifier::UNDERLINED,
+        ..Default::default()
+    }
+}
+
+#[inline]
+fn search_match() -> Style {
+    Style {
+        fg: Some(Color::Black),
+        bg: Some(SELECTED_FOREGROUND_COLOR),
+        ..Default::default()
+    }
+}
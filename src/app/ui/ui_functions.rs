@@ -1,6 +1,6 @@
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
     Frame,
 };
 
@@ -106,6 +106,33 @@ pub fn render_message_centered(frame: &mut Frame, message: &str) {
     frame.render_widget(paragraph, area);
 }
 
+/// Renders the message for terminals smaller than the given minimum size, wrapping it to fit
+/// in the available area regardless of how small it is.
+pub fn render_terminal_too_small(frame: &mut Frame, min_width: u16, min_height: u16) {
+    let message = format!("Terminal too small (need at least {min_width}x{min_height})");
+
+    let area = frame.area();
+    if area.width == 0 || area.height == 0 {
+        return;
+    }
+
+    let lines_count = textwrap::wrap(&message, area.width as usize).len() as u16;
+    let height = lines_count.min(area.height);
+    let message_area = Rect::new(
+        area.x,
+        area.y + (area.height - height) / 2,
+        area.width,
+        height,
+    );
+
+    let paragraph = Paragraph::new(message)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(paragraph, message_area);
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -0,0 +1,101 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::{changelog::ReleaseNotes, keymap::Input};
+
+use super::{ui_functions::centered_rect, PopupReturn};
+
+type WhatsNewReturn = PopupReturn<()>;
+
+const FOOTER_TEXT: &str = "j/k or Up/Down: Scroll | Esc, q or Enter: Close";
+const FOOTER_MARGIN: u16 = 8;
+
+/// Popup showing the changes of the current version once after upgrading the app.
+pub struct WhatsNewPopup {
+    notes: ReleaseNotes,
+    scroll: u16,
+}
+
+impl WhatsNewPopup {
+    pub fn new(notes: ReleaseNotes) -> Self {
+        Self { notes, scroll: 0 }
+    }
+
+    fn get_lines(&self) -> Vec<Line<'_>> {
+        let mut lines = Vec::new();
+        for (category, items) in self.notes.changes.iter() {
+            if !lines.is_empty() {
+                lines.push(Line::default());
+            }
+
+            lines.push(Line::from(Span::styled(
+                category.as_str(),
+                Style::default().add_modifier(Modifier::BOLD),
+            )));
+
+            lines.extend(items.iter().map(|item| Line::from(format!(" - {item}"))));
+        }
+
+        lines
+    }
+
+    pub fn render_widget(&mut self, frame: &mut Frame, area: Rect) {
+        let area = centered_rect(70, 60, area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("What's New in {}", self.notes.version));
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        let footer_height = if area.width < FOOTER_TEXT.len() as u16 + FOOTER_MARGIN {
+            2
+        } else {
+            1
+        };
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .horizontal_margin(3)
+            .vertical_margin(2)
+            .constraints([Constraint::Min(3), Constraint::Length(footer_height)].as_ref())
+            .split(area);
+
+        let notes = Paragraph::new(self.get_lines())
+            .wrap(Wrap { trim: false })
+            .scroll((self.scroll, 0));
+
+        frame.render_widget(notes, chunks[0]);
+
+        let footer = Paragraph::new(FOOTER_TEXT)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(footer, chunks[1]);
+    }
+
+    pub fn handle_input(&mut self, input: &Input) -> WhatsNewReturn {
+        let has_ctrl = input.modifiers.contains(KeyModifiers::CONTROL);
+        match input.key_code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => WhatsNewReturn::Cancel,
+            KeyCode::Char('c') if has_ctrl => WhatsNewReturn::Cancel,
+            KeyCode::Down | KeyCode::Char('j') => {
+                let max_scroll = self.get_lines().len().saturating_sub(1) as u16;
+                self.scroll = (self.scroll + 1).min(max_scroll);
+                WhatsNewReturn::KeepPopup
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.scroll = self.scroll.saturating_sub(1);
+                WhatsNewReturn::KeepPopup
+            }
+            _ => WhatsNewReturn::KeepPopup,
+        }
+    }
+}
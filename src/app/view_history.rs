@@ -0,0 +1,84 @@
+use std::collections::VecDeque;
+
+/// Maximum count of the viewed entries kept in the history.
+const VIEW_HISTORY_LIMIT: usize = 100;
+
+#[derive(Debug, Default)]
+/// Keeps the sequence of the viewed entries, enabling navigating back and forward through them
+/// like in web browsers.
+pub struct ViewHistory {
+    entries: VecDeque<u32>,
+    /// Index of the currently viewed entry in the history.
+    position: usize,
+}
+
+impl ViewHistory {
+    /// Adds the given entry as the viewed one, dropping the entries after the current position.
+    /// Viewing the current entry again doesn't change the history.
+    pub fn visit(&mut self, entry_id: u32) {
+        if self.entries.get(self.position) == Some(&entry_id) {
+            return;
+        }
+
+        if !self.entries.is_empty() {
+            self.entries.truncate(self.position + 1);
+        }
+
+        self.entries.push_back(entry_id);
+        if self.entries.len() > VIEW_HISTORY_LIMIT {
+            _ = self.entries.pop_front();
+        }
+
+        self.position = self.entries.len() - 1;
+    }
+
+    /// Moves back to the previous viewed entry that still exists, returning its id.
+    pub fn back(&mut self, exists: impl Fn(u32) -> bool) -> Option<u32> {
+        let position = (0..self.position)
+            .rev()
+            .find(|pos| exists(self.entries[*pos]))?;
+
+        self.position = position;
+        Some(self.entries[position])
+    }
+
+    /// Moves forward to the next viewed entry that still exists, returning its id.
+    pub fn forward(&mut self, exists: impl Fn(u32) -> bool) -> Option<u32> {
+        let position =
+            (self.position + 1..self.entries.len()).find(|pos| exists(self.entries[*pos]))?;
+
+        self.position = position;
+        Some(self.entries[position])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn visit_drops_forward_entries() {
+        let mut history = ViewHistory::default();
+        for id in [1, 2, 3] {
+            history.visit(id);
+        }
+
+        assert_eq!(history.back(|_| true), Some(2));
+        history.visit(4);
+        assert_eq!(history.forward(|_| true), None);
+        assert_eq!(history.back(|_| true), Some(2));
+        assert_eq!(history.back(|_| true), Some(1));
+        assert_eq!(history.back(|_| true), None);
+    }
+
+    #[test]
+    fn history_limit() {
+        let mut history = ViewHistory::default();
+        for id in 0..VIEW_HISTORY_LIMIT as u32 + 10 {
+            history.visit(id);
+        }
+
+        assert_eq!(history.entries.len(), VIEW_HISTORY_LIMIT);
+        assert_eq!(history.entries.front(), Some(&10));
+    }
+}
@@ -16,9 +16,48 @@ pub enum CliCommand {
     #[clap(visible_alias = "imj")]
     ImportJournals {
         /// Path of the JSON file to import from.
+        #[arg(
+            short = 'p',
+            long = "path",
+            required_unless_present_any = ["ndjson", "markdown_dir"],
+            value_name = "FILE PATH"
+        )]
+        file_path: Option<PathBuf>,
+        /// Import newline-delimited JSON entries without starting the app, reading from the given
+        /// path or from the standard input if no path is provided.
+        #[arg(long)]
+        ndjson: bool,
+        /// Import the Markdown files with optional YAML front matter in the given directory
+        /// without starting the app.
+        #[arg(long, value_name = "DIR PATH", conflicts_with_all = ["file_path", "ndjson"])]
+        markdown_dir: Option<PathBuf>,
+        /// How the imported journals with the same title and date as existing journals are
+        /// handled while importing from a transfer JSON file.
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = ImportStrategy::AddAll,
+            conflicts_with_all = ["ndjson", "markdown_dir"]
+        )]
+        strategy: ImportStrategy,
+    },
+    /// Compare the current journals with the journals of the given file, reporting the journals
+    /// found only in each of them.
+    #[clap(visible_alias = "dj")]
+    DiffJournals {
+        /// Path of the JSON back-end file or the transfer file to compare with.
         #[arg(short = 'p', long = "path", required = true, value_name = "FILE PATH")]
         file_path: PathBuf,
+        /// How journals are matched between the two files.
+        #[arg(long = "by", value_enum, default_value_t = DiffMatch::Content)]
+        match_by: DiffMatch,
+        /// Export the journals found only in the given file to a transfer JSON file, which can be
+        /// imported afterwards.
+        #[arg(short = 'e', long = "export", value_name = "EXPORT PATH")]
+        export_path: Option<PathBuf>,
     },
+    /// Print the JSON schema of the transfer files used for exporting and importing journals.
+    Schema,
     /// Assign priority for all the entires with empty priority field
     #[clap(visible_alias = "ap")]
     AssignPriority {
@@ -48,7 +87,7 @@ pub enum Themes {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PendingCliCommand {
-    ImportJournals(PathBuf),
+    ImportJournals(PathBuf, ImportStrategy),
     AssignPriority(u32),
 }
 
@@ -56,9 +95,36 @@ impl CliCommand {
     pub fn exec(self, settings: &mut Settings) -> anyhow::Result<CliResult> {
         match self {
             CliCommand::PrintConfig => exec_print_config(settings),
-            CliCommand::ImportJournals { file_path: path } => Ok(CliResult::PendingCommand(
-                PendingCliCommand::ImportJournals(path),
-            )),
+            CliCommand::ImportJournals {
+                markdown_dir: Some(dir),
+                ..
+            } => Ok(CliResult::ImportMarkdown(dir)),
+            CliCommand::ImportJournals {
+                file_path,
+                ndjson: true,
+                ..
+            } => Ok(CliResult::ImportNdjson(file_path)),
+            CliCommand::ImportJournals {
+                file_path,
+                ndjson: false,
+                strategy,
+                ..
+            } => {
+                let path = file_path.expect("path is required without ndjson flag");
+                Ok(CliResult::PendingCommand(
+                    PendingCliCommand::ImportJournals(path, strategy),
+                ))
+            }
+            CliCommand::DiffJournals {
+                file_path,
+                match_by,
+                export_path,
+            } => Ok(CliResult::DiffJournals {
+                file_path,
+                match_by,
+                export_path,
+            }),
+            CliCommand::Schema => exec_print_schema(),
             CliCommand::AssignPriority { priority } => Ok(CliResult::PendingCommand(
                 PendingCliCommand::AssignPriority(priority),
             )),
@@ -79,6 +145,13 @@ fn exec_print_config(settings: &mut Settings) -> anyhow::Result<CliResult> {
     Ok(CliResult::Return)
 }
 
+fn exec_print_schema() -> anyhow::Result<CliResult> {
+    let schema_txt = serde_json::to_string_pretty(&backend::entries_dto_schema())?;
+    println!("{schema_txt}");
+
+    Ok(CliResult::Return)
+}
+
 fn exec_print_themes_path() -> anyhow::Result<CliResult> {
     let themes_path = Styles::file_path()?;
 
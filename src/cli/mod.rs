@@ -5,6 +5,7 @@ use std::{
 };
 
 use crate::{
+    app::{diff::DiffMatch, import::ImportStrategy},
     logging::{get_default_path as defaul_log_path, setup_logging},
     settings::{settings_default_path, BackendType, Settings},
 };
@@ -12,6 +13,10 @@ use crate::{
 pub mod commands;
 pub use commands::CliCommand;
 pub use commands::PendingCliCommand;
+#[cfg(feature = "encrypted_json")]
+mod passphrase;
+#[cfg(feature = "encrypted_json")]
+pub use passphrase::{read_new_passphrase, read_passphrase};
 use path_absolutize::Absolutize;
 
 #[derive(Parser, Debug)]
@@ -38,6 +43,10 @@ pub struct Cli {
     #[arg(short, long)]
     write_config: bool,
 
+    /// Opens the journals in read-only mode, ignoring all commands which modify them.
+    #[arg(short, long)]
+    read_only: bool,
+
     /// Increases logging verbosity each use for up to 3 times.
     #[arg(short = 'v', long, action = clap::ArgAction::Count)]
     verbose: u8,
@@ -54,6 +63,16 @@ pub enum CliResult {
     Return,
     Continue,
     PendingCommand(PendingCliCommand),
+    /// Import entries from newline-delimited JSON without starting the app.
+    ImportNdjson(Option<PathBuf>),
+    /// Import entries from a directory of Markdown files without starting the app.
+    ImportMarkdown(PathBuf),
+    /// Compare the current journals with the given journal file without starting the app.
+    DiffJournals {
+        file_path: PathBuf,
+        match_by: DiffMatch,
+        export_path: Option<PathBuf>,
+    },
 }
 
 impl Cli {
@@ -80,6 +99,11 @@ impl Cli {
                 .await?;
         }
 
+        // Applied after writing the config since the flag is meant for the current session only.
+        if self.read_only {
+            settings.read_only_session = true;
+        }
+
         setup_logging(self.verbose, self.log_file)?;
 
         if let Some(cmd) = self.command.take() {
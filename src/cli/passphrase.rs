@@ -0,0 +1,76 @@
+use std::io::{self, Write};
+
+use anyhow::bail;
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    terminal,
+};
+
+/// Environment variable to provide the passphrase of the encrypted JSON back-end without being
+/// prompted for it.
+const PASSPHRASE_ENV_VAR: &str = "TJOURNAL_PASSPHRASE";
+
+/// Reads the passphrase of the encrypted JSON back-end from the environment variable if set,
+/// otherwise it prompts for it in the terminal without echoing the typed characters.
+pub fn read_passphrase() -> anyhow::Result<String> {
+    if let Ok(passphrase) = std::env::var(PASSPHRASE_ENV_VAR) {
+        return Ok(passphrase);
+    }
+
+    prompt_hidden("Passphrase for the encrypted journals: ")
+}
+
+/// Reads the passphrase for new encrypted journals like [`read_passphrase`], prompting for it
+/// twice to make sure it has no typos since the journals can't be decrypted otherwise.
+pub fn read_new_passphrase() -> anyhow::Result<String> {
+    if let Ok(passphrase) = std::env::var(PASSPHRASE_ENV_VAR) {
+        return Ok(passphrase);
+    }
+
+    let passphrase = prompt_hidden("New passphrase for the encrypted journals: ")?;
+    let confirmation = prompt_hidden("Confirm the passphrase: ")?;
+    if passphrase != confirmation {
+        bail!("Passphrases don't match");
+    }
+
+    Ok(passphrase)
+}
+
+fn prompt_hidden(prompt: &str) -> anyhow::Result<String> {
+    eprint!("{prompt}");
+    io::stderr().flush()?;
+
+    terminal::enable_raw_mode()?;
+    let result = read_hidden_line();
+    terminal::disable_raw_mode()?;
+    eprintln!();
+
+    result
+}
+
+fn read_hidden_line() -> anyhow::Result<String> {
+    let mut line = String::new();
+
+    loop {
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        match key.code {
+            KeyCode::Enter => return Ok(line),
+            KeyCode::Esc => bail!("Passphrase input canceled"),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                bail!("Passphrase input canceled")
+            }
+            KeyCode::Backspace => {
+                line.pop();
+            }
+            KeyCode::Char(ch) => line.push(ch),
+            _ => {}
+        }
+    }
+}
@@ -23,12 +23,37 @@ async fn main() -> Result<()> {
 
     let mut pending_cmd = None;
 
-    match cli.handle_cli(&mut settings).await? {
+    let cli_result = cli.handle_cli(&mut settings).await?;
+
+    #[cfg(feature = "encrypted_json")]
+    if !matches!(cli_result, cli::CliResult::Return)
+        && settings.backend_type.unwrap_or_default() == settings::BackendType::EncryptedJson
+    {
+        let passphrase = if settings.encrypted_json_backend.is_new()? {
+            cli::read_new_passphrase()?
+        } else {
+            cli::read_passphrase()?
+        };
+        settings.unlock_encrypted_json(&passphrase).await?;
+    }
+
+    match cli_result {
         cli::CliResult::Return => return Ok(()),
         cli::CliResult::Continue => {}
         cli::CliResult::PendingCommand(cmd) => pending_cmd = Some(cmd),
+        cli::CliResult::ImportNdjson(file_path) => {
+            return app::import_ndjson(settings, file_path).await
+        }
+        cli::CliResult::ImportMarkdown(dir) => return app::import_markdown(settings, dir).await,
+        cli::CliResult::DiffJournals {
+            file_path,
+            match_by,
+            export_path,
+        } => return app::diff_journals(settings, file_path, match_by, export_path).await,
     }
 
+    settings.apply_journal_overrides().await?;
+
     let styles = Styles::load().context("Error while retrieving app styles")?;
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -38,11 +63,13 @@ async fn main() -> Result<()> {
 
     chain_panic_hook();
 
-    app::run(&mut terminal, settings, styles, pending_cmd)
+    let result = app::run(&mut terminal, settings, styles, pending_cmd)
         .await
         .inspect_err(|err| {
             log::error!("[PANIC] {}", err.to_string());
-        })?;
+        });
+    app::reset_cursor_style();
+    result?;
 
     // restore terminal
     disable_raw_mode()?;
@@ -57,6 +84,7 @@ fn chain_panic_hook() {
     let original_hook = std::panic::take_hook();
 
     std::panic::set_hook(Box::new(move |panic| {
+        app::reset_cursor_style();
         disable_raw_mode().unwrap();
         execute!(io::stdout(), LeaveAlternateScreen).unwrap();
         original_hook(panic);
@@ -0,0 +1,106 @@
+use std::path::PathBuf;
+
+use anyhow::ensure;
+use backend::{EncryptionKey, KdfParams};
+use serde::{Deserialize, Serialize};
+
+use super::get_default_data_dir;
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct EncryptedJsonBackend {
+    #[serde(default)]
+    pub file_path: Option<PathBuf>,
+    /// Key derived from the passphrase after unlocking the back-end. It's never persisted.
+    #[serde(skip)]
+    pub key: Option<EncryptionKey>,
+}
+
+pub fn get_default_encrypted_json_path() -> anyhow::Result<PathBuf> {
+    Ok(get_default_data_dir()?.join("entries.json.enc"))
+}
+
+impl EncryptedJsonBackend {
+    /// Gets the path of the encrypted entries file, falling back to the default one.
+    pub fn get_file_path(&self) -> anyhow::Result<PathBuf> {
+        match &self.file_path {
+            Some(path) => Ok(path.clone()),
+            None => get_default_encrypted_json_path(),
+        }
+    }
+
+    /// Checks if the encrypted entries file doesn't contain any data yet, meaning that the
+    /// passphrase will be set on unlocking.
+    pub fn is_new(&self) -> anyhow::Result<bool> {
+        let file_path = self.get_file_path()?;
+
+        Ok(!file_path.try_exists()? || std::fs::metadata(&file_path)?.len() == 0)
+    }
+
+    /// Derives the encryption key from the given passphrase, keeping it to be used by the
+    /// back-end. The key derivation parameters are read from the header of the entries file if
+    /// it exists, otherwise they are generated and written to the file on the first save.
+    pub async fn unlock(&mut self, passphrase: &str) -> anyhow::Result<EncryptionKey> {
+        ensure!(!passphrase.is_empty(), "Passphrase can't be empty");
+
+        let params = if self.is_new()? {
+            KdfParams::generate()
+        } else {
+            let content = tokio::fs::read(self.get_file_path()?).await?;
+            KdfParams::from_encrypted(&content)?
+        };
+
+        let key = EncryptionKey::derive(passphrase, params)?;
+        self.key = Some(key.clone());
+
+        Ok(key)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use backend::{DataProvider, EntryDraft, JsonDataProvide};
+    use chrono::Utc;
+
+    use crate::app::test::temp_dir::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn unlock_with_file_header() {
+        let dir = TempDir::new("unlock");
+        let file_path = dir.join("entries.json.enc");
+        let mut backend = EncryptedJsonBackend {
+            file_path: Some(file_path.clone()),
+            key: None,
+        };
+        assert!(backend.is_new().unwrap());
+        assert!(backend.unlock("").await.is_err());
+
+        let key = backend.unlock("secret").await.unwrap();
+        let provider = JsonDataProvide::new_encrypted(file_path.clone(), key)
+            .await
+            .unwrap();
+        let draft = EntryDraft::new(Utc::now(), String::from("Private"), Vec::new(), None);
+        provider.add_entry(draft).await.unwrap();
+        assert!(!backend.is_new().unwrap());
+
+        // A fresh back-end without any saved settings unlocks the file with the passphrase only.
+        let mut backend = EncryptedJsonBackend {
+            file_path: Some(file_path.clone()),
+            key: None,
+        };
+        let key = backend.unlock("secret").await.unwrap();
+        let provider = JsonDataProvide::new_encrypted(file_path.clone(), key)
+            .await
+            .unwrap();
+        assert_eq!(
+            provider.load_all_entries().await.unwrap()[0].title,
+            "Private"
+        );
+
+        let key = backend.unlock("wrong").await.unwrap();
+        assert!(JsonDataProvide::new_encrypted(file_path.clone(), key)
+            .await
+            .is_err());
+    }
+}
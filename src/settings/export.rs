@@ -8,6 +8,32 @@ pub struct ExportSettings {
     pub default_path: Option<PathBuf>,
     #[serde(default = "return_true")]
     pub show_confirmation: bool,
+    /// Pattern for the file name suggested in export dialog. Supported placeholders are
+    /// `{date}`, `{format}` and `{journal}`.
+    #[serde(default)]
+    pub file_name_pattern: Option<String>,
+    /// Ends the exported journal content and the copied Markdown with a newline when set,
+    /// otherwise removes the trailing newlines from them.
+    #[serde(default = "return_true")]
+    pub trailing_newline: bool,
+    /// Export the selected journals in the order they have been selected instead of the order
+    /// they are stored in.
+    #[serde(default)]
+    pub selection_order: bool,
+    /// Sets how lines exceeding the width of the export preview are shown.
+    #[serde(default)]
+    pub preview_long_lines: PreviewLongLines,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+/// Represents how lines exceeding the width of the export preview are shown.
+pub enum PreviewLongLines {
+    #[default]
+    /// Wrap the long lines to fit in the preview.
+    Wrap,
+    /// Keep the lines as they are, scrolling horizontally to see their rest.
+    Scroll,
 }
 
 fn return_true() -> bool {
@@ -19,6 +45,10 @@ impl Default for ExportSettings {
         Self {
             default_path: None,
             show_confirmation: true,
+            file_name_pattern: None,
+            trailing_newline: true,
+            selection_order: false,
+            preview_long_lines: Default::default(),
         }
     }
 }
@@ -26,7 +26,7 @@ impl Default for ExternalEditor {
 }
 
 fn default_temp_file_extension() -> String {
-    String::from("txt")
+    String::from("md")
 }
 
 impl FromStr for ExternalEditor {
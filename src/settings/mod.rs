@@ -1,21 +1,35 @@
-use std::{convert::Infallible, fmt, marker::PhantomData, path::PathBuf, str::FromStr};
+use std::{
+    collections::BTreeMap,
+    convert::Infallible,
+    fmt,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
 use anyhow::{anyhow, Context};
 use clap::ValueEnum;
+use crossterm::cursor::SetCursorStyle;
 use directories::{BaseDirs, UserDirs};
 use serde::{
     de::{self, MapAccess, Visitor},
     Deserialize, Deserializer, Serialize,
 };
 
-use crate::app::state::AppState;
+use crate::app::{filter::Filter, state::AppState, TagColors};
 
+#[cfg(feature = "encrypted_json")]
+use self::encrypted_json_backend::{get_default_encrypted_json_path, EncryptedJsonBackend};
 #[cfg(feature = "json")]
 use self::json_backend::{get_default_json_path, JsonBackend};
 #[cfg(feature = "sqlite")]
 use self::sqlite_backend::{get_default_sqlite_path, SqliteBackend};
 use self::{export::ExportSettings, external_editor::ExternalEditor};
+pub use export::PreviewLongLines;
+pub use options::{search_options, OptionKind, SettingOption, SETTING_OPTIONS};
 
+#[cfg(feature = "encrypted_json")]
+pub mod encrypted_json_backend;
 #[cfg(feature = "json")]
 pub mod json_backend;
 #[cfg(feature = "sqlite")]
@@ -23,9 +37,21 @@ pub mod sqlite_backend;
 
 mod export;
 mod external_editor;
+mod options;
 
 const DEFAULT_SCROLL_PER_PAGE: usize = 5;
 
+/// Suffix appended to the file name of the backend to get the path of the per-journal settings.
+const JOURNAL_SETTINGS_SUFFIX: &str = ".config.toml";
+
+/// Fields that define the journal itself and can't be overridden from the per-journal settings.
+const NON_OVERRIDABLE_FIELDS: [&str; 4] = [
+    "backend_type",
+    "json_backend",
+    "sqlite_backend",
+    "encrypted_json_backend",
+];
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Settings {
     #[serde(default)]
@@ -40,22 +66,167 @@ pub struct Settings {
     #[cfg(feature = "sqlite")]
     #[serde(default)]
     pub sqlite_backend: SqliteBackend,
+    #[cfg(feature = "encrypted_json")]
+    #[serde(default)]
+    pub encrypted_json_backend: EncryptedJsonBackend,
     #[serde(default)]
     pub default_journal_priority: Option<u32>,
+    #[serde(default = "default_min_journal_priority")]
+    /// Sets the lowest priority reachable when lowering the priority with the quick commands.
+    pub min_journal_priority: u32,
+    #[serde(default = "default_max_journal_priority")]
+    /// Sets the highest priority reachable when bumping the priority with the quick commands.
+    pub max_journal_priority: u32,
     #[serde(default)]
     pub scroll_per_page: Option<usize>,
     #[serde(default)]
+    /// Suggest the closest journal title in the fuzzy finder when the search query has no matches.
+    pub fuzzy_find_suggestions: bool,
+    #[serde(default)]
     pub sync_os_clipboard: bool,
     #[serde(default = "default_history_limit")]
-    /// Set the maximum size of the history stacks (undo & redo) size.
+    /// Set the maximum size of the history stacks (undo & redo) size, where zero disables them.
     pub history_limit: usize,
+    #[serde(default = "default_history_grouping_interval")]
+    /// Interval in seconds to group consecutive changes on the content of the same journal into
+    /// one undo step, where zero makes every change a separate step.
+    pub history_grouping_interval: u64,
     #[serde(default = "default_colored_tags")]
     pub colored_tags: bool,
+    #[serde(default = "default_min_terminal_width")]
+    /// Sets the minimum terminal width needed to render the app.
+    pub min_terminal_width: u16,
+    #[serde(default = "default_min_terminal_height")]
+    /// Sets the minimum terminal height needed to render the app.
+    pub min_terminal_height: u16,
     #[serde(default)]
     /// Sets the visibility options for the datum of journals when rendered in entries list.
     pub datum_visibility: DatumVisibility,
+    #[serde(default)]
+    /// Sets which one of date and priority is rendered first in entries list.
+    pub date_priority_order: DatePriorityOrder,
+    #[serde(default)]
+    /// Render the priority on its own line regardless of the available width.
+    pub priority_own_line: bool,
+    #[serde(default)]
+    /// Show the count of journals having each tag next to the tags in the journals list.
+    pub show_tag_counts: bool,
+    #[serde(default)]
+    /// Sets how much information is rendered for each journal in entries list.
+    pub list_density: ListDensity,
+    #[serde(default)]
+    /// Sets when the scrollbar of the journals list is shown.
+    pub list_scrollbar: ScrollbarVisibility,
+    #[serde(default)]
+    /// Sets what the content area shows when no journal is selected.
+    pub empty_content_view: EmptyContentView,
+    #[serde(default)]
+    /// Show the number of journals for each priority in the footer.
+    pub priority_counts: bool,
+    #[serde(default)]
+    /// Sets which journals are counted in the priority counts of the footer.
+    pub priority_counts_scope: PriorityCountsScope,
+    #[serde(default)]
+    /// Sets the style of the terminal cursor while the content editor is focused.
+    pub editor_cursor_style: EditorCursorStyle,
+    #[serde(default)]
+    /// Ask for confirmation before discarding the changed values in the journal form.
+    pub confirm_discard_entry_form: bool,
+    #[serde(default)]
+    /// Ask for confirmation before undo or redo moves past the last explicit save of a journal
+    /// content.
+    pub confirm_history_past_save: bool,
+    #[serde(default)]
+    /// Sets how to handle tags in the filter which aren't assigned to any journal anymore.
+    pub missing_filter_tags: MissingFilterTagsBehavior,
+    #[serde(default)]
+    /// Ask for confirmation before applying a filter which hides all journals.
+    pub confirm_empty_filter: bool,
+    #[serde(default = "default_restore_filter_on_startup")]
+    /// Restore the filter applied when the app was closed on the next start.
+    pub restore_filter_on_startup: bool,
+    #[serde(default)]
+    /// Keep the undo and redo history in the app state to restore it on the next start. It's
+    /// ignored for the encrypted JSON back-end.
+    pub persist_history: bool,
+    #[serde(default)]
+    /// Timeout in seconds after which unanswered confirmation prompts are dismissed, resolving
+    /// to cancel. Prompts wait for an answer when not set or set to zero.
+    pub confirmation_timeout: Option<u64>,
+    #[serde(default)]
+    /// Open the journals in read-only mode, ignoring all commands which modify them.
+    pub read_only: bool,
+    #[serde(default)]
+    /// Sets how to handle failures while saving changes of journals.
+    pub save_failure: SaveFailureBehavior,
+    #[serde(default)]
+    /// Interval in seconds to save the unsaved changes of the journal content automatically.
+    /// Autosave is disabled when not set.
+    pub autosave_interval: Option<u64>,
+    #[serde(default)]
+    /// Sets how pinned journals are ordered on top of the others.
+    pub pinned_order: PinnedOrder,
+    #[serde(default)]
+    /// Sets which journal gets selected after deleting the current one.
+    pub selection_after_delete: SelectionAfterDelete,
+    #[serde(default)]
+    /// Sets how to handle navigating to a journal which is hidden by the filter.
+    pub hidden_entry_navigation: HiddenEntryNavigation,
+    #[serde(default)]
+    /// Sets what happens when the filter or the focus mode leaves exactly one journal.
+    pub single_entry_filter: SingleEntryFilter,
+    #[serde(default)]
+    /// Prompt to add tags after creating a journal without any tags.
+    pub prompt_tags_on_create: bool,
+    #[serde(default)]
+    /// Sets how to handle saving a journal with an empty title.
+    pub empty_title: EmptyTitleBehavior,
+    #[serde(default)]
+    /// Convert the tags to lowercase when they are assigned to journals.
+    pub lowercase_tags: bool,
+    #[serde(default)]
+    /// Sets how to handle duplicated tags within one journal.
+    pub duplicate_tags: DuplicateTagsBehavior,
+    #[serde(default)]
+    /// Compare the tags ignoring their case when removing duplicates. This is always the case
+    /// when the tags are converted to lowercase.
+    pub duplicate_tags_ignore_case: bool,
+    #[serde(default)]
+    /// Treat tags as a hierarchy with the `parent/child` notation, where filtering by a parent
+    /// tag matches its children too.
+    pub tag_hierarchy: bool,
+    #[serde(default)]
+    /// Sets how the tags are ordered in the tags sidebar, the tags popups and the filter.
+    pub tags_order: TagsOrder,
+    #[serde(default)]
+    /// Filters applied directly with the number keys they are bound to.
+    pub filter_presets: Vec<FilterPreset>,
+    #[serde(default)]
+    /// Content templates offered to be inserted when assigning their tags to journals.
+    pub tag_templates: BTreeMap<String, String>,
+    #[serde(default)]
+    /// Advisory limits for the sections of tag templates, mapping the tags to the limits of their
+    /// sections by heading text. The editor warns when a section exceeds its limits.
+    pub tag_template_limits: BTreeMap<String, BTreeMap<String, SectionLimit>>,
+    #[serde(default)]
+    /// Colors assigned to tags by the user, overriding the automatically assigned colors.
+    pub tag_colors: BTreeMap<String, TagColors>,
+    #[serde(default)]
+    /// Keys overriding the default keys of commands, mapping the command names in snake case to
+    /// their new keys.
+    pub keybindings: BTreeMap<String, String>,
+    #[serde(default)]
+    /// Sets how to handle keybindings with conflicting keys or unknown commands at startup.
+    pub keybinding_conflicts: KeybindingConflicts,
     /// Overwrite the path for the directory used to persist the app state.
     pub app_state_dir: Option<PathBuf>,
+    /// Path of the settings file the settings are loaded from.
+    #[serde(skip)]
+    pub settings_path: Option<PathBuf>,
+    /// Read-only mode requested via the CLI flag for the current session only, which can't be
+    /// changed by the journal settings or the settings popup.
+    #[serde(skip)]
+    pub read_only_session: bool,
 }
 
 impl Default for Settings {
@@ -68,13 +239,59 @@ impl Default for Settings {
             json_backend: Default::default(),
             #[cfg(feature = "sqlite")]
             sqlite_backend: Default::default(),
+            #[cfg(feature = "encrypted_json")]
+            encrypted_json_backend: Default::default(),
             default_journal_priority: Default::default(),
+            min_journal_priority: default_min_journal_priority(),
+            max_journal_priority: default_max_journal_priority(),
             scroll_per_page: Default::default(),
+            fuzzy_find_suggestions: Default::default(),
             sync_os_clipboard: Default::default(),
             history_limit: default_history_limit(),
+            history_grouping_interval: default_history_grouping_interval(),
             colored_tags: default_colored_tags(),
+            min_terminal_width: default_min_terminal_width(),
+            min_terminal_height: default_min_terminal_height(),
             datum_visibility: Default::default(),
+            date_priority_order: Default::default(),
+            priority_own_line: Default::default(),
+            show_tag_counts: Default::default(),
+            list_density: Default::default(),
+            list_scrollbar: Default::default(),
+            empty_content_view: Default::default(),
+            priority_counts: Default::default(),
+            priority_counts_scope: Default::default(),
+            editor_cursor_style: Default::default(),
+            confirm_discard_entry_form: Default::default(),
+            confirm_history_past_save: Default::default(),
+            missing_filter_tags: Default::default(),
+            confirm_empty_filter: Default::default(),
+            restore_filter_on_startup: default_restore_filter_on_startup(),
+            persist_history: Default::default(),
+            confirmation_timeout: Default::default(),
+            read_only: Default::default(),
+            save_failure: Default::default(),
+            autosave_interval: Default::default(),
+            pinned_order: Default::default(),
+            selection_after_delete: Default::default(),
+            hidden_entry_navigation: Default::default(),
+            single_entry_filter: Default::default(),
+            prompt_tags_on_create: Default::default(),
+            empty_title: Default::default(),
+            lowercase_tags: Default::default(),
+            duplicate_tags: Default::default(),
+            duplicate_tags_ignore_case: Default::default(),
+            tag_hierarchy: Default::default(),
+            tags_order: Default::default(),
+            filter_presets: Default::default(),
+            tag_templates: Default::default(),
+            tag_template_limits: Default::default(),
+            tag_colors: Default::default(),
+            keybindings: Default::default(),
+            keybinding_conflicts: Default::default(),
             app_state_dir: Default::default(),
+            settings_path: Default::default(),
+            read_only_session: Default::default(),
         }
     }
 }
@@ -93,22 +310,296 @@ pub enum DatumVisibility {
     EmptyLine,
 }
 
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+/// Represents the order of date and priority of journals when rendered in entries list.
+pub enum DatePriorityOrder {
+    #[default]
+    /// Render the date before the priority.
+    DateFirst,
+    /// Render the priority before the date.
+    PriorityFirst,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+/// Represents how to handle saving a journal with an empty title.
+pub enum EmptyTitleBehavior {
+    #[default]
+    /// Save the journal with the empty title.
+    Allow,
+    /// Refuse saving the journal showing an error message.
+    Reject,
+    /// Generate a title from the first line of the content, falling back to the date.
+    Generate,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+/// Represents how to handle duplicated tags within one journal.
+pub enum DuplicateTagsBehavior {
+    #[default]
+    /// Remove the duplicated tags silently.
+    Dedupe,
+    /// Remove the duplicated tags showing a notification about them.
+    Warn,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+/// Represents how to handle keybindings with conflicting keys or unknown commands.
+pub enum KeybindingConflicts {
+    #[default]
+    /// Keep the default keys of the affected commands showing a warning about them.
+    Warn,
+    /// Fail the startup with an error.
+    Fail,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+/// Filter bound to a number key to be applied directly with it.
+pub struct FilterPreset {
+    /// The number key (1 to 9) the filter is bound to.
+    pub key: u8,
+    #[serde(flatten)]
+    pub filter: Filter,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, Default)]
+/// Advisory limits of a section in journal content.
+pub struct SectionLimit {
+    /// Maximum count of words in the section.
+    pub max_words: Option<usize>,
+    /// Maximum count of characters in the section without the line breaks.
+    pub max_chars: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+/// Represents how to handle tag criteria in the filter once their tags aren't assigned to any
+/// journal anymore.
+pub enum MissingFilterTagsBehavior {
+    #[default]
+    /// Drop the tag criteria from the filter silently.
+    Drop,
+    /// Drop the tag criteria from the filter showing a notification about it.
+    DropNotify,
+    /// Keep the tag criteria in the filter even though they don't match any journal.
+    Keep,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+/// Represents how to handle failures while persisting the changes of a journal.
+pub enum SaveFailureBehavior {
+    #[default]
+    /// Roll back the changes in the app so it stays consistent with the saved journals.
+    Rollback,
+    /// Keep the changes in the app marking the journal as unsynced, retrying to save it later.
+    KeepUnsynced,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+/// Represents how pinned journals are ordered among themselves. Pinned journals are always kept
+/// above the unpinned ones.
+pub enum PinnedOrder {
+    #[default]
+    /// Sort the pinned journals with the active sort criteria like the other journals.
+    Sorted,
+    /// Order the pinned journals by their pin time with the latest pinned on top.
+    PinTime,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+/// Represents how the tags are ordered wherever they are listed.
+pub enum TagsOrder {
+    #[default]
+    /// Order the tags alphabetically.
+    Alphabetical,
+    /// Order the tags by the count of journals having them with the most used on top.
+    Count,
+    /// Order the tags by the latest modification of the journals having them with the most
+    /// recently used on top.
+    Recent,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+/// Represents which journal gets selected after deleting the current one, considering the
+/// journals visible with the applied filter only.
+pub enum SelectionAfterDelete {
+    #[default]
+    /// Select the next journal, falling back to the previous one if the deleted was the last.
+    Next,
+    /// Select the previous journal, falling back to the next one if the deleted was the first.
+    Previous,
+    /// Clear the selection leaving the content pane empty.
+    Clear,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+/// Represents what happens when the filter or the focus mode leaves exactly one journal.
+pub enum SingleEntryFilter {
+    #[default]
+    /// Keep the current selection.
+    Keep,
+    /// Select the journal showing its content.
+    Select,
+    /// Select the journal and move the focus to its content.
+    Open,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+/// Represents how to handle navigating to a journal which is hidden by the filter, like undoing
+/// a change of a hidden journal.
+pub enum HiddenEntryNavigation {
+    #[default]
+    /// Clear the filter to show the journal.
+    ClearFilter,
+    /// Keep the filter and refuse showing the journal with a message.
+    Refuse,
+    /// Keep the filter and add the journal to the visible ones until the filter is changed.
+    Reveal,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+/// Represents how much information is rendered for each journal in entries list.
+pub enum ListDensity {
+    /// Render the title only.
+    Compact,
+    #[default]
+    /// Render the title, the datum and the tags.
+    Normal,
+    /// Render the title, a preview of the content, the datum, the modification date and the tags.
+    Detailed,
+}
+
+impl ListDensity {
+    /// Returns the next density mode to cycle through them.
+    pub fn next(self) -> Self {
+        match self {
+            ListDensity::Compact => ListDensity::Normal,
+            ListDensity::Normal => ListDensity::Detailed,
+            ListDensity::Detailed => ListDensity::Compact,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+/// Represents when a scrollbar is shown.
+pub enum ScrollbarVisibility {
+    #[default]
+    /// Show the scrollbar only when the content overflows.
+    Auto,
+    /// Show the scrollbar even when the content fits, filling it with the thumb.
+    Always,
+    /// Never show the scrollbar.
+    Never,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+/// Represents which journals are counted in the priority counts.
+pub enum PriorityCountsScope {
+    #[default]
+    /// Count the journals that meet the filter criteria only.
+    Active,
+    /// Count all journals ignoring the filter.
+    All,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+/// Represents what the content area shows when no journal is selected.
+pub enum EmptyContentView {
+    #[default]
+    /// Keep the content area empty.
+    Blank,
+    /// Show the keys of the main commands.
+    Help,
+    /// Show statistics about the journals.
+    Stats,
+    /// Show the content of the most recently modified journal.
+    RecentEntry,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+/// Represents the style of the terminal cursor while the content editor is focused.
+/// Terminals which don't support changing the cursor style will ignore it.
+pub enum EditorCursorStyle {
+    #[default]
+    /// Keep the cursor style configured in the terminal.
+    Default,
+    BlinkingBlock,
+    SteadyBlock,
+    BlinkingUnderline,
+    SteadyUnderline,
+    BlinkingBar,
+    SteadyBar,
+}
+
+impl From<EditorCursorStyle> for SetCursorStyle {
+    fn from(value: EditorCursorStyle) -> Self {
+        match value {
+            EditorCursorStyle::Default => SetCursorStyle::DefaultUserShape,
+            EditorCursorStyle::BlinkingBlock => SetCursorStyle::BlinkingBlock,
+            EditorCursorStyle::SteadyBlock => SetCursorStyle::SteadyBlock,
+            EditorCursorStyle::BlinkingUnderline => SetCursorStyle::BlinkingUnderScore,
+            EditorCursorStyle::SteadyUnderline => SetCursorStyle::SteadyUnderScore,
+            EditorCursorStyle::BlinkingBar => SetCursorStyle::BlinkingBar,
+            EditorCursorStyle::SteadyBar => SetCursorStyle::SteadyBar,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, ValueEnum, Clone, Copy, Default)]
 pub enum BackendType {
     #[cfg_attr(all(feature = "json", not(feature = "sqlite")), default)]
     Json,
     #[cfg_attr(feature = "sqlite", default)]
     Sqlite,
+    EncryptedJson,
 }
 
 const fn default_history_limit() -> usize {
     10
 }
 
+const fn default_history_grouping_interval() -> u64 {
+    2
+}
+
+const fn default_min_journal_priority() -> u32 {
+    1
+}
+
+const fn default_max_journal_priority() -> u32 {
+    10
+}
+
 const fn default_colored_tags() -> bool {
     true
 }
 
+const fn default_restore_filter_on_startup() -> bool {
+    true
+}
+
+const fn default_min_terminal_width() -> u16 {
+    40
+}
+
+const fn default_min_terminal_height() -> u16 {
+    10
+}
+
 impl Settings {
     pub async fn new(custom_path: Option<PathBuf>) -> anyhow::Result<Self> {
         let settings_path = if let Some(path) = custom_path {
@@ -117,8 +608,8 @@ impl Settings {
             settings_default_path()?
         };
 
-        let settings = if settings_path.exists() {
-            let file_content = tokio::fs::read_to_string(settings_path)
+        let mut settings: Settings = if settings_path.exists() {
+            let file_content = tokio::fs::read_to_string(&settings_path)
                 .await
                 .map_err(|err| anyhow!("Failed to load settings file. Error infos: {err}"))?;
             toml::from_str(file_content.as_str())
@@ -127,6 +618,8 @@ impl Settings {
             Settings::default()
         };
 
+        settings.settings_path = Some(settings_path);
+
         Ok(settings)
     }
 
@@ -162,20 +655,70 @@ impl Settings {
 
     pub fn complete_missing_options(&mut self) -> anyhow::Result<()> {
         // This check is to ensure that all added fields to settings struct are considered here
-        #[cfg(all(debug_assertions, feature = "sqlite", feature = "json"))]
+        #[cfg(all(
+            debug_assertions,
+            feature = "sqlite",
+            feature = "json",
+            feature = "encrypted_json"
+        ))]
         let Settings {
             backend_type: _,
             json_backend: _,
             sqlite_backend: _,
+            encrypted_json_backend: _,
             export: _,
             external_editor: _,
             default_journal_priority: _,
+            min_journal_priority: _,
+            max_journal_priority: _,
             scroll_per_page: _,
+            fuzzy_find_suggestions: _,
             sync_os_clipboard: _,
             history_limit: _,
+            history_grouping_interval: _,
             colored_tags: _,
+            min_terminal_width: _,
+            min_terminal_height: _,
             datum_visibility: _,
+            date_priority_order: _,
+            priority_own_line: _,
+            show_tag_counts: _,
+            list_density: _,
+            list_scrollbar: _,
+            empty_content_view: _,
+            priority_counts: _,
+            priority_counts_scope: _,
+            editor_cursor_style: _,
+            confirm_discard_entry_form: _,
+            confirm_history_past_save: _,
+            missing_filter_tags: _,
+            confirm_empty_filter: _,
+            restore_filter_on_startup: _,
+            persist_history: _,
+            confirmation_timeout: _,
+            read_only: _,
+            save_failure: _,
+            autosave_interval: _,
+            pinned_order: _,
+            selection_after_delete: _,
+            hidden_entry_navigation: _,
+            single_entry_filter: _,
+            prompt_tags_on_create: _,
+            empty_title: _,
+            lowercase_tags: _,
+            duplicate_tags: _,
+            duplicate_tags_ignore_case: _,
+            tag_hierarchy: _,
+            tags_order: _,
+            filter_presets: _,
+            tag_templates: _,
+            tag_template_limits: _,
+            tag_colors: _,
+            keybindings: _,
+            keybinding_conflicts: _,
             app_state_dir: _,
+            settings_path: _,
+            read_only_session: _,
         } = self;
 
         if self.backend_type.is_none() {
@@ -192,6 +735,11 @@ impl Settings {
             self.sqlite_backend.file_path = Some(get_default_sqlite_path()?)
         }
 
+        #[cfg(feature = "encrypted_json")]
+        if self.encrypted_json_backend.file_path.is_none() {
+            self.encrypted_json_backend.file_path = Some(get_default_encrypted_json_path()?)
+        }
+
         if self.scroll_per_page.is_none() {
             self.scroll_per_page = Some(DEFAULT_SCROLL_PER_PAGE);
         }
@@ -206,6 +754,288 @@ impl Settings {
     pub fn get_scroll_per_page(&self) -> usize {
         self.scroll_per_page.unwrap_or(DEFAULT_SCROLL_PER_PAGE)
     }
+
+    /// Returns the path of the file of the currently active backend.
+    pub fn get_active_backend_path(&self) -> anyhow::Result<PathBuf> {
+        match self.backend_type.unwrap_or_default() {
+            #[cfg(feature = "json")]
+            BackendType::Json => match &self.json_backend.file_path {
+                Some(path) => Ok(path.clone()),
+                None => get_default_json_path(),
+            },
+            #[cfg(feature = "sqlite")]
+            BackendType::Sqlite => match &self.sqlite_backend.file_path {
+                Some(path) => Ok(path.clone()),
+                None => get_default_sqlite_path(),
+            },
+            #[cfg(feature = "encrypted_json")]
+            BackendType::EncryptedJson => match &self.encrypted_json_backend.file_path {
+                Some(path) => Ok(path.clone()),
+                None => get_default_encrypted_json_path(),
+            },
+            #[allow(unreachable_patterns)]
+            backend => anyhow::bail!("Feature for backend '{backend:?}' is not installed"),
+        }
+    }
+
+    /// Checks if the journals are opened in read-only mode, either from the settings or the CLI
+    /// flag.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only || self.read_only_session
+    }
+
+    /// Checks if the undo and redo history is kept between runs. It's never kept for encrypted
+    /// journals since the history contains the contents of the journals in plain text.
+    pub fn is_history_persisted(&self) -> bool {
+        self.persist_history && self.backend_type.unwrap_or_default() != BackendType::EncryptedJson
+    }
+
+    /// Returns a key identifying the active journal by its back-end and file path.
+    pub fn get_journal_key(&self) -> anyhow::Result<String> {
+        let backend = self.backend_type.unwrap_or_default();
+        let path = self.get_active_backend_path()?;
+
+        Ok(format!("{backend:?}:{}", path.display()))
+    }
+
+    /// Unlocks the encrypted JSON back-end with the given passphrase. The passphrase is checked
+    /// against the existing entries file to fail early before starting the app.
+    #[cfg(feature = "encrypted_json")]
+    pub async fn unlock_encrypted_json(&mut self, passphrase: &str) -> anyhow::Result<()> {
+        let key = self.encrypted_json_backend.unlock(passphrase).await?;
+        let file_path = self.encrypted_json_backend.get_file_path()?;
+
+        backend::JsonDataProvide::new_encrypted(file_path, key).await?;
+
+        Ok(())
+    }
+
+    /// Applies the per-journal settings file placed next to the active backend file if exists.
+    /// Only the fields set in that file override the global settings.
+    pub async fn apply_journal_overrides(&mut self) -> anyhow::Result<()> {
+        let overrides_path = journal_settings_path(&self.get_active_backend_path()?);
+        if !overrides_path.exists() {
+            return Ok(());
+        }
+
+        let overrides = tokio::fs::read_to_string(&overrides_path)
+            .await
+            .map_err(|err| anyhow!("Failed to load journal settings file. Error infos: {err}"))?;
+
+        self.merge_overrides(&overrides)
+            .map_err(|err| anyhow!("Failed to read journal settings file. Error infos: {err}"))
+    }
+
+    fn merge_overrides(&mut self, overrides: &str) -> anyhow::Result<()> {
+        let mut overrides: toml::Table = toml::from_str(overrides)?;
+        for field in NON_OVERRIDABLE_FIELDS {
+            if overrides.remove(field).is_some() {
+                log::warn!("Field '{field}' can't be overridden in journal settings");
+            }
+        }
+
+        let mut merged = toml::Table::try_from(&*self)?;
+        merge_tables(&mut merged, overrides);
+
+        self.replace_from_table(merged)
+    }
+
+    /// Replaces the settings with the ones in the given table, keeping the fields which aren't
+    /// serialized.
+    fn replace_from_table(&mut self, table: toml::Table) -> anyhow::Result<()> {
+        let mut settings: Settings = table.try_into()?;
+        settings.settings_path = self.settings_path.take();
+        settings.read_only_session = self.read_only_session;
+        #[cfg(feature = "encrypted_json")]
+        {
+            settings.encrypted_json_backend.key = self.encrypted_json_backend.key.take();
+        }
+        *self = settings;
+
+        Ok(())
+    }
+
+    /// Gets the value of the option with the given key. See [`SettingOption::key`]
+    pub fn get_option(&self, key: &str) -> anyhow::Result<Option<toml::Value>> {
+        let table = toml::Table::try_from(self)?;
+
+        Ok(get_table_value(&table, &split_key(key)).cloned())
+    }
+
+    /// Sets the value of the option with the given key, where `None` unsets optional values.
+    /// Invalid values are rejected keeping the settings unchanged.
+    pub fn set_option(&mut self, key: &str, value: Option<toml::Value>) -> anyhow::Result<()> {
+        let mut table = toml::Table::try_from(&*self)?;
+        set_table_value(&mut table, &split_key(key), value);
+
+        self.replace_from_table(table)
+            .map_err(|err| anyhow!("Invalid value for option '{key}'. Error infos: {err}"))
+    }
+
+    /// Assigns the given colors to the tag, removing its assigned colors on `None`.
+    pub fn set_tag_color(&mut self, tag: &str, colors: Option<TagColors>) {
+        match colors {
+            Some(colors) => {
+                self.tag_colors.insert(tag.to_owned(), colors);
+            }
+            None => {
+                self.tag_colors.remove(tag);
+            }
+        }
+    }
+
+    /// Writes the colors assigned to the given tag to the settings file, removing them from the
+    /// file if the tag has no assigned colors. See [`Self::persist_option`]
+    pub async fn persist_tag_color(&self, tag: &str) -> anyhow::Result<()> {
+        let value = self
+            .tag_colors
+            .get(tag)
+            .map(toml::Value::try_from)
+            .transpose()?;
+
+        self.persist_value(&["tag_colors", tag], value).await
+    }
+
+    /// Writes the value of the option with the given key to the settings file, keeping the
+    /// other options in the file as they are.
+    pub async fn persist_option(
+        &self,
+        key: &str,
+        value: Option<toml::Value>,
+    ) -> anyhow::Result<()> {
+        self.persist_value(&split_key(key), value).await
+    }
+
+    /// Writes the value with the given keys path to the per-journal settings file if it's
+    /// overridden there, otherwise to the global settings file.
+    /// Only the given value is changed in the file, so the values merged from the other file
+    /// aren't written into it.
+    async fn persist_value(&self, keys: &[&str], value: Option<toml::Value>) -> anyhow::Result<()> {
+        let overridable = keys
+            .first()
+            .is_some_and(|field| !NON_OVERRIDABLE_FIELDS.contains(field));
+        let journal_path = self
+            .get_active_backend_path()
+            .map(|backend_path| journal_settings_path(&backend_path))
+            .ok()
+            .filter(|path| overridable && path.exists());
+
+        if let Some(journal_path) = journal_path {
+            let mut table = read_settings_table(&journal_path).await?;
+            if get_table_value(&table, keys).is_some() {
+                set_table_value(&mut table, keys, value);
+                return write_settings_table(&journal_path, &table).await;
+            }
+        }
+
+        let settings_path = match &self.settings_path {
+            Some(path) => path.clone(),
+            None => settings_default_path()?,
+        };
+
+        let mut table = if settings_path.exists() {
+            read_settings_table(&settings_path).await?
+        } else {
+            toml::Table::new()
+        };
+
+        set_table_value(&mut table, keys, value);
+
+        write_settings_table(&settings_path, &table).await
+    }
+}
+
+/// Reads the settings file with the given path as a table without merging it with any other
+/// settings.
+async fn read_settings_table(path: &Path) -> anyhow::Result<toml::Table> {
+    let file_content = tokio::fs::read_to_string(path)
+        .await
+        .map_err(|err| anyhow!("Failed to load settings file. Error infos: {err}"))?;
+
+    toml::from_str(&file_content)
+        .map_err(|err| anyhow!("Failed to read settings file. Error infos: {err}"))
+}
+
+/// Writes the given table to the settings file with the given path.
+async fn write_settings_table(path: &Path, table: &toml::Table) -> anyhow::Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+
+    let toml = toml::to_string(table)
+        .map_err(|err| anyhow!("Settings couldn't be serialized\nError info: {}", err))?;
+    tokio::fs::write(path, toml)
+        .await
+        .map_err(|err| anyhow!("Settings couldn't be written\nError info: {}", err))
+}
+
+/// Splits the key of an option into the keys of the nested tables, which are separated with
+/// dots.
+fn split_key(key: &str) -> Vec<&str> {
+    key.split('.').collect()
+}
+
+/// Gets the value in the given table with the keys path through the nested tables.
+fn get_table_value<'a>(table: &'a toml::Table, keys: &[&str]) -> Option<&'a toml::Value> {
+    match keys {
+        [] => None,
+        [key] => table.get(*key),
+        [parent, rest @ ..] => match table.get(*parent)? {
+            toml::Value::Table(nested) => get_table_value(nested, rest),
+            _ => None,
+        },
+    }
+}
+
+/// Sets the value in the given table with the keys path through the nested tables.
+/// Missing nested tables are created, while `None` removes the value.
+fn set_table_value(table: &mut toml::Table, keys: &[&str], value: Option<toml::Value>) {
+    match keys {
+        [] => {}
+        [key] => match value {
+            Some(value) => {
+                table.insert((*key).to_owned(), value);
+            }
+            None => {
+                table.remove(*key);
+            }
+        },
+        [parent, rest @ ..] => {
+            let nested = table
+                .entry(*parent)
+                .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+            if !nested.is_table() {
+                *nested = toml::Value::Table(toml::Table::new());
+            }
+            let nested = nested
+                .as_table_mut()
+                .expect("Value is a table at this point");
+            set_table_value(nested, rest, value);
+        }
+    }
+}
+
+/// Returns the path of the per-journal settings file for the given backend file path.
+pub fn journal_settings_path(backend_path: &Path) -> PathBuf {
+    let mut file_name = backend_path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(JOURNAL_SETTINGS_SUFFIX);
+
+    backend_path.with_file_name(file_name)
+}
+
+/// Merges the overrides table into the base table recursively, replacing the values which exist
+/// in both.
+fn merge_tables(base: &mut toml::Table, overrides: toml::Table) {
+    for (key, value) in overrides {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base_table)), toml::Value::Table(override_table)) => {
+                merge_tables(base_table, override_table)
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
 }
 
 pub fn settings_default_path() -> anyhow::Result<PathBuf> {
@@ -275,3 +1105,310 @@ where
 
     deserializer.deserialize_any(StringOrStruct(PhantomData))
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn journal_overrides_set_fields_only() {
+        let mut settings = Settings {
+            history_limit: 20,
+            colored_tags: false,
+            default_journal_priority: Some(2),
+            ..Default::default()
+        };
+        settings.export.show_confirmation = false;
+
+        let overrides = r#"
+            history_limit = 5
+            datum_visibility = "hide"
+
+            [export]
+            default_path = "/journals/work"
+        "#;
+
+        settings.merge_overrides(overrides).unwrap();
+
+        // Overridden fields
+        assert_eq!(settings.history_limit, 5);
+        assert_eq!(settings.datum_visibility, DatumVisibility::Hide);
+        assert_eq!(
+            settings.export.default_path,
+            Some(PathBuf::from("/journals/work"))
+        );
+
+        // Global fields
+        assert!(!settings.colored_tags);
+        assert_eq!(settings.default_journal_priority, Some(2));
+        assert!(!settings.export.show_confirmation);
+    }
+
+    #[test]
+    fn journal_overrides_ignore_backend() {
+        let mut settings = Settings {
+            backend_type: Some(BackendType::Json),
+            ..Default::default()
+        };
+
+        settings
+            .merge_overrides(r#"backend_type = "Sqlite""#)
+            .unwrap();
+
+        assert_eq!(settings.backend_type, Some(BackendType::Json));
+    }
+
+    #[test]
+    fn read_only_flag_kept() {
+        let mut settings = Settings {
+            read_only_session: true,
+            ..Default::default()
+        };
+
+        settings.merge_overrides("read_only = false").unwrap();
+        assert!(settings.is_read_only());
+
+        settings
+            .set_option("read_only", Some(toml::Value::Boolean(false)))
+            .unwrap();
+        assert!(settings.is_read_only());
+        assert!(search_options("read_only").is_empty());
+    }
+
+    #[cfg(feature = "encrypted_json")]
+    #[tokio::test]
+    async fn journal_overrides_keep_encryption_key() {
+        use crate::app::test::temp_dir::TempDir;
+
+        let dir = TempDir::new("enc_overrides");
+        let entries_path = dir.join("entries.json.enc");
+
+        let mut settings = Settings {
+            backend_type: Some(BackendType::EncryptedJson),
+            settings_path: Some(dir.join("config.toml")),
+            ..Default::default()
+        };
+        settings.encrypted_json_backend.file_path = Some(entries_path.clone());
+        settings.unlock_encrypted_json("secret").await.unwrap();
+
+        std::fs::write(journal_settings_path(&entries_path), "history_limit = 3").unwrap();
+        settings.apply_journal_overrides().await.unwrap();
+
+        assert_eq!(settings.history_limit, 3);
+        assert!(settings.encrypted_json_backend.key.is_some());
+
+        settings
+            .set_option("history_limit", Some(toml::Value::Integer(4)))
+            .unwrap();
+        assert!(settings.encrypted_json_backend.key.is_some());
+    }
+
+    #[tokio::test]
+    async fn persist_with_journal_overrides() {
+        use crate::app::test::temp_dir::TempDir;
+        use ratatui::style::Color;
+
+        let dir = TempDir::new("persist_overrides");
+        let global_path = dir.join("config.toml");
+        let entries_path = dir.join("entries.json");
+        let journal_path = journal_settings_path(&entries_path);
+
+        std::fs::write(
+            &global_path,
+            r##"
+            history_limit = 10
+            backend_type = "Json"
+
+            [json_backend]
+            file_path = "entries.json"
+
+            [tag_colors]
+            "Global" = { foreground = "black", background = "white" }
+            "##,
+        )
+        .unwrap();
+        std::fs::write(
+            &journal_path,
+            r##"
+            history_limit = 3
+
+            [tag_colors]
+            "Work" = { foreground = "black", background = "red" }
+            "##,
+        )
+        .unwrap();
+
+        let mut settings = Settings::new(Some(global_path.clone())).await.unwrap();
+        settings.json_backend.file_path = Some(entries_path);
+        settings.apply_journal_overrides().await.unwrap();
+        assert_eq!(settings.history_limit, 3);
+
+        let read_table = |path: &Path| -> toml::Table {
+            toml::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+        };
+
+        // Values not overridden in the journal are written to the global file only.
+        let colors = TagColors::new(Color::Black, Color::Yellow);
+        settings.set_tag_color("Project.A", Some(colors));
+        settings.persist_tag_color("Project.A").await.unwrap();
+        settings
+            .persist_option("colored_tags", Some(toml::Value::Boolean(false)))
+            .await
+            .unwrap();
+
+        let global = read_table(&global_path);
+        let global_colors = global["tag_colors"].as_table().unwrap();
+        assert!(global_colors.contains_key("Global"));
+        assert!(global_colors.contains_key("Project.A"));
+        assert!(!global_colors.contains_key("Work"));
+        assert_eq!(global["history_limit"].as_integer(), Some(10));
+        assert_eq!(global["colored_tags"].as_bool(), Some(false));
+
+        // Values overridden in the journal are written to the journal file only.
+        let colors = TagColors::new(Color::White, Color::Blue);
+        settings.set_tag_color("Work", Some(colors));
+        settings.persist_tag_color("Work").await.unwrap();
+        settings
+            .persist_option("history_limit", Some(toml::Value::Integer(4)))
+            .await
+            .unwrap();
+
+        let journal = read_table(&journal_path);
+        let journal_colors = journal["tag_colors"].as_table().unwrap();
+        assert_eq!(
+            journal_colors["Work"],
+            toml::Value::try_from(colors).unwrap()
+        );
+        assert_eq!(journal_colors.len(), 1);
+        assert_eq!(journal["history_limit"].as_integer(), Some(4));
+        assert!(!journal.contains_key("colored_tags"));
+
+        let global = read_table(&global_path);
+        assert!(!global["tag_colors"]
+            .as_table()
+            .unwrap()
+            .contains_key("Work"));
+        assert_eq!(global["history_limit"].as_integer(), Some(10));
+    }
+
+    #[test]
+    fn editor_cursor_style_mapping() {
+        fn ansi(style: SetCursorStyle) -> String {
+            let mut ansi = String::new();
+            crossterm::Command::write_ansi(&style, &mut ansi).unwrap();
+            ansi
+        }
+
+        let cases = [
+            (EditorCursorStyle::Default, SetCursorStyle::DefaultUserShape),
+            (
+                EditorCursorStyle::BlinkingBlock,
+                SetCursorStyle::BlinkingBlock,
+            ),
+            (EditorCursorStyle::SteadyBlock, SetCursorStyle::SteadyBlock),
+            (
+                EditorCursorStyle::BlinkingUnderline,
+                SetCursorStyle::BlinkingUnderScore,
+            ),
+            (
+                EditorCursorStyle::SteadyUnderline,
+                SetCursorStyle::SteadyUnderScore,
+            ),
+            (EditorCursorStyle::BlinkingBar, SetCursorStyle::BlinkingBar),
+            (EditorCursorStyle::SteadyBar, SetCursorStyle::SteadyBar),
+        ];
+
+        for (config, expected) in cases {
+            assert_eq!(ansi(config.into()), ansi(expected), "{config:?}");
+        }
+
+        let settings: Settings = toml::from_str(r#"editor_cursor_style = "steady_bar""#).unwrap();
+        assert_eq!(settings.editor_cursor_style, EditorCursorStyle::SteadyBar);
+    }
+
+    #[test]
+    fn tag_colors_round_trip() {
+        use ratatui::style::Color;
+
+        let settings: Settings = toml::from_str(
+            r##"
+            [tag_colors]
+            "Project A" = { foreground = "black", background = "#AABBCC" }
+            "##,
+        )
+        .unwrap();
+
+        let colors = TagColors::new(Color::Black, Color::Rgb(0xAA, 0xBB, 0xCC));
+        assert_eq!(settings.tag_colors.get("Project A"), Some(&colors));
+
+        let mut settings = Settings::default();
+        settings.set_tag_color("Project A", Some(colors));
+        let table = toml::Table::try_from(&settings).unwrap();
+        let loaded: Settings = toml::from_str(&toml::to_string(&table).unwrap()).unwrap();
+        assert_eq!(loaded.tag_colors.get("Project A"), Some(&colors));
+
+        settings.set_tag_color("Project A", None);
+        assert!(settings.tag_colors.is_empty());
+    }
+
+    #[test]
+    fn journal_settings_path_next_to_backend() {
+        let path = journal_settings_path(Path::new("/journals/work.json"));
+        assert_eq!(path, PathBuf::from("/journals/work.json.config.toml"));
+    }
+
+    #[test]
+    fn change_options() {
+        let mut settings = Settings::default();
+
+        for option in SETTING_OPTIONS {
+            match option.kind {
+                OptionKind::Bool => {
+                    let value = settings.get_option(option.key).unwrap().unwrap();
+                    let toggled = toml::Value::Boolean(!value.as_bool().unwrap());
+                    settings
+                        .set_option(option.key, Some(toggled.clone()))
+                        .unwrap();
+                    assert_eq!(settings.get_option(option.key).unwrap(), Some(toggled));
+                }
+                OptionKind::Number { optional } => {
+                    let value = toml::Value::Integer(7);
+                    settings
+                        .set_option(option.key, Some(value.clone()))
+                        .unwrap();
+                    assert_eq!(
+                        settings.get_option(option.key).unwrap(),
+                        Some(value.clone())
+                    );
+
+                    if optional {
+                        settings.set_option(option.key, None).unwrap();
+                        assert!(settings.get_option(option.key).unwrap().is_none());
+                        settings.set_option(option.key, Some(value)).unwrap();
+                    }
+                }
+                OptionKind::Choice(choices) => {
+                    let current = settings.get_option(option.key).unwrap().unwrap();
+                    assert!(choices.contains(&current.as_str().unwrap()));
+
+                    for choice in choices {
+                        let value = toml::Value::String(choice.to_string());
+                        settings
+                            .set_option(option.key, Some(value.clone()))
+                            .unwrap();
+                        assert_eq!(settings.get_option(option.key).unwrap(), Some(value));
+                    }
+                }
+            }
+        }
+
+        assert_eq!(settings.history_limit, 7);
+        assert!(!settings.export.trailing_newline);
+        assert_eq!(settings.tags_order, TagsOrder::Recent);
+
+        // Invalid values keep the settings unchanged
+        let invalid = toml::Value::String("invalid".into());
+        assert!(settings.set_option("list_density", Some(invalid)).is_err());
+        assert_eq!(settings.list_density, ListDensity::Detailed);
+    }
+}
@@ -0,0 +1,325 @@
+/// Represents the type of the value of a setting option and how it's edited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionKind {
+    Bool,
+    /// Positive number which can be unset when it's optional.
+    Number {
+        optional: bool,
+    },
+    /// One of the given values in their serialized form.
+    Choice(&'static [&'static str]),
+}
+
+/// Describes an option in the settings which can be changed from within the app.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SettingOption {
+    /// Key of the option in the settings file, where nested options are separated with dots.
+    pub key: &'static str,
+    pub description: &'static str,
+    pub kind: OptionKind,
+}
+
+impl SettingOption {
+    const fn new(key: &'static str, description: &'static str, kind: OptionKind) -> Self {
+        Self {
+            key,
+            description,
+            kind,
+        }
+    }
+
+    /// Readable name of the option generated from its key.
+    pub fn name(&self) -> String {
+        self.key.replace(['_', '.'], " ")
+    }
+
+    /// Checks if each word of the given query is found in the name or the description of the
+    /// option, ignoring the case.
+    pub fn matches(&self, query: &str) -> bool {
+        let name = self.name().to_lowercase();
+        let description = self.description.to_lowercase();
+
+        query
+            .to_lowercase()
+            .split_whitespace()
+            .all(|word| name.contains(word) || description.contains(word))
+    }
+}
+
+/// Options of the settings which can be changed from within the app. Options with complex
+/// values like paths, filter presets and templates are changed in the settings file only.
+pub const SETTING_OPTIONS: &[SettingOption] = &[
+    SettingOption::new(
+        "sync_os_clipboard",
+        "Synchronize the clipboard of the editor with the clipboard of the operating system.",
+        OptionKind::Bool,
+    ),
+    SettingOption::new(
+        "history_limit",
+        "Maximum size of the history stacks (undo & redo), where zero disables the history.",
+        OptionKind::Number { optional: false },
+    ),
+    SettingOption::new(
+        "history_grouping_interval",
+        "Interval in seconds to group consecutive content changes of the same journal into one undo step, where zero disables it.",
+        OptionKind::Number { optional: false },
+    ),
+    SettingOption::new(
+        "colored_tags",
+        "Color the tags in the journals list. Applied after restarting the app.",
+        OptionKind::Bool,
+    ),
+    SettingOption::new(
+        "default_journal_priority",
+        "Priority assigned to new journals.",
+        OptionKind::Number { optional: true },
+    ),
+    SettingOption::new(
+        "min_journal_priority",
+        "Lowest priority reachable when lowering the priority with the quick commands.",
+        OptionKind::Number { optional: false },
+    ),
+    SettingOption::new(
+        "max_journal_priority",
+        "Highest priority reachable when bumping the priority with the quick commands.",
+        OptionKind::Number { optional: false },
+    ),
+    SettingOption::new(
+        "scroll_per_page",
+        "How many journals are scrolled using Page-Up and Page-Down commands.",
+        OptionKind::Number { optional: true },
+    ),
+    SettingOption::new(
+        "fuzzy_find_suggestions",
+        "Suggest the closest journal title in the fuzzy finder when the query has no matches.",
+        OptionKind::Bool,
+    ),
+    SettingOption::new(
+        "min_terminal_width",
+        "Minimum terminal width needed to render the app.",
+        OptionKind::Number { optional: false },
+    ),
+    SettingOption::new(
+        "min_terminal_height",
+        "Minimum terminal height needed to render the app.",
+        OptionKind::Number { optional: false },
+    ),
+    SettingOption::new(
+        "datum_visibility",
+        "Visibility of the datum of journals in the journals list.",
+        OptionKind::Choice(&["show", "hide", "empty_line"]),
+    ),
+    SettingOption::new(
+        "date_priority_order",
+        "Which one of date and priority is rendered first in the journals list.",
+        OptionKind::Choice(&["date_first", "priority_first"]),
+    ),
+    SettingOption::new(
+        "priority_own_line",
+        "Render the priority on its own line regardless of the available width.",
+        OptionKind::Bool,
+    ),
+    SettingOption::new(
+        "show_tag_counts",
+        "Show the count of journals having each tag next to the tags.",
+        OptionKind::Bool,
+    ),
+    SettingOption::new(
+        "list_density",
+        "How much information is rendered for each journal in the journals list.",
+        OptionKind::Choice(&["compact", "normal", "detailed"]),
+    ),
+    SettingOption::new(
+        "list_scrollbar",
+        "When the scrollbar of the journals list is shown.",
+        OptionKind::Choice(&["auto", "always", "never"]),
+    ),
+    SettingOption::new(
+        "keybinding_conflicts",
+        "How to handle keybindings with conflicting keys or unknown commands at startup.",
+        OptionKind::Choice(&["warn", "fail"]),
+    ),
+    SettingOption::new(
+        "empty_content_view",
+        "What the content area shows when no journal is selected.",
+        OptionKind::Choice(&["blank", "help", "stats", "recent_entry"]),
+    ),
+    SettingOption::new(
+        "priority_counts",
+        "Show the number of journals for each priority in the footer.",
+        OptionKind::Bool,
+    ),
+    SettingOption::new(
+        "priority_counts_scope",
+        "Which journals are counted in the priority counts.",
+        OptionKind::Choice(&["active", "all"]),
+    ),
+    SettingOption::new(
+        "editor_cursor_style",
+        "Style of the terminal cursor while the content editor is focused.",
+        OptionKind::Choice(&[
+            "default",
+            "blinking_block",
+            "steady_block",
+            "blinking_underline",
+            "steady_underline",
+            "blinking_bar",
+            "steady_bar",
+        ]),
+    ),
+    SettingOption::new(
+        "confirm_discard_entry_form",
+        "Ask for confirmation before discarding the changed values in the journal form.",
+        OptionKind::Bool,
+    ),
+    SettingOption::new(
+        "confirm_history_past_save",
+        "Ask for confirmation before undo or redo moves past the last save of a journal content.",
+        OptionKind::Bool,
+    ),
+    SettingOption::new(
+        "missing_filter_tags",
+        "How to handle tags in the filter which aren't assigned to any journal anymore.",
+        OptionKind::Choice(&["drop", "drop_notify", "keep"]),
+    ),
+    SettingOption::new(
+        "confirm_empty_filter",
+        "Ask for confirmation before applying a filter which hides all journals.",
+        OptionKind::Bool,
+    ),
+    SettingOption::new(
+        "restore_filter_on_startup",
+        "Restore the filter applied when the app was closed on the next start.",
+        OptionKind::Bool,
+    ),
+    SettingOption::new(
+        "persist_history",
+        "Keep the undo and redo history when the app is closed to restore it on the next start. Not available for encrypted journals.",
+        OptionKind::Bool,
+    ),
+    SettingOption::new(
+        "confirmation_timeout",
+        "Timeout in seconds to dismiss unanswered confirmation prompts as cancelled.",
+        OptionKind::Number { optional: true },
+    ),
+    SettingOption::new(
+        "save_failure",
+        "How to handle failures while saving changes of journals.",
+        OptionKind::Choice(&["rollback", "keep_unsynced"]),
+    ),
+    SettingOption::new(
+        "autosave_interval",
+        "Interval in seconds to save the unsaved changes of the journal content automatically.",
+        OptionKind::Number { optional: true },
+    ),
+    SettingOption::new(
+        "pinned_order",
+        "How pinned journals are ordered on top of the others.",
+        OptionKind::Choice(&["sorted", "pin_time"]),
+    ),
+    SettingOption::new(
+        "selection_after_delete",
+        "Which journal gets selected after deleting the current one.",
+        OptionKind::Choice(&["next", "previous", "clear"]),
+    ),
+    SettingOption::new(
+        "hidden_entry_navigation",
+        "How to handle navigating to a journal which is hidden by the filter.",
+        OptionKind::Choice(&["clear_filter", "refuse", "reveal"]),
+    ),
+    SettingOption::new(
+        "single_entry_filter",
+        "What happens when the filter or the focus mode leaves exactly one journal.",
+        OptionKind::Choice(&["keep", "select", "open"]),
+    ),
+    SettingOption::new(
+        "prompt_tags_on_create",
+        "Prompt to add tags after creating a journal without any tags.",
+        OptionKind::Bool,
+    ),
+    SettingOption::new(
+        "empty_title",
+        "How to handle saving a journal with an empty title.",
+        OptionKind::Choice(&["allow", "reject", "generate"]),
+    ),
+    SettingOption::new(
+        "lowercase_tags",
+        "Convert the tags to lowercase when they are assigned to journals.",
+        OptionKind::Bool,
+    ),
+    SettingOption::new(
+        "duplicate_tags",
+        "How to handle duplicated tags within one journal.",
+        OptionKind::Choice(&["dedupe", "warn"]),
+    ),
+    SettingOption::new(
+        "duplicate_tags_ignore_case",
+        "Treat tags differing only in case as duplicates. Always on when converting tags to lowercase.",
+        OptionKind::Bool,
+    ),
+    SettingOption::new(
+        "tag_hierarchy",
+        "Treat tags as a hierarchy with the parent/child notation.",
+        OptionKind::Bool,
+    ),
+    SettingOption::new(
+        "tags_order",
+        "How the tags are ordered in the tags sidebar, the tags popups and the filter.",
+        OptionKind::Choice(&["alphabetical", "count", "recent"]),
+    ),
+    SettingOption::new(
+        "export.show_confirmation",
+        "Show confirmation after successful export.",
+        OptionKind::Bool,
+    ),
+    SettingOption::new(
+        "export.trailing_newline",
+        "End exported journal content and copied Markdown with a newline.",
+        OptionKind::Bool,
+    ),
+    SettingOption::new(
+        "export.selection_order",
+        "Export the selected journals in the order they have been selected.",
+        OptionKind::Bool,
+    ),
+    SettingOption::new(
+        "export.preview_long_lines",
+        "How lines exceeding the width of the export preview are shown.",
+        OptionKind::Choice(&["wrap", "scroll"]),
+    ),
+];
+
+/// Returns the options matching the given query keeping their order.
+pub fn search_options(query: &str) -> Vec<&'static SettingOption> {
+    SETTING_OPTIONS
+        .iter()
+        .filter(|option| option.matches(query))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn keys(options: &[&SettingOption]) -> Vec<&'static str> {
+        options.iter().map(|option| option.key).collect()
+    }
+
+    #[test]
+    fn search_by_substring() {
+        assert_eq!(search_options("").len(), SETTING_OPTIONS.len());
+
+        assert_eq!(keys(&search_options("history limit")), ["history_limit"]);
+        assert_eq!(keys(&search_options("LIMIT")), ["history_limit"]);
+        assert_eq!(
+            keys(&search_options("export newline")),
+            ["export.trailing_newline"]
+        );
+
+        // Descriptions are searched too
+        assert_eq!(keys(&search_options("page-up")), ["scroll_per_page"]);
+        assert_eq!(keys(&search_options("cursor")), ["editor_cursor_style"]);
+
+        assert!(search_options("not existing option").is_empty());
+    }
+}